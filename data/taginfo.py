@@ -0,0 +1,89 @@
+#!/bin/env python3
+"""
+Generate the snapshot of taginfo in taginfo.tsv, from the candidate keys in taginfo_keys.txt.
+
+Keys are kept if they are used at least MIN_KEY_COUNT times,
+and their values if they are used at least MIN_VALUE_COUNT times.
+Lane (`|`) and multiple (`;`) values are counted separately, as they are checked.
+Keys with free-form values (`*`) allow any value.
+"""
+import datetime
+import json
+import time
+import urllib.parse
+import urllib.request
+from collections import Counter
+from pathlib import Path
+
+KEYS = Path(__file__).parent / "taginfo_keys.txt"
+SNAPSHOT = Path(__file__).parent / "taginfo.tsv"
+API = "https://taginfo.openstreetmap.org/api/4"
+MIN_KEY_COUNT = 1000
+MIN_VALUE_COUNT = 100
+# Values fetched per key, most used first
+MAX_VALUES = 500
+
+
+def get(path: str, **params: str) -> dict:
+    url = f"{API}/{path}?{urllib.parse.urlencode(params)}"
+    with urllib.request.urlopen(url) as response:
+        # Be gentle with the shared instance
+        time.sleep(0.5)
+        return json.load(response)
+
+
+def key_count(key: str) -> int:
+    stats = get("key/stats", key=key)["data"]
+    return next(row["count"] for row in stats if row["type"] == "all")
+
+
+def values(key: str) -> list[tuple[str, int]]:
+    data = get(
+        "key/values",
+        key=key,
+        sortname="count_all",
+        sortorder="desc",
+        rp=str(MAX_VALUES),
+        page="1",
+    )["data"]
+    is_lanes = "lanes" in key.split(":")
+    counts: Counter[str] = Counter()
+    for row in data:
+        lanes = row["value"].split("|") if is_lanes else [row["value"]]
+        for lane in lanes:
+            # An empty lane is always allowed
+            for part in filter(None, lane.split(";")):
+                counts[part] += row["count"]
+    return [(value, count) for value, count in counts.most_common() if count >= MIN_VALUE_COUNT]
+
+
+def main() -> None:
+    header = [
+        "# Keys and values in common use in OpenStreetMap, to check the tags generated from lanes.",
+        "# Generated by `python3 data/taginfo.py` from the keys in data/taginfo_keys.txt,"
+        " do not edit by hand.",
+        f"# From {API} on {datetime.date.today()},"
+        f" keys used at least {MIN_KEY_COUNT} times, values at least {MIN_VALUE_COUNT} times.",
+        "#",
+        "# <key>\\t<value>\\t<count>, where a value of `*` allows any value.",
+        "# Lane (`|`) and multiple (`;`) values are checked separately.",
+    ]
+    rows = []
+    for line in KEYS.read_text().splitlines():
+        if not line or line.startswith("#"):
+            continue
+        key, _, any_value = line.partition("\t")
+        count = key_count(key)
+        if count < MIN_KEY_COUNT:
+            print(f"dropping rare key {key}, used {count} times")
+            continue
+        if any_value == "*":
+            rows.append(f"{key}\t*\t{count}")
+        else:
+            rows.extend(f"{key}\t{value}\t{count}" for value, count in values(key))
+
+    SNAPSHOT.write_text("\n".join(header + rows) + "\n")
+
+
+if __name__ == "__main__":
+    main()
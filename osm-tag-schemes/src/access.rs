@@ -16,3 +16,10 @@ pub enum Access {
     Customers,
     Designated,
 }
+
+impl std::fmt::Display for Access {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s: &'static str = self.into();
+        f.write_str(s)
+    }
+}
@@ -1,12 +1,9 @@
-use std::borrow::Borrow;
 use std::error::Error;
 use std::fmt::{Display, Formatter};
-use std::hash::Hash;
+use std::str::FromStr;
 
-use osm_tags::{TagKey, Tags};
-use strum::{EnumString, IntoStaticStr, ParseError};
-
-use crate::keys;
+use osm_tags::{TagKey, TagsRef};
+use strum::{EnumString, IntoStaticStr};
 
 /// <https://wiki.openstreetmap.org/wiki/Key:access#Lane_dependent_restrictions>
 #[derive(Debug, Clone, Copy, PartialEq, Eq, EnumString, IntoStaticStr)]
@@ -19,42 +16,33 @@ pub enum Access {
     Designated,
 }
 
-impl Access {
-    fn split(lanes: &str) -> Result<Vec<Self>, ParseError> {
-        lanes.split('|').map(str::parse).collect()
-    }
-}
-
-/// Get value from tags given a key
-fn get_access<'tag, Q, O>(
-    tags: &'tag Tags,
-    key: &Q,
-) -> Result<Option<Vec<Access>>, LaneDependentAccessError<'tag>>
-where
-    TagKey: Borrow<Q>,
-    Q: Ord + Hash + Eq + ?Sized + ToOwned<Owned = O>,
-    O: Into<TagKey>,
-{
+/// Get | separated lane values from tags given a key
+fn get_lanes<'tag, T, E>(
+    tags: &'tag TagsRef<'_>,
+    key: &TagKey,
+    parse: impl Fn(&str) -> Result<T, E>,
+) -> Result<Option<Vec<T>>, LaneDependentAccessError<'tag>> {
     match tags.get(key) {
-        Some(s) => match Access::split(s) {
-            Ok(access) => Ok(Some(access)),
-            Err(_parse_error) => Err(LaneDependentAccessError::Unknown(keys::TRACK_TYPE, s)),
+        Some(s) => match s.split('|').map(parse).collect() {
+            Ok(lanes) => Ok(Some(lanes)),
+            Err(_parse_error) => Err(LaneDependentAccessError::Unknown(key.clone(), s)),
         },
         None => Ok(None),
     }
 }
 
+/// Values for each lane, from `KEY=*`, `KEY:forward=*` and `KEY:backward=*`
 #[derive(Debug)]
-pub enum LaneDependentAccess {
-    LeftToRight(Vec<Access>),
-    Forward(Vec<Access>),
-    Backward(Vec<Access>),
-    ForwardBackward {
-        forward: Vec<Access>,
-        backward: Vec<Access>,
-    },
+pub enum LaneDependent<T> {
+    LeftToRight(Vec<T>),
+    Forward(Vec<T>),
+    Backward(Vec<T>),
+    ForwardBackward { forward: Vec<T>, backward: Vec<T> },
 }
 
+#[allow(clippy::module_name_repetitions)]
+pub type LaneDependentAccess = LaneDependent<Access>;
+
 #[derive(Debug)]
 pub enum LaneDependentAccessError<'tag> {
     Unknown(TagKey, &'tag str),
@@ -74,23 +62,38 @@ impl Display for LaneDependentAccessError<'_> {
 
 impl Error for LaneDependentAccessError<'_> {}
 
-impl LaneDependentAccess {
-    /// Parse access given | separated lanes in `Tags` given `TagKey`
+impl<T: FromStr + PartialEq> LaneDependent<T> {
+    /// Parse values given | separated lanes in `Tags` given `TagKey`
     ///
     /// # Errors
-    /// When the tags conflict.
+    /// When a value is unknown or the tags conflict.
     pub fn from_tags<'tag>(
-        tags: &'tag Tags,
+        tags: &'tag TagsRef<'_>,
+        key: &TagKey,
+    ) -> Result<Option<Self>, LaneDependentAccessError<'tag>> {
+        Self::from_tags_with(tags, key, str::parse)
+    }
+}
+
+impl<T: PartialEq> LaneDependent<T> {
+    /// Parse values given | separated lanes in `Tags` given `TagKey`,
+    /// with a parser for the value of each lane
+    ///
+    /// # Errors
+    /// When a value is unknown or the tags conflict.
+    pub fn from_tags_with<'tag, E>(
+        tags: &'tag TagsRef<'_>,
         key: &TagKey,
+        parse: impl Fn(&str) -> Result<T, E>,
     ) -> Result<Option<Self>, LaneDependentAccessError<'tag>> {
         let key_forward = key + "forward";
         let key_backward = key + "backward";
         Ok(
             match (
-                get_access(tags, key)?,
+                get_lanes(tags, key, &parse)?,
                 (
-                    get_access(tags, &key_forward)?,
-                    get_access(tags, &key_backward)?,
+                    get_lanes(tags, &key_forward, &parse)?,
+                    get_lanes(tags, &key_backward, &parse)?,
                 ),
             ) {
                 (None, (Some(forward), None)) => Some(Self::Forward(forward)),
@@ -0,0 +1,34 @@
+use strum::{EnumString, IntoStaticStr};
+
+/// Whether vehicles may change out of a lane, the value of each lane of `change:lanes=*`,
+/// left and right in the direction of travel
+/// <https://wiki.openstreetmap.org/wiki/Key:change>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumString, IntoStaticStr)]
+#[strum(serialize_all = "snake_case")]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum Change {
+    Yes,
+    No,
+    /// May not change to the lane on the left
+    NotLeft,
+    /// May not change to the lane on the right
+    NotRight,
+    /// May only change to the lane on the left, the same as `not_right`
+    OnlyLeft,
+    /// May only change to the lane on the right, the same as `not_left`
+    OnlyRight,
+}
+
+impl Change {
+    /// Whether vehicles may change to the lane on the left, or on the right
+    #[must_use]
+    pub fn permitted(self, left: bool) -> bool {
+        match self {
+            Self::Yes => true,
+            Self::No => false,
+            Self::NotLeft | Self::OnlyRight => !left,
+            Self::NotRight | Self::OnlyLeft => left,
+        }
+    }
+}
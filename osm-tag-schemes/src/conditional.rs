@@ -0,0 +1,108 @@
+/// A value that only applies under a condition, `value @ (condition)`
+///
+/// <https://wiki.openstreetmap.org/wiki/Conditional_restrictions>
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Conditional<T> {
+    pub value: T,
+    /// The condition, without enclosing parentheses, e.g. `Mo-Fr 07:00-09:00`
+    pub condition: String,
+}
+
+#[derive(Debug)]
+pub enum ConditionalError<E> {
+    /// Not of the form `value @ condition`
+    Syntax(String),
+    /// The value could not be parsed
+    Value(E),
+}
+
+impl<E: std::fmt::Display> std::fmt::Display for ConditionalError<E> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Syntax(s) => write!(f, "invalid conditional restriction '{s}'"),
+            Self::Value(e) => e.fmt(f),
+        }
+    }
+}
+
+impl<E: std::error::Error> std::error::Error for ConditionalError<E> {}
+
+/// Split on `separator`, ignoring any inside parentheses
+fn split_outside_parentheses(s: &str, separator: char) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let mut depth: usize = 0;
+    let mut start = 0;
+    for (i, c) in s.char_indices() {
+        match c {
+            '(' => depth = depth.saturating_add(1),
+            ')' => depth = depth.saturating_sub(1),
+            c if c == separator && depth == 0 => {
+                parts.push(s.get(start..i).unwrap_or_default());
+                start = i.saturating_add(c.len_utf8());
+            },
+            _ => {},
+        }
+    }
+    parts.push(s.get(start..).unwrap_or_default());
+    parts
+}
+
+impl<T> Conditional<T> {
+    /// Parse a single `value @ (condition)`, with a parser for the value
+    ///
+    /// # Errors
+    ///
+    /// When the syntax is invalid, or the value cannot be parsed.
+    pub fn from_str_with<E>(
+        s: &str,
+        parse: impl Fn(&str) -> Result<T, E>,
+    ) -> Result<Self, ConditionalError<E>> {
+        let syntax = || ConditionalError::Syntax(s.to_owned());
+        let (value, condition) = s.split_once('@').ok_or_else(syntax)?;
+        let (value, condition) = (value.trim(), condition.trim());
+        let condition = condition
+            .strip_prefix('(')
+            .and_then(|c| c.strip_suffix(')'))
+            .unwrap_or(condition)
+            .trim();
+        if value.is_empty() || condition.is_empty() {
+            return Err(syntax());
+        }
+        Ok(Self {
+            value: parse(value).map_err(ConditionalError::Value)?,
+            condition: condition.to_owned(),
+        })
+    }
+
+    /// Parse the `;` separated restrictions of a `*:conditional` tag value,
+    /// e.g. `no @ (Mo-Fr 07:00-09:00); destination @ (weight > 7.5)`
+    ///
+    /// # Errors
+    ///
+    /// When any restriction is invalid.
+    pub fn split<E>(
+        s: &str,
+        parse: impl Fn(&str) -> Result<T, E>,
+    ) -> Result<Vec<Self>, ConditionalError<E>> {
+        split_outside_parentheses(s, ';')
+            .into_iter()
+            .filter(|part| !part.trim().is_empty())
+            .map(|part| Self::from_str_with(part, &parse))
+            .collect()
+    }
+}
+
+impl<T: std::str::FromStr> std::str::FromStr for Conditional<T> {
+    type Err = ConditionalError<T::Err>;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_str_with(s, str::parse)
+    }
+}
+
+impl<T: std::fmt::Display> std::fmt::Display for Conditional<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} @ ({})", self.value, self.condition)
+    }
+}
@@ -1,4 +1,4 @@
-use osm_tags::Tags;
+use osm_tags::TagsRef;
 use strum::ParseError;
 
 use crate::{keys, FromTags, Tagged};
@@ -127,7 +127,7 @@ impl std::fmt::Display for HighwayType {
     }
 }
 
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 pub enum Lifecycle {
@@ -227,7 +227,7 @@ impl Highway {
     ///
     /// If highway missing return None
     /// If highway tag value unknown, `Err`
-    pub fn from_tags(tags: &Tags) -> Result<Option<Self>, Error<'_>> {
+    pub fn from_tags<'tag>(tags: &'tag TagsRef<'_>) -> Result<Option<Self>, Error<'tag>> {
         match HighwayType::from_tags(tags, &keys::HIGHWAY) {
             Tagged::None => Ok(None),
             Tagged::Some(t) => Ok(Some(Highway::active(t))),
@@ -239,8 +239,8 @@ impl Highway {
                 },
                 "proposed" => match HighwayType::from_tags(tags, &keys::PROPOSED) {
                     Tagged::None => Err(Error::ProposedNone),
-                    Tagged::Some(t) => Ok(Some(Highway::construction(t))),
-                    Tagged::Unknown(s) => Err(Error::Construction(s)),
+                    Tagged::Some(t) => Ok(Some(Highway::proposed(t))),
+                    Tagged::Unknown(s) => Err(Error::Proposed(s)),
                 },
                 val => Err(Error::Highway(val)),
             },
@@ -303,4 +303,10 @@ impl Highway {
     pub fn r#type(&self) -> HighwayType {
         self.highway
     }
+
+    /// The lifecycle of the highway, the type is what it is or will be once built
+    #[must_use]
+    pub fn lifecycle(&self) -> Lifecycle {
+        self.lifecycle
+    }
 }
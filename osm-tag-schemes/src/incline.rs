@@ -0,0 +1,13 @@
+use strum::{EnumString, IntoStaticStr};
+
+/// Direction of an incline relative to the direction of the way, the value of `incline=*`,
+/// only `up` and `down` as tagged on steps rather than a gradient
+/// <https://wiki.openstreetmap.org/wiki/Key:incline>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumString, IntoStaticStr)]
+#[strum(serialize_all = "snake_case")]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum Incline {
+    Up,
+    Down,
+}
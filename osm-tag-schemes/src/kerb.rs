@@ -0,0 +1,28 @@
+use strum::{EnumString, IntoStaticStr};
+
+/// The kind of kerb between a sidewalk and the carriageway,
+/// the value of `kerb=*`
+/// <https://wiki.openstreetmap.org/wiki/Key:kerb>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumString, IntoStaticStr)]
+#[strum(serialize_all = "snake_case")]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum Kerb {
+    /// A full height kerb, usually 10cm or more
+    Raised,
+    /// A kerb with a sloped edge that can be driven over
+    Rolled,
+    /// A kerb lowered to a few centimetres
+    Lowered,
+    /// Level with the carriageway
+    Flush,
+    No,
+}
+
+impl Kerb {
+    /// Whether the kerb can be crossed without a step, e.g. by a wheelchair
+    #[must_use]
+    pub fn is_step_free(self) -> bool {
+        matches!(self, Self::Flush | Self::No)
+    }
+}
@@ -9,11 +9,19 @@ pub const PROPOSED: TagKey = TagKey::from_static("proposed");
 pub const LIFECYCLE: [TagKey; 3] = [HIGHWAY, CONSTRUCTION, PROPOSED];
 
 pub const ONEWAY: TagKey = TagKey::from_static("oneway");
+pub const PLACEMENT: TagKey = TagKey::from_static("placement");
+pub const OVERTAKING: TagKey = TagKey::from_static("overtaking");
+pub const PRIORITY_ROAD: TagKey = TagKey::from_static("priority_road");
 
 pub const LIT: TagKey = TagKey::from_static("lit");
 
+pub const INCLINE: TagKey = TagKey::from_static("incline");
+pub const HANDRAIL: TagKey = TagKey::from_static("handrail");
+pub const STEP_COUNT: TagKey = TagKey::from_static("step_count");
+
 pub const TRACK_TYPE: TagKey = TagKey::from_static("tracktype");
 pub const SMOOTHNESS: TagKey = TagKey::from_static("smoothness");
+pub const SURFACE: TagKey = TagKey::from_static("surface");
 
 pub const LANES: TagKey = TagKey::from_static("lanes");
 // Unstable: const evaluation https://github.com/rust-lang/rust/issues/90080
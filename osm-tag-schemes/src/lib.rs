@@ -45,11 +45,9 @@
     clippy::use_debug
 )]
 
-use std::borrow::Borrow;
-use std::hash::Hash;
 use std::str::FromStr;
 
-use osm_tags::{TagKey, Tags};
+use osm_tags::{TagKey, TagsRef};
 
 pub mod keys;
 
@@ -59,17 +57,52 @@ pub use highway::{Error as HighwayError, Highway, HighwayImportance, HighwayType
 mod lit;
 pub use lit::Lit;
 
+mod incline;
+pub use incline::Incline;
+
+mod overtaking;
+pub use overtaking::Overtaking;
+
+mod priority_road;
+pub use priority_road::PriorityRoad;
+
+mod change;
+pub use change::Change;
+
+mod separation;
+pub use separation::Separation;
+
+mod kerb;
+pub use kerb::Kerb;
+
 mod track_type;
 pub use track_type::TrackType;
 
 mod smoothness;
 pub use smoothness::Smoothness;
 
+mod surface;
+pub use surface::{Surface, SurfaceScheme};
+
 mod access;
 pub use access::Access;
 
+mod conditional;
+pub use conditional::{Conditional, ConditionalError};
+
 mod access_by_lane;
-pub use access_by_lane::{Access as LaneAccess, LaneDependentAccess, LaneDependentAccessError};
+pub use access_by_lane::{
+    Access as LaneAccess, LaneDependent, LaneDependentAccess, LaneDependentAccessError,
+};
+
+mod turn;
+pub use turn::TurnIndication;
+
+mod parking;
+pub use parking::{ParkingCondition, ParkingOrientation};
+
+mod placement;
+pub use placement::Placement;
 
 #[derive(Debug)]
 pub struct TagError<'tag>(TagKey, &'tag str);
@@ -132,10 +165,9 @@ impl<'tag, T> Tagged<'tag, T> {
 
 trait FromTags: FromStr {
     /// From tags given key
-    fn from_tags<'tag, Q>(tags: &'tag Tags, key: &Q) -> Tagged<'tag, Self>
+    fn from_tags<'tag, Q>(tags: &'tag TagsRef<'_>, key: &Q) -> Tagged<'tag, Self>
     where
-        TagKey: Borrow<Q>,
-        Q: Ord + Hash + Eq + ?Sized,
+        Q: AsRef<str> + ?Sized,
     {
         match tags.get(key) {
             Some(s) => match s.parse() {
@@ -154,7 +186,7 @@ trait FromTagsDefault: FromTags {
     const KEY: TagKey;
 
     /// From tags with default key
-    fn from_tags_default(tags: &Tags) -> Tagged<Self> {
+    fn from_tags_default<'tag>(tags: &'tag TagsRef<'_>) -> Tagged<'tag, Self> {
         Self::from_tags(tags, &Self::KEY)
     }
 }
@@ -173,7 +205,7 @@ pub struct Schemes<'tag> {
 
 impl<'tag> Schemes<'tag> {
     #[must_use]
-    pub fn from_tags(tags: &'tag Tags) -> Self {
+    pub fn from_tags(tags: &'tag TagsRef<'_>) -> Self {
         Self {
             name: tags.get(&keys::NAME).map(ToOwned::to_owned),
             r#ref: tags.get(&keys::REF).map(ToOwned::to_owned),
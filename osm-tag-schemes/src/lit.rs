@@ -2,6 +2,8 @@ use strum::{EnumString, IntoStaticStr};
 
 use crate::{keys, FromTagsDefault};
 
+/// Street lighting, from `lit=*`,
+/// see <https://wiki.openstreetmap.org/wiki/Key:lit>
 #[derive(Debug, Clone, Copy, PartialOrd, Ord, PartialEq, Eq, EnumString, IntoStaticStr)]
 #[strum(serialize_all = "kebab-case")]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -11,6 +13,24 @@ pub enum Lit {
     No,
     SunsetSunrise,
     Automatic,
+    /// Lit all night, `lit=24/7`
+    #[strum(serialize = "24/7")]
+    #[cfg_attr(feature = "serde", serde(rename = "24/7"))]
+    AllNight,
+    /// Lit for only part of the night
+    Limited,
+    /// Lit at intervals too long to light the whole way
+    Interval,
+    /// Lights that are no longer in use
+    Disused,
+}
+
+impl Lit {
+    /// Whether there is working lighting for at least part of the night
+    #[must_use]
+    pub fn is_lit(self) -> bool {
+        !matches!(self, Self::No | Self::Disused)
+    }
 }
 
 impl FromTagsDefault for Lit {
@@ -0,0 +1,30 @@
+use strum::{EnumString, IntoStaticStr};
+
+/// Which directions of traffic may overtake, the value of `overtaking=*`,
+/// relative to the direction of the way
+/// <https://wiki.openstreetmap.org/wiki/Key:overtaking>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumString, IntoStaticStr)]
+#[strum(serialize_all = "snake_case")]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum Overtaking {
+    Yes,
+    No,
+    /// Only traffic in the direction of the way may overtake
+    Forward,
+    /// Only traffic against the direction of the way may overtake
+    Backward,
+}
+
+impl Overtaking {
+    /// Whether traffic in the direction, or against the direction, of the way may overtake
+    #[must_use]
+    pub fn permitted(self, forward: bool) -> bool {
+        match self {
+            Self::Yes => true,
+            Self::No => false,
+            Self::Forward => forward,
+            Self::Backward => !forward,
+        }
+    }
+}
@@ -0,0 +1,24 @@
+use strum::{EnumString, IntoStaticStr};
+
+/// Arrangement of the parked vehicles relative to the carriageway,
+/// the value of `parking:lane:<side>=*`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumString, IntoStaticStr)]
+#[strum(serialize_all = "snake_case")]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum ParkingOrientation {
+    Parallel,
+    Diagonal,
+    Perpendicular,
+}
+
+/// Restriction on stopping or parking along the carriageway,
+/// the value of `parking:condition:<side>=*`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumString, IntoStaticStr)]
+#[strum(serialize_all = "snake_case")]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum ParkingCondition {
+    NoStopping,
+    NoParking,
+}
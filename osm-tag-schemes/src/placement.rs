@@ -0,0 +1,69 @@
+/// Position of the way within the carriageway, the value of `placement=*`.
+/// Lanes are numbered from 1, left to right in the direction of the way.
+/// <https://wiki.openstreetmap.org/wiki/Proposed_features/placement>
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Placement {
+    LeftOf(usize),
+    MiddleOf(usize),
+    RightOf(usize),
+    /// The way moves between lanes, e.g. where lanes are added or removed
+    Transition,
+}
+
+impl std::str::FromStr for Placement {
+    type Err = strum::ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s == "transition" {
+            return Ok(Self::Transition);
+        }
+        let (position, lane) = s
+            .split_once(':')
+            .ok_or(strum::ParseError::VariantNotFound)?;
+        let lane = lane
+            .parse()
+            .ok()
+            .filter(|lane| *lane > 0)
+            .ok_or(strum::ParseError::VariantNotFound)?;
+        match position {
+            "left_of" => Ok(Self::LeftOf(lane)),
+            "middle_of" => Ok(Self::MiddleOf(lane)),
+            "right_of" => Ok(Self::RightOf(lane)),
+            _ => Err(strum::ParseError::VariantNotFound),
+        }
+    }
+}
+
+impl std::fmt::Display for Placement {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::LeftOf(lane) => write!(f, "left_of:{lane}"),
+            Self::MiddleOf(lane) => write!(f, "middle_of:{lane}"),
+            Self::RightOf(lane) => write!(f, "right_of:{lane}"),
+            Self::Transition => write!(f, "transition"),
+        }
+    }
+}
+
+/// Serialized as the tag value, e.g. `"right_of:1"`
+#[cfg(feature = "serde")]
+impl serde::Serialize for Placement {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Placement {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = <std::borrow::Cow<'de, str>>::deserialize(deserializer)?;
+        s.parse()
+            .map_err(|e| serde::de::Error::custom(format!("invalid placement '{s}': {e}")))
+    }
+}
@@ -0,0 +1,26 @@
+use strum::{EnumString, IntoStaticStr};
+
+/// Whether the way is a priority road, with right of way at junctions,
+/// the value of `priority_road=*`
+/// <https://wiki.openstreetmap.org/wiki/Key:priority_road>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumString, IntoStaticStr)]
+#[strum(serialize_all = "snake_case")]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum PriorityRoad {
+    /// Signposted as a priority road
+    Designated,
+    /// Has priority at junctions without being signposted as a priority road
+    YesUnposted,
+    /// The end of the priority road is signposted on the way
+    End,
+    No,
+}
+
+impl PriorityRoad {
+    /// Whether traffic on the way has priority at junctions
+    #[must_use]
+    pub fn is_priority(self) -> bool {
+        matches!(self, Self::Designated | Self::YesUnposted)
+    }
+}
@@ -0,0 +1,39 @@
+use strum::{EnumString, IntoStaticStr};
+
+/// What separates a cycle lane or sidewalk from the adjacent lane,
+/// the value of `cycleway:<side>:separation:<side>=*`
+/// <https://wiki.openstreetmap.org/wiki/Proposal:Separation>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumString, IntoStaticStr)]
+#[strum(serialize_all = "snake_case")]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum Separation {
+    No,
+    SolidLine,
+    DashedLine,
+    DoubleSolidLine,
+    /// A kerb step between the two
+    Kerb,
+    /// A low kerb, an island between the two
+    SeparationKerb,
+    Bollard,
+    FlexPost,
+    VerticalPanel,
+    Planter,
+    Fence,
+    GrassVerge,
+    TreeRow,
+    /// Cars parked between the two
+    ParkingLane,
+}
+
+impl Separation {
+    /// Whether the separation is only road paint, or nothing
+    #[must_use]
+    pub fn is_painted(self) -> bool {
+        matches!(
+            self,
+            Self::No | Self::SolidLine | Self::DashedLine | Self::DoubleSolidLine
+        )
+    }
+}
@@ -0,0 +1,81 @@
+use osm_tags::{TagKey, TagsRef};
+use strum::{EnumString, IntoStaticStr};
+
+use crate::{keys, FromTags, FromTagsDefault, Smoothness, TagError};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumString, IntoStaticStr)]
+#[strum(serialize_all = "snake_case")]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum Surface {
+    // Paved
+    Paved,
+    Asphalt,
+    Chipseal,
+    Concrete,
+    #[strum(serialize = "concrete:lanes")]
+    #[cfg_attr(feature = "serde", serde(rename = "concrete:lanes"))]
+    ConcreteLanes,
+    #[strum(serialize = "concrete:plates")]
+    #[cfg_attr(feature = "serde", serde(rename = "concrete:plates"))]
+    ConcretePlates,
+    PavingStones,
+    Sett,
+    UnhewnCobblestone,
+    Cobblestone,
+    Metal,
+    Wood,
+    // Unpaved
+    Unpaved,
+    Compacted,
+    FineGravel,
+    Gravel,
+    Pebblestone,
+    Ground,
+    Dirt,
+    Earth,
+    Grass,
+    Mud,
+    Sand,
+    // Seasonal
+    Ice,
+    Snow,
+}
+
+impl FromTagsDefault for Surface {
+    const KEY: TagKey = keys::SURFACE;
+}
+
+const WINTER: TagKey = TagKey::from_static("winter");
+const DATE: TagKey = TagKey::from_static("date");
+
+/// Surface and smoothness of a way, or of a part of it such as a sidewalk
+#[allow(clippy::module_name_repetitions)]
+pub struct SurfaceScheme<'tag> {
+    pub surface: Result<Option<Surface>, TagError<'tag>>,
+    pub smoothness: Result<Option<Smoothness>, TagError<'tag>>,
+    /// Surface in winter, such as on an ice road, from `surface:winter=*`
+    pub winter: Result<Option<Surface>, TagError<'tag>>,
+    /// Date the surface was last surveyed, from `surface:date=*`
+    pub date: Option<&'tag str>,
+}
+
+impl<'tag> SurfaceScheme<'tag> {
+    /// From `surface=*` and `smoothness=*`,
+    /// or from `PREFIX:surface=*` and `PREFIX:smoothness=*` given a prefix such as `sidewalk:left`.
+    #[must_use]
+    pub fn from_tags(tags: &'tag TagsRef<'_>, prefix: Option<&TagKey>) -> Self {
+        let (surface_key, smoothness_key) = match prefix {
+            None => (Surface::KEY, Smoothness::KEY),
+            Some(prefix) => (prefix + &keys::SURFACE, prefix + &keys::SMOOTHNESS),
+        };
+        let winter_key = &surface_key + &WINTER;
+        let date_key = &surface_key + &DATE;
+        Self {
+            surface: Surface::from_tags(tags, &surface_key).ok_with(surface_key),
+            smoothness: Smoothness::from_tags(tags, &smoothness_key).ok_with(smoothness_key),
+            winter: Surface::from_tags(tags, &winter_key).ok_with(winter_key),
+            date: tags.get(&date_key),
+        }
+    }
+}
@@ -0,0 +1,34 @@
+use strum::{EnumString, IntoStaticStr};
+
+/// <https://wiki.openstreetmap.org/wiki/Key:turn>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumString, IntoStaticStr)]
+#[strum(serialize_all = "snake_case")]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum TurnIndication {
+    None,
+    Left,
+    SlightLeft,
+    SharpLeft,
+    Through,
+    Right,
+    SlightRight,
+    SharpRight,
+    Reverse,
+    MergeToLeft,
+    MergeToRight,
+}
+
+impl TurnIndication {
+    /// Parse the `;` separated indications of a single lane, empty if untagged
+    ///
+    /// # Errors
+    ///
+    /// When an indication is unknown.
+    pub fn split(lane: &str) -> Result<Vec<Self>, strum::ParseError> {
+        if lane.is_empty() {
+            return Ok(Vec::new());
+        }
+        lane.split(';').map(str::parse).collect()
+    }
+}
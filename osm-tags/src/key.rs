@@ -42,6 +42,10 @@ impl TagKey {
     pub fn as_str(&self) -> &str {
         self.0.as_str()
     }
+
+    pub(crate) fn into_kstring(self) -> KString {
+        self.0
+    }
 }
 
 impl Deref for TagKey {
@@ -1,6 +1,6 @@
 //! OSM Tags
 //!
-//! Provides `Tags`, `TagKey`, and `TagVal` structures to represent and help manipulate OpenStreetMap tags
+//! Provides `Tags`, `TagsRef`, `TagKey`, and `TagVal` structures to represent and help manipulate OpenStreetMap tags
 
 #![warn(explicit_outlives_requirements)]
 #![warn(missing_abi)]
@@ -45,12 +45,14 @@
     clippy::use_debug
 )]
 
-use std::borrow::Borrow;
+use std::borrow::Cow;
 use std::collections::btree_map::Entry;
 use std::collections::BTreeMap;
-use std::hash::Hash;
+use std::ops::Bound;
 use std::str::FromStr;
 
+use kstring::KStringCow;
+
 mod key;
 pub use key::TagKey;
 
@@ -74,16 +76,32 @@ impl std::fmt::Display for DuplicateKeyError {
 
 impl std::error::Error for DuplicateKeyError {}
 
-/// A map from string keys to string values. This makes copies of strings for
-/// convenience; don't use in performance sensitive contexts.
+/// A map from string keys to string values, owning them or borrowing `'static` strings.
+/// This makes copies of strings for convenience,
+/// see [`TagsRef`] to borrow them in performance sensitive contexts.
+pub type Tags = TagsRef<'static>;
+
+/// A map from string keys to string values, borrowing them for `'tags` where possible,
+/// such as those of the ways in a buffer being imported.
+///
+/// ```
+/// use osm_tags::TagsRef;
+/// let buffer = String::from("highway residential lanes 2");
+/// let words: Vec<&str> = buffer.split(' ').collect();
+/// let tags = TagsRef::from_ref_pairs(words.chunks(2).map(|pair| (pair[0], pair[1]))).unwrap();
+/// assert_eq!(tags.get("lanes"), Some("2"));
+/// let owned = tags.into_owned();
+/// drop(buffer);
+/// assert_eq!(owned.get("lanes"), Some("2"));
+/// ```
 //
 // BTreeMap chosen for deterministic serialization.
 // We often need to compare output directly, so cannot tolerate reordering
 //
 // TODO: fix this in the serialization by having the keys sorted.
 #[derive(Clone, Debug, Default)]
-pub struct Tags {
-    map: BTreeMap<TagKey, TagVal>,
+pub struct TagsRef<'tags> {
+    map: BTreeMap<KStringCow<'tags>, Cow<'tags, str>>,
 }
 
 impl Tags {
@@ -99,17 +117,11 @@ impl Tags {
         K: Into<TagKey>,
         V: Into<TagVal>,
     {
-        let mut map: BTreeMap<TagKey, TagVal> = BTreeMap::new();
-        for tag_pair in tags {
-            let key: TagKey = tag_pair.0.into();
-            let val: TagVal = tag_pair.1.into();
-            // This may become cleaner with https://github.com/rust-lang/rust/issues/82766
-            match map.entry(key) {
-                Entry::Vacant(entry) => entry.insert(val),
-                Entry::Occupied(entry) => return Err(DuplicateKeyError(entry.remove_entry().0)),
-            };
+        let mut map = Self::default();
+        for (key, val) in tags {
+            map.checked_insert(key, val)?;
         }
-        Ok(Self { map })
+        Ok(map)
     }
 
     /// Construct from pair
@@ -119,10 +131,105 @@ impl Tags {
         K: Into<TagKey>,
         V: Into<TagVal>,
     {
-        let mut map = BTreeMap::default();
-        let duplicate_val = map.insert(key.into(), val.into());
-        debug_assert!(duplicate_val.is_none());
-        Self { map }
+        let mut map = Self::default();
+        let insert = map.checked_insert(key, val);
+        debug_assert!(insert.is_ok());
+        map
+    }
+
+    /// # Errors
+    ///
+    /// If duplicate key is inserted.   
+    ///
+    pub fn checked_insert<K: Into<TagKey>, V: Into<TagVal>>(
+        &mut self,
+        key: K,
+        val: V,
+    ) -> Result<(), DuplicateKeyError> {
+        let key: TagKey = key.into();
+        let val: TagVal = val.into();
+        self.insert(
+            KStringCow::from(key.into_kstring()),
+            Cow::Owned(val.into_string()),
+        )
+    }
+
+    /// Parse '=' separated tag pairs from a newline separated list,
+    /// skipping blank lines and `#` comments,
+    /// and trimming whitespace around keys and values.
+    ///
+    /// ```
+    /// use osm_tags::Tags;
+    /// let tags = Tags::from_str_lenient("# From the wiki\n\nfoo = bar\n  abra=cadabra\n").unwrap();
+    /// assert_eq!(tags.get("foo"), Some("bar"));
+    /// assert_eq!(tags.get("abra"), Some("cadabra"));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// If a line is missing an '=', or a duplicate key is provided.
+    pub fn from_str_lenient(s: &str) -> Result<Self, ParseTagsError> {
+        let tags = s
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .map(|line| {
+                let (key, val) = line
+                    .split_once('=')
+                    .ok_or_else(|| ParseTagsError::MissingEquals(line.to_owned()))?;
+                Ok((key.trim_end().to_owned(), val.trim_start().to_owned()))
+            })
+            .collect::<Result<Vec<(String, String)>, ParseTagsError>>()?;
+        Self::from_pairs(tags).map_err(ParseTagsError::DuplicateKey)
+    }
+}
+
+impl<'tags> TagsRef<'tags> {
+    /// Construct from iterator of borrowed pairs, without copying them
+    ///
+    /// # Errors
+    ///
+    /// If a duplicate key is provided.
+    ///
+    pub fn from_ref_pairs<I>(tags: I) -> Result<Self, DuplicateKeyError>
+    where
+        I: IntoIterator<Item = (&'tags str, &'tags str)>,
+    {
+        let mut map = Self::default();
+        for (key, val) in tags {
+            map.insert(KStringCow::from_ref(key), Cow::Borrowed(val))?;
+        }
+        Ok(map)
+    }
+
+    fn insert(
+        &mut self,
+        key: KStringCow<'tags>,
+        val: Cow<'tags, str>,
+    ) -> Result<(), DuplicateKeyError> {
+        // This may become cleaner with https://github.com/rust-lang/rust/issues/82766
+        match self.map.entry(key) {
+            Entry::Vacant(entry) => entry.insert(val),
+            Entry::Occupied(entry) => return Err(DuplicateKeyError(TagKey::from_ref(entry.key()))),
+        };
+        Ok(())
+    }
+
+    /// Copy the borrowed keys and values, so that the tags outlive them
+    #[must_use]
+    pub fn into_owned(self) -> Tags {
+        TagsRef {
+            map: self
+                .map
+                .into_iter()
+                .map(|(key, val)| {
+                    (
+                        KStringCow::from(key.into_owned()),
+                        Cow::Owned(val.into_owned()),
+                    )
+                })
+                .collect(),
+        }
     }
 
     /// Expose data as vector of pairs
@@ -136,7 +243,7 @@ impl Tags {
     pub fn to_str_pairs(&self) -> Vec<(&str, &str)> {
         self.map
             .iter()
-            .map(|(k, v)| (k.as_str(), v.as_str()))
+            .map(|(k, v)| (k.as_str(), v.as_ref()))
             .collect()
     }
 
@@ -153,10 +260,9 @@ impl Tags {
     /// Get value from tags given a key
     pub fn get<Q>(&self, q: &Q) -> Option<&str>
     where
-        TagKey: Borrow<Q>,
-        Q: Ord + Hash + Eq + ?Sized,
+        Q: AsRef<str> + ?Sized,
     {
-        self.map.get(q).map(TagVal::as_str)
+        self.map.get(q.as_ref()).map(AsRef::as_ref)
     }
 
     /// Return if tags key has value,
@@ -164,8 +270,7 @@ impl Tags {
     #[must_use]
     pub fn is<Q>(&self, q: &Q, v: &str) -> bool
     where
-        TagKey: Borrow<Q>,
-        Q: Ord + Hash + Eq + ?Sized,
+        Q: AsRef<str> + ?Sized,
     {
         self.get(q) == Some(v)
     }
@@ -175,8 +280,7 @@ impl Tags {
     #[must_use]
     pub fn is_any<Q>(&self, q: &Q, values: &[&str]) -> bool
     where
-        TagKey: Borrow<Q>,
-        Q: Ord + Hash + Eq + ?Sized,
+        Q: AsRef<str> + ?Sized,
     {
         if let Some(v) = self.get(q) {
             values.contains(&v)
@@ -185,57 +289,77 @@ impl Tags {
         }
     }
 
-    /// Get a subset of the tags
+    /// Get a subset of the tags, borrowing from the same strings as these tags
     #[must_use]
-    pub fn subset<'any, I, Q, O>(&self, keys: I) -> Self
+    pub fn subset<'any, I, Q>(&self, keys: I) -> Self
     where
         I: IntoIterator<Item = &'any Q>,
-        TagKey: Borrow<Q>,
-        Q: 'any + Ord + Hash + Eq + ?Sized + ToOwned<Owned = O>,
-        O: Into<TagKey>,
+        Q: 'any + AsRef<str> + ?Sized,
     {
-        let mut map = Self::default();
-        for key in keys {
-            if let Some(val) = self.get(key) {
-                let owned: O = key.to_owned();
-                let insert = map.checked_insert(owned.into(), TagVal::from(val));
-                debug_assert!(insert.is_ok());
-            }
+        Self {
+            map: keys
+                .into_iter()
+                .filter_map(|key| self.map.get_key_value(key.as_ref()))
+                .map(|(key, val)| (key.clone(), val.clone()))
+                .collect(),
         }
-        map
     }
 
-    /// Get node given a key part
+    /// Get the pairs whose key starts with the key part,
+    /// found from the sorted keys rather than scanning all of them
     pub fn pairs_with_stem<Q>(&self, q: &Q) -> Vec<(&str, &str)>
     where
         Q: AsRef<str> + ?Sized,
     {
-        self.map
-            .iter()
-            .filter_map(|(key, val)| {
-                key.as_str()
-                    .starts_with(q.as_ref())
-                    .then(|| (key.as_str(), val.as_str()))
-            })
+        self.range_with_stem(q.as_ref())
+            .map(|(key, val)| (key.as_str(), val.as_ref()))
             .collect()
     }
 
-    /// # Errors
+    /// Get the tags nested beneath a key, `KEY:*`, with `KEY:` removed from their keys.
     ///
-    /// If duplicate key is inserted.   
-    ///
-    pub fn checked_insert<K: Into<TagKey>, V: Into<TagVal>>(
-        &mut self,
-        key: K,
-        val: V,
-    ) -> Result<(), DuplicateKeyError> {
-        let key: TagKey = key.into();
-        // This may become cleaner with https://github.com/rust-lang/rust/issues/82766
-        match self.map.entry(key) {
-            Entry::Vacant(entry) => entry.insert(val.into()),
-            Entry::Occupied(entry) => return Err(DuplicateKeyError(entry.remove_entry().0)),
-        };
-        Ok(())
+    /// ```
+    /// use osm_tags::{TagKey, Tags};
+    /// let tags = Tags::from_pairs([
+    ///     ("cycleway:right", "lane"),
+    ///     ("cycleway:right:width", "1.5"),
+    ///     ("cycleway:right:oneway", "yes"),
+    ///     ("cycleway:righteous", "yes"),
+    /// ])
+    /// .unwrap();
+    /// let right = tags.subtree(&TagKey::from_static("cycleway:right"));
+    /// assert_eq!(right.to_vec(), vec!["oneway=yes", "width=1.5"]);
+    /// ```
+    #[must_use]
+    pub fn subtree<Q>(&self, q: &Q) -> TagsRef<'_>
+    where
+        Q: AsRef<str> + ?Sized,
+    {
+        let stem = q.as_ref();
+        TagsRef {
+            map: self
+                .range_with_stem(stem)
+                .filter_map(|(key, val)| {
+                    key.as_str()
+                        .strip_prefix(stem)
+                        .and_then(|nested| nested.strip_prefix(':'))
+                        .map(|nested| (KStringCow::from_ref(nested), Cow::Borrowed(val.as_ref())))
+                })
+                .collect(),
+        }
+    }
+
+    /// The keys starting with the stem are contiguous in the sorted map
+    fn range_with_stem<'map, 'stem>(
+        &'map self,
+        stem: &'stem str,
+    ) -> impl Iterator<Item = (&'map KStringCow<'tags>, &'map Cow<'tags, str>)> + 'stem
+    where
+        'map: 'stem,
+    {
+        self.map
+            .range::<str, _>((Bound::Included(stem), Bound::Unbounded))
+            .take_while(move |(key, _val)| key.as_str().starts_with(stem))
     }
 }
 
@@ -281,7 +405,7 @@ impl FromStr for Tags {
     }
 }
 
-impl ToString for Tags {
+impl ToString for TagsRef<'_> {
     /// Return tags as an '=' separated list
     ///
     /// ```
@@ -345,7 +469,7 @@ impl<'de> serde::Deserialize<'de> for Tags {
 }
 
 #[cfg(feature = "serde")]
-impl serde::Serialize for Tags {
+impl serde::Serialize for TagsRef<'_> {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: serde::Serializer,
@@ -353,7 +477,7 @@ impl serde::Serialize for Tags {
         use serde::ser::SerializeMap;
         let mut map = serializer.serialize_map(Some(self.map.len()))?;
         for (k, v) in &self.map {
-            map.serialize_entry(k.as_str(), v.as_str())?;
+            map.serialize_entry(k.as_str(), v.as_ref())?;
         }
         map.end()
     }
@@ -361,7 +485,7 @@ impl serde::Serialize for Tags {
 
 #[cfg(test)]
 mod tests {
-    use crate::{DuplicateKeyError, TagKey, Tags};
+    use crate::{DuplicateKeyError, ParseTagsError, TagKey, Tags, TagsRef};
 
     #[test]
     fn test_tags() {
@@ -432,7 +556,62 @@ mod tests {
         // Tree interfaces
         assert_eq!(tags.pairs_with_stem(&FOO_KEY).len(), 2);
         assert_eq!(tags.pairs_with_stem(&(FOO_KEY + "multi")).len(), 1);
+        assert_eq!(tags.pairs_with_stem("multi").len(), 1);
+        assert!(tags.pairs_with_stem("zebra").is_empty());
+        assert_eq!(tags.subtree(&FOO_KEY).to_vec(), vec!["multi:key=value"]);
+        assert_eq!(
+            tags.subtree(&(FOO_KEY + "multi")).to_vec(),
+            vec!["key=value"]
+        );
+        assert!(tags.subtree("abra").is_empty());
 
         // TODO: Multi Value
     }
+
+    #[test]
+    fn test_tags_lenient() {
+        let tags = Tags::from_str_lenient(
+            "# highway\nhighway=residential\n\n  # lanes\n  lanes = 2 \n\t\nname=A = B\n",
+        )
+        .unwrap();
+        assert_eq!(
+            tags.to_vec(),
+            vec!["highway=residential", "lanes=2", "name=A = B"]
+        );
+        assert!(matches!(
+            Tags::from_str_lenient("highway=residential\nlanes"),
+            Err(ParseTagsError::MissingEquals(_))
+        ));
+        assert!(matches!(
+            Tags::from_str_lenient("lanes=1\n# lanes=3\nlanes=2"),
+            Err(ParseTagsError::DuplicateKey(_))
+        ));
+    }
+
+    #[test]
+    fn test_tags_ref() {
+        let buffer = String::from("highway=residential\nlanes=2\nlanes:forward=1");
+        let pairs: Vec<(&str, &str)> = buffer
+            .lines()
+            .filter_map(|line| line.split_once('='))
+            .collect();
+        let tags = TagsRef::from_ref_pairs(pairs.iter().copied()).unwrap();
+        assert_eq!(tags.to_string(), buffer);
+        assert_eq!(tags.get("lanes"), Some("2"));
+        assert_eq!(
+            tags.get(&TagKey::from_static("highway")),
+            Some("residential")
+        );
+        assert!(tags.is("lanes", "2"));
+        assert_eq!(tags.pairs_with_stem("lanes").len(), 2);
+        assert_eq!(tags.subset(["lanes", "width"]).to_vec(), vec!["lanes=2"]);
+        assert!(matches!(
+            TagsRef::from_ref_pairs([("lanes", "1"), ("lanes", "2")]),
+            Err(DuplicateKeyError(_))
+        ));
+
+        let owned: Tags = tags.into_owned();
+        drop(buffer);
+        assert_eq!(owned.get("lanes:forward"), Some("1"));
+    }
 }
@@ -11,6 +11,10 @@ impl TagVal {
     pub fn as_str(&self) -> &str {
         self.0.as_str()
     }
+
+    pub(crate) fn into_string(self) -> String {
+        self.0
+    }
 }
 
 impl Deref for TagVal {
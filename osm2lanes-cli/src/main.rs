@@ -1,13 +1,15 @@
-use std::fs::File;
-use std::io::BufReader;
-use std::path::PathBuf;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
 
 use clap::{Parser, Subcommand};
-use futures::executor::block_on;
-use osm2lanes::locale::Locale;
-use osm2lanes::overpass::get_way;
-use osm2lanes::transform::{tags_to_lanes, TagsToLanesConfig};
+use osm2lanes::locale::{DrivingSide, Locale};
+use osm2lanes::overpass::query_for_way;
+use osm2lanes::road::Road;
+use osm2lanes::service;
+use osm2lanes::transform::{lanes_to_tags, tags_to_lanes, LanesToTagsConfig, TagsToLanesConfig};
 use osm_tags::Tags;
+use serde::Deserialize;
+use serde_json::Value;
 
 /// Generate lanes from an OSM way
 #[derive(Parser, Debug)]
@@ -31,14 +33,92 @@ enum Command {
     /// Convert OSM way tags to lanes
     #[clap(arg_required_else_help = true)]
     Convert {
-        /// JSON of OSM Tags
+        /// JSON of OSM Tags, or '=' separated tags one per line
         #[clap(required = true, parse(from_os_str))]
         path: PathBuf,
     },
+    /// Convert lanes back to OSM way tags
+    #[clap(arg_required_else_help = true)]
+    Reverse {
+        /// JSON of a Road
+        #[clap(required = true, parse(from_os_str))]
+        path: PathBuf,
+        /// ISO 3166 code of the country or subdivision, such as 'DE' or 'US-NY'
+        #[clap(long, required = true)]
+        locale: String,
+        /// 'left' or 'right', otherwise that of the country
+        #[clap(long)]
+        driving_side: Option<DrivingSide>,
+        /// Fail unless the tags convert back to the same lanes
+        #[clap(long)]
+        check_roundtrip: bool,
+    },
+    /// Locale data, for contributors to the country metadata
+    Locale {
+        #[clap(subcommand)]
+        command: LocaleCommand,
+    },
+    /// Report the ways whose lanes differ from a baseline,
+    /// exiting with 1 if any differ, or 2 if the ways or baseline cannot be read
+    DiffCorpus {
+        /// JSON of the lanes of each way, as written by `--write`
+        #[clap(long, required = true, parse(from_os_str))]
+        baseline: PathBuf,
+        /// NDJSON of ways, one `{"id": .., "tags": {..}, "locale": "DE"}` per line,
+        /// otherwise the test cases
+        #[clap(long, parse(from_os_str))]
+        input: Option<PathBuf>,
+        /// Write the baseline from the current lanes instead of comparing
+        #[clap(long)]
+        write: bool,
+    },
+    /// Check that the tags of test cases convert to lanes and back,
+    /// exiting with 1 if any fail, or 2 if the test cases cannot be read
+    #[cfg(feature = "validate")]
+    #[clap(arg_required_else_help = true)]
+    Validate {
+        /// YAML or JSON of test cases, as in data/tests.yml
+        #[clap(required = true, parse(from_os_str))]
+        path: PathBuf,
+    },
+    /// Write markdown and SVG for each example of the test cases
+    #[cfg(feature = "cookbook")]
+    Cookbook {
+        /// Output directory
+        #[clap(default_value = "target/cookbook", parse(from_os_str))]
+        path: PathBuf,
+    },
 }
 
-#[tokio::main]
-async fn main() {
+#[derive(Subcommand, Debug)]
+enum LocaleCommand {
+    /// Print the defaults assumed for untagged ways in a country,
+    /// exiting with 1 if the country is unknown
+    #[clap(arg_required_else_help = true)]
+    Check {
+        /// ISO 3166 code of the country or subdivision, such as 'DE' or 'US-NY'
+        #[clap(required = true)]
+        country: String,
+        /// 'left' or 'right', otherwise that of the country
+        #[clap(long)]
+        driving_side: Option<DrivingSide>,
+    },
+}
+
+/// Locale of the country, driving on the given side or that of the country
+fn build_locale(iso_3166: &str, driving_side: Option<DrivingSide>) -> Locale {
+    let builder = Locale::builder().iso_3166(iso_3166);
+    let locale = match driving_side.or_else(|| builder.build().country_driving_side()) {
+        Some(driving_side) => builder.driving_side(driving_side).build(),
+        None => builder.build(),
+    };
+    for warning in locale.warnings() {
+        log::warn!("{warning}");
+    }
+    locale
+}
+
+fn main() {
     flexi_logger::Logger::try_with_env()
         .unwrap()
         .start()
@@ -46,18 +126,295 @@ async fn main() {
     let args = Cli::parse();
     match args.command {
         Command::Way { id } => {
-            let (tags, _geom, locale) = block_on(get_way(id)).unwrap();
-            log::info!("{:#?}", tags);
-            log::info!("{:#?}", locale);
-            let lanes = tags_to_lanes(&tags, &locale, &TagsToLanesConfig::default());
+            if args.verbose > 0 {
+                eprintln!("Overpass query:\n{}", query_for_way(id));
+            }
+            let way = service::way_blocking(id).unwrap();
+            log::info!("{:#?}", way.tags);
+            log::info!("{:#?}", way.locale);
+            for warning in way.locale.warnings() {
+                log::warn!("{warning}");
+            }
+            let lanes = tags_to_lanes(&way.tags, &way.locale, &TagsToLanesConfig::default());
             println!("{}", serde_json::to_string_pretty(&lanes).unwrap());
         },
         Command::Convert { path } => {
-            let tags: Tags =
-                serde_json::from_reader(BufReader::new(File::open(path).unwrap())).unwrap();
+            let contents = std::fs::read_to_string(path).unwrap();
+            let tags: Tags = serde_json::from_str(&contents)
+                .or_else(|_| Tags::from_str_lenient(&contents))
+                .unwrap();
             let locale = Locale::builder().build();
             let lanes = tags_to_lanes(&tags, &locale, &TagsToLanesConfig::default());
             println!("{}", serde_json::to_string_pretty(&lanes).unwrap());
         },
+        Command::Reverse {
+            path,
+            locale,
+            driving_side,
+            check_roundtrip,
+        } => {
+            let contents = std::fs::read_to_string(path).unwrap();
+            let road: Road = serde_json::from_str(&contents).unwrap();
+            let locale = build_locale(&locale, driving_side);
+            match lanes_to_tags(&road, &locale, &LanesToTagsConfig::new(check_roundtrip)) {
+                Ok(tags) => println!("{}", tags.to_string()),
+                Err(e) => {
+                    eprintln!("{e}");
+                    std::process::exit(1);
+                },
+            }
+        },
+        Command::Locale {
+            command:
+                LocaleCommand::Check {
+                    country,
+                    driving_side,
+                },
+        } => {
+            let locale = build_locale(&country, driving_side);
+            if locale.country.is_none() {
+                eprintln!("unknown country '{country}'");
+                std::process::exit(1);
+            }
+            println!(
+                "{}",
+                serde_json::to_string_pretty(&locale.defaults()).unwrap()
+            );
+        },
+        Command::DiffCorpus {
+            baseline,
+            input,
+            write,
+        } => std::process::exit(diff_corpus(&baseline, input.as_deref(), write)),
+        #[cfg(feature = "validate")]
+        Command::Validate { path } => std::process::exit(validate(&path)),
+        #[cfg(feature = "cookbook")]
+        Command::Cookbook { path } => cookbook(&path).unwrap(),
+    }
+}
+
+/// A way of the NDJSON input of `diff-corpus`
+#[derive(Deserialize)]
+struct CorpusWay {
+    id: Value,
+    tags: Tags,
+    /// ISO 3166 code of the country or subdivision
+    locale: Option<String>,
+    driving_side: Option<DrivingSide>,
+}
+
+/// Lanes of each way, as JSON, keyed by way
+type Corpus = BTreeMap<String, Value>;
+
+/// Lanes of each way of the NDJSON input, or of each test case
+fn read_corpus(input: Option<&Path>) -> Result<Corpus, String> {
+    let ways: Vec<(String, Tags, Locale)> = match input {
+        Some(path) => std::fs::read_to_string(path)
+            .map_err(|e| e.to_string())?
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty())
+            .map(|(i, line)| {
+                let way: CorpusWay =
+                    serde_json::from_str(line).map_err(|e| format!("line {}: {e}", i + 1))?;
+                let id = match way.id {
+                    Value::String(id) => id,
+                    id => id.to_string(),
+                };
+                let locale = match way.locale {
+                    Some(iso_3166) => build_locale(&iso_3166, way.driving_side),
+                    None => match way.driving_side {
+                        Some(driving_side) => Locale::builder().driving_side(driving_side).build(),
+                        None => Locale::builder().build(),
+                    },
+                };
+                Ok((id, way.tags, locale))
+            })
+            .collect::<Result<_, String>>()?,
+        #[cfg(feature = "validate")]
+        None => osm2lanes::test::get_tests()
+            .into_iter()
+            .map(|test| {
+                let locale = test.locale().map_err(|e| e.to_string())?;
+                Ok((test.name(), test.tags.clone(), locale))
+            })
+            .collect::<Result<_, String>>()?,
+        #[cfg(not(feature = "validate"))]
+        None => return Err("no input, and built without the test cases".to_owned()),
+    };
+    let mut corpus = Corpus::new();
+    for (id, tags, locale) in ways {
+        let lanes = tags_to_lanes(&tags, &locale, &TagsToLanesConfig::default());
+        let lanes = serde_json::to_value(&lanes).map_err(|e| e.to_string())?;
+        if corpus.insert(id.clone(), lanes).is_some() {
+            log::warn!("duplicate way {id}, only the last is compared");
+        }
+    }
+    Ok(corpus)
+}
+
+/// Path of the first difference between two JSON values, `None` if equal
+fn first_difference(baseline: &Value, current: &Value, path: &str) -> Option<String> {
+    match (baseline, current) {
+        (Value::Object(baseline), Value::Object(current)) => baseline
+            .keys()
+            .chain(current.keys().filter(|key| !baseline.contains_key(*key)))
+            .find_map(|key| match (baseline.get(key), current.get(key)) {
+                (Some(baseline), Some(current)) => {
+                    first_difference(baseline, current, &format!("{path}/{key}"))
+                },
+                _ => Some(format!("{path}/{key}")),
+            }),
+        (Value::Array(baseline), Value::Array(current)) if baseline.len() == current.len() => {
+            baseline
+                .iter()
+                .zip(current)
+                .enumerate()
+                .find_map(|(i, (baseline, current))| {
+                    first_difference(baseline, current, &format!("{path}/{i}"))
+                })
+        },
+        (baseline, current) => (baseline != current).then(|| {
+            if path.is_empty() {
+                "/".to_owned()
+            } else {
+                path.to_owned()
+            }
+        }),
+    }
+}
+
+/// Compare the lanes of each way to the baseline, or write the baseline, returning the exit code
+fn diff_corpus(baseline_path: &Path, input: Option<&Path>, write: bool) -> i32 {
+    let current = match read_corpus(input) {
+        Ok(corpus) => corpus,
+        Err(e) => {
+            eprintln!("cannot read ways: {e}");
+            return 2;
+        },
+    };
+    if write {
+        let written = serde_json::to_string_pretty(&current)
+            .map_err(|e| e.to_string())
+            .and_then(|json| std::fs::write(baseline_path, json).map_err(|e| e.to_string()));
+        return match written {
+            Ok(()) => {
+                eprintln!(
+                    "{} ways written to {}",
+                    current.len(),
+                    baseline_path.display()
+                );
+                0
+            },
+            Err(e) => {
+                eprintln!("cannot write baseline to {}: {e}", baseline_path.display());
+                2
+            },
+        };
+    }
+    let baseline: Corpus = match std::fs::read_to_string(baseline_path)
+        .map_err(|e| e.to_string())
+        .and_then(|contents| serde_json::from_str(&contents).map_err(|e| e.to_string()))
+    {
+        Ok(baseline) => baseline,
+        Err(e) => {
+            eprintln!("cannot read baseline from {}: {e}", baseline_path.display());
+            return 2;
+        },
+    };
+    let mut changed = 0_usize;
+    let mut unchanged = 0_usize;
+    for (id, lanes) in &current {
+        match baseline.get(id) {
+            Some(baseline) => {
+                if let Some(path) = first_difference(baseline, lanes, "") {
+                    changed += 1;
+                    println!("changed {id}: {path}");
+                } else {
+                    unchanged += 1;
+                }
+            },
+            None => {
+                changed += 1;
+                println!("added {id}");
+            },
+        }
+    }
+    for id in baseline.keys().filter(|id| !current.contains_key(*id)) {
+        changed += 1;
+        println!("removed {id}");
+    }
+    println!("{unchanged} unchanged, {changed} changed");
+    i32::from(changed > 0)
+}
+
+/// Roundtrip each test case, reporting the differences, returning the exit code
+#[cfg(feature = "validate")]
+fn validate(path: &std::path::Path) -> i32 {
+    use osm2lanes::test::parse_tests;
+
+    let tests = match std::fs::read_to_string(path)
+        .map_err(|e| e.to_string())
+        .and_then(|contents| parse_tests(&contents).map_err(|e| e.to_string()))
+    {
+        Ok(tests) => tests,
+        Err(e) => {
+            eprintln!("cannot read test cases from {}: {e}", path.display());
+            return 2;
+        },
+    };
+    let mut failed = 0_usize;
+    for test in &tests {
+        match test.roundtrip() {
+            Ok(()) => println!("ok {}", test.name()),
+            Err(e) => {
+                failed += 1;
+                println!("FAILED {}: {e}", test.name());
+            },
+        }
+    }
+    println!("{} passed, {failed} failed", tests.len() - failed);
+    i32::from(failed > 0)
+}
+
+/// Write a page for each example of the test cases, and an index of them
+#[cfg(feature = "cookbook")]
+fn cookbook(path: &std::path::Path) -> std::io::Result<()> {
+    use osm2lanes::example::Example;
+    use osm2lanes::test::get_tests;
+
+    std::fs::create_dir_all(path)?;
+    let mut index = String::from("# Examples\n\n");
+    for test in get_tests() {
+        let name = match test.example() {
+            Some(name) => name.to_owned(),
+            None => continue,
+        };
+        let example = Example::new(
+            name,
+            test.tags.clone(),
+            test.locale()
+                .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))?,
+            &TagsToLanesConfig::default(),
+        );
+        let file = test.name();
+        let image = example
+            .svg()
+            .map(|svg| {
+                let image = format!("{file}.svg");
+                std::fs::write(path.join(&image), svg).map(|_| image)
+            })
+            .transpose()?;
+        std::fs::write(
+            path.join(format!("{file}.md")),
+            example.markdown(image.as_deref()),
+        )?;
+        index.push_str(&format!("- [{}]({file}.md)\n", example.name));
+        for problem in example.problems() {
+            log::warn!("{}: {problem}", example.name);
+        }
     }
+    std::fs::write(path.join("index.md"), index)?;
+    eprintln!("Examples written to {}", path.display());
+    Ok(())
 }
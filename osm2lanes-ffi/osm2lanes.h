@@ -0,0 +1,33 @@
+/* C interface to osm2lanes, built from the osm2lanes-ffi crate. */
+#ifndef OSM2LANES_H
+#define OSM2LANES_H
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+/*
+ * Convert the tags of an OpenStreetMap way to lanes.
+ *
+ * tags_json: the tags as a JSON object, e.g. {"highway": "residential", "lanes": "2"}
+ * locale_json: the place the way is in, e.g. {"driving_side": "left", "iso_3166": "GB-ENG"},
+ *              where iso_3166 may be omitted
+ *
+ * Returns the road and its warnings as JSON, {"road": {...}, "warnings": [...]},
+ * or an error, {"error": {"code": "...", "message": "..."}}.
+ * The result is never NULL and must be freed with osm2lanes_free.
+ *
+ * Panics are only caught, and returned as errors with the code "panic",
+ * in a library built with `cargo build --profile ffi`.
+ * The release profile aborts the process on a panic instead.
+ */
+char *osm2lanes_convert(const char *tags_json, const char *locale_json);
+
+/* Free a string returned by osm2lanes_convert, NULL is ignored. */
+void osm2lanes_free(char *s);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* OSM2LANES_H */
@@ -0,0 +1,213 @@
+//! C interface to `tags_to_lanes`, for consumers such as C++ or Python with cffi.
+//!
+//! Everything crosses the boundary as JSON, see `osm2lanes.h`.
+//! Panics are caught and returned as errors,
+//! which needs a build that unwinds, such as `cargo build --profile ffi`,
+//! as the release profile aborts on panic.
+
+use std::ffi::{CStr, CString};
+use std::os::raw::c_char;
+use std::panic::catch_unwind;
+
+use osm2lanes::locale::{DrivingSide, Locale};
+use osm2lanes::transform::{tags_to_lanes, RoadError, TagsToLanesConfig};
+use osm_tags::Tags;
+use serde::{Deserialize, Serialize};
+
+/// The place the way is in, as in the test cases
+#[derive(Deserialize)]
+struct LocaleInput {
+    driving_side: DrivingSide,
+    /// ISO 3166-1 alpha-2 country or ISO 3166-2 subdivision code, e.g. `GB` or `GB-ENG`
+    #[serde(default)]
+    iso_3166: Option<String>,
+}
+
+#[derive(Serialize)]
+struct Error {
+    code: &'static str,
+    message: String,
+}
+
+#[derive(Serialize)]
+struct ErrorOutput {
+    error: Error,
+}
+
+impl ErrorOutput {
+    fn new(code: &'static str, message: impl Into<String>) -> Self {
+        Self {
+            error: Error {
+                code,
+                message: message.into(),
+            },
+        }
+    }
+}
+
+impl From<RoadError> for ErrorOutput {
+    fn from(e: RoadError) -> Self {
+        let code = match &e {
+            RoadError::WayNotRoad => "way_not_road",
+            RoadError::Msg(msg) => msg.code().as_str(),
+            RoadError::Warnings(_) => "warnings",
+            RoadError::RoundTrip => "round_trip",
+        };
+        Self::new(code, e.to_string())
+    }
+}
+
+/// # Safety
+///
+/// `ptr` is null or a nul terminated string
+unsafe fn input<'any>(ptr: *const c_char, name: &str) -> Result<&'any str, ErrorOutput> {
+    if ptr.is_null() {
+        return Err(ErrorOutput::new("invalid_input", format!("{name} is null")));
+    }
+    CStr::from_ptr(ptr)
+        .to_str()
+        .map_err(|e| ErrorOutput::new("invalid_input", format!("{name}: {e}")))
+}
+
+fn convert(tags_json: &str, locale_json: &str) -> Result<String, ErrorOutput> {
+    let tags: Tags = serde_json::from_str(tags_json)
+        .map_err(|e| ErrorOutput::new("invalid_input", format!("tags: {e}")))?;
+    let locale: LocaleInput = serde_json::from_str(locale_json)
+        .map_err(|e| ErrorOutput::new("invalid_input", format!("locale: {e}")))?;
+    if let Some(code) = &locale.iso_3166 {
+        // `Locale::builder().iso_3166` panics on codes it cannot split
+        if !matches!(code.len(), 2 | 3) && !code.contains('-') {
+            return Err(ErrorOutput::new(
+                "invalid_input",
+                format!("locale: unknown ISO 3166 code {code}"),
+            ));
+        }
+    }
+    let locale = Locale::builder()
+        .driving_side(locale.driving_side)
+        .iso_3166_option(locale.iso_3166.as_deref())
+        .build();
+    let road = tags_to_lanes(&tags, &locale, &TagsToLanesConfig::default())?;
+    serde_json::to_string(&road).map_err(|e| ErrorOutput::new("internal", e.to_string()))
+}
+
+/// Convert the tags of a way to lanes.
+///
+/// Returns the JSON of the road and its warnings, or `{"error": {"code": ..., "message": ...}}`,
+/// to be freed with [`osm2lanes_free`].
+///
+/// # Safety
+///
+/// `tags_json` and `locale_json` are null or nul terminated strings
+#[no_mangle]
+pub unsafe extern "C" fn osm2lanes_convert(
+    tags_json: *const c_char,
+    locale_json: *const c_char,
+) -> *mut c_char {
+    let output = catch_unwind(|| {
+        let tags_json = input(tags_json, "tags")?;
+        let locale_json = input(locale_json, "locale")?;
+        convert(tags_json, locale_json)
+    })
+    .unwrap_or_else(|panic| {
+        let message = panic
+            .downcast_ref::<&str>()
+            .map(|s| (*s).to_owned())
+            .or_else(|| panic.downcast_ref::<String>().cloned())
+            .unwrap_or_else(|| "unknown panic".to_owned());
+        Err(ErrorOutput::new("panic", message))
+    })
+    .unwrap_or_else(|error| {
+        serde_json::to_string(&error).expect("error output is always serializable")
+    });
+    // JSON escapes control characters, so there is no interior nul
+    CString::new(output)
+        .expect("JSON has no interior nul")
+        .into_raw()
+}
+
+/// Free a string returned by [`osm2lanes_convert`].
+///
+/// # Safety
+///
+/// `s` is null or was returned by [`osm2lanes_convert`], and is not used afterwards
+#[no_mangle]
+pub unsafe extern "C" fn osm2lanes_free(s: *mut c_char) {
+    if !s.is_null() {
+        drop(CString::from_raw(s));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ffi::{CStr, CString};
+    use std::os::raw::c_char;
+    use std::ptr;
+
+    use super::{osm2lanes_convert, osm2lanes_free};
+
+    /// Convert, returning the parsed output
+    fn convert(tags: *const c_char, locale: *const c_char) -> serde_json::Value {
+        unsafe {
+            let output = osm2lanes_convert(tags, locale);
+            assert!(!output.is_null());
+            let json = CStr::from_ptr(output).to_str().unwrap().to_owned();
+            osm2lanes_free(output);
+            serde_json::from_str(&json).unwrap()
+        }
+    }
+
+    fn convert_str(tags: &str, locale: &str) -> serde_json::Value {
+        let tags = CString::new(tags).unwrap();
+        let locale = CString::new(locale).unwrap();
+        convert(tags.as_ptr(), locale.as_ptr())
+    }
+
+    fn error_code(output: &serde_json::Value) -> Option<&str> {
+        output["error"]["code"].as_str()
+    }
+
+    #[test]
+    fn null_pointers() {
+        let locale = CString::new(r#"{"driving_side":"right"}"#).unwrap();
+        let output = convert(ptr::null(), locale.as_ptr());
+        assert_eq!(error_code(&output), Some("invalid_input"));
+        let tags = CString::new(r#"{"highway":"residential"}"#).unwrap();
+        let output = convert(tags.as_ptr(), ptr::null());
+        assert_eq!(error_code(&output), Some("invalid_input"));
+    }
+
+    #[test]
+    fn invalid_json() {
+        let output = convert_str("{", r#"{"driving_side":"right"}"#);
+        assert_eq!(error_code(&output), Some("invalid_input"));
+        let output = convert_str(r#"{"highway":"residential"}"#, r#"{"driving_side":"up"}"#);
+        assert_eq!(error_code(&output), Some("invalid_input"));
+    }
+
+    #[test]
+    fn invalid_iso_3166() {
+        let output = convert_str(
+            r#"{"highway":"residential"}"#,
+            r#"{"driving_side":"left","iso_3166":"XYZW"}"#,
+        );
+        assert_eq!(error_code(&output), Some("invalid_input"));
+    }
+
+    #[test]
+    fn convert_road() {
+        let output = convert_str(
+            r#"{"highway":"residential","lanes":"2"}"#,
+            r#"{"driving_side":"left","iso_3166":"GB-ENG"}"#,
+        );
+        assert_eq!(error_code(&output), None);
+        assert_eq!(output["road"]["highway"], "residential");
+        assert!(output["road"]["lanes"].as_array().unwrap().len() >= 2);
+        assert!(output["warnings"].is_array());
+    }
+
+    #[test]
+    fn free_null() {
+        unsafe { osm2lanes_free(ptr::null_mut()) };
+    }
+}
@@ -1,9 +1,13 @@
 use std::collections::HashMap;
 
 use osm2lanes::locale::{DrivingSide, Locale};
-use osm2lanes::overpass::get_way;
-use osm2lanes::road::Road;
-use osm2lanes::transform::{lanes_to_tags, tags_to_lanes, LanesToTagsConfig, TagsToLanesConfig};
+use osm2lanes::metric::Metre;
+use osm2lanes::road::{CompactRoad, Road};
+use osm2lanes::service;
+use osm2lanes::transform::{
+    lanes_to_tags, tags_to_lanes, InferenceLevel, LanesToTagsConfig, LanesToTagsMsg, ObservedLanes,
+    RoadFromTags, RoadWarnings, TagsToLanesConfig, WidthReconciliation,
+};
 use osm_tags::Tags;
 use serde::{Deserialize, Serialize};
 use wasm_bindgen::prelude::*;
@@ -13,7 +17,110 @@ use wasm_bindgen::prelude::*;
 #[derive(Serialize, Deserialize)]
 pub struct Input {
     key_values: HashMap<String, String>,
-    drive_on_right: bool,
+    /// Superseded by `locale.driving_side`
+    #[serde(default)]
+    drive_on_right: Option<bool>,
+    #[serde(default)]
+    locale: LocaleInput,
+    /// Fail with the warnings as an error, rather than returning them alongside the road
+    #[serde(default)]
+    error_on_warnings: bool,
+    #[serde(default = "include_separators_default")]
+    include_separators: bool,
+    #[serde(default)]
+    inference: InferenceLevel,
+    #[serde(default)]
+    include_provenance: bool,
+    #[serde(default)]
+    include_lane_tags: bool,
+    #[serde(default)]
+    include_connectivity: bool,
+    #[serde(default)]
+    width_reconciliation: WidthReconciliation,
+    /// Carriageway width in metres measured from an `area:highway=*` polygon or imagery
+    #[serde(default)]
+    measured_width: Option<f64>,
+    /// Motor lane counts per direction observed from imagery, constraining assumed counts
+    #[serde(default)]
+    observed_lanes: ObservedLanes,
+    #[serde(default)]
+    only_tagged_markings: bool,
+    #[serde(default)]
+    unknown_highway_as_road: bool,
+    /// Serialize lanes as arrays of type, direction, designation and width
+    #[serde(default)]
+    compact_lanes: bool,
+}
+
+fn include_separators_default() -> bool {
+    true
+}
+
+/// The place the way is in, all fields may be omitted
+#[derive(Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct LocaleInput {
+    /// ISO 3166-1 country or ISO 3166-2 subdivision code, e.g. `GB` or `US-WA`
+    iso_3166: Option<String>,
+    /// Defaults to the side the country drives on, else the right
+    driving_side: Option<DrivingSide>,
+}
+
+impl LocaleInput {
+    fn build(&self, drive_on_right: Option<bool>) -> Result<Locale, JsValue> {
+        if let Some(code) = &self.iso_3166 {
+            // `Locale::builder().iso_3166` panics on codes it cannot split
+            if !matches!(code.len(), 2 | 3) && !code.contains('-') {
+                return Err(JsValue::from_str(&format!("unknown ISO 3166 code {code}")));
+            }
+        }
+        let builder = Locale::builder().iso_3166_option(self.iso_3166.as_deref());
+        let driving_side = self
+            .driving_side
+            .or_else(|| {
+                drive_on_right.map(|right| {
+                    if right {
+                        DrivingSide::Right
+                    } else {
+                        DrivingSide::Left
+                    }
+                })
+            })
+            .or_else(|| builder.build().country_driving_side())
+            .unwrap_or(DrivingSide::Right);
+        Ok(builder.driving_side(driving_side).build())
+    }
+}
+
+/// `RoadFromTags`, with problems found in the locale
+#[derive(Serialize)]
+pub struct Output {
+    #[serde(flatten)]
+    road: RoadFromTags,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    locale_warnings: Vec<String>,
+}
+
+/// `RoadFromTags`, with the lanes in their compact form
+#[derive(Serialize)]
+pub struct CompactOutput {
+    road: CompactRoad,
+    warnings: RoadWarnings,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    locale_warnings: Vec<String>,
+}
+
+/// Options for `js_lanes_to_tags`, all fields may be omitted.
+#[derive(Default, Deserialize)]
+#[serde(default)]
+pub struct LanesToTagsInput {
+    check_roundtrip: bool,
+}
+
+#[derive(Serialize)]
+pub struct LanesToTagsOutput {
+    tags: Tags,
+    warnings: RoadWarnings,
 }
 
 #[wasm_bindgen]
@@ -24,44 +131,85 @@ pub fn js_tags_to_lanes(val: &JsValue) -> Result<JsValue, JsValue> {
     let input: Input = val.into_serde().map_err(err_to_string)?;
 
     let mut config = TagsToLanesConfig::default();
-    config.error_on_warnings = false;
-    config.include_separators = true;
-
-    let locale = Locale::builder()
-        .driving_side(if input.drive_on_right {
-            DrivingSide::Right
-        } else {
-            DrivingSide::Left
-        })
-        .build();
+    config.error_on_warnings = input.error_on_warnings;
+    config.include_separators = input.include_separators;
+    config.inference = input.inference;
+    config.include_provenance = input.include_provenance;
+    config.include_lane_tags = input.include_lane_tags;
+    config.include_connectivity = input.include_connectivity;
+    config.width_reconciliation = input.width_reconciliation;
+    config.measured_width = input.measured_width.map(Metre::new);
+    config.observed_lanes = input.observed_lanes;
+    config.only_tagged_markings = input.only_tagged_markings;
+    config.unknown_highway_as_road = input.unknown_highway_as_road;
+
+    let locale = input.locale.build(input.drive_on_right)?;
+    let locale_warnings = locale.warnings().iter().map(ToString::to_string).collect();
 
     let mut tags = Tags::default();
     for (key, value) in input.key_values {
         tags.checked_insert(key, value).map_err(err_to_string)?;
     }
     let lanes = tags_to_lanes(&tags, &locale, &config).map_err(err_to_string)?;
-    JsValue::from_serde(&lanes).map_err(err_to_string)
+    if input.compact_lanes {
+        return JsValue::from_serde(&CompactOutput {
+            road: CompactRoad::from(&lanes.road),
+            warnings: lanes.warnings,
+            locale_warnings,
+        })
+        .map_err(err_to_string);
+    }
+    JsValue::from_serde(&Output {
+        road: lanes,
+        locale_warnings,
+    })
+    .map_err(err_to_string)
 }
 
 #[wasm_bindgen]
 pub async fn js_way_to_lanes(osm_way_id: u64) -> Result<JsValue, JsValue> {
     console_error_panic_hook::set_once();
 
-    let (tags, _geom, locale) = get_way(osm_way_id).await.map_err(err_to_string)?;
-    let lanes = tags_to_lanes(&tags, &locale, &TagsToLanesConfig::default());
+    let way = service::way(osm_way_id).await.map_err(err_to_string)?;
+    let lanes = tags_to_lanes(&way.tags, &way.locale, &TagsToLanesConfig::default());
     // Also return the tags and locale
-    JsValue::from_serde(&(lanes, locale, tags)).map_err(err_to_string)
+    JsValue::from_serde(&(lanes, way.locale, way.tags)).map_err(err_to_string)
 }
 
 #[wasm_bindgen]
-pub fn js_lanes_to_tags(road: &JsValue, locale: &JsValue) -> Result<JsValue, JsValue> {
+pub fn js_lanes_to_tags(
+    road: &JsValue,
+    locale: &JsValue,
+    config: &JsValue,
+) -> Result<JsValue, JsValue> {
     console_error_panic_hook::set_once();
 
     let road: Road = road.into_serde().map_err(err_to_string)?;
     let locale: Locale = locale.into_serde().map_err(err_to_string)?;
+    let input: LanesToTagsInput = if config.is_undefined() || config.is_null() {
+        LanesToTagsInput::default()
+    } else {
+        config.into_serde().map_err(err_to_string)?
+    };
+
+    // The roundtrip is checked here rather than by `lanes_to_tags`,
+    // so that its warnings can be returned instead of failing.
     let tags =
         lanes_to_tags(&road, &locale, &LanesToTagsConfig::new(false)).map_err(err_to_string)?;
-    JsValue::from_serde(&tags).map_err(err_to_string)
+    let warnings = if input.check_roundtrip {
+        let mut config = TagsToLanesConfig::default();
+        config.error_on_warnings = false;
+        config.include_separators = road.has_separators();
+        let roundtrip = tags_to_lanes(&tags, &locale, &config).map_err(err_to_string)?;
+        if roundtrip.road.lanes != road.lanes {
+            return Err(err_to_string(LanesToTagsMsg::roundtrip()));
+        }
+        roundtrip.warnings
+    } else {
+        RoadWarnings::default()
+    };
+
+    JsValue::from_serde(&LanesToTagsOutput { tags, warnings }).map_err(err_to_string)
 }
 
 fn err_to_string<T: std::fmt::Display>(err: T) -> JsValue {
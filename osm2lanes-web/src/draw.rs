@@ -1,11 +1,12 @@
 use osm2lanes::locale::Locale;
 use osm2lanes::metric::Metre;
 use osm2lanes::road::{
-    Color as MarkingColor, Designated, Direction, Lane, Printable, Road, Semantic, Style,
+    Color as MarkingColor, Designated, Direction, Lane, Marking, Markings, Printable, Road,
+    Semantic, Style,
 };
 use piet::kurbo::{Line, Point, Rect};
 use piet::{
-    Color as PietColor, FontFamily, RenderContext, StrokeStyle, Text, TextAttribute,
+    Color as PietColor, FontFamily, RenderContext, StrokeStyle, Text, TextAttribute, TextLayout,
     TextLayoutBuilder,
 };
 
@@ -86,27 +87,40 @@ pub(crate) fn lanes<R: RenderContext>(
                 let width =
                     width.unwrap_or_else(|| locale.travel_width(designated, road.highway.r#type()));
                 let x = scale.scale(left_edge + (0.5 * width));
-                let font_size = 24.0;
-                let layout = rc
-                    .text()
-                    .new_text_layout(lane.as_utf8().to_string())
-                    .font(FontFamily::SYSTEM_UI, font_size)
-                    .default_attribute(TextAttribute::TextColor(PietColor::WHITE))
-                    .build()?;
-                rc.draw_text(&layout, (x - (0.5 * font_size), 0.5 * canvas_height));
+                draw_glyph(
+                    rc,
+                    lane,
+                    Point {
+                        x,
+                        y: 0.5 * canvas_height,
+                    },
+                )?;
                 left_edge += width;
             },
-            Lane::Shoulder { width } => {
+            Lane::Shoulder { width, .. } => {
                 let width = width.unwrap_or(default_lane_width);
                 let x = scale.scale(left_edge + (0.5 * width));
-                let font_size = 24.0;
-                let layout = rc
-                    .text()
-                    .new_text_layout(lane.as_utf8().to_string())
-                    .font(FontFamily::SYSTEM_UI, font_size)
-                    .default_attribute(TextAttribute::TextColor(PietColor::WHITE))
-                    .build()?;
-                rc.draw_text(&layout, (x - (0.5 * font_size), 0.5 * canvas_height));
+                draw_glyph(
+                    rc,
+                    lane,
+                    Point {
+                        x,
+                        y: 0.5 * canvas_height,
+                    },
+                )?;
+                left_edge += width;
+            },
+            Lane::Verge { width } => {
+                let width = width.unwrap_or_else(|| locale.verge_width(road.highway.r#type()));
+                rc.fill(
+                    Rect::new(
+                        scale.scale(left_edge),
+                        0.0,
+                        scale.scale(left_edge + width),
+                        canvas_height,
+                    ),
+                    &PietColor::GREEN,
+                );
                 left_edge += width;
             },
             Lane::Separator {
@@ -154,27 +168,9 @@ fn draw_travel_lane<R: RenderContext>(
     canvas_height: f64,
     lane: &Lane,
 ) -> Result<(), RenderError> {
-    let width = width.unwrap_or_else(|| locale.travel_width(&designated, road.highway.r#type()));
+    let width = width.unwrap_or_else(|| lane.width(locale, road.highway.r#type()));
     let x = scale.scale(*left_edge + (0.5 * width));
-    if let Some(direction) = direction {
-        draw_arrow(
-            rc,
-            Point {
-                x,
-                y: 0.3 * canvas_height,
-            },
-            direction,
-        );
-        draw_arrow(
-            rc,
-            Point {
-                x,
-                y: 0.7 * canvas_height,
-            },
-            direction,
-        );
-    }
-    if lane.is_foot() {
+    if let Some(surface) = surface_color(designated) {
         rc.fill(
             Rect::new(
                 scale.scale(*left_edge),
@@ -182,9 +178,58 @@ fn draw_travel_lane<R: RenderContext>(
                 scale.scale(*left_edge + width),
                 canvas_height,
             ),
-            &PietColor::GRAY,
+            &surface,
         );
     }
+    if let Lane::Travel {
+        embedded_rail: true,
+        ..
+    } = lane
+    {
+        draw_rails(rc, x, scale, canvas_height);
+    }
+    if let Some(direction) = direction {
+        let arrow_length = scale.scale(0.5 * width).min(40.0);
+        for y in [0.25 * canvas_height, 0.75 * canvas_height] {
+            draw_arrow(rc, Point { x, y }, direction, arrow_length);
+        }
+    }
+    draw_glyph(
+        rc,
+        lane,
+        Point {
+            x,
+            y: 0.5 * canvas_height,
+        },
+    )?;
+    *left_edge += width;
+    Ok(())
+}
+
+/// A pair of standard gauge rails embedded in a lane, centred on `x`
+fn draw_rails<R: RenderContext>(rc: &mut R, x: f64, scale: &Scale, canvas_height: f64) {
+    let offset = 0.5 * scale.scale(Metre::new(1.435));
+    for x in [x - offset, x + offset] {
+        rc.stroke(
+            Line::new(Point { x, y: 0.0 }, Point { x, y: canvas_height }),
+            &PietColor::SILVER,
+            3.0,
+        );
+    }
+}
+
+/// The surface of a lane designated for a mode, as commonly painted
+fn surface_color(designated: Designated) -> Option<PietColor> {
+    match designated {
+        Designated::Foot => Some(PietColor::GRAY),
+        Designated::Bicycle => Some(PietColor::rgb8(0x2e, 0x5e, 0x2e)),
+        Designated::Bus => Some(PietColor::rgb8(0x7a, 0x1f, 0x1f)),
+        Designated::Motor | Designated::Hov => None,
+    }
+}
+
+/// Draw the icon of the lane, from its `Printable` representation, centred on a point
+fn draw_glyph<R: RenderContext>(rc: &mut R, lane: &Lane, centre: Point) -> Result<(), RenderError> {
     let font_size = 24.0;
     let layout = rc
         .text()
@@ -192,31 +237,48 @@ fn draw_travel_lane<R: RenderContext>(
         .font(FontFamily::SYSTEM_UI, font_size)
         .default_attribute(TextAttribute::TextColor(PietColor::WHITE))
         .build()?;
-    rc.draw_text(&layout, (x - (0.5 * font_size), 0.5 * canvas_height));
-    *left_edge += width;
+    let size = layout.size();
+    rc.draw_text(
+        &layout,
+        (centre.x - 0.5 * size.width, centre.y - 0.5 * size.height),
+    );
     Ok(())
 }
 
+/// The colour of a marking, `None` if nothing is drawn
+fn marking_color(marking: &Marking) -> Option<PietColor> {
+    match (marking.style, marking.color) {
+        (Style::NoFill, _) => None,
+        (_, Some(c)) => Some(color_into(c)),
+        (
+            Style::KerbUp
+            | Style::KerbDown
+            | Style::Bollard
+            | Style::FlexPost
+            | Style::VerticalPanel
+            | Style::Planter
+            | Style::Fence
+            | Style::TreeRow,
+            None,
+        ) => Some(PietColor::GRAY),
+        // Paint with no colour given is white in every locale
+        (Style::SolidLine | Style::BrokenLine | Style::DashedLine | Style::DottedLine, None) => {
+            Some(PietColor::WHITE)
+        },
+    }
+}
+
 fn draw_separator<R: RenderContext>(
     rc: &mut R,
     left_edge: &mut Metre,
-    markings: &osm2lanes::road::Markings,
+    markings: &Markings,
     scale: &Scale,
     canvas_height: f64,
 ) {
     for marking in markings.iter() {
         let width = marking.width.unwrap_or_else(|| Metre::new(0.2));
         let x = scale.scale(*left_edge + 0.5 * width);
-        let color = match (marking.style, marking.color) {
-            (_, Some(c)) => color_into(c),
-            (Style::KerbUp | Style::KerbDown, None) => PietColor::GRAY,
-            // Remains for debugging
-            _ => PietColor::BLUE,
-            // _ => return Err(RenderError::UnknownSeparator),
-        };
-        if let Style::NoFill = marking.style {
-            // nope
-        } else {
+        if let Some(color) = marking_color(marking) {
             rc.stroke_styled(
                 Line::new(
                     Point { x, y: 0.0 },
@@ -231,9 +293,17 @@ fn draw_separator<R: RenderContext>(
                     Style::DottedLine => StrokeStyle::new().dash_pattern(&[50.0, 100.0]),
                     Style::DashedLine => StrokeStyle::new().dash_pattern(&[100.0, 100.0]),
                     Style::BrokenLine => StrokeStyle::new().dash_pattern(&[100.0, 50.0]),
-                    Style::SolidLine | Style::KerbUp | Style::KerbDown => StrokeStyle::new(),
-                    Style::NoFill => unreachable!(),
-                    // _ => return Err(RenderError::UnknownSeparator),
+                    Style::Bollard | Style::FlexPost | Style::TreeRow => {
+                        StrokeStyle::new().dash_pattern(&[50.0, 200.0])
+                    },
+                    Style::VerticalPanel | Style::Planter => {
+                        StrokeStyle::new().dash_pattern(&[150.0, 100.0])
+                    },
+                    Style::SolidLine
+                    | Style::KerbUp
+                    | Style::KerbDown
+                    | Style::NoFill
+                    | Style::Fence => StrokeStyle::new(),
                 },
             );
         }
@@ -241,27 +311,30 @@ fn draw_separator<R: RenderContext>(
     }
 }
 
-fn draw_arrow<R: RenderContext>(rc: &mut R, mid: Point, direction: Direction) {
-    fn draw_point<R: RenderContext>(rc: &mut R, mid: Point, direction: Direction) {
+/// Draw a direction arrow of the given length centred on a point,
+/// with a head at each end for lanes in both directions
+fn draw_arrow<R: RenderContext>(rc: &mut R, mid: Point, direction: Direction, length: f64) {
+    fn draw_point<R: RenderContext>(rc: &mut R, mid: Point, direction: Direction, length: f64) {
         let dir_sign = match direction {
             Direction::Forward => -1.0,
             Direction::Backward => 1.0,
-            Direction::Both => unreachable!(),
+            Direction::Both | Direction::Reversible => unreachable!(),
         };
-        for x in [-10.0, 10.0] {
+        let head = 0.25 * length;
+        for x in [-head, head] {
             rc.stroke(
                 Line::new(
                     Point {
                         x: mid.x,
-                        y: mid.y + dir_sign * 20.0,
+                        y: mid.y + dir_sign * 0.5 * length,
                     },
                     Point {
                         x: mid.x + x,
-                        y: mid.y + dir_sign * 10.0,
+                        y: mid.y + dir_sign * (0.5 * length - head),
                     },
                 ),
                 &PietColor::WHITE,
-                1.0,
+                2.0,
             );
         }
     }
@@ -270,21 +343,21 @@ fn draw_arrow<R: RenderContext>(rc: &mut R, mid: Point, direction: Direction) {
         Line::new(
             Point {
                 x: mid.x,
-                y: mid.y - 20.0,
+                y: mid.y - 0.5 * length,
             },
             Point {
                 x: mid.x,
-                y: mid.y + 20.0,
+                y: mid.y + 0.5 * length,
             },
         ),
         &PietColor::WHITE,
-        1.0,
+        2.0,
     );
     match direction {
-        Direction::Forward | Direction::Backward => draw_point(rc, mid, direction),
-        Direction::Both => {
-            draw_point(rc, mid, Direction::Forward);
-            draw_point(rc, mid, Direction::Backward);
+        Direction::Forward | Direction::Backward => draw_point(rc, mid, direction, length),
+        Direction::Both | Direction::Reversible => {
+            draw_point(rc, mid, Direction::Forward, length);
+            draw_point(rc, mid, Direction::Backward, length);
         },
     }
 }
@@ -42,19 +42,19 @@
 
 use std::cell::RefCell;
 use std::rc::Rc;
-use std::str::FromStr;
 
 use osm2lanes::locale::{Country, Locale};
-use osm2lanes::overpass::get_way;
+use osm2lanes::overpass::query_for_way;
 use osm2lanes::road::{Lane, Printable, Road};
-use osm2lanes::transform::{
-    lanes_to_tags, tags_to_lanes, LanesToTagsConfig, RoadFromTags, TagsToLanesConfig,
-};
+use osm2lanes::service::{self, Conversion};
+use osm2lanes::test::{TestCase, TESTS_PATH};
+use osm2lanes::transform::{RoadFromTags, TagsToLanesConfig};
 use osm_tags::Tags;
 use syntect::highlighting::ThemeSet;
 use syntect::html::highlighted_html_for_string;
 use syntect::parsing::SyntaxSet;
-use web_sys::HtmlInputElement;
+use wasm_bindgen::JsCast;
+use web_sys::{HtmlDocument, HtmlInputElement, HtmlTextAreaElement};
 use yew::prelude::*;
 
 pub mod agent;
@@ -114,8 +114,12 @@ pub struct State {
     pub road: Option<Road>,
     /// Message for user
     pub message: Option<String>,
+    /// Warnings as structured JSON
+    pub warnings: Option<String>,
     /// Ref to input for way id
     pub way_ref: NodeRef,
+    /// Ref to the test case contribution snippet
+    pub contribution_ref: NodeRef,
 }
 
 #[derive(Debug)]
@@ -129,6 +133,7 @@ pub enum Msg {
     ToggleDrivingSide,
     CountrySet(Result<Country, &'static str>),
     WayFetch,
+    CopyContribution,
     Error(String),
 }
 
@@ -150,7 +155,9 @@ impl Component for App {
             normalized_tags: None,
             road: None,
             message: None,
+            warnings: None,
             way_ref: NodeRef::default(),
+            contribution_ref: NodeRef::default(),
         }));
         Self { state }
     }
@@ -207,11 +214,11 @@ impl Component for App {
                 match way_id.parse() {
                     Ok(way_id) => {
                         ctx.link().send_future(async move {
-                            match get_way(way_id).await {
-                                Ok((tags, _geom, locale)) => Msg::TagsLocaleSet {
-                                    id: way_id.to_string(),
-                                    tags,
-                                    locale,
+                            match service::way(way_id).await {
+                                Ok(way) => Msg::TagsLocaleSet {
+                                    id: way.id.to_string(),
+                                    tags: way.tags,
+                                    locale: way.locale,
                                 },
                                 Err(e) => Msg::Error(e.to_string()),
                             }
@@ -221,6 +228,22 @@ impl Component for App {
                 }
                 true
             },
+            Msg::CopyContribution => {
+                let state = self.state.borrow();
+                let textarea = state
+                    .contribution_ref
+                    .cast::<HtmlTextAreaElement>()
+                    .unwrap();
+                textarea.select();
+                let copied = gloo_utils::document()
+                    .dyn_into::<HtmlDocument>()
+                    .ok()
+                    .and_then(|document| document.exec_command("copy").ok());
+                if copied != Some(true) {
+                    log::warn!("failed to copy test case to clipboard");
+                }
+                false
+            },
             Msg::Error(e) => {
                 let mut state = self.state.borrow_mut();
                 state.message = Some(format!("Error: {}", e));
@@ -287,6 +310,43 @@ impl Component for App {
                         html!{}
                     }
                 }
+                {
+                    if let Some(warnings) = &state.warnings {
+                        html!{
+                            <section>
+                                <details>
+                                <summary>
+                                    {"Warnings JSON"}
+                                </summary>
+                                <div class="json">
+                                    <CodeHtml code={warnings.clone()}/>
+                                </div>
+                                </details>
+                            </section>
+                        }
+                    } else {
+                        html!{}
+                    }
+                }
+                {
+                    if let Some(way_id) = state.id.as_ref().and_then(|id| id.parse().ok()) {
+                        html!{
+                            <section>
+                                <details>
+                                <summary>
+                                    {"Overpass query"}
+                                </summary>
+                                <div class="json">
+                                    <p>{"Run this in Overpass turbo to reproduce the input data."}</p>
+                                    <pre>{query_for_way(way_id)}</pre>
+                                </div>
+                                </details>
+                            </section>
+                        }
+                    } else {
+                        html!{}
+                    }
+                }
                 <>
                 <section>
                     <details>
@@ -294,14 +354,24 @@ impl Component for App {
                         {"Test case YAML"}
                     </summary>
                     <div class="json">
-                        <p>{"You probably need to modify the expected output manually."}</p>
-                        <CodeHtml code={
-                            let way_id = state
-                                .way_ref
-                                .cast::<HtmlInputElement>()
-                                .and_then(|elem| elem.value().parse::<i64>().ok());
-                            generate_test_yaml(state.road.clone(), &state.edit_tags, &state.locale, way_id)
-                        }/>
+                        <p>{"You probably need to modify the expected output manually. "}
+                        {"Paste this into a GitHub issue or pull request."}</p>
+                        <textarea
+                            readonly=true
+                            rows="20"
+                            cols="80"
+                            ref={state.contribution_ref.clone()}
+                            value={
+                                let way_id = state
+                                    .way_ref
+                                    .cast::<HtmlInputElement>()
+                                    .and_then(|elem| elem.value().parse::<i64>().ok());
+                                generate_test_contribution(state.road.clone(), &state.edit_tags, &state.locale, way_id)
+                            }
+                        />
+                        <button onclick={ctx.link().callback(|_| Msg::CopyContribution)}>
+                            {"Copy to clipboard"}
+                        </button>
                     </div>
                     </details>
                 </section>
@@ -321,10 +391,18 @@ impl App {
         let locale = &state.locale;
         log::trace!("Update Tags: {}", value);
         log::trace!("Locale: {:?}", locale);
-        match Tags::from_str(value) {
-            Ok(tags) => match tags_to_lanes(&tags, locale, &TagsToLanesConfig::default()) {
-                Ok(RoadFromTags { road, warnings }) => {
-                    match lanes_to_tags(&road, locale, &LanesToTagsConfig::new(false)) {
+        match Tags::from_str_lenient(value) {
+            Ok(tags) => match service::convert(&tags, locale, &TagsToLanesConfig::default()) {
+                Ok(Conversion {
+                    road: RoadFromTags { road, warnings, .. },
+                    normalized,
+                }) => {
+                    state.warnings = if warnings.is_empty() {
+                        None
+                    } else {
+                        Some(serde_json::to_string_pretty(&warnings).unwrap())
+                    };
+                    match normalized {
                         Ok(tags) => {
                             state.road = Some(road);
                             state.normalized_tags = Some(tags.to_string());
@@ -349,12 +427,14 @@ impl App {
                 Err(road_error) => {
                     state.road = None;
                     state.normalized_tags = None;
+                    state.warnings = None;
                     state.message = Some(format!("Conversion Error: {}", road_error));
                 },
             },
             Err(tags_error) => {
                 state.road = None;
                 state.normalized_tags = None;
+                state.warnings = None;
                 state.message = Some(format!("Conversion Error: {}", tags_error));
             },
         };
@@ -383,15 +463,24 @@ impl App {
     }
 }
 
-fn generate_test_yaml(
+/// Markdown snippet for a GitHub issue or pull request, adding the test case to the test data
+fn generate_test_contribution(
     road: Option<Road>,
     tags: &str,
     locale: &Locale,
     way_id: Option<i64>,
 ) -> String {
-    use osm2lanes::test::TestCase;
+    let test = generate_test(road, tags, locale, way_id);
+    format!(
+        "Test case `{}`, to add to `{}`:\n\n```yaml\n{}```\n",
+        test.name(),
+        TESTS_PATH,
+        generate_test_yaml(&test),
+    )
+}
 
-    let test = TestCase {
+fn generate_test(road: Option<Road>, tags: &str, locale: &Locale, way_id: Option<i64>) -> TestCase {
+    TestCase {
         way_id,
         link: None,
         comment: None,
@@ -399,13 +488,16 @@ fn generate_test_yaml(
         example: None,
         driving_side: locale.driving_side,
         iso_3166_2: locale.iso_3166_2_subdivision.clone(),
-        tags: Tags::from_str(tags).unwrap_or_else(|_| Tags::default()),
+        tags: Tags::from_str_lenient(tags).unwrap_or_else(|_| Tags::default()),
         road: road.unwrap_or_else(Road::empty),
         rust: None,
-    };
+    }
+}
+
+fn generate_test_yaml(test: &TestCase) -> String {
     // TODO Strip out road's name, ref, and other things we don't test for?
     // TODO Based on checkboxes, strip out separators
-    let raw = serde_yaml::to_string(&vec![test]).unwrap();
+    let raw = serde_yaml::to_string(&[test]).unwrap();
 
     let mut output = String::new();
     for line in raw.lines() {
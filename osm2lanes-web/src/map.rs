@@ -1,7 +1,8 @@
 use geo::{LineString, Point};
 use leaflet::{Circle, LatLng, Map, MouseEvent, Path, Polyline, TileLayer};
 use osm2lanes::locale::Locale;
-use osm2lanes::overpass::{get_nearby, Error as OverpassError};
+use osm2lanes::overpass::Error as OverpassError;
+use osm2lanes::service;
 use osm_tags::Tags;
 use serde::Serialize;
 use wasm_bindgen::prelude::*;
@@ -114,10 +115,13 @@ impl Component for MapComponent {
                 } else {
                     log::debug!("map search click");
                     ctx.link().send_future(async move {
-                        match get_nearby(point, SEARCH_RADIUS).await {
-                            Ok((id, tags, geometry, locale)) => {
-                                Msg::MapUpdate(id.to_string(), tags, locale, geometry)
-                            },
+                        match service::nearby(point, SEARCH_RADIUS).await {
+                            Ok(way) => Msg::MapUpdate(
+                                way.id.to_string(),
+                                way.tags,
+                                way.locale,
+                                way.geometry,
+                            ),
                             Err(OverpassError::Empty) => Msg::Error(String::from("no ways found")),
                             Err(e) => Msg::Error(e.to_string()),
                         }
@@ -0,0 +1,167 @@
+//! Conversions bundled with what is needed to show them,
+//! for generated documentation and the web app alike.
+
+use std::fmt::Write;
+
+use osm_tags::Tags;
+
+use crate::locale::Locale;
+use crate::road::{Lane, Printable};
+use crate::transform::{
+    lanes_to_tags, tags_to_lanes, LanesToTagsConfig, LanesToTagsMsg, RoadError, RoadFromTags,
+    TagsToLanesConfig,
+};
+
+/// Tags converted to lanes and back, with the problems found on the way
+#[derive(Debug)]
+pub struct Example {
+    pub name: String,
+    pub tags: Tags,
+    pub locale: Locale,
+    pub road: Result<RoadFromTags, RoadError>,
+    /// The tags normalized from the lanes, `None` if the tags could not be converted
+    pub normalized: Option<Result<Tags, LanesToTagsMsg>>,
+}
+
+impl Example {
+    #[must_use]
+    pub fn new(
+        name: impl Into<String>,
+        tags: Tags,
+        locale: Locale,
+        config: &TagsToLanesConfig,
+    ) -> Self {
+        let road = tags_to_lanes(&tags, &locale, config);
+        let normalized = road
+            .as_ref()
+            .ok()
+            .map(|road| lanes_to_tags(&road.road, &locale, &LanesToTagsConfig::new(false)));
+        Self {
+            name: name.into(),
+            tags,
+            locale,
+            road,
+            normalized,
+        }
+    }
+
+    /// Cross-section of the lanes, see [`crate::road::Road::svg`]
+    #[must_use]
+    pub fn svg(&self) -> Option<String> {
+        self.road
+            .as_ref()
+            .ok()
+            .map(|road| road.road.svg(&self.locale))
+    }
+
+    /// Each problem described in a sentence:
+    /// those of the locale, of converting to lanes, and of normalizing back to tags
+    #[must_use]
+    pub fn problems(&self) -> Vec<String> {
+        let mut problems: Vec<String> = self
+            .locale
+            .warnings()
+            .iter()
+            .map(ToString::to_string)
+            .collect();
+        match &self.road {
+            Ok(road) => problems.extend(road.warnings.as_slice().iter().map(ToString::to_string)),
+            Err(RoadError::Warnings(warnings)) => {
+                problems.extend(warnings.as_slice().iter().map(ToString::to_string));
+            },
+            Err(e) => problems.push(e.to_string()),
+        }
+        if let Some(Err(e)) = &self.normalized {
+            problems.push(format!("cannot normalize: {e}"));
+        }
+        problems
+    }
+
+    /// Markdown describing the example, linking to the cross-section at `image` if given
+    #[must_use]
+    pub fn markdown(&self, image: Option<&str>) -> String {
+        let mut md = String::new();
+        self.write_markdown(&mut md, image)
+            .expect("writing to a String cannot fail");
+        md
+    }
+
+    fn write_markdown(&self, md: &mut impl Write, image: Option<&str>) -> std::fmt::Result {
+        writeln!(md, "## {}\n", self.name)?;
+        writeln!(md, "```")?;
+        for (key, value) in self.tags.to_str_pairs() {
+            writeln!(md, "{key}={value}")?;
+        }
+        writeln!(md, "```\n")?;
+        write!(
+            md,
+            "Driving on the {}",
+            self.locale.driving_side.to_string()
+        )?;
+        if let Some(country) = &self.locale.country {
+            write!(md, " in {country}")?;
+        }
+        writeln!(md, ".\n")?;
+        if let Ok(road) = &self.road {
+            if let Some(image) = image {
+                writeln!(md, "![{}]({image})\n", self.name)?;
+            }
+            let lanes: Vec<String> = road.road.lanes.iter().map(symbols).collect();
+            writeln!(md, "Lanes: {}\n", lanes.join(" "))?;
+        }
+        let problems = self.problems();
+        if !problems.is_empty() {
+            writeln!(md, "Problems:\n")?;
+            for problem in problems {
+                writeln!(md, "- {problem}")?;
+            }
+            writeln!(md)?;
+        }
+        Ok(())
+    }
+}
+
+/// Symbol of the lane followed by its direction, if any
+fn symbols(lane: &Lane) -> String {
+    let direction = match lane {
+        Lane::Travel {
+            direction: Some(direction),
+            ..
+        } => Some(direction.as_utf8()),
+        _ => None,
+    };
+    std::iter::once(lane.as_utf8()).chain(direction).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use osm_tags::Tags;
+
+    use super::Example;
+    use crate::locale::{DrivingSide, Locale};
+    use crate::transform::TagsToLanesConfig;
+
+    #[test]
+    fn example() {
+        let tags = Tags::from_str_lenient("highway=residential\nlanes=2").unwrap();
+        let locale = Locale::builder().driving_side(DrivingSide::Right).build();
+        let example = Example::new("residential", tags, locale, &TagsToLanesConfig::default());
+        assert!(example.svg().unwrap().starts_with("<svg"));
+        assert!(example.normalized.unwrap().is_ok());
+    }
+
+    #[test]
+    fn markdown() {
+        let tags = Tags::from_str_lenient("highway=residential\nlanes=2\ndivider=unknown").unwrap();
+        let locale = Locale::builder().iso_3166("DE").build();
+        let example = Example::new("residential", tags, locale, &TagsToLanesConfig::default());
+        let md = example.markdown(Some("residential.svg"));
+        assert!(md.starts_with("## residential\n"));
+        assert!(md.contains("lanes=2\n"));
+        assert!(md.contains("![residential](residential.svg)"));
+        assert!(md.contains("🚗↓"));
+        assert!(md.contains("Driving on the right in Germany."));
+        let problems = example.problems();
+        assert!(md.contains(&format!("Problems:\n\n- {}\n", problems.first().unwrap())));
+    }
+}
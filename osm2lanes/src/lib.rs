@@ -48,12 +48,18 @@
     clippy::use_debug
 )]
 
+pub mod example;
 pub mod locale;
 pub mod metric;
+pub mod render;
 pub mod road;
 
+#[cfg(feature = "osmchange")]
+pub mod osmchange;
 #[cfg(feature = "overpass")]
 pub mod overpass;
+#[cfg(feature = "overpass")]
+pub mod service;
 
 pub mod transform;
 
@@ -1,11 +1,31 @@
+use std::ops::RangeInclusive;
+
 pub use celes::Country;
-use osm_tag_schemes::{HighwayImportance, HighwayType};
+use osm_tag_schemes::{
+    HighwayImportance, HighwayType, Kerb, ParkingCondition, ParkingOrientation, Surface,
+};
+
+use crate::metric::{Metre, Speed, SpeedUnit};
+use crate::road::{Color, Designated, Elevation, Marking, Markings, Style};
+
+mod country_metadata;
+mod defaults;
+pub use defaults::{HighwayDefaults, LocaleDefaults};
 
-use crate::metric::Metre;
-use crate::road::{Color, Designated};
+/// Highway type with no `oneway=*` is oneway, the same in every locale,
+/// as motorways are mapped as one way per carriageway everywhere,
+/// see <https://wiki.openstreetmap.org/wiki/Key:oneway>
+#[must_use]
+pub fn highway_implies_oneway(highway: HighwayType) -> bool {
+    matches!(
+        highway,
+        HighwayType::Classified(HighwayImportance::Motorway)
+            | HighwayType::Link(HighwayImportance::Motorway)
+    )
+}
 
 /// Context about the place where an OSM way exists.
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Locale {
     /// The ISO 3166 Country
@@ -13,6 +33,9 @@ pub struct Locale {
     pub iso_3166_2_subdivision: Option<String>,
     /// The driving side
     pub driving_side: DrivingSide,
+    /// Problems found while building the locale
+    #[cfg_attr(feature = "serde", serde(skip))]
+    warnings: Vec<LocaleWarning>,
 }
 
 impl Locale {
@@ -21,11 +44,29 @@ impl Locale {
         Config::default()
     }
 
+    /// Problems found while building the locale, such as a driving side contradicting the country
+    #[must_use]
+    pub fn warnings(&self) -> &[LocaleWarning] {
+        &self.warnings
+    }
+
+    /// The side the country drives on, if known
+    #[must_use]
+    pub fn country_driving_side(&self) -> Option<DrivingSide> {
+        self.country.as_ref().map(|country| {
+            if LEFT_HAND_TRAFFIC.contains(&country.alpha2) {
+                DrivingSide::Left
+            } else {
+                DrivingSide::Right
+            }
+        })
+    }
+
     #[must_use]
     #[allow(clippy::unused_self)]
     pub fn travel_width(&self, designated: &Designated, _highway: HighwayType) -> Metre {
         match designated {
-            Designated::Motor | Designated::Bus => {
+            Designated::Motor | Designated::Bus | Designated::Hov => {
                 let uk = Country::the_united_kingdom_of_great_britain_and_northern_ireland();
                 match &self.country {
                     // Guessed, TODO: find DfT source.
@@ -40,10 +81,115 @@ impl Locale {
         }
     }
 
-    /// Road paint colour separating opposite directions of motor traffic
-    /// default is white
+    /// Width of a travel lane with rails embedded in it, widened to fit a tram
+    #[must_use]
+    pub fn embedded_rail_width(&self, designated: &Designated, highway: HighwayType) -> Metre {
+        let width = self.travel_width(designated, highway);
+        // Guessed from a tram around 2.65m wide, with clearance, TODO: find source.
+        let tram = Metre::new(3.5);
+        if width.val() < tram.val() {
+            tram
+        } else {
+            width
+        }
+    }
+
+    /// Width of a two-way carriageway without `lanes=*` below which it is assumed to be
+    /// a single lane with travel in both directions
+    #[must_use]
+    pub fn single_track_width(&self) -> Metre {
+        // Guessed, too narrow for two of the narrowest motor lanes
+        let narrowest = self.travel_width_range(&Designated::Motor).start().val();
+        Metre::new(2.0 * narrowest)
+    }
+
+    /// Plausible widths of a travel lane, when fitting lanes to a tagged total width
+    #[must_use]
+    #[allow(clippy::unused_self)]
+    pub fn travel_width_range(&self, designated: &Designated) -> RangeInclusive<Metre> {
+        // Guessed, TODO: find source.
+        match designated {
+            Designated::Motor | Designated::Hov => Metre::new(2.5)..=Metre::new(4.0),
+            // Wide bus lanes are shared with cycling rather than growing indefinitely
+            Designated::Bus => Metre::new(3.0)..=Metre::new(4.5),
+            Designated::Foot => Metre::new(1.0)..=Metre::new(5.0),
+            Designated::Bicycle => Metre::new(1.0)..=Metre::new(3.0),
+        }
+    }
+
+    /// Width of a bus bay without `bus_bay:<side>:width=*`
+    #[must_use]
+    #[allow(clippy::unused_self)]
+    pub fn bus_bay_width(&self) -> Metre {
+        // Guessed, TODO: find source.
+        Metre::new(3.0)
+    }
+
+    /// Width of a parking lane without `parking:lane:<side>:width=*`,
+    /// the depth of a bay measured across the road
+    #[must_use]
+    pub fn parking_width(&self, orientation: ParkingOrientation) -> Metre {
+        let us = Country::the_united_states_of_america();
+        let uk = Country::the_united_kingdom_of_great_britain_and_northern_ireland();
+        match (&self.country, orientation) {
+            // Guessed from 8ft and 18ft stalls, TODO: find source.
+            (Some(c), ParkingOrientation::Parallel) if c == &us => Metre::new(2.4),
+            (Some(c), ParkingOrientation::Diagonal | ParkingOrientation::Perpendicular)
+                if c == &us =>
+            {
+                Metre::new(5.5)
+            },
+            // Guessed, TODO: find Manual for Streets source.
+            (Some(c), ParkingOrientation::Parallel) if c == &uk => Metre::new(1.8),
+            (Some(c), ParkingOrientation::Perpendicular) if c == &uk => Metre::new(4.8),
+            // Guessed, TODO: find source.
+            (_, ParkingOrientation::Parallel) => Metre::new(2.0),
+            (_, ParkingOrientation::Diagonal) => Metre::new(4.5),
+            (_, ParkingOrientation::Perpendicular) => Metre::new(5.0),
+        }
+    }
+
+    /// Plausible widths of a parking lane, when fitting lanes to a tagged total width
+    #[must_use]
+    #[allow(clippy::unused_self)]
+    pub fn parking_width_range(&self, orientation: ParkingOrientation) -> RangeInclusive<Metre> {
+        // Guessed, TODO: find source.
+        match orientation {
+            ParkingOrientation::Parallel => Metre::new(1.8)..=Metre::new(3.0),
+            ParkingOrientation::Diagonal => Metre::new(3.5)..=Metre::new(6.0),
+            ParkingOrientation::Perpendicular => Metre::new(4.5)..=Metre::new(6.0),
+        }
+    }
+
+    /// Plausible widths of a shoulder, when fitting lanes to a tagged total width
+    #[must_use]
+    #[allow(clippy::unused_self)]
+    pub fn shoulder_width_range(&self) -> RangeInclusive<Metre> {
+        // Guessed, TODO: find source.
+        Metre::new(0.0)..=Metre::new(3.5)
+    }
+
+    /// The value for the country from a file of `country_metadata`
+    fn country_metadata<T>(
+        &self,
+        file: &'static str,
+        lookup: fn(&'static str, &str) -> Option<T>,
+    ) -> Option<T> {
+        self.country
+            .as_ref()
+            .and_then(|country| lookup(file, country.alpha2))
+    }
+
+    /// Road paint colour separating opposite directions of motor traffic,
+    /// from `country_metadata/centre_line_color.yml`,
+    /// default is yellow in the Americas and white elsewhere
     #[must_use]
     pub fn separator_motor_color(&self) -> Color {
+        if let Some(color) =
+            self.country_metadata(country_metadata::CENTRE_LINE_COLOR, country_metadata::color)
+        {
+            return color;
+        }
         match self
             .country
             .as_ref()
@@ -58,27 +204,173 @@ impl Locale {
         }
     }
 
-    /// Road marking width separating opposite directions of motor traffic
-    /// default is 0.2, TODO: is this a good default?
+    /// Road paint along the edge of the carriageway for a parking condition,
+    /// if the locale marks it.
+    /// A condition that only applies at times is marked with a single line.
     #[must_use]
-    pub fn separator_motor_width(&self) -> Metre {
+    pub fn parking_condition_markings(
+        &self,
+        condition: ParkingCondition,
+        at_times: bool,
+    ) -> Option<Markings> {
         match &self.country {
             Some(c)
                 if c == &Country::the_united_kingdom_of_great_britain_and_northern_ireland() =>
             {
-                // https://en.wikisource.org/wiki/Traffic_Signs_Manual/Chapter_5/2009/4
-                Metre::new(0.1)
+                // https://www.gov.uk/guidance/the-highway-code/waiting-and-parking-238-to-252
+                // Red routes forbid stopping, yellow lines forbid waiting.
+                let color = match condition {
+                    ParkingCondition::NoStopping => Color::Red,
+                    ParkingCondition::NoParking => Color::Yellow,
+                };
+                let line = Marking {
+                    style: Style::SolidLine,
+                    color: Some(color),
+                    width: Some(Metre::new(0.100)),
+                    kerb: None,
+                };
+                Some(Markings::new(if at_times {
+                    vec![line]
+                } else {
+                    vec![
+                        line.clone(),
+                        Marking {
+                            style: Style::NoFill,
+                            color: None,
+                            width: Some(Metre::new(0.080)),
+                            kerb: None,
+                        },
+                        line,
+                    ]
+                }))
+            },
+            _ => None,
+        }
+    }
+
+    /// Parking condition from the road paint along the edge of the carriageway,
+    /// and whether it only applies at times
+    #[must_use]
+    pub fn parking_condition_from_markings(
+        &self,
+        markings: &Markings,
+    ) -> Option<(ParkingCondition, bool)> {
+        [ParkingCondition::NoStopping, ParkingCondition::NoParking]
+            .into_iter()
+            .flat_map(|condition| [(condition, false), (condition, true)])
+            .find(|(condition, at_times)| {
+                self.parking_condition_markings(*condition, *at_times)
+                    .as_ref()
+                    == Some(markings)
+            })
+    }
+
+    /// Road marking width separating opposite directions of motor traffic,
+    /// from `country_metadata/centre_line_width.yml`,
+    /// default is 0.2, TODO: is this a good default?
+    #[must_use]
+    pub fn separator_motor_width(&self) -> Metre {
+        self.country_metadata(country_metadata::CENTRE_LINE_WIDTH, country_metadata::metre)
+            .unwrap_or(Metre::new(0.2))
+    }
+
+    /// Road markings separating opposite directions of motor traffic, if the country has a convention,
+    /// from `country_metadata/centre_line_style*.yml`,
+    /// where `priority_road` is a priority road outside of built-up areas
+    #[must_use]
+    pub fn centre_line_markings(
+        &self,
+        multilane: bool,
+        motorroad: bool,
+        priority_road: bool,
+    ) -> Option<Markings> {
+        let lookup = |file| self.country_metadata(file, country_metadata::markings);
+        motorroad
+            .then(|| lookup(country_metadata::CENTRE_LINE_STYLE_MOTORROAD))
+            .flatten()
+            .or_else(|| {
+                multilane
+                    .then(|| lookup(country_metadata::CENTRE_LINE_STYLE_MULTILANE))
+                    .flatten()
+            })
+            .or_else(|| {
+                priority_road
+                    .then(|| lookup(country_metadata::CENTRE_LINE_STYLE_PRIORITY_ROAD))
+                    .flatten()
+            })
+            .or_else(|| lookup(country_metadata::CENTRE_LINE_STYLE))
+    }
+
+    /// Road markings along the edge of the carriageway before a shoulder,
+    /// if the country has a convention,
+    /// from `country_metadata/edge_line_style*.yml`
+    #[must_use]
+    pub fn edge_line_markings(&self, motorroad: bool) -> Option<Markings> {
+        let lookup = |file| self.country_metadata(file, country_metadata::markings);
+        motorroad
+            .then(|| lookup(country_metadata::EDGE_LINE_STYLE_MOTORROAD))
+            .flatten()
+            .or_else(|| lookup(country_metadata::EDGE_LINE_STYLE))
+    }
+
+    /// Road markings separating a lane designated for a mode from the other traffic,
+    /// if the country has a convention,
+    /// from `country_metadata/bus_lane_line_style.yml` and `country_metadata/cycle_lane_line_style.yml`
+    #[must_use]
+    pub fn modal_line_markings(&self, designated: Designated) -> Option<Markings> {
+        let file = match designated {
+            Designated::Bus => country_metadata::BUS_LANE_LINE_STYLE,
+            Designated::Bicycle => country_metadata::CYCLE_LANE_LINE_STYLE,
+            Designated::Motor | Designated::Hov | Designated::Foot => return None,
+        };
+        self.country_metadata(file, country_metadata::markings)
+    }
+
+    /// Number of motor lanes assumed for a two-way highway type with no `lanes=*`,
+    /// a single lane has travel in both directions,
+    /// for residential streets from `country_metadata/residential_lanes.yml`
+    #[must_use]
+    pub fn default_lanes(&self, highway: HighwayType) -> usize {
+        if highway == HighwayType::Residential {
+            if let Some(lanes) =
+                self.country_metadata(country_metadata::RESIDENTIAL_LANES, country_metadata::count)
+            {
+                return lanes;
+            }
+        }
+        match highway {
+            _ if self.default_lanes_per_direction(highway) > 1 => {
+                2 * self.default_lanes_per_direction(highway)
             },
-            _ => Metre::new(0.2),
+            _ if self.has_common_split_lanes(highway) => 2,
+            _ => 1,
         }
     }
 
+    /// Number of motor lanes assumed in each direction of a highway type with no `lanes=*`,
+    /// for motorways from `country_metadata/motorway_lanes_per_direction.yml`
+    #[must_use]
+    pub fn default_lanes_per_direction(&self, highway: HighwayType) -> usize {
+        let (file, default) = match highway {
+            HighwayType::Classified(HighwayImportance::Motorway) => {
+                (country_metadata::MOTORWAY_LANES_PER_DIRECTION, 2)
+            },
+            _ => return 1,
+        };
+        self.country_metadata(file, country_metadata::count)
+            .unwrap_or(default)
+    }
+
     /// Highway type with no `lanes=*` has:
     /// - (false) one lane with travel in both directions or
     /// - (true) two lanes with travel in opposite directions
-    #[allow(clippy::unused_self)]
     #[must_use]
     pub fn has_split_lanes(&self, highway: HighwayType) -> bool {
+        self.default_lanes(highway) > 1
+    }
+
+    #[allow(clippy::unused_self)]
+    fn has_common_split_lanes(&self, highway: HighwayType) -> bool {
         matches!(
             highway,
             HighwayType::Classified(
@@ -97,6 +389,96 @@ impl Locale {
         )
     }
 
+    /// Elevation of cycle tracks without `kerb=*` hints, if there is a common design
+    #[must_use]
+    pub fn cycle_track_elevation(&self) -> Option<Elevation> {
+        match &self.country {
+            // Copenhagen style cycle tracks, between the carriageway and the sidewalk.
+            Some(c) if c == &Country::denmark() => Some(Elevation::Raised),
+            _ => None,
+        }
+    }
+
+    /// Width of a verge without `verge:width=*`
+    #[must_use]
+    pub fn verge_width(&self, highway: HighwayType) -> Metre {
+        match highway {
+            // Guessed, a recovery area beside high speed roads
+            HighwayType::Classified(HighwayImportance::Motorway | HighwayImportance::Trunk) => {
+                Metre::new(3.0)
+            },
+            _ => Metre::new(1.0),
+        }
+    }
+
+    /// Kerb between a sidewalk and the carriageway if untagged
+    #[allow(clippy::unused_self)]
+    #[must_use]
+    pub fn kerb(&self) -> Kerb {
+        Kerb::Raised
+    }
+
+    /// Surface of the carriageway if untagged
+    #[allow(clippy::unused_self)]
+    #[must_use]
+    pub fn surface(&self, highway: HighwayType) -> Option<Surface> {
+        match highway {
+            HighwayType::Classified(HighwayImportance::Motorway | HighwayImportance::Trunk)
+            | HighwayType::Link(HighwayImportance::Motorway | HighwayImportance::Trunk) => {
+                Some(Surface::Asphalt)
+            },
+            _ => None,
+        }
+    }
+
+    /// Width of a shoulder without `shoulder:width=*`, if there is a common design
+    #[must_use]
+    pub fn shoulder_width(&self, highway: HighwayType) -> Option<Metre> {
+        let motorway = highway == HighwayType::Classified(HighwayImportance::Motorway);
+        match &self.country {
+            // Guessed vluchtstrook, TODO: find Rijkswaterstaat source.
+            Some(c) if c == &Country::the_netherlands() && motorway => Some(Metre::new(3.5)),
+            Some(c) if c == &Country::the_netherlands() => Some(Metre::new(0.6)),
+            // Guessed hard shoulder, TODO: find DMRB source.
+            Some(c)
+                if c == &Country::the_united_kingdom_of_great_britain_and_northern_ireland()
+                    && motorway =>
+            {
+                Some(Metre::new(3.3))
+            },
+            // Guessed Standstreifen, TODO: find RAA source.
+            Some(c) if c == &Country::germany() && motorway => Some(Metre::new(2.5)),
+            _ if motorway => Some(Metre::new(3.0)),
+            _ => None,
+        }
+    }
+
+    /// The speed in km/h, approximating symbolic speeds such as `maxspeed=none`
+    /// for consumers that need a number
+    #[must_use]
+    pub fn speed_kph(&self, speed: Speed) -> f64 {
+        if let Some(kph) = speed.kph() {
+            return kph;
+        }
+        match (speed, &self.country) {
+            // Richtgeschwindigkeit, the advisory speed where there is no limit
+            (Speed::None, _) => 130.0_f64,
+            // Schrittgeschwindigkeit, taken by courts to be up to 7 km/h
+            (_, Some(c)) if c == &Country::germany() => 7.0_f64,
+            _ => 5.0_f64,
+        }
+    }
+
+    /// The unit of speeds without one, such as `maxspeed=30`,
+    /// km/h unless speeds are signed in mph in the country
+    #[must_use]
+    pub fn speed_unit(&self) -> SpeedUnit {
+        match &self.country {
+            Some(country) if MILES_PER_HOUR.contains(&country.alpha2) => SpeedUnit::Mph,
+            _ => SpeedUnit::Kph,
+        }
+    }
+
     /// Highway type has shoulder(s) by default
     #[allow(clippy::unused_self)]
     #[must_use]
@@ -202,10 +584,68 @@ impl Config {
             (None | Some(_), None | Some(_), _, Some(_c)) => unimplemented!(),
             (Some(_), Some(_), _, None) => unimplemented!(),
         };
-        Locale {
+        let mut locale = Locale {
             country,
             iso_3166_2_subdivision: self.iso_3166_2_subdivision.clone(),
             driving_side: self.driving_side.unwrap_or(DrivingSide::Right),
+            warnings: Vec::new(),
+        };
+        if let (Some(country), Some(expected)) = (locale.country, locale.country_driving_side()) {
+            if locale.driving_side != expected {
+                locale.warnings.push(LocaleWarning::DrivingSideConflict {
+                    country,
+                    driving_side: locale.driving_side,
+                    expected,
+                });
+            }
+        }
+        locale
+    }
+}
+
+/// ISO 3166-1 alpha-2 codes of the countries and territories that drive on the left.
+/// <https://en.wikipedia.org/wiki/Left-_and_right-hand_traffic>
+const LEFT_HAND_TRAFFIC: [&str; 75] = [
+    "AG", "AI", "AU", "BB", "BD", "BM", "BN", "BS", "BT", "BW", "CC", "CK", "CX", "CY", "DM", "FJ",
+    "FK", "GB", "GD", "GG", "GY", "HK", "ID", "IE", "IM", "IN", "JE", "JM", "JP", "KE", "KI", "KN",
+    "KY", "LC", "LK", "LS", "MO", "MS", "MT", "MU", "MV", "MW", "MY", "MZ", "NA", "NF", "NP", "NR",
+    "NU", "NZ", "PG", "PK", "PN", "SB", "SC", "SG", "SH", "SR", "SZ", "TC", "TH", "TK", "TL", "TO",
+    "TT", "TV", "TZ", "UG", "VC", "VG", "VI", "WS", "ZA", "ZM", "ZW",
+];
+
+/// ISO 3166-1 alpha-2 codes of the countries and territories that sign speeds in miles per hour.
+/// <https://en.wikipedia.org/wiki/Miles_per_hour>
+const MILES_PER_HOUR: [&str; 26] = [
+    "AG", "AS", "BS", "BZ", "DM", "FK", "GB", "GD", "GG", "GU", "IM", "JE", "KN", "KY", "LC", "LR",
+    "MP", "MS", "PR", "SH", "TC", "US", "VC", "VG", "VI", "WS",
+];
+
+/// A problem found while building a locale
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum LocaleWarning {
+    /// The driving side given is not the one of the country,
+    /// so roads will be mirrored if it is wrong
+    DrivingSideConflict {
+        country: Country,
+        driving_side: DrivingSide,
+        expected: DrivingSide,
+    },
+}
+
+impl std::fmt::Display for LocaleWarning {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::DrivingSideConflict {
+                country,
+                driving_side,
+                expected,
+            } => write!(
+                f,
+                "driving on the {} in {}, which drives on the {}",
+                driving_side.to_string(),
+                country.long_name,
+                expected.to_string(),
+            ),
         }
     }
 }
@@ -252,8 +692,42 @@ impl ToString for DrivingSide {
 #[cfg(test)]
 mod tests {
     use celes::Country;
+    use osm_tag_schemes::{HighwayImportance, HighwayType, ParkingCondition, ParkingOrientation};
 
-    use crate::locale::{DrivingSide, Locale};
+    use crate::locale::{
+        highway_implies_oneway, DrivingSide, Locale, LocaleWarning, LEFT_HAND_TRAFFIC,
+        MILES_PER_HOUR,
+    };
+    use crate::metric::{Metre, Speed, SpeedUnit};
+
+    /// The country tables are sorted and unique ISO 3166-1 alpha-2 codes,
+    /// so that mistakes in new entries fail here instead of silently never matching
+    #[test]
+    fn test_country_tables() {
+        for (name, table) in [
+            ("LEFT_HAND_TRAFFIC", &LEFT_HAND_TRAFFIC[..]),
+            ("MILES_PER_HOUR", &MILES_PER_HOUR[..]),
+        ] {
+            for code in table {
+                assert!(
+                    code.len() == 2 && code.chars().all(|c| c.is_ascii_uppercase()),
+                    "{name}: '{code}' is not an uppercase ISO 3166-1 alpha-2 code"
+                );
+                assert!(
+                    Country::from_alpha2(code).is_ok(),
+                    "{name}: '{code}' is not a known country"
+                );
+            }
+            for pair in table.windows(2) {
+                if let [previous, code] = pair {
+                    assert!(
+                        previous < code,
+                        "{name}: '{code}' is duplicated or out of order after '{previous}'"
+                    );
+                }
+            }
+        }
+    }
 
     #[test]
     fn test_locale() {
@@ -264,4 +738,144 @@ mod tests {
         assert_eq!(locale.driving_side, DrivingSide::Right);
         assert_eq!(locale.country.unwrap(), Country::germany());
     }
+
+    #[test]
+    fn test_driving_side_conflict() {
+        let gb = Locale::builder()
+            .driving_side(DrivingSide::Left)
+            .iso_3166("GB")
+            .build();
+        assert_eq!(gb.country_driving_side(), Some(DrivingSide::Left));
+        assert!(gb.warnings().is_empty());
+        let gb = Locale::builder()
+            .driving_side(DrivingSide::Right)
+            .iso_3166("GB")
+            .build();
+        assert_eq!(
+            gb.warnings(),
+            &[LocaleWarning::DrivingSideConflict {
+                country: Country::the_united_kingdom_of_great_britain_and_northern_ireland(),
+                driving_side: DrivingSide::Right,
+                expected: DrivingSide::Left,
+            }]
+        );
+        assert!(Locale::builder()
+            .driving_side(DrivingSide::Left)
+            .build()
+            .warnings()
+            .is_empty());
+    }
+
+    #[test]
+    fn test_parking_condition_markings() {
+        let de = Locale::builder().iso_3166("DE").build();
+        let gb = Locale::builder().iso_3166("GB").build();
+        assert!(de
+            .parking_condition_markings(ParkingCondition::NoStopping, false)
+            .is_none());
+        for condition in [ParkingCondition::NoStopping, ParkingCondition::NoParking] {
+            for at_times in [false, true] {
+                let markings = gb.parking_condition_markings(condition, at_times).unwrap();
+                assert_eq!(
+                    gb.parking_condition_from_markings(&markings),
+                    Some((condition, at_times))
+                );
+                assert_eq!(de.parking_condition_from_markings(&markings), None);
+            }
+        }
+    }
+
+    #[test]
+    fn test_default_lanes() {
+        let de = Locale::builder().iso_3166("DE").build();
+        let gb = Locale::builder().iso_3166("GB").build();
+        assert_eq!(de.default_lanes(HighwayType::Residential), 2);
+        assert_eq!(gb.default_lanes(HighwayType::Residential), 1);
+        assert_eq!(gb.default_lanes(HighwayType::Service), 1);
+        assert!(gb.has_split_lanes(HighwayType::Classified(HighwayImportance::Primary)));
+        let motorway = HighwayType::Classified(HighwayImportance::Motorway);
+        assert_eq!(de.default_lanes_per_direction(motorway), 2);
+        assert_eq!(de.default_lanes(motorway), 4);
+        assert_eq!(de.default_lanes_per_direction(HighwayType::Residential), 1);
+        assert!(highway_implies_oneway(motorway));
+        assert!(highway_implies_oneway(HighwayType::Link(
+            HighwayImportance::Motorway
+        )));
+        assert!(!highway_implies_oneway(HighwayType::Classified(
+            HighwayImportance::Trunk
+        )));
+    }
+
+    #[test]
+    fn test_defaults() {
+        let gb = Locale::builder().iso_3166("GB").build().defaults();
+        assert_eq!(gb.speed_unit, SpeedUnit::Mph);
+        assert_eq!(gb.parallel_parking_width, Metre::new(1.8));
+        let highway = |name: &str| {
+            gb.highways
+                .iter()
+                .find(|highway| highway.highway == name)
+                .unwrap()
+        };
+        assert_eq!(highway("residential").lanes, 1);
+        assert!(highway("motorway").oneway);
+        assert_eq!(highway("motorway").shoulder_width, Some(Metre::new(3.3)));
+    }
+
+    #[test]
+    fn test_speed_kph() {
+        let de = Locale::builder().iso_3166("DE").build();
+        let gb = Locale::builder().iso_3166("GB").build();
+        assert_eq!(de.speed_kph(Speed::Kph(50.0_f64)), 50.0_f64);
+        assert_eq!(de.speed_kph(Speed::None), 130.0_f64);
+        assert_eq!(de.speed_kph(Speed::Walk), 7.0_f64);
+        assert_eq!(gb.speed_kph(Speed::Walk), 5.0_f64);
+    }
+
+    #[test]
+    fn test_speed_unit() {
+        assert_eq!(
+            Locale::builder().iso_3166("DE").build().speed_unit(),
+            SpeedUnit::Kph
+        );
+        assert_eq!(
+            Locale::builder().iso_3166("GB").build().speed_unit(),
+            SpeedUnit::Mph
+        );
+        assert_eq!(Locale::builder().build().speed_unit(), SpeedUnit::Kph);
+    }
+
+    #[test]
+    fn test_shoulder_width() {
+        let motorway = HighwayType::Classified(HighwayImportance::Motorway);
+        let gb = Locale::builder().iso_3166("GB").build();
+        let nl = Locale::builder().iso_3166("NL").build();
+        let unknown = Locale::builder().build();
+        assert_eq!(gb.shoulder_width(motorway), Some(Metre::new(3.3)));
+        assert_eq!(
+            nl.shoulder_width(HighwayType::Residential),
+            Some(Metre::new(0.6))
+        );
+        assert!(unknown.shoulder_width(motorway).is_some());
+        assert_eq!(unknown.shoulder_width(HighwayType::Residential), None);
+    }
+
+    #[test]
+    fn test_parking_width() {
+        let us = Locale::builder().iso_3166("US").build();
+        let unknown = Locale::builder().build();
+        assert_eq!(
+            us.parking_width(ParkingOrientation::Parallel),
+            Metre::new(2.4)
+        );
+        for orientation in [
+            ParkingOrientation::Parallel,
+            ParkingOrientation::Diagonal,
+            ParkingOrientation::Perpendicular,
+        ] {
+            let range = unknown.parking_width_range(orientation);
+            let width = unknown.parking_width(orientation);
+            assert!(range.start().val() <= width.val() && width.val() <= range.end().val());
+        }
+    }
 }
@@ -0,0 +1,232 @@
+//! Conventions of each country, bundled from the data files in `country_metadata`.
+//!
+//! Each file is a YAML mapping from the ISO 3166-1 alpha-2 code to a value,
+//! either a scalar on the same line or a list of road markings indented below it:
+//!
+//! ```yaml
+//! GB:
+//!   - style: broken_line
+//!     color: white
+//!     width: 0.1
+//! ```
+//!
+//! Only this subset of YAML is read, so that the mandatory dependencies need no YAML parser.
+
+use crate::metric::Metre;
+use crate::road::{Color, Marking, Markings, Style};
+
+pub(super) const CENTRE_LINE_COLOR: &str = include_str!("country_metadata/centre_line_color.yml");
+pub(super) const CENTRE_LINE_WIDTH: &str = include_str!("country_metadata/centre_line_width.yml");
+pub(super) const CENTRE_LINE_STYLE: &str = include_str!("country_metadata/centre_line_style.yml");
+pub(super) const CENTRE_LINE_STYLE_MULTILANE: &str =
+    include_str!("country_metadata/centre_line_style_multilane.yml");
+pub(super) const CENTRE_LINE_STYLE_MOTORROAD: &str =
+    include_str!("country_metadata/centre_line_style_motorroad.yml");
+pub(super) const CENTRE_LINE_STYLE_PRIORITY_ROAD: &str =
+    include_str!("country_metadata/centre_line_style_priority_road.yml");
+pub(super) const EDGE_LINE_STYLE: &str = include_str!("country_metadata/edge_line_style.yml");
+pub(super) const EDGE_LINE_STYLE_MOTORROAD: &str =
+    include_str!("country_metadata/edge_line_style_motorroad.yml");
+pub(super) const BUS_LANE_LINE_STYLE: &str =
+    include_str!("country_metadata/bus_lane_line_style.yml");
+pub(super) const CYCLE_LANE_LINE_STYLE: &str =
+    include_str!("country_metadata/cycle_lane_line_style.yml");
+pub(super) const RESIDENTIAL_LANES: &str = include_str!("country_metadata/residential_lanes.yml");
+pub(super) const MOTORWAY_LANES_PER_DIRECTION: &str =
+    include_str!("country_metadata/motorway_lanes_per_direction.yml");
+
+/// The entry of a country in a file,
+/// the value on the line of the code and the lines indented below it
+struct Entry {
+    value: &'static str,
+    lines: Vec<&'static str>,
+}
+
+/// The lines of a file without comments or blank lines
+fn content(file: &'static str) -> impl Iterator<Item = &'static str> {
+    file.lines()
+        .filter(|line| !line.trim_start().starts_with('#') && !line.trim().is_empty())
+}
+
+/// The codes of the countries with an entry in a file
+#[cfg(test)]
+fn codes(file: &'static str) -> Vec<&'static str> {
+    content(file)
+        .filter(|line| !line.starts_with(' '))
+        .filter_map(|line| line.split_once(':'))
+        .map(|(code, _value)| code)
+        .collect()
+}
+
+fn entry(file: &'static str, alpha2: &str) -> Option<Entry> {
+    let mut lines = content(file)
+        .skip_while(|line| line.split_once(':').map(|(code, _value)| code) != Some(alpha2));
+    let (_code, value) = lines.next()?.split_once(':')?;
+    Some(Entry {
+        value: value.trim(),
+        lines: lines
+            .take_while(|line| line.starts_with(' '))
+            .map(str::trim)
+            .collect(),
+    })
+}
+
+fn parse_color(s: &str) -> Option<Color> {
+    match s {
+        "white" => Some(Color::White),
+        "yellow" => Some(Color::Yellow),
+        "red" => Some(Color::Red),
+        "green" => Some(Color::Green),
+        _ => None,
+    }
+}
+
+fn parse_style(s: &str) -> Option<Style> {
+    match s {
+        "solid_line" => Some(Style::SolidLine),
+        "broken_line" => Some(Style::BrokenLine),
+        "dashed_line" => Some(Style::DashedLine),
+        "dotted_line" => Some(Style::DottedLine),
+        "no_fill" => Some(Style::NoFill),
+        "kerb_up" => Some(Style::KerbUp),
+        "kerb_down" => Some(Style::KerbDown),
+        _ => None,
+    }
+}
+
+/// The colour of the country in a file, `None` without an entry or if it is malformed
+pub(super) fn color(file: &'static str, alpha2: &str) -> Option<Color> {
+    parse_color(entry(file, alpha2)?.value)
+}
+
+/// The width of the country in a file, `None` without an entry or if it is malformed
+pub(super) fn metre(file: &'static str, alpha2: &str) -> Option<Metre> {
+    entry(file, alpha2)?.value.parse().ok()
+}
+
+/// The count of the country in a file, `None` without an entry or if it is malformed
+pub(super) fn count(file: &'static str, alpha2: &str) -> Option<usize> {
+    entry(file, alpha2)?.value.parse().ok()
+}
+
+/// The road markings of the country in a file, `None` without an entry or if it is malformed
+pub(super) fn markings(file: &'static str, alpha2: &str) -> Option<Markings> {
+    let mut markings: Vec<Marking> = Vec::new();
+    for line in entry(file, alpha2)?.lines {
+        let line = match line.strip_prefix("- ") {
+            Some(line) => {
+                markings.push(Marking {
+                    style: Style::NoFill,
+                    width: None,
+                    color: None,
+                    kerb: None,
+                });
+                line
+            },
+            None => line,
+        };
+        let marking = markings.last_mut()?;
+        let (key, value) = line.split_once(':')?;
+        match (key.trim(), value.trim()) {
+            ("style", style) => marking.style = parse_style(style)?,
+            ("color", color) => marking.color = Some(parse_color(color)?),
+            ("width", width) => marking.width = Some(width.parse().ok()?),
+            _ => return None,
+        }
+    }
+    (!markings.is_empty()).then(|| Markings::new(markings))
+}
+
+#[cfg(test)]
+mod tests {
+    use celes::Country;
+
+    use super::{codes, color, count, markings, metre};
+    use crate::metric::Metre;
+    use crate::road::{Color, Marking, Markings, Style};
+
+    const MARKINGS: [&str; 8] = [
+        super::CENTRE_LINE_STYLE,
+        super::CENTRE_LINE_STYLE_MULTILANE,
+        super::CENTRE_LINE_STYLE_MOTORROAD,
+        super::CENTRE_LINE_STYLE_PRIORITY_ROAD,
+        super::EDGE_LINE_STYLE,
+        super::EDGE_LINE_STYLE_MOTORROAD,
+        super::BUS_LANE_LINE_STYLE,
+        super::CYCLE_LANE_LINE_STYLE,
+    ];
+
+    #[test]
+    fn files_parse() {
+        for code in codes(super::CENTRE_LINE_COLOR) {
+            assert!(Country::from_alpha2(code).is_ok(), "{code}");
+            assert!(color(super::CENTRE_LINE_COLOR, code).is_some(), "{code}");
+        }
+        for code in codes(super::CENTRE_LINE_WIDTH) {
+            assert!(Country::from_alpha2(code).is_ok(), "{code}");
+            assert!(metre(super::CENTRE_LINE_WIDTH, code).is_some(), "{code}");
+        }
+        for file in [
+            super::RESIDENTIAL_LANES,
+            super::MOTORWAY_LANES_PER_DIRECTION,
+        ] {
+            for code in codes(file) {
+                assert!(Country::from_alpha2(code).is_ok(), "{code}");
+                assert!(count(file, code).is_some(), "{code}");
+            }
+        }
+        for file in MARKINGS {
+            for code in codes(file) {
+                assert!(Country::from_alpha2(code).is_ok(), "{code}");
+                assert!(markings(file, code).is_some(), "{code}");
+            }
+        }
+    }
+
+    #[cfg(feature = "tests")]
+    #[test]
+    fn files_are_yaml() {
+        for file in MARKINGS {
+            let parsed: std::collections::BTreeMap<String, Markings> =
+                serde_yaml::from_str(file).unwrap();
+            for (code, value) in parsed {
+                assert_eq!(markings(file, &code), Some(value), "{code}");
+            }
+        }
+    }
+
+    #[test]
+    fn lookup() {
+        assert_eq!(color(super::CENTRE_LINE_COLOR, "US"), Some(Color::Yellow));
+        assert_eq!(color(super::CENTRE_LINE_COLOR, "ZZ"), None);
+        assert_eq!(count(super::RESIDENTIAL_LANES, "GB"), Some(1));
+        assert_eq!(
+            metre(super::CENTRE_LINE_WIDTH, "GB"),
+            Some(Metre::new(0.1_f64))
+        );
+        assert_eq!(
+            markings(super::CENTRE_LINE_STYLE_MULTILANE, "US"),
+            Some(Markings::new(vec![
+                Marking {
+                    style: Style::SolidLine,
+                    width: None,
+                    color: Some(Color::Yellow),
+                    kerb: None,
+                },
+                Marking {
+                    style: Style::NoFill,
+                    width: Some(Metre::new(0.1_f64)),
+                    color: None,
+                    kerb: None,
+                },
+                Marking {
+                    style: Style::SolidLine,
+                    width: None,
+                    color: Some(Color::Yellow),
+                    kerb: None,
+                },
+            ]))
+        );
+        assert_eq!(markings(super::CYCLE_LANE_LINE_STYLE, "US"), None);
+    }
+}
@@ -0,0 +1,106 @@
+use osm_tag_schemes::{HighwayImportance, HighwayType, ParkingOrientation, Surface};
+
+use super::{highway_implies_oneway, DrivingSide, Locale};
+use crate::metric::{Metre, SpeedUnit};
+use crate::road::{Color, Designated, Elevation, Markings};
+
+/// The highway types with defaults, in order of importance
+const HIGHWAYS: [HighwayType; 15] = [
+    HighwayType::Classified(HighwayImportance::Motorway),
+    HighwayType::Link(HighwayImportance::Motorway),
+    HighwayType::Classified(HighwayImportance::Trunk),
+    HighwayType::Link(HighwayImportance::Trunk),
+    HighwayType::Classified(HighwayImportance::Primary),
+    HighwayType::Link(HighwayImportance::Primary),
+    HighwayType::Classified(HighwayImportance::Secondary),
+    HighwayType::Link(HighwayImportance::Secondary),
+    HighwayType::Classified(HighwayImportance::Tertiary),
+    HighwayType::Link(HighwayImportance::Tertiary),
+    HighwayType::Unclassified,
+    HighwayType::Residential,
+    HighwayType::LivingStreet,
+    HighwayType::Service,
+    HighwayType::Track,
+];
+
+/// The defaults assumed for untagged ways in a locale,
+/// assembled from the rules in `Locale` and the `country_metadata`,
+/// so that contributors can check the data for a country
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct LocaleDefaults {
+    pub driving_side: DrivingSide,
+    pub speed_unit: SpeedUnit,
+    pub separator_motor_color: Color,
+    pub separator_motor_width: Metre,
+    pub centre_line_markings: Option<Markings>,
+    pub centre_line_markings_multilane: Option<Markings>,
+    pub centre_line_markings_motorroad: Option<Markings>,
+    pub centre_line_markings_priority_road: Option<Markings>,
+    pub edge_line_markings: Option<Markings>,
+    pub edge_line_markings_motorroad: Option<Markings>,
+    pub bus_lane_line_markings: Option<Markings>,
+    pub cycle_lane_line_markings: Option<Markings>,
+    pub motor_travel_width: Metre,
+    pub bicycle_travel_width: Metre,
+    pub foot_travel_width: Metre,
+    pub bus_bay_width: Metre,
+    pub parallel_parking_width: Metre,
+    pub diagonal_parking_width: Metre,
+    pub perpendicular_parking_width: Metre,
+    pub cycle_track_elevation: Option<Elevation>,
+    pub highways: Vec<HighwayDefaults>,
+}
+
+/// The defaults assumed for an untagged way of a highway type
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct HighwayDefaults {
+    /// The value of `highway=*`
+    pub highway: String,
+    pub lanes: usize,
+    pub oneway: bool,
+    pub shoulder: bool,
+    pub shoulder_width: Option<Metre>,
+    pub surface: Option<Surface>,
+}
+
+impl Locale {
+    /// The defaults assumed for untagged ways in the locale
+    #[must_use]
+    pub fn defaults(&self) -> LocaleDefaults {
+        LocaleDefaults {
+            driving_side: self.driving_side,
+            speed_unit: self.speed_unit(),
+            separator_motor_color: self.separator_motor_color(),
+            separator_motor_width: self.separator_motor_width(),
+            centre_line_markings: self.centre_line_markings(false, false, false),
+            centre_line_markings_multilane: self.centre_line_markings(true, false, false),
+            centre_line_markings_motorroad: self.centre_line_markings(false, true, false),
+            centre_line_markings_priority_road: self.centre_line_markings(false, false, true),
+            edge_line_markings: self.edge_line_markings(false),
+            edge_line_markings_motorroad: self.edge_line_markings(true),
+            bus_lane_line_markings: self.modal_line_markings(Designated::Bus),
+            cycle_lane_line_markings: self.modal_line_markings(Designated::Bicycle),
+            motor_travel_width: self.travel_width(&Designated::Motor, HighwayType::Residential),
+            bicycle_travel_width: self.travel_width(&Designated::Bicycle, HighwayType::Residential),
+            foot_travel_width: self.travel_width(&Designated::Foot, HighwayType::Residential),
+            bus_bay_width: self.bus_bay_width(),
+            parallel_parking_width: self.parking_width(ParkingOrientation::Parallel),
+            diagonal_parking_width: self.parking_width(ParkingOrientation::Diagonal),
+            perpendicular_parking_width: self.parking_width(ParkingOrientation::Perpendicular),
+            cycle_track_elevation: self.cycle_track_elevation(),
+            highways: HIGHWAYS
+                .iter()
+                .map(|&highway| HighwayDefaults {
+                    highway: highway.to_string(),
+                    lanes: self.default_lanes(highway),
+                    oneway: highway_implies_oneway(highway),
+                    shoulder: self.has_shoulder(highway),
+                    shoulder_width: self.shoulder_width(highway),
+                    surface: self.surface(highway),
+                })
+                .collect(),
+        }
+    }
+}
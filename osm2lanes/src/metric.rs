@@ -44,6 +44,199 @@ impl std::iter::Sum for Metre {
     }
 }
 
+#[derive(Debug)]
+pub enum MetreError {
+    Empty,
+    Parse(std::num::ParseFloatError),
+    UnknownUnit(String),
+    OutOfRange,
+    InvertedRange,
+}
+
+impl std::fmt::Display for MetreError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Empty => write!(f, "empty"),
+            Self::Parse(e) => e.fmt(f),
+            Self::UnknownUnit(unit) => write!(f, "unknown unit '{unit}'"),
+            Self::OutOfRange => write!(f, "out of range"),
+            Self::InvertedRange => write!(f, "range minimum exceeds maximum"),
+        }
+    }
+}
+
+impl std::error::Error for MetreError {}
+
+impl From<std::num::ParseFloatError> for MetreError {
+    fn from(e: std::num::ParseFloatError) -> Self {
+        MetreError::Parse(e)
+    }
+}
+
+/// Strict parsing, a plain number optionally followed by the unit `m`, e.g. `3` or `3 m`
+impl std::str::FromStr for Metre {
+    type Err = MetreError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        if s.is_empty() {
+            return Err(MetreError::Empty);
+        }
+        let val: f64 = match s.strip_suffix('m') {
+            Some(s) => s.trim_end().parse()?,
+            None => match s.split_once(' ') {
+                None => s.parse()?,
+                Some((_, unit)) => return Err(MetreError::UnknownUnit(unit.to_owned())),
+            },
+        };
+        if !val.is_finite() || val < 0.0_f64 {
+            return Err(MetreError::OutOfRange);
+        }
+        Ok(Self(val))
+    }
+}
+
+/// A length as found in real-world tagging, which may be approximate or a range.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum LenientMetre {
+    /// e.g. `3` or `3 m`
+    Exact(Metre),
+    /// e.g. `~3` or `ca. 3`
+    Approximate(Metre),
+    /// e.g. `2-2.5`
+    Range { min: Metre, max: Metre },
+}
+
+impl LenientMetre {
+    /// The single most likely value, the midpoint for ranges.
+    #[must_use]
+    pub fn target(&self) -> Metre {
+        match self {
+            Self::Exact(m) | Self::Approximate(m) => *m,
+            Self::Range { min, max } => Metre::new(min.val() + (max.val() - min.val()) / 2.0_f64),
+        }
+    }
+}
+
+impl std::str::FromStr for LenientMetre {
+    type Err = MetreError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        for marker in ["~", "≈", "ca.", "approx."] {
+            if let Some(s) = s.strip_prefix(marker) {
+                return Ok(Self::Approximate(s.parse()?));
+            }
+        }
+        // Skip the first character to not confuse a leading sign with a range.
+        if let Some((i, _)) = s.char_indices().skip(1).find(|(_, c)| *c == '-') {
+            let (min, max) = s.split_at(i);
+            let (min, max): (Metre, Metre) = (min.parse()?, max.trim_start_matches('-').parse()?);
+            if min.val() > max.val() {
+                return Err(MetreError::InvertedRange);
+            }
+            return Ok(Self::Range { min, max });
+        }
+        Ok(Self::Exact(s.parse()?))
+    }
+}
+
+/// Vehicle weight in metric tonnes, used for weight limits.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Tonne(f64);
+
+impl Tonne {
+    #[must_use]
+    pub const fn new(val: f64) -> Self {
+        Self(val)
+    }
+
+    #[must_use]
+    pub const fn val(&self) -> f64 {
+        self.0
+    }
+}
+
+#[derive(Debug)]
+pub enum TonneError {
+    Empty,
+    Parse(std::num::ParseFloatError),
+    UnknownUnit(String),
+    OutOfRange,
+}
+
+impl std::fmt::Display for TonneError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Empty => write!(f, "empty"),
+            Self::Parse(e) => e.fmt(f),
+            Self::UnknownUnit(unit) => write!(f, "unknown unit '{unit}'"),
+            Self::OutOfRange => write!(f, "out of range"),
+        }
+    }
+}
+
+impl std::error::Error for TonneError {}
+
+impl From<std::num::ParseFloatError> for TonneError {
+    fn from(e: std::num::ParseFloatError) -> Self {
+        TonneError::Parse(e)
+    }
+}
+
+/// Strict parsing, a plain number optionally followed by the unit `t`, e.g. `7.5` or `7.5 t`
+impl std::str::FromStr for Tonne {
+    type Err = TonneError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        if s.is_empty() {
+            return Err(TonneError::Empty);
+        }
+        let val: f64 = match s.strip_suffix('t') {
+            Some(s) => s.trim_end().parse()?,
+            None => match s.split_once(' ') {
+                None => s.parse()?,
+                Some((_, unit)) => return Err(TonneError::UnknownUnit(unit.to_owned())),
+            },
+        };
+        if !val.is_finite() || val < 0.0_f64 {
+            return Err(TonneError::OutOfRange);
+        }
+        Ok(Self(val))
+    }
+}
+
+/// Unit of a speed, kept so that speeds are written back as they were tagged
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum SpeedUnit {
+    Kph,
+    Mph,
+    Knots,
+}
+
+impl std::fmt::Display for SpeedUnit {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Kph => write!(f, "km/h"),
+            Self::Mph => write!(f, "mph"),
+            Self::Knots => write!(f, "knots"),
+        }
+    }
+}
+
+impl std::str::FromStr for SpeedUnit {
+    type Err = SpeedError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "km/h" | "kmh" | "kph" => Ok(Self::Kph),
+            "mph" => Ok(Self::Mph),
+            "knots" => Ok(Self::Knots),
+            unit => Err(SpeedError::UnknownUnit(unit.to_owned())),
+        }
+    }
+}
+
 /// Vehicle Speed, used for speed limits and similar.
 // TODO: This part of the API may need to be revisited entirely
 // It is unclear whether a speed unit is needed per lane,
@@ -57,17 +250,169 @@ pub enum Speed {
     Kph(f64),
     Mph(f64),
     Knots(f64),
+    /// No speed limit, `maxspeed=none`, such as on parts of the German Autobahn
+    None,
+    /// Walking pace, `maxspeed=walk`
+    Walk,
 }
 
 impl Speed {
     #[must_use]
-    pub fn kph(&self) -> f64 {
+    pub fn new(val: f64, unit: SpeedUnit) -> Self {
+        match unit {
+            SpeedUnit::Kph => Self::Kph(val),
+            SpeedUnit::Mph => Self::Mph(val),
+            SpeedUnit::Knots => Self::Knots(val),
+        }
+    }
+
+    /// The unit of the speed, `None` for symbolic speeds
+    #[must_use]
+    pub fn unit(&self) -> Option<SpeedUnit> {
         match self {
-            Self::Kph(val) => *val,
-            Self::Mph(val) => 1.60934_f64 * val,
-            Self::Knots(val) => 1.852_f64 * val,
+            Self::Kph(_) => Some(SpeedUnit::Kph),
+            Self::Mph(_) => Some(SpeedUnit::Mph),
+            Self::Knots(_) => Some(SpeedUnit::Knots),
+            Self::None | Self::Walk => None,
         }
     }
+
+    /// Parse a speed such as `50`, `30 mph` or `none`,
+    /// where a plain number is in the given unit
+    ///
+    /// # Errors
+    ///
+    /// When the number or unit cannot be parsed, or the speed is out of range.
+    pub fn parse_in(s: &str, default: SpeedUnit) -> Result<Self, SpeedError> {
+        let s = s.trim();
+        if s.is_empty() {
+            return Err(SpeedError::Empty);
+        }
+        match s {
+            "none" => return Ok(Self::None),
+            "walk" => return Ok(Self::Walk),
+            _ => {},
+        }
+        // The unit may follow the number with or without a space, e.g. `30 mph` or `30mph`
+        let (val, unit) = s.split_at(
+            s.find(|c: char| !c.is_ascii_digit() && c != '.')
+                .unwrap_or(s.len()),
+        );
+        let unit = match unit.trim_start() {
+            "" => default,
+            unit => unit.parse()?,
+        };
+        let speed = Self::new(val.parse()?, unit);
+        if !matches!(speed.kph(), Some(kph) if (0_f64..=300_f64).contains(&kph)) {
+            return Err(SpeedError::OutOfRange);
+        }
+        Ok(speed)
+    }
+
+    /// The tag value of the speed, to be parsed with `Speed::parse_in` in the given unit.
+    /// Units other than km/h are always written, as a plain number is km/h to most consumers.
+    #[must_use]
+    pub fn to_string_in(&self, default: SpeedUnit) -> String {
+        match self {
+            Self::Kph(v) if default != SpeedUnit::Kph => format!("{v} km/h"),
+            _ => self.to_string(),
+        }
+    }
+
+    /// The speed in km/h, `None` for symbolic speeds,
+    /// see `Locale::speed_kph` for an approximation of those
+    #[must_use]
+    pub fn kph(&self) -> Option<f64> {
+        match self {
+            Self::Kph(val) => Some(*val),
+            Self::Mph(val) => Some(KPH_PER_MPH * val),
+            Self::Knots(val) => Some(KPH_PER_KNOT * val),
+            Self::None | Self::Walk => None,
+        }
+    }
+
+    /// The speed in mph, `None` for symbolic speeds
+    #[must_use]
+    pub fn mph(&self) -> Option<f64> {
+        match self {
+            Self::Mph(val) => Some(*val),
+            s => s.kph().map(|kph| kph / KPH_PER_MPH),
+        }
+    }
+
+    /// The same speed in another unit, symbolic speeds are unchanged
+    #[must_use]
+    pub fn to_unit(&self, unit: SpeedUnit) -> Self {
+        match (self, unit) {
+            (Self::None | Self::Walk, _) => *self,
+            (s, SpeedUnit::Kph) => Self::Kph(s.kph().unwrap_or_default()),
+            (s, SpeedUnit::Mph) => Self::Mph(s.mph().unwrap_or_default()),
+            (Self::Knots(val), SpeedUnit::Knots) => Self::Knots(*val),
+            (s, SpeedUnit::Knots) => Self::Knots(s.kph().unwrap_or_default() / KPH_PER_KNOT),
+        }
+    }
+
+    /// The class of the speed, see [`SpeedClass`]
+    #[must_use]
+    pub fn class(&self) -> SpeedClass {
+        if *self == Self::Walk {
+            return SpeedClass::Walking;
+        }
+        match self.kph() {
+            Some(kph) if kph < 15.0_f64 => SpeedClass::Walking,
+            Some(kph) if kph < 40.0_f64 => SpeedClass::Living,
+            Some(kph) if kph < 70.0_f64 => SpeedClass::Intra,
+            Some(kph) if kph < 100.0_f64 => SpeedClass::Inter,
+            Some(_) | None => SpeedClass::Max,
+        }
+    }
+}
+
+const KPH_PER_MPH: f64 = 1.609_344;
+const KPH_PER_KNOT: f64 = 1.852;
+
+/// Speeds are ordered by km/h, with `walk` slower and `none` faster than any number.
+///
+/// Speeds in different units that are the same in km/h are not equal, so they are unordered.
+impl PartialOrd for Speed {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        use std::cmp::Ordering;
+
+        match (self, other) {
+            (Self::Walk, Self::Walk) | (Self::None, Self::None) => Some(Ordering::Equal),
+            (Self::Walk, _) | (_, Self::None) => Some(Ordering::Less),
+            (_, Self::Walk) | (Self::None, _) => Some(Ordering::Greater),
+            (a, b) => match a.kph()?.partial_cmp(&b.kph()?)? {
+                Ordering::Equal if a != b => None,
+                ordering => Some(ordering),
+            },
+        }
+    }
+}
+
+/// Semantic class of a speed, such as of a speed limit, by which roads can be bucketed.
+///
+/// Classes are ordered from slowest to fastest.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum SpeedClass {
+    /// Below ~15kph, or `maxspeed=walk`
+    Walking,
+    /// ~30kph / ~20mph
+    Living,
+    /// ~50kph / ~30mph
+    Intra,
+    /// ~80kph / ~50mph
+    Inter,
+    /// From ~100kph / ~60mph, or `maxspeed=none`
+    Max,
+}
+
+impl From<Speed> for SpeedClass {
+    fn from(s: Speed) -> Self {
+        s.class()
+    }
 }
 
 #[derive(Debug)]
@@ -97,22 +442,11 @@ impl From<std::num::ParseFloatError> for SpeedError {
     }
 }
 
+/// A plain number is in km/h, see `Speed::parse_in` for other units
 impl std::str::FromStr for Speed {
     type Err = SpeedError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        if s.is_empty() {
-            return Err(SpeedError::Empty);
-        }
-        let speed = match s.split_once(' ') {
-            None => Self::Kph(s.parse()?),
-            Some((s, "mph")) => Self::Mph(s.parse()?),
-            Some((s, "knots")) => Self::Knots(s.parse()?),
-            Some((_, unit)) => return Err(SpeedError::UnknownUnit(unit.to_owned())),
-        };
-        if speed.kph() < 0_f64 || speed.kph() > 300_f64 {
-            return Err(SpeedError::OutOfRange);
-        }
-        Ok(speed)
+        Self::parse_in(s, SpeedUnit::Kph)
     }
 }
 
@@ -122,6 +456,8 @@ impl std::fmt::Display for Speed {
             Self::Kph(v) => write!(f, "{}", v),
             Self::Mph(v) => write!(f, "{} mph", v),
             Self::Knots(v) => write!(f, "{} knots", v),
+            Self::None => write!(f, "none"),
+            Self::Walk => write!(f, "walk"),
         }
     }
 }
@@ -146,27 +482,18 @@ impl<'de> serde::Deserialize<'de> for Speed {
     }
 }
 
+#[cfg(feature = "serde")]
 mod speed {
     use std::num::ParseFloatError;
 
-    #[derive(Debug, PartialEq)]
-    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    use super::SpeedUnit;
+
+    #[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
     struct SpeedStruct {
         unit: SpeedUnit,
         value: f64,
     }
 
-    #[derive(Debug, PartialEq, Eq)]
-    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-    #[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
-    enum SpeedUnit {
-        Kph,
-        #[cfg(feature = "serde")]
-        Mph,
-        #[cfg(feature = "serde")]
-        Knots,
-    }
-
     impl std::str::FromStr for SpeedStruct {
         type Err = ParseFloatError;
         fn from_str(s: &str) -> Result<Self, Self::Err> {
@@ -177,7 +504,6 @@ mod speed {
         }
     }
 
-    #[cfg(feature = "serde")]
     pub(crate) fn serialize<S>(speed: &super::Speed, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: serde::Serializer,
@@ -197,106 +523,92 @@ mod speed {
                 value: *v,
             }
             .serialize(serializer),
+            Speed::None | Speed::Walk => serializer.collect_str(speed),
         }
     }
 
     // https://serde.rs/string-or-struct.html
 
-    #[cfg(feature = "serde")]
     struct FloatOrStruct;
 
-    #[cfg(feature = "serde")]
+    impl From<SpeedStruct> for super::Speed {
+        fn from(s: SpeedStruct) -> Self {
+            Self::new(s.value, s.unit)
+        }
+    }
+
     impl<'de> serde::de::Visitor<'de> for FloatOrStruct {
-        type Value = SpeedStruct;
+        type Value = super::Speed;
 
         fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
-            formatter.write_str("number or map")
+            formatter.write_str("number, map, \"none\" or \"walk\"")
         }
 
-        fn visit_f64<E>(self, value: f64) -> Result<SpeedStruct, E>
+        fn visit_f64<E>(self, value: f64) -> Result<super::Speed, E>
         where
             E: serde::de::Error,
         {
-            Ok(SpeedStruct {
-                unit: SpeedUnit::Kph,
-                value,
-            })
+            Ok(super::Speed::Kph(value))
         }
 
-        fn visit_u32<E>(self, value: u32) -> Result<SpeedStruct, E>
+        fn visit_u32<E>(self, value: u32) -> Result<super::Speed, E>
         where
             E: serde::de::Error,
         {
-            Ok(SpeedStruct {
-                unit: SpeedUnit::Kph,
-                value: f64::from(value),
-            })
+            Ok(super::Speed::Kph(f64::from(value)))
         }
 
         // TODO: why is this needed if u32 is enough?
-        fn visit_u64<E>(self, value: u64) -> Result<SpeedStruct, E>
+        fn visit_u64<E>(self, value: u64) -> Result<super::Speed, E>
         where
             E: serde::de::Error,
         {
             let value = u32::try_from(value).unwrap();
-            let value = f64::from(value);
-            Ok(SpeedStruct {
-                unit: SpeedUnit::Kph,
-                value,
-            })
+            Ok(super::Speed::Kph(f64::from(value)))
         }
 
-        fn visit_i32<E>(self, value: i32) -> Result<SpeedStruct, E>
+        fn visit_i32<E>(self, value: i32) -> Result<super::Speed, E>
         where
             E: serde::de::Error,
         {
-            Ok(SpeedStruct {
-                unit: SpeedUnit::Kph,
-                value: f64::from(value),
-            })
+            Ok(super::Speed::Kph(f64::from(value)))
         }
 
         // TODO: why is this needed if i32 is enough?
-        fn visit_i64<E>(self, value: i64) -> Result<SpeedStruct, E>
+        fn visit_i64<E>(self, value: i64) -> Result<super::Speed, E>
         where
             E: serde::de::Error,
         {
             let value = i32::try_from(value).unwrap();
-            let value = f64::from(value);
-            Ok(SpeedStruct {
-                unit: SpeedUnit::Kph,
-                value,
-            })
+            Ok(super::Speed::Kph(f64::from(value)))
+        }
+
+        fn visit_str<E>(self, value: &str) -> Result<super::Speed, E>
+        where
+            E: serde::de::Error,
+        {
+            match value {
+                "none" => Ok(super::Speed::None),
+                "walk" => Ok(super::Speed::Walk),
+                _ => Err(E::invalid_value(serde::de::Unexpected::Str(value), &self)),
+            }
         }
 
         fn visit_map<M>(self, map: M) -> Result<Self::Value, M::Error>
         where
             M: serde::de::MapAccess<'de>,
         {
-            serde::Deserialize::deserialize(serde::de::value::MapAccessDeserializer::new(map))
+            let s: SpeedStruct =
+                serde::Deserialize::deserialize(serde::de::value::MapAccessDeserializer::new(map))?;
+            Ok(s.into())
         }
     }
 
-    #[cfg(feature = "serde")]
     pub(crate) fn deserialize<'de, D>(deserializer: D) -> Result<super::Speed, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
-        let s: SpeedStruct = deserializer.deserialize_any(FloatOrStruct)?;
-        Ok(match s {
-            SpeedStruct {
-                unit: SpeedUnit::Kph,
-                value,
-            } => super::Speed::Kph(value),
-            SpeedStruct {
-                unit: SpeedUnit::Mph,
-                value,
-            } => super::Speed::Mph(value),
-            SpeedStruct {
-                unit: SpeedUnit::Knots,
-                value,
-            } => super::Speed::Knots(value),
-        })
+        deserializer.deserialize_any(FloatOrStruct)
     }
 
     #[cfg(test)]
@@ -328,7 +640,10 @@ mod speed {
 #[allow(clippy::similar_names)]
 #[cfg(test)]
 mod tests {
-    use crate::metric::Speed;
+    use crate::metric::{
+        LenientMetre, Metre, MetreError, Speed, SpeedClass, SpeedError, SpeedUnit, Tonne,
+        TonneError,
+    };
 
     #[test]
     fn test_speed() {
@@ -380,4 +695,161 @@ mod tests {
             serde_json::from_str(&serde_json::to_string(&speed_knots_struct.0).unwrap()).unwrap(),
         );
     }
+
+    #[test]
+    fn test_speed_symbolic() {
+        assert_eq!("none".parse::<Speed>().unwrap(), Speed::None);
+        assert_eq!("walk".parse::<Speed>().unwrap(), Speed::Walk);
+        assert_eq!(Speed::None.to_string(), "none");
+        assert_eq!(Speed::Walk.kph(), None);
+        assert_eq!(serde_json::to_string(&Speed::None).unwrap(), r#""none""#);
+        assert_eq!(
+            Speed::Walk,
+            serde_json::from_str::<Speed>(r#""walk""#).unwrap()
+        );
+        assert!(serde_json::from_str::<Speed>(r#""fast""#).is_err());
+    }
+
+    #[test]
+    fn test_speed_unit() {
+        assert_eq!("50".parse::<Speed>().unwrap(), Speed::Kph(50.0));
+        assert_eq!("50 km/h".parse::<Speed>().unwrap(), Speed::Kph(50.0));
+        assert_eq!("30 mph".parse::<Speed>().unwrap(), Speed::Mph(30.0));
+        assert_eq!("30mph".parse::<Speed>().unwrap(), Speed::Mph(30.0));
+        assert_eq!("10 knots".parse::<Speed>().unwrap(), Speed::Knots(10.0));
+        assert!(matches!(
+            "30 fps".parse::<Speed>(),
+            Err(SpeedError::UnknownUnit(_))
+        ));
+        assert!(matches!(
+            "3025 mph".parse::<Speed>(),
+            Err(SpeedError::OutOfRange)
+        ));
+
+        // A plain number is in the default unit, other units are explicit
+        assert_eq!(
+            Speed::parse_in("30", SpeedUnit::Mph).unwrap(),
+            Speed::Mph(30.0)
+        );
+        assert_eq!(
+            Speed::parse_in("50 km/h", SpeedUnit::Mph).unwrap(),
+            Speed::Kph(50.0)
+        );
+        assert_eq!(Speed::Mph(30.0).to_string_in(SpeedUnit::Mph), "30 mph");
+        assert_eq!(Speed::Kph(50.0).to_string_in(SpeedUnit::Mph), "50 km/h");
+        assert_eq!(Speed::Kph(50.0).to_string_in(SpeedUnit::Kph), "50");
+        assert_eq!(Speed::Mph(30.0).to_string_in(SpeedUnit::Kph), "30 mph");
+        assert_eq!(Speed::None.to_string_in(SpeedUnit::Mph), "none");
+    }
+
+    #[test]
+    fn test_speed_compare() {
+        assert!(Speed::Kph(50.0_f64) < Speed::Kph(60.0_f64));
+        assert!(Speed::Mph(30.0_f64) < Speed::Kph(50.0_f64));
+        assert!(Speed::Knots(10.0_f64) > Speed::Kph(10.0_f64));
+        assert!(Speed::Walk < Speed::Kph(5.0_f64));
+        assert!(Speed::None > Speed::Kph(300.0_f64));
+        assert!(Speed::Walk < Speed::None);
+        assert_eq!(
+            Speed::Kph(1.609_344_f64).partial_cmp(&Speed::Mph(1.0_f64)),
+            None,
+            "equal in km/h but not equal"
+        );
+    }
+
+    #[test]
+    fn test_speed_convert() {
+        assert_eq!(Speed::Mph(10.0_f64).kph(), Some(16.093_44_f64));
+        assert_eq!(Speed::Mph(30.0_f64).mph(), Some(30.0_f64));
+        assert!((Speed::Kph(100.0_f64).mph().unwrap() - 62.137_f64).abs() < 0.001_f64);
+        assert_eq!(Speed::Walk.mph(), None);
+        assert_eq!(
+            Speed::Mph(10.0_f64).to_unit(SpeedUnit::Kph),
+            Speed::Kph(16.093_44_f64)
+        );
+        assert_eq!(
+            Speed::Knots(10.0_f64).to_unit(SpeedUnit::Kph),
+            Speed::Kph(18.52_f64)
+        );
+        assert_eq!(
+            Speed::Kph(18.52_f64).to_unit(SpeedUnit::Knots),
+            Speed::Knots(10.0_f64)
+        );
+        assert_eq!(Speed::None.to_unit(SpeedUnit::Mph), Speed::None);
+    }
+
+    #[test]
+    fn test_speed_class() {
+        assert_eq!(Speed::Walk.class(), SpeedClass::Walking);
+        assert_eq!(Speed::Kph(10.0_f64).class(), SpeedClass::Walking);
+        assert_eq!(Speed::Mph(20.0_f64).class(), SpeedClass::Living);
+        assert_eq!(Speed::Kph(50.0_f64).class(), SpeedClass::Intra);
+        assert_eq!(Speed::Mph(50.0_f64).class(), SpeedClass::Inter);
+        assert_eq!(Speed::Kph(130.0_f64).class(), SpeedClass::Max);
+        assert_eq!(Speed::Kph(250.0_f64).class(), SpeedClass::Max);
+        assert_eq!(Speed::None.class(), SpeedClass::Max);
+        assert!(SpeedClass::Living < SpeedClass::Inter);
+    }
+
+    #[test]
+    fn test_metre() {
+        assert_eq!("3".parse::<Metre>().unwrap(), Metre::new(3.0));
+        assert_eq!("3 m".parse::<Metre>().unwrap(), Metre::new(3.0));
+        assert_eq!("2.5m".parse::<Metre>().unwrap(), Metre::new(2.5));
+        assert!(matches!("".parse::<Metre>(), Err(MetreError::Empty)));
+        assert!(matches!("-1".parse::<Metre>(), Err(MetreError::OutOfRange)));
+        assert!(matches!(
+            "3 ft".parse::<Metre>(),
+            Err(MetreError::UnknownUnit(_))
+        ));
+        assert!(matches!("wide".parse::<Metre>(), Err(MetreError::Parse(_))));
+    }
+
+    #[test]
+    fn test_tonne() {
+        assert_eq!("7.5".parse::<Tonne>().unwrap(), Tonne::new(7.5));
+        assert_eq!("3.5 t".parse::<Tonne>().unwrap(), Tonne::new(3.5));
+        assert!(matches!("".parse::<Tonne>(), Err(TonneError::Empty)));
+        assert!(matches!(
+            "10 lbs".parse::<Tonne>(),
+            Err(TonneError::UnknownUnit(_))
+        ));
+    }
+
+    #[test]
+    fn test_metre_lenient() {
+        assert_eq!(
+            "3 m".parse::<LenientMetre>().unwrap(),
+            LenientMetre::Exact(Metre::new(3.0))
+        );
+        assert_eq!(
+            "~3".parse::<LenientMetre>().unwrap(),
+            LenientMetre::Approximate(Metre::new(3.0))
+        );
+        assert_eq!(
+            "ca. 1.5 m".parse::<LenientMetre>().unwrap(),
+            LenientMetre::Approximate(Metre::new(1.5))
+        );
+        let range = "2-2.5".parse::<LenientMetre>().unwrap();
+        assert_eq!(
+            range,
+            LenientMetre::Range {
+                min: Metre::new(2.0),
+                max: Metre::new(2.5)
+            }
+        );
+        assert_eq!(range.target(), Metre::new(2.25));
+        assert_eq!(
+            "2 m - 3 m".parse::<LenientMetre>().unwrap(),
+            LenientMetre::Range {
+                min: Metre::new(2.0),
+                max: Metre::new(3.0)
+            }
+        );
+        assert!(matches!(
+            "3-2".parse::<LenientMetre>(),
+            Err(MetreError::InvertedRange)
+        ));
+        assert!("-1".parse::<LenientMetre>().is_err());
+    }
 }
@@ -0,0 +1,234 @@
+//! Retagging a way from its lanes, as an [OsmChange](https://wiki.openstreetmap.org/wiki/OsmChange)
+//! document that editors such as JOSM can open and upload
+
+use std::collections::BTreeSet;
+use std::fmt::Write;
+
+use osm_tags::Tags;
+
+use crate::locale::Locale;
+use crate::road::Road;
+use crate::transform::{
+    lane_keys, lanes_to_tags, tags_to_lanes, LanesToTagsConfig, LanesToTagsMsg, TagsToLanesConfig,
+};
+
+/// A way as it currently is in OpenStreetMap
+pub struct Way {
+    pub id: u64,
+    /// Version of the way being modified, the upload is rejected if it has since changed
+    pub version: u64,
+    /// Node references, which an `OsmChange` must repeat even when only the tags change
+    pub nodes: Vec<u64>,
+    pub tags: Tags,
+}
+
+/// Changes to the tags of a way
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct TagChanges {
+    /// Added or modified tags
+    pub set: Vec<(String, String)>,
+    /// Removed keys
+    pub removed: Vec<String>,
+}
+
+impl TagChanges {
+    /// Between the original tags of a way and those of its lanes.
+    ///
+    /// Keys of the original tags that are not generated for the lanes are only removed
+    /// if they describe the lanes of the original tags, other tags such as `name=*` are kept.
+    ///
+    /// # Errors
+    ///
+    /// If the lanes cannot be converted to tags
+    pub fn new(
+        original: &Tags,
+        road: &Road,
+        locale: &Locale,
+        config: &LanesToTagsConfig,
+    ) -> Result<Self, LanesToTagsMsg> {
+        let updated = lanes_to_tags(road, locale, config)?;
+        let lane_keys: BTreeSet<String> =
+            tags_to_lanes(original, locale, &TagsToLanesConfig::default())
+                .map(|road_from_tags| lane_keys(original, &road_from_tags.road.lanes))
+                .unwrap_or_default();
+        Ok(Self {
+            set: updated
+                .to_str_pairs()
+                .into_iter()
+                .filter(|(key, val)| original.get(*key) != Some(*val))
+                .map(|(key, val)| (key.to_owned(), val.to_owned()))
+                .collect(),
+            removed: original
+                .to_str_pairs()
+                .into_iter()
+                .map(|(key, _val)| key)
+                .filter(|key| lane_keys.contains(*key) && updated.get(*key).is_none())
+                .map(str::to_owned)
+                .collect(),
+        })
+    }
+
+    /// Whether the tags are unchanged
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.set.is_empty() && self.removed.is_empty()
+    }
+
+    /// The tags with the changes applied
+    #[must_use]
+    pub fn apply(&self, tags: &Tags) -> Tags {
+        let kept = tags
+            .to_str_pairs()
+            .into_iter()
+            .filter(|(key, _val)| {
+                !self.removed.iter().any(|removed| removed == key)
+                    && !self.set.iter().any(|(set, _val)| set == key)
+            })
+            .map(|(key, val)| (key.to_owned(), val.to_owned()));
+        let set = self.set.iter().cloned();
+        Tags::from_pairs(kept.chain(set)).expect("changed keys are unique")
+    }
+}
+
+/// An `OsmChange` document modifying the tags of the way to those of the road,
+/// empty if the tags are unchanged.
+///
+/// # Errors
+///
+/// If the lanes cannot be converted to tags
+pub fn osmchange(
+    way: &Way,
+    road: &Road,
+    locale: &Locale,
+    config: &LanesToTagsConfig,
+) -> Result<String, LanesToTagsMsg> {
+    let changes = TagChanges::new(&way.tags, road, locale, config)?;
+    let mut xml = String::new();
+    write_osmchange(&mut xml, way, &changes).expect("writing to a String cannot fail");
+    Ok(xml)
+}
+
+fn write_osmchange(xml: &mut impl Write, way: &Way, changes: &TagChanges) -> std::fmt::Result {
+    writeln!(xml, r#"<?xml version="1.0" encoding="UTF-8"?>"#)?;
+    writeln!(xml, r#"<osmChange version="0.6" generator="osm2lanes">"#)?;
+    if !changes.is_empty() {
+        writeln!(xml, "  <modify>")?;
+        writeln!(
+            xml,
+            r#"    <way id="{}" version="{}">"#,
+            way.id, way.version
+        )?;
+        for node in &way.nodes {
+            writeln!(xml, r#"      <nd ref="{node}"/>"#)?;
+        }
+        for (key, val) in changes.apply(&way.tags).to_str_pairs() {
+            writeln!(
+                xml,
+                r#"      <tag k="{}" v="{}"/>"#,
+                escape(key),
+                escape(val)
+            )?;
+        }
+        writeln!(xml, "    </way>")?;
+        writeln!(xml, "  </modify>")?;
+    }
+    writeln!(xml, "</osmChange>")
+}
+
+/// Escape text for an XML attribute
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use osm_tags::Tags;
+
+    use super::{escape, osmchange, TagChanges, Way};
+    use crate::locale::{DrivingSide, Locale};
+    use crate::transform::{tags_to_lanes, LanesToTagsConfig, TagsToLanesConfig};
+
+    #[test]
+    fn tag_changes() {
+        let locale = Locale::builder().driving_side(DrivingSide::Right).build();
+        let original = Tags::from_pairs([
+            ("highway", "residential"),
+            ("name", "Main Street"),
+            ("lanes", "2"),
+            ("cycleway", "lane"),
+        ])
+        .unwrap();
+        let retagged = Tags::from_pairs([
+            ("highway", "residential"),
+            ("lanes", "3"),
+            ("lanes:forward", "2"),
+            ("cycleway:right", "lane"),
+        ])
+        .unwrap();
+        let road = tags_to_lanes(&retagged, &locale, &TagsToLanesConfig::default())
+            .unwrap()
+            .road;
+        let changes =
+            TagChanges::new(&original, &road, &locale, &LanesToTagsConfig::new(false)).unwrap();
+        assert!(changes.set.contains(&("lanes".to_owned(), "3".to_owned())));
+        assert!(changes
+            .set
+            .contains(&("cycleway:right".to_owned(), "lane".to_owned())));
+        assert_eq!(changes.removed, vec!["cycleway".to_owned()]);
+
+        let tags = changes.apply(&original);
+        assert_eq!(tags.get("name"), Some("Main Street"));
+        assert_eq!(tags.get("cycleway"), None);
+        assert_eq!(tags.get("lanes"), Some("3"));
+
+        let way = Way {
+            id: 1,
+            version: 4,
+            nodes: vec![10, 11],
+            tags: original,
+        };
+        let xml = osmchange(&way, &road, &locale, &LanesToTagsConfig::new(false)).unwrap();
+        assert!(xml.contains(r#"<way id="1" version="4">"#));
+        assert!(xml.contains(r#"<nd ref="11"/>"#));
+        assert!(xml.contains(r#"<tag k="name" v="Main Street"/>"#));
+        assert!(!xml.contains(r#"k="cycleway""#));
+    }
+
+    #[test]
+    fn unchanged() {
+        let locale = Locale::builder().driving_side(DrivingSide::Right).build();
+        let tags = Tags::from_pairs([("highway", "residential"), ("lanes", "2")]).unwrap();
+        let road = tags_to_lanes(&tags, &locale, &TagsToLanesConfig::default())
+            .unwrap()
+            .road;
+        let config = LanesToTagsConfig::new(false);
+        let normalized = crate::transform::lanes_to_tags(&road, &locale, &config).unwrap();
+        let way = Way {
+            id: 1,
+            version: 1,
+            nodes: Vec::new(),
+            tags: normalized,
+        };
+        let xml = osmchange(&way, &road, &locale, &config).unwrap();
+        assert!(!xml.contains("<modify>"));
+    }
+
+    #[test]
+    fn escaping() {
+        assert_eq!(
+            escape(r#"Tom & "Jerry's" <road>"#),
+            "Tom &amp; &quot;Jerry&apos;s&quot; &lt;road&gt;"
+        );
+    }
+}
@@ -1,8 +1,18 @@
+use std::collections::HashMap;
+use std::time::Duration;
+
 use geo::algorithm::euclidean_distance::EuclideanDistance;
-use geo::{LineString, Point};
+use geo::{LineString, Point, Rect};
 use osm_tags::Tags;
+use reqwest::header::{HeaderMap, RETRY_AFTER};
+use reqwest::StatusCode;
+
+use crate::locale::Locale;
+use crate::service;
+use crate::transform::{tags_to_lanes, RoadError, RoadFromTags, TagsToLanesConfig};
 
-use crate::locale::{DrivingSide, Locale};
+#[cfg(feature = "overpass-blocking")]
+pub mod blocking;
 
 #[derive(Debug, Clone)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize))]
@@ -29,13 +39,105 @@ impl OverpassResponse {
             .find_map(|element| element.tags.get("driving_side"))
     }
     fn locale(&self) -> Locale {
-        Locale::builder()
-            .driving_side(
-                self.driving_side()
-                    .map_or(DrivingSide::Right, |d| d.parse().unwrap()),
-            )
-            .iso_3166_option(self.iso3166_2().or_else(|| self.iso3166_1()))
-            .build()
+        service::locale(
+            self.driving_side(),
+            self.iso3166_2().or_else(|| self.iso3166_1()),
+        )
+    }
+
+    /// The ways with a geometry in the response to [`query_for_bbox`] converted to lanes,
+    /// each in the locale of the areas enclosing it
+    fn into_roads(self, config: &TagsToLanesConfig) -> Vec<RoadFromWay> {
+        self.split_ways()
+            .into_iter()
+            .filter_map(|resp| {
+                let locale = resp.locale();
+                let element = resp.elements.into_iter().next()?;
+                let geometry = convert(&element.geometry?);
+                let road = tags_to_lanes(&element.tags, &locale, config);
+                Some(RoadFromWay {
+                    id: element.id,
+                    tags: element.tags,
+                    geometry,
+                    locale,
+                    road,
+                })
+            })
+            .collect()
+    }
+
+    /// The tags of the way in the response to [`query_for_tags`]
+    fn into_tags(mut self, id: ElementId) -> Result<Tags, Error> {
+        let way_element = self.elements.pop().ok_or(Error::Empty)?;
+        if !self.elements.is_empty()
+            || way_element.r#type != ElementType::Way
+            || way_element.id != id
+        {
+            return Err(Error::Malformed);
+        }
+        Ok(way_element.tags)
+    }
+
+    /// The ways in the response to [`query_for_ways`], by ID
+    fn into_ways(self, ids: &[ElementId]) -> Result<Ways, Error> {
+        self.split_ways()
+            .into_iter()
+            .map(|resp| {
+                let locale = resp.locale();
+                let way_element = resp.elements.into_iter().next().ok_or(Error::Empty)?;
+                if !ids.contains(&way_element.id) {
+                    return Err(Error::Malformed);
+                }
+                let geometry = convert(&way_element.geometry.ok_or(Error::Malformed)?);
+                Ok((way_element.id, (way_element.tags, geometry, locale)))
+            })
+            .collect()
+    }
+
+    /// The way in the response to [`query_for_nearby`] nearest to the point
+    fn into_nearest(
+        self,
+        point: Point<f64>,
+    ) -> Result<(ElementId, Tags, LineString<f64>, Locale), Error> {
+        let locale = self.locale();
+        let (way_element, geometry, _distance) = self
+            .elements
+            .into_iter()
+            .filter_map(|element| {
+                let geometry = element.geometry.as_ref().map(|g| convert(g));
+                geometry.map(|geometry| {
+                    let distance = point.euclidean_distance(&geometry);
+                    (element, geometry, distance)
+                })
+            })
+            .min_by(|(_, _, left_distance), (_, _, right_distance)| {
+                left_distance.partial_cmp(right_distance).unwrap()
+            })
+            .ok_or(Error::Empty)?;
+
+        if way_element.r#type != ElementType::Way {
+            return Err(Error::Malformed);
+        }
+
+        Ok((way_element.id, way_element.tags, geometry, locale))
+    }
+
+    /// Split the response to [`query_for_ways`] or [`query_for_bbox`] into a response per way,
+    /// each with the way followed by the areas enclosing it
+    fn split_ways(self) -> Vec<Self> {
+        let mut responses: Vec<Self> = Vec::new();
+        for element in self.elements {
+            if element.r#type == ElementType::Way {
+                responses.push(Self {
+                    elements: vec![element],
+                });
+            } else if let Some(response) = responses.last_mut() {
+                response.elements.push(element);
+            } else {
+                log::warn!("overpass area {} before any way", element.id);
+            }
+        }
+        responses
     }
 }
 
@@ -102,85 +204,354 @@ impl From<reqwest::Error> for Error {
     }
 }
 
-/// Get Tags from Overpass
+const INTERPRETER: &str = "https://overpass-api.de/api/interpreter";
+const TIMEOUT: Duration = Duration::from_secs(25);
+
+/// Overpass QL used by [`get_way`], for reproducing its input with e.g. Overpass turbo
+#[must_use]
+pub fn query_for_way(id: ElementId) -> String {
+    query_for_ways(&[id], TIMEOUT)
+}
+
+fn query_for_tags(id: ElementId, timeout: Duration) -> String {
+    let timeout = timeout.as_secs();
+    format!("[out:json][timeout:{timeout}];way(id:{id});out tags;")
+}
+
+/// Overpass QL used by [`OverpassClient::get_ways`],
+/// each way is followed by the areas enclosing it, which give its locale
+#[must_use]
+pub fn query_for_ways(ids: &[ElementId], timeout: Duration) -> String {
+    let timeout = timeout.as_secs();
+    let ids = ids
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join(",");
+    format!(
+        r#"[out:json][timeout:{timeout}];
+            way(id:{ids});
+            foreach->.way(
+                .way out tags geom;
+                .way >;
+                is_in->.enclosing;
+                (
+                    area.enclosing["ISO3166-2"];
+                    area.enclosing["ISO3166-1"];
+                    area.enclosing["driving_side"];
+                );
+                out tags;
+            );"#
+    )
+}
+
+/// Overpass QL for all highways within a bounding box of latitude (x) and longitude (y)
+#[must_use]
+pub fn query_for_bbox(bbox: Rect<f64>) -> String {
+    query_for_bbox_with(bbox, TIMEOUT)
+}
+
+fn query_for_bbox_with(bbox: Rect<f64>, timeout: Duration) -> String {
+    let (south, west) = (bbox.min().x, bbox.min().y);
+    let (north, east) = (bbox.max().x, bbox.max().y);
+    let timeout = timeout.as_secs();
+    format!(
+        r#"[out:json][timeout:{timeout}];
+            way
+                ({south},{west},{north},{east})
+                ["highway"];
+            foreach->.way(
+                .way out tags geom;
+                .way >;
+                is_in->.enclosing;
+                (
+                    area.enclosing["ISO3166-2"];
+                    area.enclosing["ISO3166-1"];
+                    area.enclosing["driving_side"];
+                );
+                out tags;
+            );"#
+    )
+}
+
+fn query_for_nearby(point: Point<f64>, radius: f64, timeout: Duration) -> String {
+    let lat = point.x();
+    let lon = point.y();
+    let timeout = timeout.as_secs();
+    format!(
+        r#"[out:json][timeout:{timeout}];
+            way
+                (around:{radius},{lat},{lon})
+                ["highway"];
+            out tags geom;
+            >;
+            is_in->.enclosing;
+            (
+                area.enclosing["ISO3166-2"];
+                area.enclosing["ISO3166-1"];
+                area.enclosing["driving_side"];
+            );
+            out tags;"#
+    )
+}
+
+/// A way converted to lanes
+#[derive(Debug)]
+pub struct RoadFromWay {
+    pub id: ElementId,
+    pub tags: Tags,
+    pub geometry: LineString<f64>,
+    pub locale: Locale,
+    pub road: Result<RoadFromTags, RoadError>,
+}
+
+/// Ways by ID, with their tags, geometry and locale
+pub type Ways = HashMap<ElementId, (Tags, LineString<f64>, Locale)>;
+
+/// The delay before retrying a request, from the `Retry-After` header in seconds
+fn retry_after(headers: &HeaderMap) -> Option<Duration> {
+    headers
+        .get(RETRY_AFTER)?
+        .to_str()
+        .ok()?
+        .parse()
+        .ok()
+        .map(Duration::from_secs)
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+async fn sleep(duration: Duration) {
+    tokio::time::sleep(duration).await;
+}
+
+/// Never called, as without a timer in the browser requests are not retried, see [`Settings::retry`]
+#[cfg(target_arch = "wasm32")]
+#[allow(clippy::unused_async)] // Same signature as the native sleep
+async fn sleep(_duration: Duration) {}
+
+/// Where and how requests are made, shared by the async and blocking clients
+#[derive(Debug, Clone)]
+struct Settings {
+    endpoint: String,
+    timeout: Duration,
+    retries: u32,
+    retry_delay: Duration,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            endpoint: INTERPRETER.to_owned(),
+            timeout: TIMEOUT,
+            retries: 2,
+            retry_delay: Duration::from_secs(5),
+        }
+    }
+}
+
+impl Settings {
+    /// The delay before retrying a request when the server is rate limiting or overloaded,
+    /// `None` if it is not to be retried after `attempt` retries, or ever in the browser
+    fn retry(&self, status: StatusCode, headers: &HeaderMap, attempt: u32) -> Option<Duration> {
+        if cfg!(target_arch = "wasm32")
+            || (status != StatusCode::TOO_MANY_REQUESTS && status != StatusCode::GATEWAY_TIMEOUT)
+            || attempt >= self.retries
+        {
+            return None;
+        }
+        let attempt = attempt + 1;
+        let delay = retry_after(headers).unwrap_or(self.retry_delay * attempt);
+        log::warn!(
+            "overpass responded {status}, retry {attempt} of {} in {delay:?}",
+            self.retries
+        );
+        Some(delay)
+    }
+}
+
+/// Requests to an Overpass API interpreter
+#[derive(Debug, Clone, Default)]
+pub struct OverpassClient {
+    client: reqwest::Client,
+    settings: Settings,
+}
+
+impl OverpassClient {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// URL of the interpreter, <https://overpass-api.de/api/interpreter> by default
+    #[must_use]
+    pub fn endpoint(mut self, endpoint: impl Into<String>) -> Self {
+        self.settings.endpoint = endpoint.into();
+        self
+    }
+
+    /// Time the server may spend on a query, in whole seconds
+    #[must_use]
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.settings.timeout = timeout;
+        self
+    }
+
+    /// Times a request is retried when the server is rate limiting or overloaded,
+    /// waiting as long as it asks to, or otherwise `delay` times the number of attempts.
+    /// Requests are never retried in the browser, as there is no timer to wait with.
+    #[must_use]
+    pub fn retries(mut self, retries: u32, delay: Duration) -> Self {
+        self.settings.retries = retries;
+        self.settings.retry_delay = delay;
+        self
+    }
+
+    async fn query(&self, query: &str) -> Result<OverpassResponse, Error> {
+        let mut attempt = 0;
+        loop {
+            let response = self
+                .client
+                .get(&self.settings.endpoint)
+                .query(&[("data", query)])
+                .send()
+                .await?;
+            if let Some(delay) = self
+                .settings
+                .retry(response.status(), response.headers(), attempt)
+            {
+                attempt += 1;
+                sleep(delay).await;
+                continue;
+            }
+            let resp = response
+                .error_for_status()?
+                .json::<OverpassResponse>()
+                .await?;
+            log::debug!("{resp:#?}");
+            return Ok(resp);
+        }
+    }
+
+    /// Get Tags
+    ///
+    /// # Errors
+    ///
+    /// May occur when processing a request.
+    pub async fn get_tags(&self, id: &ElementId) -> Result<Tags, Error> {
+        self.query(&query_for_tags(*id, self.settings.timeout))
+            .await?
+            .into_tags(*id)
+    }
+
+    /// Get Way
+    ///
+    /// # Errors
+    ///
+    /// May occur when processing a request, or if the way is not found.
+    pub async fn get_way(&self, id: ElementId) -> Result<(Tags, LineString<f64>, Locale), Error> {
+        self.get_ways(&[id]).await?.remove(&id).ok_or(Error::Empty)
+    }
+
+    /// Get Ways in a single query, by ID.
+    /// Ways that are not found are missing from the result.
+    ///
+    /// # Errors
+    ///
+    /// May occur when processing a request.
+    pub async fn get_ways(&self, ids: &[ElementId]) -> Result<Ways, Error> {
+        if ids.is_empty() {
+            return Ok(HashMap::new());
+        }
+        self.query(&query_for_ways(ids, self.settings.timeout))
+            .await?
+            .into_ways(ids)
+    }
+
+    /// Get every highway within a bounding box, and convert each to lanes
+    /// in the locale of the areas enclosing it.
+    /// A way that fails to convert keeps its error, instead of failing the whole area.
+    ///
+    /// # Errors
+    ///
+    /// May occur when processing a request.
+    pub async fn get_roads_in_bbox(
+        &self,
+        min_lon: f64,
+        min_lat: f64,
+        max_lon: f64,
+        max_lat: f64,
+        config: &TagsToLanesConfig,
+    ) -> Result<Vec<RoadFromWay>, Error> {
+        let bbox = Rect::new((min_lat, min_lon), (max_lat, max_lon));
+        let resp = self
+            .query(&query_for_bbox_with(bbox, self.settings.timeout))
+            .await?;
+        Ok(resp.into_roads(config))
+    }
+
+    /// Get Tags and Geometries.
+    /// Given a longitude and latitude, find the nearest way within `radius` by euclidean distance.
+    ///
+    /// # Errors
+    ///
+    /// May occur when processing a request.
+    ///
+    /// # Panics
+    ///
+    /// Unexpected data from overpass and/or openstreetmap.
+    ///
+    pub async fn get_nearby(
+        &self,
+        point: Point<f64>,
+        radius: f64,
+    ) -> Result<(ElementId, Tags, LineString<f64>, Locale), Error> {
+        self.query(&query_for_nearby(point, radius, self.settings.timeout))
+            .await?
+            .into_nearest(point)
+    }
+}
+
+/// Get all roads in a bounding box from Overpass, see [`OverpassClient::get_roads_in_bbox`]
 ///
 /// # Errors
 ///
 /// May occur when processing a request.
+pub async fn get_roads_in_bbox(
+    min_lon: f64,
+    min_lat: f64,
+    max_lon: f64,
+    max_lat: f64,
+) -> Result<Vec<RoadFromWay>, Error> {
+    OverpassClient::default()
+        .get_roads_in_bbox(
+            min_lon,
+            min_lat,
+            max_lon,
+            max_lat,
+            &TagsToLanesConfig::default(),
+        )
+        .await
+}
+
+/// Get Tags from Overpass, see [`OverpassClient::get_tags`]
 ///
-/// # Panics
-///
-/// Unexpected data from overpass and/or openstreetmap.
+/// # Errors
 ///
+/// May occur when processing a request.
 pub async fn get_tags(id: &ElementId) -> Result<Tags, Error> {
-    let mut resp = reqwest::Client::new()
-        .get(format!(
-            "https://overpass-api.de/api/interpreter?data=[out:json][timeout:2];way(id:{});out tags;",
-            id
-        ))
-        .send()
-        .await?
-        .json::<OverpassResponse>()
-        .await?;
-    log::debug!("{:#?}", resp);
-    let way_element = resp.elements.pop().ok_or(Error::Empty)?;
-    if !resp.elements.is_empty() || way_element.r#type != ElementType::Way || &way_element.id != id
-    {
-        return Err(Error::Malformed);
-    }
-    Ok(way_element.tags)
+    OverpassClient::default().get_tags(id).await
 }
 
-/// Get Way from Overpass
+/// Get Way from Overpass, see [`OverpassClient::get_way`]
 ///
 /// # Errors
 ///
 /// May occur when processing a request.
-///
-/// # Panics
-///
-/// Unexpected data from overpass and/or openstreetmap.
-///
 pub async fn get_way(id: ElementId) -> Result<(Tags, LineString<f64>, Locale), Error> {
-    let resp = reqwest::Client::new()
-        .get(format!(
-            r#"https://overpass-api.de/api/interpreter?data=[out:json][timeout:25];
-            way(id:{id});
-            out tags geom;
-            >;
-            is_in->.enclosing;
-            (
-                area.enclosing["ISO3166-2"];
-                area.enclosing["ISO3166-1"];
-                area.enclosing["driving_side"];
-            );
-            out tags;"#
-        ))
-        .send()
-        .await?
-        .json::<OverpassResponse>()
-        .await?;
-    log::debug!("{:#?}", resp);
-
-    let locale = resp.locale();
-    let way_element = {
-        let mut elements = resp.elements;
-        elements.truncate(1);
-        elements.pop().ok_or(Error::Empty)?
-    };
-
-    if way_element.r#type != ElementType::Way || way_element.id != id {
-        return Err(Error::Malformed);
-    }
-    Ok((
-        way_element.tags,
-        convert(&way_element.geometry.ok_or(Error::Malformed)?),
-        locale,
-    ))
+    OverpassClient::default().get_way(id).await
 }
 
-/// Get Tags and Geometries from Overpass.
-/// Given a longitude and latitude, find the nearest way within 100m by euclidean distance.
+/// Get the nearest way from Overpass, see [`OverpassClient::get_nearby`]
 ///
 /// # Errors
 ///
@@ -194,51 +565,7 @@ pub async fn get_nearby(
     point: Point<f64>,
     radius: f64,
 ) -> Result<(ElementId, Tags, LineString<f64>, Locale), Error> {
-    let lat = point.x();
-    let lon = point.y();
-    let resp = reqwest::Client::new()
-        .get(format!(
-            r#"https://overpass-api.de/api/interpreter?data=[out:json][timeout:25];
-            way
-                (around:{radius},{lat},{lon})
-                ["highway"];
-            out tags geom;
-            >;
-            is_in->.enclosing;
-            (
-                area.enclosing["ISO3166-2"];
-                area.enclosing["ISO3166-1"];
-                area.enclosing["driving_side"];
-            );
-            out tags;"#
-        ))
-        .send()
-        .await?
-        .json::<OverpassResponse>()
-        .await?;
-    log::debug!("{:#?}", resp);
-
-    let locale = resp.locale();
-    let (way_element, geometry, _distance) = resp
-        .elements
-        .into_iter()
-        .filter_map(|element| {
-            let geometry = element.geometry.as_ref().map(|g| convert(g));
-            geometry.map(|geometry| {
-                let distance = point.euclidean_distance(&geometry);
-                (element, geometry, distance)
-            })
-        })
-        .min_by(|(_, _, left_distance), (_, _, right_distance)| {
-            left_distance.partial_cmp(right_distance).unwrap()
-        })
-        .ok_or(Error::Empty)?;
-
-    if way_element.r#type != ElementType::Way {
-        return Err(Error::Malformed);
-    }
-
-    Ok((way_element.id, way_element.tags, geometry, locale))
+    OverpassClient::default().get_nearby(point, radius).await
 }
 
 #[cfg(test)]
@@ -328,7 +655,18 @@ mod tests {
       }      
     "#;
 
-    use super::OverpassResponse;
+    use std::time::Duration;
+
+    use geo::Rect;
+    use reqwest::header::{HeaderMap, HeaderValue, RETRY_AFTER};
+    use reqwest::StatusCode;
+
+    use super::{
+        query_for_bbox, query_for_way, query_for_ways, retry_after, ElementType, OverpassResponse,
+        Settings,
+    };
+    use crate::locale::DrivingSide;
+    use crate::transform::TagsToLanesConfig;
 
     #[test]
     fn element_from_response() {
@@ -337,4 +675,81 @@ mod tests {
         let element = result.elements.first().unwrap();
         assert!(element.geometry.is_some());
     }
+
+    #[test]
+    fn queries() {
+        assert!(query_for_way(62_176_050).contains("way(id:62176050);"));
+        let bbox = Rect::new((-25.2_f64, 119.3_f64), (-24.7_f64, 119.6_f64));
+        let bbox = query_for_bbox(bbox);
+        assert!(bbox.contains("(-25.2,119.3,-24.7,119.6)"));
+        assert!(bbox.contains("foreach->.way("));
+        let ways = query_for_ways(&[1, 2], Duration::from_secs(50));
+        assert!(ways.starts_with("[out:json][timeout:50];"));
+        assert!(ways.contains("way(id:1,2);"));
+    }
+
+    #[test]
+    fn split_ways() {
+        let mut result: OverpassResponse = serde_json::from_str(RESPONSE).unwrap();
+        let mut second = result.elements.first().unwrap().clone();
+        second.id = 1;
+        result.elements.insert(1, second);
+
+        let mut ways = result.split_ways().into_iter();
+        let first = ways.next().unwrap();
+        assert_eq!(first.elements.len(), 1);
+        assert_eq!(first.locale().iso_3166_2_subdivision, None);
+        let second = ways.next().unwrap();
+        assert_eq!(second.elements.first().unwrap().r#type, ElementType::Way);
+        assert_eq!(second.elements.len(), 3);
+        assert_eq!(second.locale().driving_side, DrivingSide::Left);
+        assert!(ways.next().is_none());
+    }
+
+    #[test]
+    fn into_roads() {
+        let mut result: OverpassResponse = serde_json::from_str(RESPONSE).unwrap();
+        let mut second = result.elements.first().unwrap().clone();
+        second.id = 1;
+        result.elements.push(second);
+
+        let roads = result.into_roads(&TagsToLanesConfig::default());
+        assert_eq!(roads.len(), 2);
+        let road = roads.first().unwrap();
+        assert_eq!(road.id, 62_176_050);
+        assert_eq!(road.geometry.0.len(), 4);
+        assert_eq!(road.locale.driving_side, DrivingSide::Left);
+        assert!(road.road.is_ok());
+        // Without enclosing areas of its own, the second way does not share the locale of the first
+        let road = roads.last().unwrap();
+        assert_eq!(road.id, 1);
+        assert_eq!(road.locale.driving_side, DrivingSide::Right);
+    }
+
+    #[test]
+    fn retry_after_header() {
+        let mut headers = HeaderMap::new();
+        assert_eq!(retry_after(&headers), None);
+        headers.insert(RETRY_AFTER, HeaderValue::from_static("30"));
+        assert_eq!(retry_after(&headers), Some(Duration::from_secs(30)));
+    }
+
+    #[test]
+    fn retry() {
+        let settings = Settings {
+            retries: 2,
+            retry_delay: Duration::from_secs(5),
+            ..Settings::default()
+        };
+        let headers = HeaderMap::new();
+        assert_eq!(settings.retry(StatusCode::OK, &headers, 0), None);
+        assert_eq!(
+            settings.retry(StatusCode::GATEWAY_TIMEOUT, &headers, 1),
+            Some(Duration::from_secs(10))
+        );
+        assert_eq!(
+            settings.retry(StatusCode::TOO_MANY_REQUESTS, &headers, 2),
+            None
+        );
+    }
 }
@@ -0,0 +1,203 @@
+//! A blocking client to Overpass, for scripts and tests without an async runtime.
+//!
+//! The queries, retries and processing are those of the async [`super::OverpassClient`].
+//! As with `reqwest::blocking`, do not use it from within an async runtime.
+
+use std::collections::HashMap;
+use std::time::Duration;
+
+use geo::{LineString, Point, Rect};
+use osm_tags::Tags;
+
+use super::{
+    query_for_bbox_with, query_for_nearby, query_for_tags, query_for_ways, ElementId, Error,
+    OverpassResponse, RoadFromWay, Settings, Ways,
+};
+use crate::locale::Locale;
+use crate::transform::TagsToLanesConfig;
+
+/// Blocking requests to an Overpass API interpreter
+#[derive(Debug, Clone, Default)]
+pub struct OverpassClient {
+    client: reqwest::blocking::Client,
+    settings: Settings,
+}
+
+impl OverpassClient {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// URL of the interpreter, <https://overpass-api.de/api/interpreter> by default
+    #[must_use]
+    pub fn endpoint(mut self, endpoint: impl Into<String>) -> Self {
+        self.settings.endpoint = endpoint.into();
+        self
+    }
+
+    /// Time the server may spend on a query, in whole seconds
+    #[must_use]
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.settings.timeout = timeout;
+        self
+    }
+
+    /// Times a request is retried when the server is rate limiting or overloaded,
+    /// waiting as long as it asks to, or otherwise `delay` times the number of attempts
+    #[must_use]
+    pub fn retries(mut self, retries: u32, delay: Duration) -> Self {
+        self.settings.retries = retries;
+        self.settings.retry_delay = delay;
+        self
+    }
+
+    fn query(&self, query: &str) -> Result<OverpassResponse, Error> {
+        let mut attempt = 0;
+        loop {
+            let response = self
+                .client
+                .get(&self.settings.endpoint)
+                .query(&[("data", query)])
+                .send()?;
+            if let Some(delay) = self
+                .settings
+                .retry(response.status(), response.headers(), attempt)
+            {
+                attempt += 1;
+                std::thread::sleep(delay);
+                continue;
+            }
+            let resp = response.error_for_status()?.json::<OverpassResponse>()?;
+            log::debug!("{resp:#?}");
+            return Ok(resp);
+        }
+    }
+
+    /// Get Tags
+    ///
+    /// # Errors
+    ///
+    /// May occur when processing a request.
+    pub fn get_tags(&self, id: &ElementId) -> Result<Tags, Error> {
+        self.query(&query_for_tags(*id, self.settings.timeout))?
+            .into_tags(*id)
+    }
+
+    /// Get Way
+    ///
+    /// # Errors
+    ///
+    /// May occur when processing a request, or if the way is not found.
+    pub fn get_way(&self, id: ElementId) -> Result<(Tags, LineString<f64>, Locale), Error> {
+        self.get_ways(&[id])?.remove(&id).ok_or(Error::Empty)
+    }
+
+    /// Get Ways in a single query, by ID.
+    /// Ways that are not found are missing from the result.
+    ///
+    /// # Errors
+    ///
+    /// May occur when processing a request.
+    pub fn get_ways(&self, ids: &[ElementId]) -> Result<Ways, Error> {
+        if ids.is_empty() {
+            return Ok(HashMap::new());
+        }
+        self.query(&query_for_ways(ids, self.settings.timeout))?
+            .into_ways(ids)
+    }
+
+    /// Get every highway within a bounding box, and convert each to lanes,
+    /// see [`super::OverpassClient::get_roads_in_bbox`]
+    ///
+    /// # Errors
+    ///
+    /// May occur when processing a request.
+    pub fn get_roads_in_bbox(
+        &self,
+        min_lon: f64,
+        min_lat: f64,
+        max_lon: f64,
+        max_lat: f64,
+        config: &TagsToLanesConfig,
+    ) -> Result<Vec<RoadFromWay>, Error> {
+        let bbox = Rect::new((min_lat, min_lon), (max_lat, max_lon));
+        let resp = self.query(&query_for_bbox_with(bbox, self.settings.timeout))?;
+        Ok(resp.into_roads(config))
+    }
+
+    /// Get Tags and Geometries.
+    /// Given a longitude and latitude, find the nearest way within `radius` by euclidean distance.
+    ///
+    /// # Errors
+    ///
+    /// May occur when processing a request.
+    ///
+    /// # Panics
+    ///
+    /// Unexpected data from overpass and/or openstreetmap.
+    ///
+    pub fn get_nearby(
+        &self,
+        point: Point<f64>,
+        radius: f64,
+    ) -> Result<(ElementId, Tags, LineString<f64>, Locale), Error> {
+        self.query(&query_for_nearby(point, radius, self.settings.timeout))?
+            .into_nearest(point)
+    }
+}
+
+/// Get all roads in a bounding box from Overpass, see [`OverpassClient::get_roads_in_bbox`]
+///
+/// # Errors
+///
+/// May occur when processing a request.
+pub fn get_roads_in_bbox(
+    min_lon: f64,
+    min_lat: f64,
+    max_lon: f64,
+    max_lat: f64,
+) -> Result<Vec<RoadFromWay>, Error> {
+    OverpassClient::default().get_roads_in_bbox(
+        min_lon,
+        min_lat,
+        max_lon,
+        max_lat,
+        &TagsToLanesConfig::default(),
+    )
+}
+
+/// Get Tags from Overpass, see [`OverpassClient::get_tags`]
+///
+/// # Errors
+///
+/// May occur when processing a request.
+pub fn get_tags(id: &ElementId) -> Result<Tags, Error> {
+    OverpassClient::default().get_tags(id)
+}
+
+/// Get Way from Overpass, see [`OverpassClient::get_way`]
+///
+/// # Errors
+///
+/// May occur when processing a request.
+pub fn get_way(id: ElementId) -> Result<(Tags, LineString<f64>, Locale), Error> {
+    OverpassClient::default().get_way(id)
+}
+
+/// Get the nearest way from Overpass, see [`OverpassClient::get_nearby`]
+///
+/// # Errors
+///
+/// May occur when processing a request.
+///
+/// # Panics
+///
+/// Unexpected data from overpass and/or openstreetmap.
+///
+pub fn get_nearby(
+    point: Point<f64>,
+    radius: f64,
+) -> Result<(ElementId, Tags, LineString<f64>, Locale), Error> {
+    OverpassClient::default().get_nearby(point, radius)
+}
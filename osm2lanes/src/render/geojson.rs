@@ -0,0 +1,230 @@
+//! Lanes placed along the geometry of their way, for GIS tools such as QGIS
+
+use geo::{Coordinate, LineString};
+use serde_json::{json, Map, Value};
+
+use crate::locale::Locale;
+use crate::road::Road;
+
+/// Metres per degree of latitude, on the WGS 84 ellipsoid's equatorial radius
+const METRES_PER_DEGREE: f64 = 6_378_137.0 * std::f64::consts::PI / 180.0;
+
+/// Tightest angle at which offset lines are joined with a sharp corner,
+/// as the cosine of half the turn, sharper turns are bevelled at this angle
+const MITRE_LIMIT: f64 = 0.25;
+
+/// Local planar coordinates in metres, x east and y north, around an origin
+struct Projection {
+    origin: Coordinate<f64>,
+    metres_per_degree_lon: f64,
+}
+
+impl Projection {
+    /// Around the first point of a way, in latitude (x) and longitude (y) as from Overpass
+    fn new(origin: Coordinate<f64>) -> Self {
+        Self {
+            origin,
+            metres_per_degree_lon: METRES_PER_DEGREE * origin.x.to_radians().cos(),
+        }
+    }
+
+    fn project(&self, lat_lon: Coordinate<f64>) -> (f64, f64) {
+        (
+            (lat_lon.y - self.origin.y) * self.metres_per_degree_lon,
+            (lat_lon.x - self.origin.x) * METRES_PER_DEGREE,
+        )
+    }
+
+    /// `GeoJSON` position, longitude first
+    fn unproject(&self, (x, y): (f64, f64)) -> [f64; 2] {
+        [
+            self.origin.y + x / self.metres_per_degree_lon,
+            self.origin.x + y / METRES_PER_DEGREE,
+        ]
+    }
+}
+
+/// Unit vector to the right of the segment from `a` to `b`
+fn right_normal(a: (f64, f64), b: (f64, f64)) -> Option<(f64, f64)> {
+    let (dx, dy) = (b.0 - a.0, b.1 - a.1);
+    let length = dx.hypot(dy);
+    (length > f64::EPSILON).then(|| (dy / length, -dx / length))
+}
+
+/// The line offset to its right by `offset` metres, left if negative
+fn offset_line(points: &[(f64, f64)], offset: f64) -> Vec<(f64, f64)> {
+    let normals: Vec<(f64, f64)> = points
+        .windows(2)
+        .filter_map(|segment| match segment {
+            [a, b] => right_normal(*a, *b),
+            _ => None,
+        })
+        .collect();
+    let first = normals.first().copied();
+    let last = normals.last().copied();
+    let mut joins = Vec::with_capacity(points.len());
+    joins.extend(first);
+    for pair in normals.windows(2) {
+        if let [before, after] = pair {
+            let (x, y) = (before.0 + after.0, before.1 + after.1);
+            let length = x.hypot(y);
+            let (x, y) = if length > f64::EPSILON {
+                (x / length, y / length)
+            } else {
+                *before
+            };
+            // Extend the join so that both offset segments keep their distance
+            let cos = (x * before.0 + y * before.1).max(MITRE_LIMIT);
+            joins.push((x / cos, y / cos));
+        }
+    }
+    joins.extend(last);
+    points
+        .iter()
+        .zip(joins)
+        .map(|((x, y), (nx, ny))| (x + nx * offset, y + ny * offset))
+        .collect()
+}
+
+/// Each lane of the road as a `GeoJSON` polygon feature, placed along the geometry of its way,
+/// as a feature collection.
+///
+/// The way is positioned within the road as given by [`Road::offset_of_lane`],
+/// or in the middle of the carriageway where that is unknown.
+/// The properties of each feature are those of its lane, including its type, direction,
+/// designated mode, and its width in metres, whether tagged or not.
+/// Lanes without width, such as separators without markings, are left out.
+#[must_use]
+pub fn road_to_geojson(road: &Road, way_geometry: &LineString<f64>, locale: &Locale) -> Value {
+    let mut coords = way_geometry.coords().copied();
+    let projection = match coords.next() {
+        Some(origin) => Projection::new(origin),
+        None => return json!({"type": "FeatureCollection", "features": []}),
+    };
+    let mut points: Vec<(f64, f64)> = Vec::new();
+    for point in std::iter::once(projection.origin)
+        .chain(coords)
+        .map(|c| projection.project(c))
+    {
+        // Repeated nodes would have no direction
+        if let Some(last) = points.last() {
+            if right_normal(*last, point).is_none() {
+                continue;
+            }
+        }
+        points.push(point);
+    }
+
+    let centred;
+    let placed = if (0..road.lanes.len()).all(|index| road.offset_of_lane(index, locale).is_some())
+    {
+        road
+    } else {
+        centred = Road {
+            placement: None,
+            ..road.clone()
+        };
+        &centred
+    };
+
+    let features: Vec<Value> = if points.len() < 2 {
+        Vec::new()
+    } else {
+        placed
+            .lanes
+            .iter()
+            .enumerate()
+            .filter_map(|(index, lane)| {
+                let width = lane.width(locale, road.highway.r#type()).val();
+                let centre = placed.offset_of_lane(index, locale)?.val();
+                if width <= 0.0_f64 {
+                    return None;
+                }
+                let left = offset_line(&points, centre - width / 2.0_f64);
+                let right = offset_line(&points, centre + width / 2.0_f64);
+                let ring: Vec<[f64; 2]> = left
+                    .iter()
+                    .chain(right.iter().rev())
+                    .chain(left.first())
+                    .map(|point| projection.unproject(*point))
+                    .collect();
+                let mut properties = match serde_json::to_value(lane) {
+                    Ok(Value::Object(properties)) => properties,
+                    _ => Map::new(),
+                };
+                properties.insert("index".to_owned(), json!(index));
+                properties.insert("width".to_owned(), json!(width));
+                Some(json!({
+                    "type": "Feature",
+                    "geometry": {
+                        "type": "Polygon",
+                        "coordinates": [ring],
+                    },
+                    "properties": properties,
+                }))
+            })
+            .collect()
+    };
+    json!({"type": "FeatureCollection", "features": features})
+}
+
+#[cfg(test)]
+mod tests {
+    use geo::LineString;
+    use osm_tags::Tags;
+    use serde_json::Value;
+
+    use super::{road_to_geojson, METRES_PER_DEGREE};
+    use crate::locale::{DrivingSide, Locale};
+    use crate::transform::{tags_to_lanes, TagsToLanesConfig};
+
+    #[test]
+    fn lanes_along_way() {
+        let tags = Tags::from_pairs([("highway", "residential"), ("lanes", "2")]).unwrap();
+        let locale = Locale::builder().driving_side(DrivingSide::Right).build();
+        let config = TagsToLanesConfig {
+            include_separators: false,
+            ..TagsToLanesConfig::default()
+        };
+        let road = tags_to_lanes(&tags, &locale, &config).unwrap().road;
+        let width = road
+            .lanes
+            .first()
+            .unwrap()
+            .width(&locale, road.highway.r#type())
+            .val();
+        // Northwards along the prime meridian, latitude (x) then longitude (y)
+        let way: LineString<f64> = vec![[0.0_f64, 0.0_f64], [0.001_f64, 0.0_f64]]
+            .into_iter()
+            .collect();
+
+        let geojson = road_to_geojson(&road, &way, &locale);
+        let features = geojson.pointer("/features").unwrap().as_array().unwrap();
+        assert_eq!(features.len(), 2);
+
+        let backward = features.first().unwrap();
+        let property = |name: &str| backward.pointer(&format!("/properties/{name}")).unwrap();
+        assert_eq!(property("direction"), "backward");
+        assert_eq!(property("designated"), "motor_vehicle");
+        assert_eq!(property("width"), width);
+        let ring = backward
+            .pointer("/geometry/coordinates/0")
+            .unwrap()
+            .as_array()
+            .unwrap();
+        assert_eq!(ring.len(), 5);
+        assert_eq!(ring.first(), ring.last());
+        // West of the way, up its left edge and back down along the way
+        let lon = |position: &Value| {
+            position.pointer("/0").unwrap().as_f64().unwrap() * METRES_PER_DEGREE
+        };
+        let (left, right) = ring.split_at(2);
+        assert!(left
+            .iter()
+            .all(|position| (lon(position) + width).abs() < 1e-6_f64));
+        assert!(right
+            .iter()
+            .take(2)
+            .all(|position| lon(position).abs() < 1e-6_f64));
+    }
+}
@@ -0,0 +1,8 @@
+//! Visual output of roads, as a cross-section or placed along their way
+
+pub mod svg;
+
+#[cfg(feature = "render")]
+mod geojson;
+#[cfg(feature = "render")]
+pub use geojson::road_to_geojson;
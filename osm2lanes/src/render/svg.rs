@@ -0,0 +1,362 @@
+//! Cross-section of a road as a standalone SVG image, as drawn by the web app
+
+use std::fmt::Write;
+
+use crate::locale::Locale;
+use crate::metric::Metre;
+use crate::road::{Color, Designated, Direction, Lane, Marking, Printable, Road, Style};
+
+/// Pixels per metre
+const SCALE: f64 = 40.0;
+/// Height of the image in pixels
+const HEIGHT: f64 = 240.0;
+/// Grass either side of the road
+const VERGE: Metre = Metre::new(1.0);
+/// Asphalt between the grass and the outermost lanes
+const ASPHALT_BUFFER: Metre = Metre::new(0.1);
+
+/// Distance between embedded rails, standard gauge
+const GAUGE: Metre = Metre::new(1.435);
+
+const GRASS: &str = "olive";
+const ASPHALT: &str = "#333333";
+
+fn color(color: Color) -> &'static str {
+    match color {
+        Color::White => "white",
+        Color::Yellow => "gold",
+        Color::Red => "red",
+        Color::Green => "green",
+    }
+}
+
+/// Fill of a lane over the asphalt, `None` if asphalt
+fn fill(lane: &Lane) -> Option<&'static str> {
+    match lane {
+        Lane::Travel {
+            designated: Designated::Foot,
+            ..
+        } => Some("gray"),
+        Lane::Shoulder { .. } => Some("#555555"),
+        Lane::Verge { .. } => Some("green"),
+        Lane::Travel { .. } | Lane::Parking { .. } | Lane::Separator { .. } => None,
+    }
+}
+
+/// Dash pattern of a line marking in pixels, `None` if solid
+fn dashes(style: Style) -> Option<&'static str> {
+    match style {
+        Style::DottedLine => Some("20 40"),
+        Style::DashedLine => Some("40 40"),
+        Style::BrokenLine => Some("40 20"),
+        Style::Bollard | Style::FlexPost | Style::TreeRow => Some("20 80"),
+        Style::VerticalPanel | Style::Planter => Some("60 40"),
+        Style::SolidLine | Style::KerbUp | Style::KerbDown | Style::NoFill | Style::Fence => None,
+    }
+}
+
+fn rect(svg: &mut impl Write, left: f64, width: f64, fill: &str) -> std::fmt::Result {
+    write!(
+        svg,
+        r#"<rect x="{left:.1}" y="0" width="{width:.1}" height="{HEIGHT}" fill="{fill}"/>"#
+    )
+}
+
+fn markings(svg: &mut impl Write, mut left: f64, markings: &[Marking]) -> std::fmt::Result {
+    for marking in markings {
+        let width = marking.width.unwrap_or(Marking::DEFAULT_WIDTH).val() * SCALE;
+        let stroke = match (marking.style, marking.color) {
+            (Style::NoFill, _) => None,
+            (_, Some(c)) => Some(color(c)),
+            (Style::KerbUp | Style::KerbDown, None) => Some("lightgray"),
+            (style, None) if style.is_object() => Some("darkgray"),
+            (_, None) => Some("white"),
+        };
+        if let Some(stroke) = stroke {
+            let x = left + width / 2.0_f64;
+            let dashes = dashes(marking.style)
+                .map(|dashes| format!(r#" stroke-dasharray="{dashes}""#))
+                .unwrap_or_default();
+            write!(
+                svg,
+                r#"<line x1="{x:.1}" y1="0" x2="{x:.1}" y2="{HEIGHT}" stroke="{stroke}" stroke-width="{width:.1}"{dashes}/>"#
+            )?;
+        }
+        left += width;
+    }
+    Ok(())
+}
+
+/// Arrow centred on `(x, y)`, pointing up the image when forward
+fn arrow(svg: &mut impl Write, x: f64, y: f64, direction: Direction) -> std::fmt::Result {
+    let mut path = format!("M{x:.1} {:.1}V{:.1}", y - 20.0_f64, y + 20.0_f64);
+    let heads: &[f64] = match direction {
+        Direction::Forward => &[-1.0_f64],
+        Direction::Backward => &[1.0_f64],
+        Direction::Both | Direction::Reversible => &[-1.0_f64, 1.0_f64],
+    };
+    for sign in heads {
+        write!(
+            path,
+            "M{:.1} {:.1}L{x:.1} {:.1}L{:.1} {:.1}",
+            x - 10.0_f64,
+            y + sign * 10.0_f64,
+            y + sign * 20.0_f64,
+            x + 10.0_f64,
+            y + sign * 10.0_f64,
+        )?;
+    }
+    write!(
+        svg,
+        r#"<path d="{path}" stroke="white" stroke-width="2" fill="none"/>"#
+    )
+}
+
+/// A pair of rails centred on `x`
+fn rails(svg: &mut impl Write, x: f64) -> std::fmt::Result {
+    let offset = GAUGE.val() * SCALE / 2.0_f64;
+    for x in [x - offset, x + offset] {
+        write!(
+            svg,
+            r#"<line x1="{x:.1}" y1="0" x2="{x:.1}" y2="{HEIGHT}" stroke="silver" stroke-width="3"/>"#
+        )?;
+    }
+    Ok(())
+}
+
+fn text(svg: &mut impl Write, x: f64, y: f64, size: u8, content: &str) -> std::fmt::Result {
+    write!(
+        svg,
+        r#"<text x="{x:.1}" y="{y:.1}" fill="white" font-size="{size}" text-anchor="middle" dominant-baseline="middle">{content}</text>"#
+    )
+}
+
+/// The lane, drawn from its left edge `left` in pixels
+fn lane(svg: &mut impl Write, left: f64, width: f64, lane: &Lane) -> std::fmt::Result {
+    if let Some(fill) = fill(lane) {
+        rect(svg, left, width, fill)?;
+    }
+    let x = left + width / 2.0_f64;
+    if let Lane::Travel {
+        embedded_rail: true,
+        ..
+    } = lane
+    {
+        rails(svg, x)?;
+    }
+    match lane {
+        Lane::Separator {
+            markings: Some(separator),
+            ..
+        } => return markings(svg, left, separator),
+        Lane::Separator { markings: None, .. } => return Ok(()),
+        Lane::Travel {
+            direction: Some(direction),
+            ..
+        } => {
+            arrow(svg, x, 0.3_f64 * HEIGHT, *direction)?;
+            arrow(svg, x, 0.7_f64 * HEIGHT, *direction)?;
+        },
+        Lane::Travel { .. } | Lane::Parking { .. } | Lane::Shoulder { .. } | Lane::Verge { .. } => {
+        },
+    }
+    text(svg, x, HEIGHT / 2.0_f64, 24, &lane.as_utf8().to_string())?;
+    text(
+        svg,
+        x,
+        HEIGHT - 12.0_f64,
+        14,
+        &format!("{:.1}m", width / SCALE),
+    )
+}
+
+/// Cross-section of the road as a standalone SVG image, lanes from left to right, to scale.
+///
+/// Each lane is labelled with its symbol, as in [`Printable`], its direction and its width,
+/// and separators are drawn as their markings.
+#[must_use]
+pub fn road_to_svg(road: &Road, locale: &Locale) -> String {
+    let mut svg = String::new();
+    write_svg(&mut svg, road, locale).expect("writing to a String cannot fail");
+    svg
+}
+
+fn write_svg(svg: &mut impl Write, road: &Road, locale: &Locale) -> std::fmt::Result {
+    let highway = road.highway.r#type();
+    let verge = VERGE.val() * SCALE;
+    let buffer = ASPHALT_BUFFER.val() * SCALE;
+    let road_width = road.width(locale).val() * SCALE;
+    let width = road_width + 2.0_f64 * (verge + buffer);
+    write!(
+        svg,
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="{width:.0}" height="{HEIGHT}" viewBox="0 0 {width:.1} {HEIGHT}">"#
+    )?;
+    rect(svg, 0.0_f64, width, GRASS)?;
+    rect(svg, verge, road_width + 2.0_f64 * buffer, ASPHALT)?;
+    let mut left = verge + buffer;
+    for l in &road.lanes {
+        let lane_width = l.width(locale, highway).val() * SCALE;
+        lane(svg, left, lane_width, l)?;
+        left += lane_width;
+    }
+    write!(svg, "</svg>")
+}
+
+impl Road {
+    /// Cross-section of the road as a standalone SVG image, see [`road_to_svg`]
+    #[must_use]
+    pub fn svg(&self, locale: &Locale) -> String {
+        road_to_svg(self, locale)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::road_to_svg;
+    use crate::locale::{DrivingSide, Locale};
+    use crate::road::{Designated, Direction, Lane, Road};
+    use crate::transform::{tags_to_lanes, TagsToLanesConfig};
+
+    #[test]
+    fn cross_section() {
+        let tags = osm_tags::Tags::from_pairs([
+            ("highway", "residential"),
+            ("lanes", "2"),
+            ("sidewalk", "right"),
+        ])
+        .unwrap();
+        let locale = Locale::builder().driving_side(DrivingSide::Right).build();
+        let road: Road = tags_to_lanes(&tags, &locale, &TagsToLanesConfig::default())
+            .unwrap()
+            .road;
+        let svg = road_to_svg(&road, &locale);
+        assert!(svg.starts_with("<svg"));
+        assert!(svg.ends_with("</svg>"));
+        // A pair of arrows per travel lane with a direction
+        let directed = road
+            .lanes
+            .iter()
+            .filter(|lane| {
+                matches!(
+                    lane,
+                    Lane::Travel {
+                        direction: Some(Direction::Forward | Direction::Backward),
+                        designated: Designated::Motor,
+                        ..
+                    }
+                )
+            })
+            .count();
+        assert_eq!(directed, 2);
+        assert!(svg.matches("<path").count() >= 2 * directed);
+        assert!(svg.contains("stroke-dasharray"));
+    }
+
+    /// A road whose separator markings are compared to `data/svg/<name>.svg`
+    struct Golden {
+        name: &'static str,
+        iso_3166_2: &'static str,
+        driving_side: DrivingSide,
+        tags: &'static [(&'static str, &'static str)],
+    }
+
+    /// Roads per locale
+    const GOLDEN: &[Golden] = &[
+        Golden {
+            name: "us_two_lanes",
+            iso_3166_2: "US-WA",
+            driving_side: DrivingSide::Right,
+            tags: &[("highway", "secondary"), ("lanes", "2")],
+        },
+        Golden {
+            name: "us_centre_turn_lane",
+            iso_3166_2: "US-WA",
+            driving_side: DrivingSide::Right,
+            tags: &[
+                ("highway", "secondary"),
+                ("lanes", "3"),
+                ("lanes:forward", "1"),
+                ("lanes:backward", "1"),
+                ("lanes:both_ways", "1"),
+            ],
+        },
+        Golden {
+            name: "us_cycle_lanes",
+            iso_3166_2: "US-CA",
+            driving_side: DrivingSide::Right,
+            tags: &[
+                ("highway", "secondary"),
+                ("lanes", "2"),
+                ("cycleway:both", "lane"),
+                ("sidewalk", "both"),
+            ],
+        },
+        Golden {
+            name: "gb_two_lanes",
+            iso_3166_2: "GB-ENG",
+            driving_side: DrivingSide::Left,
+            tags: &[("highway", "secondary"), ("lanes", "2")],
+        },
+        Golden {
+            name: "gb_motorway",
+            iso_3166_2: "GB-ENG",
+            driving_side: DrivingSide::Left,
+            tags: &[
+                ("highway", "motorway"),
+                ("lanes", "3"),
+                ("shoulder", "left"),
+            ],
+        },
+        Golden {
+            name: "de_two_lanes",
+            iso_3166_2: "DE-BE",
+            driving_side: DrivingSide::Right,
+            tags: &[("highway", "secondary"), ("lanes", "2")],
+        },
+        Golden {
+            name: "de_cycle_lanes",
+            iso_3166_2: "DE-BE",
+            driving_side: DrivingSide::Right,
+            tags: &[
+                ("highway", "secondary"),
+                ("lanes", "2"),
+                ("cycleway:both", "lane"),
+            ],
+        },
+    ];
+
+    /// Compare the rendered markings to the golden images,
+    /// rewriting them instead when `OSM2LANES_UPDATE_SVG` is set
+    #[test]
+    fn golden_separator_markings() {
+        let update = std::env::var_os("OSM2LANES_UPDATE_SVG").is_some();
+        let mut mismatched = Vec::new();
+        for Golden {
+            name,
+            iso_3166_2,
+            driving_side,
+            tags,
+        } in GOLDEN
+        {
+            let tags = osm_tags::Tags::from_pairs(tags.iter().copied()).unwrap();
+            let locale = Locale::builder()
+                .driving_side(*driving_side)
+                .iso_3166(iso_3166_2)
+                .build();
+            let road = tags_to_lanes(&tags, &locale, &TagsToLanesConfig::default())
+                .unwrap()
+                .road;
+            let svg = road_to_svg(&road, &locale);
+            let path = format!("{}/../data/svg/{name}.svg", env!("CARGO_MANIFEST_DIR"));
+            if update {
+                std::fs::write(&path, &svg).unwrap();
+            } else if std::fs::read_to_string(&path).ok().as_deref() != Some(svg.as_str()) {
+                mismatched.push(*name);
+            }
+        }
+        assert!(
+            mismatched.is_empty(),
+            "{mismatched:?} differ from data/svg, rerun with OSM2LANES_UPDATE_SVG=1 and review the images",
+        );
+    }
+}
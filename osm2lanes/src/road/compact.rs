@@ -0,0 +1,107 @@
+use crate::metric::Metre;
+use crate::road::{Designated, Direction, Lane, Road, RoadOf};
+
+/// The kind of a `Lane`, without its properties
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum LaneKind {
+    Travel,
+    Parking,
+    Shoulder,
+    Verge,
+    Separator,
+}
+
+/// A `Lane` reduced to its kind, direction, designation and width,
+/// serialized as an array rather than an object, e.g. `["travel","forward","motor_vehicle",3.5]`
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CompactLane(
+    pub LaneKind,
+    pub Option<Direction>,
+    pub Option<Designated>,
+    pub Option<Metre>,
+);
+
+impl From<&Lane> for CompactLane {
+    fn from(lane: &Lane) -> Self {
+        match lane {
+            Lane::Travel {
+                direction,
+                designated,
+                width,
+                ..
+            } => Self(LaneKind::Travel, *direction, Some(*designated), *width),
+            Lane::Parking {
+                direction,
+                designated,
+                width,
+                ..
+            } => Self(
+                LaneKind::Parking,
+                Some(*direction),
+                Some(*designated),
+                *width,
+            ),
+            Lane::Shoulder {
+                direction, width, ..
+            } => Self(LaneKind::Shoulder, *direction, None, *width),
+            Lane::Verge { width } => Self(LaneKind::Verge, None, None, *width),
+            Lane::Separator { .. } => Self(LaneKind::Separator, None, None, None),
+        }
+    }
+}
+
+/// A `Road` with compact lanes, for bandwidth-sensitive consumers
+pub type CompactRoad = RoadOf<CompactLane>;
+
+impl From<&Road> for CompactRoad {
+    fn from(road: &Road) -> Self {
+        road.with_lanes(road.lanes.iter().map(CompactLane::from).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CompactLane;
+    use crate::metric::Metre;
+    use crate::road::{Designated, Direction, Lane};
+
+    #[test]
+    fn test_compact_lane() {
+        let lane = Lane::Travel {
+            direction: Some(Direction::Forward),
+            designated: Designated::Motor,
+            width: Some(Metre::new(3.5)),
+            max_speed: None,
+            max_height: None,
+            max_weight: None,
+            access: None,
+            elevation: None,
+            turn: None,
+            change: None,
+            destinations: None,
+            conditions: None,
+            surface: None,
+            surface_details: None,
+            smoothness: None,
+            lit: None,
+            embedded_rail: false,
+            centre_turn: false,
+        };
+        let compact = CompactLane::from(&lane);
+        let json = serde_json::to_string(&compact).unwrap();
+        assert_eq!(json, r#"["travel","forward","motor_vehicle",3.5]"#);
+        assert_eq!(serde_json::from_str::<CompactLane>(&json).unwrap(), compact);
+
+        let separator = CompactLane::from(&Lane::Separator {
+            semantic: None,
+            markings: None,
+        });
+        assert_eq!(
+            serde_json::to_string(&separator).unwrap(),
+            r#"["separator",null,null,null]"#
+        );
+    }
+}
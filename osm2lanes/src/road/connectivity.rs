@@ -0,0 +1,160 @@
+use osm_tag_schemes::TurnIndication;
+
+use super::{Designated, Direction, Lane, Road};
+
+/// Where traffic leaves the end of a road, relative to the direction of travel
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum Exit {
+    Left,
+    Through,
+    Right,
+    UTurn,
+}
+
+impl From<TurnIndication> for Exit {
+    fn from(turn: TurnIndication) -> Self {
+        match turn {
+            TurnIndication::Left | TurnIndication::SlightLeft | TurnIndication::SharpLeft => {
+                Self::Left
+            },
+            TurnIndication::Right | TurnIndication::SlightRight | TurnIndication::SharpRight => {
+                Self::Right
+            },
+            TurnIndication::Reverse => Self::UTurn,
+            TurnIndication::None
+            | TurnIndication::Through
+            | TurnIndication::MergeToLeft
+            | TurnIndication::MergeToRight => Self::Through,
+        }
+    }
+}
+
+/// The exits plausibly reachable from a forward lane at the end of the road.
+///
+/// This is a starting point for building intersections,
+/// until lane connectivity relations are supported.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LaneConnectivity {
+    /// Index of the lane in `Road::lanes`
+    pub lane: usize,
+    pub exits: Vec<Exit>,
+}
+
+impl Road {
+    /// Connectivity of each forward motor vehicle or bus lane, from left to right.
+    ///
+    /// Exits come from the `turn:lanes` indications of the lane.
+    /// Without any, every lane goes through,
+    /// the leftmost lane may also turn left and the rightmost lane may also turn right.
+    #[must_use]
+    pub fn connectivity(&self) -> Vec<LaneConnectivity> {
+        let forward: Vec<(usize, Option<&[TurnIndication]>)> = self
+            .lanes
+            .iter()
+            .enumerate()
+            .filter_map(|(index, lane)| match lane {
+                Lane::Travel {
+                    direction: Some(Direction::Forward),
+                    designated: Designated::Motor | Designated::Bus | Designated::Hov,
+                    turn,
+                    ..
+                } => Some((index, turn.as_deref())),
+                _ => None,
+            })
+            .collect();
+        let last = forward.len().saturating_sub(1);
+        forward
+            .into_iter()
+            .enumerate()
+            .map(|(position, (lane, turn))| {
+                let mut exits = Vec::new();
+                match turn {
+                    Some(turn) if !turn.is_empty() => {
+                        for exit in turn.iter().copied().map(Exit::from) {
+                            if !exits.contains(&exit) {
+                                exits.push(exit);
+                            }
+                        }
+                    },
+                    _ => {
+                        if position == 0 {
+                            exits.push(Exit::Left);
+                        }
+                        exits.push(Exit::Through);
+                        if position == last {
+                            exits.push(Exit::Right);
+                        }
+                    },
+                }
+                LaneConnectivity { lane, exits }
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use osm_tag_schemes::TurnIndication;
+
+    use super::{Exit, LaneConnectivity};
+    use crate::road::{Designated, Direction, Lane, Road};
+
+    fn travel(direction: Direction, turn: Option<Vec<TurnIndication>>) -> Lane {
+        Lane::Travel {
+            direction: Some(direction),
+            designated: Designated::Motor,
+            width: None,
+            max_speed: None,
+            max_height: None,
+            max_weight: None,
+            access: None,
+            elevation: None,
+            turn,
+            change: None,
+            destinations: None,
+            conditions: None,
+            surface: None,
+            surface_details: None,
+            smoothness: None,
+            lit: None,
+            embedded_rail: false,
+            centre_turn: false,
+        }
+    }
+
+    #[test]
+    fn connectivity() {
+        let road = Road {
+            lanes: vec![
+                travel(Direction::Backward, None),
+                travel(
+                    Direction::Forward,
+                    Some(vec![TurnIndication::Left, TurnIndication::SlightLeft]),
+                ),
+                travel(Direction::Forward, None),
+                travel(Direction::Forward, None),
+            ],
+            ..Road::empty()
+        };
+        assert_eq!(
+            road.connectivity(),
+            vec![
+                LaneConnectivity {
+                    lane: 1,
+                    exits: vec![Exit::Left],
+                },
+                LaneConnectivity {
+                    lane: 2,
+                    exits: vec![Exit::Through],
+                },
+                LaneConnectivity {
+                    lane: 3,
+                    exits: vec![Exit::Through, Exit::Right],
+                },
+            ]
+        );
+    }
+}
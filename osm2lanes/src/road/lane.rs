@@ -1,7 +1,10 @@
-use osm_tag_schemes::{Access as AccessTagValue, HighwayType};
+use osm_tag_schemes::{
+    Access as AccessTagValue, Change, Conditional, HighwayType, Lit, ParkingOrientation,
+    Smoothness, Surface, TurnIndication,
+};
 
 use crate::locale::Locale;
-use crate::metric::{Metre, Speed};
+use crate::metric::{Metre, Speed, Tonne};
 use crate::road::separator::{Markings, Semantic};
 
 /// A single lane
@@ -18,16 +21,82 @@ pub enum Lane {
         width: Option<Metre>,
         #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
         max_speed: Option<Speed>,
+        /// Maximum vehicle height, from `maxheight:lanes=*`
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        max_height: Option<Metre>,
+        /// Maximum vehicle weight, from `maxweight:lanes=*`
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        max_weight: Option<Tonne>,
         #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
         access: Option<AccessByType>,
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        elevation: Option<Elevation>,
+        /// Turn indications, from `turn:lanes=*`
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        turn: Option<Vec<TurnIndication>>,
+        /// Whether vehicles may change out of the lane, from `change:lanes=*`
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        change: Option<Change>,
+        /// Destination signs, from `destination:lanes=*` and related keys
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        destinations: Option<Destinations>,
+        /// Restrictions that only apply under a condition, from `*:conditional=*`
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        conditions: Option<Conditions>,
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        surface: Option<Surface>,
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        surface_details: Option<SurfaceDetails>,
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        smoothness: Option<Smoothness>,
+        /// Street lighting of a sidewalk where tagged for its side,
+        /// e.g. `sidewalk:left:lit=*` or `lit:right=*`, otherwise see `Road::lit`
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        lit: Option<Lit>,
+        /// Rails embedded in the lane and shared with trams,
+        /// from `embedded_rails=*`, `embedded_rails:lanes=*` or `railway=tram`
+        #[cfg_attr(
+            feature = "serde",
+            serde(default, skip_serializing_if = "std::ops::Not::not")
+        )]
+        embedded_rail: bool,
+        /// A two-way centre turn lane shared by both directions,
+        /// from `lanes:both_ways=1` with `turn:lanes:both_ways=*` or `centre_turn_lane=yes`
+        #[cfg_attr(
+            feature = "serde",
+            serde(default, skip_serializing_if = "std::ops::Not::not")
+        )]
+        centre_turn: bool,
     },
     Parking {
         direction: Direction,
         designated: Designated,
         #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        orientation: Option<ParkingOrientation>,
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
         width: Option<Metre>,
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        surface: Option<Surface>,
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        surface_details: Option<SurfaceDetails>,
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        smoothness: Option<Smoothness>,
     },
     Shoulder {
+        /// Direction of the adjacent traffic
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        direction: Option<Direction>,
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        width: Option<Metre>,
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        surface: Option<Surface>,
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        surface_details: Option<SurfaceDetails>,
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        smoothness: Option<Smoothness>,
+    },
+    /// Grass or other unpaved strip beside the carriageway, from `verge=*`
+    Verge {
         #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
         width: Option<Metre>,
     },
@@ -52,17 +121,54 @@ impl Lane {
                 .as_ref()
                 .map(|m| m.width(locale))
                 .unwrap_or_default(),
+            Lane::Travel {
+                width,
+                designated,
+                embedded_rail: true,
+                ..
+            } => width.unwrap_or_else(|| locale.embedded_rail_width(designated, highway)),
             Lane::Travel {
                 width, designated, ..
             } => width.unwrap_or_else(|| locale.travel_width(designated, highway)),
+            Lane::Parking {
+                width,
+                designated: Designated::Bus,
+                ..
+            } => width.unwrap_or_else(|| locale.bus_bay_width()),
             // TODO: parking different from travel?
             Lane::Parking {
                 width, designated, ..
             } => width.unwrap_or_else(|| locale.travel_width(designated, highway)),
             Lane::Shoulder { width, .. } => width.unwrap_or(Lane::DEFAULT_WIDTH),
+            Lane::Verge { width } => width.unwrap_or_else(|| locale.verge_width(highway)),
         }
     }
 
+    /// Whether the lane is counted by `lanes=*` and `*:lanes=*`,
+    /// a motor, bus or high occupancy vehicle travel lane
+    #[must_use]
+    pub(crate) fn is_carriageway(&self) -> bool {
+        matches!(
+            self,
+            Lane::Travel {
+                designated: Designated::Motor | Designated::Bus | Designated::Hov,
+                ..
+            }
+        )
+    }
+
+    /// Whether the lane is a centre two-way turn lane, see `Lane::Travel::centre_turn`
+    #[must_use]
+    pub fn is_centre_turn(&self) -> bool {
+        matches!(
+            self,
+            Lane::Travel {
+                centre_turn: true,
+                ..
+            }
+        )
+    }
+
     /// Mirror the lane
     #[must_use]
     pub fn mirror(self) -> Self {
@@ -88,6 +194,19 @@ pub enum Direction {
     Forward,
     Backward,
     Both,
+    /// Alternating between forward and backward, e.g. tidal flow by time of day
+    Reversible,
+}
+
+/// Height of a lane relative to the carriageway
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum Elevation {
+    /// At the same level as the carriageway
+    Carriageway,
+    /// Raised above the carriageway, usually by a kerb
+    Raised,
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
@@ -101,6 +220,8 @@ pub enum Designated {
     #[cfg_attr(feature = "serde", serde(rename = "motor_vehicle"))]
     Motor,
     Bus,
+    /// High occupancy vehicles, from `hov=designated` and related keys
+    Hov,
 }
 
 /// Display lane detail as printable characters
@@ -128,7 +249,12 @@ impl Printable for Lane {
                 designated: Designated::Bus,
                 ..
             } => 'B',
+            Self::Travel {
+                designated: Designated::Hov,
+                ..
+            } => 'H',
             Self::Shoulder { .. } => 'S',
+            Self::Verge { .. } => 'g',
             Self::Parking { .. } => 'p',
             Self::Separator { .. } => '|',
         }
@@ -151,7 +277,12 @@ impl Printable for Lane {
                 designated: Designated::Bus,
                 ..
             } => '🚌',
+            Self::Travel {
+                designated: Designated::Hov,
+                ..
+            } => '◊',
             Self::Shoulder { .. } => '🛆',
+            Self::Verge { .. } => '🌱',
             Self::Parking { .. } => '🅿',
             Self::Separator { .. } => '|',
         }
@@ -164,6 +295,7 @@ impl Printable for Direction {
             Self::Forward => '^',
             Self::Backward => 'v',
             Self::Both => '|',
+            Self::Reversible => '~',
         }
     }
     fn as_utf8(&self) -> char {
@@ -171,10 +303,76 @@ impl Printable for Direction {
             Self::Forward => '↑',
             Self::Backward => '↓',
             Self::Both => '↕',
+            Self::Reversible => '⇅',
         }
     }
 }
 
+/// Destination signs of a lane
+///
+/// As in <https://wiki.openstreetmap.org/wiki/Key:destination#Lane-specific_destinations>
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Destinations {
+    /// Destination names, from `destination:lanes=*`
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
+    pub names: Vec<String>,
+    /// Destination road references, from `destination:ref:lanes=*`
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
+    pub refs: Vec<String>,
+    /// Destination symbols, from `destination:symbol:lanes=*`
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
+    pub symbols: Vec<String>,
+}
+
+/// Details of a lane surface beyond its usual material
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SurfaceDetails {
+    /// Surface in winter, such as on an ice road, from `surface:winter=*`
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub winter: Option<Surface>,
+    /// Date the surface was last surveyed, from `surface:date=*`
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub date: Option<String>,
+}
+
+/// Restrictions of a lane that only apply under a condition, such as a time of day
+///
+/// As in <https://wiki.openstreetmap.org/wiki/Conditional_restrictions>
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Conditions {
+    /// From `maxspeed:conditional=*`
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
+    pub max_speed: Vec<Conditional<Speed>>,
+    /// From `access:conditional=*`
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
+    pub access: Vec<Conditional<AccessTagValue>>,
+    /// Bus lane operating times, from `busway:*:conditional=*`,
+    /// with `lane` as `designated`
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
+    pub bus: Vec<Conditional<AccessTagValue>>,
+}
+
 /// Access by vehicle type
 ///
 /// Types as defined in <https://wiki.openstreetmap.org/wiki/Key:access#Land-based_transportation>
@@ -185,23 +383,23 @@ impl Printable for Direction {
 #[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 pub struct AccessByType {
     #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
-    pub(crate) foot: Option<AccessAndDirection>,
+    pub foot: Option<AccessAndDirection>,
     #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
-    pub(crate) bicycle: Option<AccessAndDirection>,
+    pub bicycle: Option<AccessAndDirection>,
     #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
-    pub(crate) taxi: Option<AccessAndDirection>,
+    pub taxi: Option<AccessAndDirection>,
     #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
-    pub(crate) bus: Option<AccessAndDirection>,
+    pub bus: Option<AccessAndDirection>,
     #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
-    pub(crate) motor: Option<AccessAndDirection>,
+    pub motor: Option<AccessAndDirection>,
 }
 
 /// Access for a given user
 #[derive(Clone, Debug, PartialEq, Eq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct AccessAndDirection {
-    pub(crate) access: AccessTagValue,
+    pub access: AccessTagValue,
     /// Direction, if different from designated direction
     #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
-    pub(crate) direction: Option<Direction>,
+    pub direction: Option<Direction>,
 }
@@ -1,17 +1,33 @@
-use osm_tag_schemes::{Highway, HighwayType, Lit, Smoothness, TrackType};
+use osm_tag_schemes::{
+    Access, Highway, HighwayImportance, HighwayType, Incline, Lit, Overtaking, Placement,
+    PriorityRoad, Smoothness, TrackType,
+};
 
-use crate::locale::Locale;
+use crate::locale::{DrivingSide, Locale};
 use crate::metric::Metre;
 
 mod lane;
-pub use lane::{AccessAndDirection, AccessByType, Designated, Direction, Lane, Printable};
+pub use lane::{
+    AccessAndDirection, AccessByType, Conditions, Designated, Destinations, Direction, Elevation,
+    Lane, Printable, SurfaceDetails,
+};
 
 mod separator;
-pub use separator::{Color, Marking, Markings, Semantic, Style};
+pub use separator::{Color, KerbProfile, Marking, Markings, Semantic, Style};
 
+mod connectivity;
+pub use connectivity::{Exit, LaneConnectivity};
+
+mod compact;
+pub use compact::{CompactLane, CompactRoad, LaneKind};
+
+mod provenance;
+pub use provenance::{Provenance, Provenanced, ProvenancedLane, ProvenancedRoad};
+
+/// A road and its metadata, with lanes of type `L`, see [`Road`], [`ProvenancedRoad`] and [`CompactRoad`]
 #[derive(Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-pub struct Road {
+pub struct RoadOf<L> {
     #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub name: Option<String>,
     #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
@@ -27,7 +43,178 @@ pub struct Road {
     #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub smoothness: Option<Smoothness>,
 
-    pub lanes: Vec<Lane>,
+    /// Whether there is cycling infrastructure,
+    /// `Some(false)` only if its absence is tagged, `None` if unknown
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub cycling_infrastructure: Option<bool>,
+
+    /// Facilities alongside the road that are mapped as ways of their own,
+    /// e.g. `sidewalk:right=separate`
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
+    pub separately_mapped: Vec<SeparatelyMapped>,
+
+    /// Position of the way within the carriageway, from `placement=*`,
+    /// counting lanes in the direction of the way
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub placement: Option<Placement>,
+
+    /// Whether the way is part of a roundabout, `junction=roundabout` or `junction=circular`,
+    /// which implies the way is oneway
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::ops::Not::not")
+    )]
+    pub roundabout: bool,
+
+    /// Whether the way is one half of a dual carriageway,
+    /// from `dual_carriageway=yes` or the hints of `TagsToLanesConfig::dual_carriageway_hints`,
+    /// for pairing the two halves of a road
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::ops::Not::not")
+    )]
+    pub dual_carriageway: bool,
+
+    /// The flight of steps of a `highway=steps` way
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub steps: Option<Steps>,
+
+    /// What a separately mapped footway is part of, from `footway=*`,
+    /// for linking it to its parent road
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub role: Option<FootwayRole>,
+
+    /// Which directions of traffic may overtake, from `overtaking=*`,
+    /// also shown by the centre line markings, `None` if untagged
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub overtaking: Option<Overtaking>,
+
+    /// Whether the way has priority at junctions, from `priority_road=*`,
+    /// which in some countries changes the centre line markings, `None` if untagged
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub priority_road: Option<PriorityRoad>,
+
+    /// Whether a single lane road has places to pull in and let oncoming traffic pass,
+    /// from `passing_places=yes`
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::ops::Not::not")
+    )]
+    pub passing_places: bool,
+
+    pub lanes: Vec<L>,
+}
+
+/// A road with its lanes left to right
+pub type Road = RoadOf<Lane>;
+
+impl<L> RoadOf<L> {
+    /// The same road's metadata, with other lanes
+    #[must_use]
+    pub fn with_lanes<M>(&self, lanes: Vec<M>) -> RoadOf<M> {
+        RoadOf {
+            name: self.name.clone(),
+            r#ref: self.r#ref.clone(),
+            highway: self.highway.clone(),
+            lit: self.lit,
+            tracktype: self.tracktype,
+            smoothness: self.smoothness,
+            cycling_infrastructure: self.cycling_infrastructure,
+            separately_mapped: self.separately_mapped.clone(),
+            placement: self.placement,
+            roundabout: self.roundabout,
+            dual_carriageway: self.dual_carriageway,
+            steps: self.steps.clone(),
+            role: self.role,
+            overtaking: self.overtaking,
+            priority_road: self.priority_road,
+            passing_places: self.passing_places,
+            lanes,
+        }
+    }
+
+    /// The same road, converting each of its lanes
+    #[must_use]
+    pub fn map_lanes<M, F>(self, f: F) -> RoadOf<M>
+    where
+        F: FnMut(L) -> M,
+    {
+        RoadOf {
+            name: self.name,
+            r#ref: self.r#ref,
+            highway: self.highway,
+            lit: self.lit,
+            tracktype: self.tracktype,
+            smoothness: self.smoothness,
+            cycling_infrastructure: self.cycling_infrastructure,
+            separately_mapped: self.separately_mapped,
+            placement: self.placement,
+            roundabout: self.roundabout,
+            dual_carriageway: self.dual_carriageway,
+            steps: self.steps,
+            role: self.role,
+            overtaking: self.overtaking,
+            priority_road: self.priority_road,
+            passing_places: self.passing_places,
+            lanes: self.lanes.into_iter().map(f).collect(),
+        }
+    }
+}
+
+/// Side of the way, relative to the direction of the way
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum Side {
+    Left,
+    Right,
+}
+
+impl From<DrivingSide> for Side {
+    fn from(side: DrivingSide) -> Self {
+        match side {
+            DrivingSide::Left => Self::Left,
+            DrivingSide::Right => Self::Right,
+        }
+    }
+}
+
+/// A sidewalk or cycleway that exists,
+/// but is mapped as its own way instead of as a lane of the road
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SeparatelyMapped {
+    pub designated: Designated,
+    pub side: Side,
+}
+
+/// A flight of steps, for pedestrian routing to tell stairs apart from a sidewalk
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Steps {
+    /// Whether the steps go up or down in the direction of the way, from `incline=*`
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub incline: Option<Incline>,
+    /// Whether there is a handrail, from `handrail=*` or `handrail:{left,right,center}=*`
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub handrail: Option<bool>,
+    /// The number of steps, from `step_count=*`
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub step_count: Option<u32>,
+}
+
+/// What a `highway=footway` way is part of, from `footway=*`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum FootwayRole {
+    /// The sidewalk of a road, mapped separately from it
+    Sidewalk,
+    /// A pedestrian crossing of a road
+    Crossing,
 }
 
 impl Road {
@@ -41,6 +228,16 @@ impl Road {
             lit: None,
             tracktype: None,
             smoothness: None,
+            cycling_infrastructure: None,
+            separately_mapped: Vec::new(),
+            placement: None,
+            roundabout: false,
+            dual_carriageway: false,
+            steps: None,
+            role: None,
+            overtaking: None,
+            priority_road: None,
+            passing_places: false,
             lanes: Vec::new(),
         }
     }
@@ -50,6 +247,22 @@ impl Road {
         self.lanes.iter().any(Lane::is_separator)
     }
 
+    /// Each lane that is not a separator, with the separators directly before and after it,
+    /// `None` where there is no separator, such as at the edges of the road
+    pub fn lanes_with_boundaries(
+        &self,
+    ) -> impl Iterator<Item = (&Lane, Option<&Lane>, Option<&Lane>)> + '_ {
+        let separator = |index: usize| self.lanes.get(index).filter(|lane| lane.is_separator());
+        self.lanes
+            .iter()
+            .enumerate()
+            .filter(|(_, lane)| !lane.is_separator())
+            .map(move |(index, lane)| {
+                let before = index.checked_sub(1).and_then(separator);
+                (lane, before, separator(index + 1))
+            })
+    }
+
     /// Width in metres
     #[must_use]
     pub fn width(&self, locale: &Locale) -> Metre {
@@ -58,4 +271,410 @@ impl Road {
             .map(|lane| lane.width(locale, self.highway.r#type()))
             .sum::<Metre>()
     }
+
+    /// Lateral offset of the centre of lane `index` from the way in metres,
+    /// positive to the right in the direction of the way.
+    ///
+    /// The way is positioned by `placement`, or otherwise in the middle of the carriageway,
+    /// the motor and bus travel lanes, or of the whole road if there are none.
+    /// `None` if there is no such lane, or if the position of the way is unknown,
+    /// i.e. `placement=transition` or a placement on a lane that does not exist.
+    #[must_use]
+    pub fn offset_of_lane(&self, index: usize, locale: &Locale) -> Option<Metre> {
+        let highway = self.highway.r#type();
+        // Left and right edge of each lane, from the left edge of the road
+        let edges: Vec<(f64, f64)> = self
+            .lanes
+            .iter()
+            .scan(0.0_f64, |left, lane| {
+                let right = *left + lane.width(locale, highway).val();
+                Some((std::mem::replace(left, right), right))
+            })
+            .collect();
+        let carriageway: Vec<(f64, f64)> = self
+            .lanes
+            .iter()
+            .zip(&edges)
+            .filter(|(lane, _)| lane.is_carriageway())
+            .map(|(_, edges)| *edges)
+            .collect();
+        let nth = |lane: usize| carriageway.get(lane.checked_sub(1)?).copied();
+        let middle = |(left, right): (f64, f64)| left + (right - left) / 2.0_f64;
+        let way = match self.placement {
+            None => middle(match (carriageway.first(), carriageway.last()) {
+                (Some((left, _)), Some((_, right))) => (*left, *right),
+                _ => (0.0_f64, edges.last().map_or(0.0_f64, |(_, right)| *right)),
+            }),
+            Some(Placement::LeftOf(lane)) => nth(lane)?.0,
+            Some(Placement::MiddleOf(lane)) => middle(nth(lane)?),
+            Some(Placement::RightOf(lane)) => nth(lane)?.1,
+            Some(Placement::Transition) => return None,
+        };
+        Some(Metre::new(middle(*edges.get(index)?) - way))
+    }
+
+    /// Whether `mode` can travel in `direction` along the road on any lane,
+    /// `Direction::Both` requires both directions, possibly on different lanes.
+    ///
+    /// Lanes are passable by their designated mode, unless their access says otherwise,
+    /// and motor lanes are also passable by buses, and by bicycles outside motorways.
+    /// Only `access=no` is impassable, restricted access such as `private` is passable.
+    #[must_use]
+    pub fn passable(&self, mode: Designated, direction: Direction) -> bool {
+        self.access(mode, direction).is_some()
+    }
+
+    /// The access of `mode` travelling in `direction` along the road, `None` if impassable,
+    /// see [`Road::passable`].
+    ///
+    /// Of the lanes that are passable, the least restricted access is given,
+    /// e.g. `destination` over `private`, and `yes` for lanes without a tagged access.
+    /// `Direction::Both` gives the more restricted access of the two directions.
+    #[must_use]
+    pub fn access(&self, mode: Designated, direction: Direction) -> Option<Access> {
+        if self.highway.is_construction() || self.highway.is_proposed() {
+            return None;
+        }
+        if direction == Direction::Both {
+            let forward = self.access(mode, Direction::Forward)?;
+            let backward = self.access(mode, Direction::Backward)?;
+            return Some(if restriction(&backward) > restriction(&forward) {
+                backward
+            } else {
+                forward
+            });
+        }
+        let motorway = matches!(
+            self.highway.r#type(),
+            HighwayType::Classified(HighwayImportance::Motorway)
+                | HighwayType::Link(HighwayImportance::Motorway)
+        );
+        self.lanes
+            .iter()
+            .filter_map(|lane| {
+                let (lane_direction, designated, access) = match lane {
+                    Lane::Travel {
+                        direction,
+                        designated,
+                        access,
+                        ..
+                    } => (*direction, *designated, access.as_ref()),
+                    _ => return None,
+                };
+                let mode_access = access.and_then(|access| match mode {
+                    Designated::Foot => access.foot.as_ref(),
+                    Designated::Bicycle => access.bicycle.as_ref(),
+                    Designated::Bus => access.bus.as_ref(),
+                    Designated::Motor | Designated::Hov => access.motor.as_ref(),
+                });
+                let (access, lane_direction) = match mode_access {
+                    Some(mode_access) if mode_access.access == Access::No => return None,
+                    Some(mode_access) => (
+                        mode_access.access.clone(),
+                        mode_access.direction.or(lane_direction),
+                    ),
+                    None if designated == mode
+                        || (designated == Designated::Motor
+                            && (mode == Designated::Bus
+                                || mode == Designated::Hov
+                                || (mode == Designated::Bicycle && !motorway)))
+                        || (designated == Designated::Hov && mode == Designated::Bus) =>
+                    {
+                        (Access::Yes, lane_direction)
+                    },
+                    None => return None,
+                };
+                match lane_direction {
+                    // Undirected lanes, such as sidewalks, can be used both ways
+                    None | Some(Direction::Both | Direction::Reversible) => Some(access),
+                    Some(lane_direction) => (lane_direction == direction).then_some(access),
+                }
+            })
+            .min_by_key(restriction)
+    }
+
+    /// Express the road as if it were driven on `driving_side`.
+    ///
+    /// Lanes are always ordered left to right and directions are relative to the way,
+    /// so a road from the other driving side is mirrored:
+    /// the lane order is reversed and separator markings are flipped,
+    /// while lane directions are kept.
+    /// The placement is renumbered to stay on the same lane.
+    #[must_use]
+    pub fn normalize_to(mut self, driving_side: DrivingSide, locale: &Locale) -> Self {
+        if locale.driving_side != driving_side {
+            let count = self
+                .lanes
+                .iter()
+                .filter(|lane| lane.is_carriageway())
+                .count();
+            let mirror = |lane: usize| (count + 1).checked_sub(lane).unwrap_or(lane);
+            self.placement = self.placement.map(|placement| match placement {
+                Placement::LeftOf(lane) => Placement::RightOf(mirror(lane)),
+                Placement::MiddleOf(lane) => Placement::MiddleOf(mirror(lane)),
+                Placement::RightOf(lane) => Placement::LeftOf(mirror(lane)),
+                Placement::Transition => Placement::Transition,
+            });
+            self.lanes = self.lanes.into_iter().rev().map(Lane::mirror).collect();
+        }
+        self
+    }
+}
+
+/// How restricted an access is, from public to only with the owner's consent
+fn restriction(access: &Access) -> u8 {
+    match access {
+        Access::Yes | Access::Designated => 0,
+        Access::Permissive => 1,
+        Access::Destination => 2,
+        Access::Customers | Access::Delivery => 3,
+        Access::Permit => 4,
+        Access::Private => 5,
+        Access::No => 6,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use osm_tag_schemes::{Access, Highway, HighwayImportance, HighwayType, Placement};
+
+    use super::{AccessAndDirection, AccessByType, Lane, Road};
+    use crate::locale::{DrivingSide, Locale};
+    use crate::metric::Metre;
+    use crate::road::{Color, Designated, Direction, Marking, Markings, Style};
+
+    fn travel(direction: Direction) -> Lane {
+        Lane::Travel {
+            direction: Some(direction),
+            designated: Designated::Motor,
+            width: None,
+            max_speed: None,
+            max_height: None,
+            max_weight: None,
+            access: None,
+            elevation: None,
+            turn: None,
+            change: None,
+            destinations: None,
+            conditions: None,
+            surface: None,
+            surface_details: None,
+            smoothness: None,
+            lit: None,
+            embedded_rail: false,
+            centre_turn: false,
+        }
+    }
+
+    #[test]
+    fn normalize_to() {
+        let separator = |left, right| Lane::Separator {
+            semantic: None,
+            markings: Some(Markings::new(vec![
+                Marking {
+                    style: left,
+                    width: None,
+                    color: Some(Color::White),
+                    kerb: None,
+                },
+                Marking {
+                    style: right,
+                    width: None,
+                    color: Some(Color::White),
+                    kerb: None,
+                },
+            ])),
+        };
+        let road = Road {
+            lanes: vec![
+                travel(Direction::Forward),
+                separator(Style::SolidLine, Style::DottedLine),
+                travel(Direction::Backward),
+            ],
+            ..Road::empty()
+        };
+        let left = Locale::builder().driving_side(DrivingSide::Left).build();
+
+        assert_eq!(road.clone().normalize_to(DrivingSide::Left, &left), road);
+        assert_eq!(
+            road.normalize_to(DrivingSide::Right, &left).lanes,
+            vec![
+                travel(Direction::Backward),
+                separator(Style::DottedLine, Style::SolidLine),
+                travel(Direction::Forward),
+            ]
+        );
+    }
+
+    #[test]
+    fn passable() {
+        let road = Road {
+            lanes: vec![
+                Lane::Travel {
+                    direction: None,
+                    designated: Designated::Foot,
+                    width: None,
+                    max_speed: None,
+                    max_height: None,
+                    max_weight: None,
+                    access: None,
+                    elevation: None,
+                    turn: None,
+                    change: None,
+                    destinations: None,
+                    conditions: None,
+                    surface: None,
+                    surface_details: None,
+                    smoothness: None,
+                    lit: None,
+                    embedded_rail: false,
+                    centre_turn: false,
+                },
+                travel(Direction::Forward),
+            ],
+            ..Road::empty()
+        };
+        assert!(road.passable(Designated::Motor, Direction::Forward));
+        assert!(!road.passable(Designated::Motor, Direction::Backward));
+        assert!(!road.passable(Designated::Motor, Direction::Both));
+        assert!(road.passable(Designated::Bus, Direction::Forward));
+        assert!(road.passable(Designated::Bicycle, Direction::Forward));
+        assert!(road.passable(Designated::Foot, Direction::Both));
+
+        let motorway = Road {
+            highway: Highway::active(HighwayType::Classified(HighwayImportance::Motorway)),
+            lanes: vec![travel(Direction::Forward)],
+            ..Road::empty()
+        };
+        assert!(!motorway.passable(Designated::Bicycle, Direction::Forward));
+    }
+
+    #[test]
+    fn offset_of_lane() {
+        let mut road = Road {
+            lanes: vec![
+                travel(Direction::Backward),
+                travel(Direction::Forward),
+                travel(Direction::Forward),
+            ],
+            ..Road::empty()
+        };
+        for lane in &mut road.lanes {
+            if let Lane::Travel { width, .. } = lane {
+                *width = Some(Metre::new(3.0));
+            }
+        }
+        let locale = Locale::builder().build();
+        let offsets = |road: &Road| -> Vec<Option<f64>> {
+            (0..=road.lanes.len())
+                .map(|index| road.offset_of_lane(index, &locale).map(|m| m.val()))
+                .collect()
+        };
+
+        assert_eq!(
+            offsets(&road),
+            vec![Some(-3.0_f64), Some(0.0_f64), Some(3.0_f64), None]
+        );
+
+        road.placement = Some(Placement::LeftOf(2));
+        assert_eq!(
+            offsets(&road),
+            vec![Some(-1.5_f64), Some(1.5_f64), Some(4.5_f64), None]
+        );
+
+        // Mirroring keeps the way on the same lane boundary
+        let mirrored = road.clone().normalize_to(
+            DrivingSide::Left,
+            &Locale::builder().driving_side(DrivingSide::Right).build(),
+        );
+        assert_eq!(mirrored.placement, Some(Placement::RightOf(2)));
+        assert_eq!(
+            offsets(&mirrored),
+            vec![Some(-4.5_f64), Some(-1.5_f64), Some(1.5_f64), None]
+        );
+
+        road.placement = Some(Placement::RightOf(4));
+        assert_eq!(offsets(&road), vec![None; 4]);
+        road.placement = Some(Placement::Transition);
+        assert_eq!(offsets(&road), vec![None; 4]);
+    }
+
+    #[test]
+    fn lanes_with_boundaries() {
+        let separator = Lane::Separator {
+            semantic: None,
+            markings: None,
+        };
+        let road = Road {
+            lanes: vec![
+                travel(Direction::Backward),
+                separator.clone(),
+                travel(Direction::Forward),
+                travel(Direction::Forward),
+            ],
+            ..Road::empty()
+        };
+        assert_eq!(
+            road.lanes_with_boundaries().collect::<Vec<_>>(),
+            vec![
+                (&travel(Direction::Backward), None, Some(&separator)),
+                (&travel(Direction::Forward), Some(&separator), None),
+                (&travel(Direction::Forward), None, None),
+            ]
+        );
+    }
+
+    #[test]
+    fn access() {
+        let with_access = |direction, access| {
+            let mut lane = travel(direction);
+            if let Lane::Travel {
+                access: lane_access,
+                ..
+            } = &mut lane
+            {
+                *lane_access = Some(AccessByType {
+                    foot: None,
+                    bicycle: None,
+                    taxi: None,
+                    bus: None,
+                    motor: Some(AccessAndDirection {
+                        access,
+                        direction: None,
+                    }),
+                });
+            }
+            lane
+        };
+        let road = Road {
+            lanes: vec![
+                with_access(Direction::Backward, Access::Private),
+                with_access(Direction::Forward, Access::Private),
+                with_access(Direction::Forward, Access::Destination),
+            ],
+            ..Road::empty()
+        };
+        assert_eq!(
+            road.access(Designated::Motor, Direction::Forward),
+            Some(Access::Destination)
+        );
+        assert_eq!(
+            road.access(Designated::Motor, Direction::Backward),
+            Some(Access::Private)
+        );
+        assert_eq!(
+            road.access(Designated::Motor, Direction::Both),
+            Some(Access::Private)
+        );
+        assert_eq!(road.access(Designated::Foot, Direction::Forward), None);
+
+        let road = Road {
+            lanes: vec![travel(Direction::Forward)],
+            ..Road::empty()
+        };
+        assert_eq!(
+            road.access(Designated::Bus, Direction::Forward),
+            Some(Access::Yes)
+        );
+    }
 }
@@ -0,0 +1,233 @@
+use osm_tag_schemes::{Change, Lit, ParkingOrientation, Smoothness, Surface, TurnIndication};
+
+use crate::metric::{Metre, Speed, Tonne};
+use crate::road::{
+    AccessByType, Conditions, Designated, Destinations, Direction, Elevation, Lane, Markings, Road,
+    RoadOf, Semantic, SurfaceDetails,
+};
+
+/// How a lane property was arrived at, from least to most certain
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum Provenance {
+    /// Guessed from the locale or the type of road
+    Default,
+    /// Derived from other tags
+    Calculated,
+    /// Tagged
+    Direct,
+}
+
+/// A lane property alongside its provenance
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Provenanced<T> {
+    pub value: T,
+    pub provenance: Provenance,
+}
+
+impl<T> Provenanced<T> {
+    #[must_use]
+    pub fn new(value: T, provenance: Provenance) -> Self {
+        Self { value, provenance }
+    }
+
+    /// Drop the provenance
+    #[must_use]
+    pub fn into_value(self) -> T {
+        self.value
+    }
+}
+
+/// A `Lane` with the provenance of each of its properties
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(tag = "type", rename_all = "snake_case"))]
+pub enum ProvenancedLane {
+    Travel {
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        direction: Option<Provenanced<Direction>>,
+        designated: Provenanced<Designated>,
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        width: Option<Provenanced<Metre>>,
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        max_speed: Option<Provenanced<Speed>>,
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        max_height: Option<Provenanced<Metre>>,
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        max_weight: Option<Provenanced<Tonne>>,
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        access: Option<Provenanced<AccessByType>>,
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        elevation: Option<Provenanced<Elevation>>,
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        turn: Option<Provenanced<Vec<TurnIndication>>>,
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        change: Option<Provenanced<Change>>,
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        destinations: Option<Provenanced<Destinations>>,
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        conditions: Option<Provenanced<Conditions>>,
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        surface: Option<Provenanced<Surface>>,
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        surface_details: Option<Provenanced<SurfaceDetails>>,
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        smoothness: Option<Provenanced<Smoothness>>,
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        lit: Option<Provenanced<Lit>>,
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        embedded_rail: Option<Provenanced<bool>>,
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        centre_turn: Option<Provenanced<bool>>,
+    },
+    Parking {
+        direction: Provenanced<Direction>,
+        designated: Provenanced<Designated>,
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        orientation: Option<Provenanced<ParkingOrientation>>,
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        width: Option<Provenanced<Metre>>,
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        surface: Option<Provenanced<Surface>>,
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        surface_details: Option<Provenanced<SurfaceDetails>>,
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        smoothness: Option<Provenanced<Smoothness>>,
+    },
+    Shoulder {
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        direction: Option<Provenanced<Direction>>,
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        width: Option<Provenanced<Metre>>,
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        surface: Option<Provenanced<Surface>>,
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        surface_details: Option<Provenanced<SurfaceDetails>>,
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        smoothness: Option<Provenanced<Smoothness>>,
+    },
+    Verge {
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        width: Option<Provenanced<Metre>>,
+    },
+    Separator {
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        semantic: Option<Provenanced<Semantic>>,
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        markings: Option<Provenanced<Markings>>,
+    },
+}
+
+impl ProvenancedLane {
+    /// Separators are never tagged, so they are always calculated
+    #[must_use]
+    pub fn separator(semantic: Option<Semantic>, markings: Option<Markings>) -> Self {
+        Self::Separator {
+            semantic: semantic.map(|semantic| Provenanced::new(semantic, Provenance::Calculated)),
+            markings: markings.map(|markings| Provenanced::new(markings, Provenance::Calculated)),
+        }
+    }
+}
+
+impl From<ProvenancedLane> for Lane {
+    fn from(lane: ProvenancedLane) -> Self {
+        match lane {
+            ProvenancedLane::Travel {
+                direction,
+                designated,
+                width,
+                max_speed,
+                max_height,
+                max_weight,
+                access,
+                elevation,
+                turn,
+                change,
+                destinations,
+                conditions,
+                surface,
+                surface_details,
+                smoothness,
+                lit,
+                embedded_rail,
+                centre_turn,
+            } => Lane::Travel {
+                direction: direction.map(Provenanced::into_value),
+                designated: designated.into_value(),
+                width: width.map(Provenanced::into_value),
+                max_speed: max_speed.map(Provenanced::into_value),
+                max_height: max_height.map(Provenanced::into_value),
+                max_weight: max_weight.map(Provenanced::into_value),
+                access: access.map(Provenanced::into_value),
+                elevation: elevation.map(Provenanced::into_value),
+                turn: turn.map(Provenanced::into_value),
+                change: change.map(Provenanced::into_value),
+                destinations: destinations.map(Provenanced::into_value),
+                conditions: conditions.map(Provenanced::into_value),
+                surface: surface.map(Provenanced::into_value),
+                surface_details: surface_details.map(Provenanced::into_value),
+                smoothness: smoothness.map(Provenanced::into_value),
+                lit: lit.map(Provenanced::into_value),
+                embedded_rail: matches!(embedded_rail, Some(Provenanced { value: true, .. })),
+                centre_turn: matches!(centre_turn, Some(Provenanced { value: true, .. })),
+            },
+            ProvenancedLane::Parking {
+                direction,
+                designated,
+                orientation,
+                width,
+                surface,
+                surface_details,
+                smoothness,
+            } => Lane::Parking {
+                direction: direction.into_value(),
+                designated: designated.into_value(),
+                orientation: orientation.map(Provenanced::into_value),
+                width: width.map(Provenanced::into_value),
+                surface: surface.map(Provenanced::into_value),
+                surface_details: surface_details.map(Provenanced::into_value),
+                smoothness: smoothness.map(Provenanced::into_value),
+            },
+            ProvenancedLane::Shoulder {
+                direction,
+                width,
+                surface,
+                surface_details,
+                smoothness,
+            } => Lane::Shoulder {
+                direction: direction.map(Provenanced::into_value),
+                width: width.map(Provenanced::into_value),
+                surface: surface.map(Provenanced::into_value),
+                surface_details: surface_details.map(Provenanced::into_value),
+                smoothness: smoothness.map(Provenanced::into_value),
+            },
+            ProvenancedLane::Verge { width } => Lane::Verge {
+                width: width.map(Provenanced::into_value),
+            },
+            ProvenancedLane::Separator { semantic, markings } => Lane::Separator {
+                semantic: semantic.map(Provenanced::into_value),
+                markings: markings.map(Provenanced::into_value),
+            },
+        }
+    }
+}
+
+/// A `Road` with the provenance of each lane property,
+/// see `TagsToLanesConfig::include_provenance`
+pub type ProvenancedRoad = RoadOf<ProvenancedLane>;
+
+impl ProvenancedRoad {
+    /// The same road's metadata, with lanes carrying provenance
+    #[must_use]
+    pub fn new(road: &Road, lanes: Vec<ProvenancedLane>) -> Self {
+        road.with_lanes(lanes)
+    }
+}
+
+impl From<ProvenancedRoad> for Road {
+    fn from(road: ProvenancedRoad) -> Self {
+        road.map_lanes(Lane::from)
+    }
+}
@@ -1,3 +1,5 @@
+use osm_tag_schemes::Kerb;
+
 use super::Printable;
 use crate::locale::Locale;
 use crate::metric::Metre;
@@ -9,6 +11,7 @@ use crate::metric::Metre;
 pub enum Semantic {
     Buffer,
     Centre,
+    CentreTurn,
     Hard,
     Kerb,
     Lane,
@@ -58,6 +61,8 @@ pub struct Marking {
     pub style: Style,
     pub width: Option<Metre>,
     pub color: Option<Color>,
+    /// The kind and height of a kerb marking
+    pub kerb: Option<KerbProfile>,
 }
 
 impl Marking {
@@ -69,6 +74,15 @@ impl Marking {
     }
 }
 
+/// A kerb, as seen by pedestrians and wheelchair users crossing it
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct KerbProfile {
+    pub kerb: Kerb,
+    /// Height above the carriageway
+    pub height: Option<Metre>,
+}
+
 /// Road Pain Style
 // TODO: deprecated, use repeating pattern definition instead
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
@@ -91,6 +105,13 @@ pub enum Style {
     // up and down are left to right
     KerbUp,
     KerbDown,
+    // Physical separation, such as between a cycle lane and the carriageway
+    Bollard,
+    FlexPost,
+    VerticalPanel,
+    Planter,
+    Fence,
+    TreeRow,
 }
 
 impl Style {
@@ -105,8 +126,27 @@ impl Style {
             Self::KerbDown => '\\',
             Self::KerbUp => '/',
             Self::NoFill => ' ',
+            Self::Bollard => '•',
+            Self::FlexPost => '¡',
+            Self::VerticalPanel => '▮',
+            Self::Planter => '✿',
+            Self::Fence => '#',
+            Self::TreeRow => '♣',
         }
     }
+    /// A physical object rather than road paint or a kerb
+    #[must_use]
+    pub const fn is_object(&self) -> bool {
+        matches!(
+            self,
+            Self::Bollard
+                | Self::FlexPost
+                | Self::VerticalPanel
+                | Self::Planter
+                | Self::Fence
+                | Self::TreeRow
+        )
+    }
     /// Opposite marking style
     #[must_use]
     pub const fn opposite(&self) -> Self {
@@ -118,6 +158,12 @@ impl Style {
             Self::KerbDown => Self::KerbUp,
             Self::KerbUp => Self::KerbDown,
             Self::NoFill => Self::NoFill,
+            Self::Bollard => Self::Bollard,
+            Self::FlexPost => Self::FlexPost,
+            Self::VerticalPanel => Self::VerticalPanel,
+            Self::Planter => Self::Planter,
+            Self::Fence => Self::Fence,
+            Self::TreeRow => Self::TreeRow,
         }
     }
 }
@@ -0,0 +1,175 @@
+//! Fetching ways, resolving their locale and converting them to lanes.
+//!
+//! The web app, the npm package and the CLI all go through these functions,
+//! so that they convert ways the same way.
+
+use geo::{LineString, Point};
+use osm_tags::Tags;
+
+use crate::locale::{DrivingSide, Locale};
+use crate::overpass;
+use crate::transform::{
+    lanes_to_tags, tags_to_lanes, LanesToTagsConfig, LanesToTagsMsg, RoadError, RoadFromTags,
+    TagsToLanesConfig,
+};
+
+/// An OSM way and the locale it is in
+#[derive(Debug)]
+pub struct Way {
+    pub id: u64,
+    pub tags: Tags,
+    pub geometry: LineString<f64>,
+    pub locale: Locale,
+}
+
+#[allow(clippy::large_enum_variant)]
+#[derive(Debug)]
+pub enum Error {
+    Overpass(overpass::Error),
+    Road(RoadError),
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Overpass(e) => e.fmt(f),
+            Self::Road(e) => e.fmt(f),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<overpass::Error> for Error {
+    fn from(e: overpass::Error) -> Self {
+        Self::Overpass(e)
+    }
+}
+
+impl From<RoadError> for Error {
+    fn from(e: RoadError) -> Self {
+        Self::Road(e)
+    }
+}
+
+/// Locale from the `driving_side=*` and `ISO3166-*=*` values of a way or the areas it is in,
+/// driving on the right if not known
+#[must_use]
+pub fn locale(driving_side: Option<&str>, iso_3166: Option<&str>) -> Locale {
+    Locale::builder()
+        .driving_side(
+            driving_side
+                .and_then(|side| side.parse().ok())
+                .unwrap_or(DrivingSide::Right),
+        )
+        .iso_3166_option(iso_3166)
+        .build()
+}
+
+/// Fetch a way by ID
+///
+/// # Errors
+///
+/// The request failed, or the way was not found.
+pub async fn way(id: u64) -> Result<Way, overpass::Error> {
+    let (tags, geometry, locale) = overpass::get_way(id).await?;
+    Ok(Way {
+        id,
+        tags,
+        geometry,
+        locale,
+    })
+}
+
+/// Fetch a way by ID, blocking instead of awaiting the response
+///
+/// # Errors
+///
+/// The request failed, or the way was not found.
+#[cfg(feature = "overpass-blocking")]
+pub fn way_blocking(id: u64) -> Result<Way, overpass::Error> {
+    let (tags, geometry, locale) = overpass::blocking::get_way(id)?;
+    Ok(Way {
+        id,
+        tags,
+        geometry,
+        locale,
+    })
+}
+
+/// Fetch the way nearest to a point, within a radius
+///
+/// # Errors
+///
+/// The request failed, or no way was found.
+pub async fn nearby(point: Point<f64>, radius: f64) -> Result<Way, overpass::Error> {
+    let (id, tags, geometry, locale) = overpass::get_nearby(point, radius).await?;
+    Ok(Way {
+        id,
+        tags,
+        geometry,
+        locale,
+    })
+}
+
+/// The lanes from some tags, and the tags normalized back from those lanes
+#[derive(Debug)]
+pub struct Conversion {
+    pub road: RoadFromTags,
+    pub normalized: Result<Tags, LanesToTagsMsg>,
+}
+
+/// Convert tags to lanes, and those lanes back to tags
+///
+/// # Errors
+///
+/// The tags could not be converted to lanes.
+pub fn convert(
+    tags: &Tags,
+    locale: &Locale,
+    config: &TagsToLanesConfig,
+) -> Result<Conversion, RoadError> {
+    let road = tags_to_lanes(tags, locale, config)?;
+    let normalized = lanes_to_tags(&road.road, locale, &LanesToTagsConfig::new(false));
+    Ok(Conversion { road, normalized })
+}
+
+/// Fetch a way by ID and convert its tags to lanes
+///
+/// # Errors
+///
+/// The way could not be fetched, or its tags could not be converted to lanes.
+pub async fn way_to_lanes(
+    id: u64,
+    config: &TagsToLanesConfig,
+) -> Result<(Way, RoadFromTags), Error> {
+    let way = way(id).await?;
+    let road = tags_to_lanes(&way.tags, &way.locale, config)?;
+    Ok((way, road))
+}
+
+#[cfg(test)]
+mod tests {
+    use osm_tags::Tags;
+
+    use super::{convert, locale};
+    use crate::locale::DrivingSide;
+    use crate::transform::TagsToLanesConfig;
+
+    #[test]
+    fn test_locale() {
+        let locale_gb = locale(Some("left"), Some("GB"));
+        assert_eq!(locale_gb.driving_side, DrivingSide::Left);
+        assert_eq!(locale_gb.country.map(|c| c.alpha2), Some("GB"));
+        assert_eq!(locale(None, None).driving_side, DrivingSide::Right);
+    }
+
+    #[test]
+    fn test_convert() {
+        let tags = Tags::from_str_lenient("highway=residential\nlanes=2").unwrap();
+        let conversion =
+            convert(&tags, &locale(None, None), &TagsToLanesConfig::default()).unwrap();
+        let normalized = conversion.normalized.unwrap();
+        assert_eq!(normalized.get("lanes"), Some("2"));
+    }
+}
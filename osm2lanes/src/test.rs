@@ -1,8 +1,20 @@
+use osm_tag_schemes::{Change, Lit, ParkingOrientation, Smoothness, Surface, TurnIndication};
 use osm_tags::Tags;
 use serde::{Deserialize, Serialize};
 
-use crate::locale::DrivingSide;
-use crate::road::{Lane, Road};
+use crate::locale::{DrivingSide, Locale};
+use crate::metric::{Metre, Speed, Tonne};
+use crate::road::{
+    AccessByType, Color, Conditions, Destinations, Direction, Elevation, KerbProfile, Lane,
+    Marking, Markings, Road, Semantic, SurfaceDetails,
+};
+use crate::transform::{
+    lanes_to_tags, tags_to_lanes, LanesToTagsConfig, LanesToTagsMsg, RoadError, RoadFromTags,
+    RoadWarnings, TagsToLanesConfig,
+};
+
+/// Path of the test cases, relative to the repository root
+pub const TESTS_PATH: &str = "data/tests.yml";
 
 #[derive(Clone, Serialize, Deserialize)]
 #[serde(untagged, deny_unknown_fields)]
@@ -80,6 +92,29 @@ impl TestCase {
     pub fn example(&self) -> Option<&str> {
         self.example.as_deref()
     }
+    /// Canonical name, e.g. `way_12345` or `bus_lanes_both_ways`
+    ///
+    /// Prefers the way id, then the description, then the link.
+    #[must_use]
+    pub fn name(&self) -> String {
+        if let Some(way_id) = self.way_id {
+            return format!("way_{way_id}");
+        }
+        let text = self
+            .description
+            .as_deref()
+            .or(self.link.as_deref())
+            .unwrap_or_default();
+        let mut name = String::with_capacity(text.len());
+        for c in text.chars() {
+            if c.is_ascii_alphanumeric() {
+                name.push(c.to_ascii_lowercase());
+            } else if !name.is_empty() && !name.ends_with('_') {
+                name.push('_');
+            }
+        }
+        name.trim_end_matches('_').to_owned()
+    }
 }
 
 impl std::fmt::Display for TestCase {
@@ -108,167 +143,652 @@ impl std::fmt::Display for TestCase {
 /// Get Test Cases from tests.yml
 #[must_use]
 pub fn get_tests() -> Vec<TestCase> {
-    let tests: Vec<TestCase> = serde_yaml::from_str(include_str!("../../data/tests.yml"))
-        .expect("invalid yaml in data/tests.yml");
-    let tests: Vec<TestCase> = tests.into_iter().filter(TestCase::test_enabled).collect();
-    tests
+    parse_tests(include_str!("../../data/tests.yml")).expect("invalid yaml in data/tests.yml")
 }
 
-#[cfg(test)]
-mod tests {
-
-    use assert_json_diff::assert_json_eq;
-
-    use super::*;
-    use crate::locale::{DrivingSide, Locale};
-    use crate::metric::{Metre, Speed};
-    use crate::road::{AccessByType, Color, Lane, Marking, Markings, Printable, Road, Semantic};
-    use crate::transform::{
-        lanes_to_tags, tags_to_lanes, LanesToTagsConfig, RoadError, RoadFromTags, RoadWarnings,
-        TagsToLanesConfig,
-    };
+/// Parse the enabled test cases from YAML or JSON, in the format of `data/tests.yml`
+///
+/// # Errors
+///
+/// If the test cases are not valid YAML or JSON, or not in the format of [`TestCase`]
+pub fn parse_tests(s: &str) -> Result<Vec<TestCase>, serde_yaml::Error> {
+    let tests: Vec<TestCase> = serde_yaml::from_str(s)?;
+    Ok(tests.into_iter().filter(TestCase::test_enabled).collect())
+}
 
-    static LOG_INIT: std::sync::Once = std::sync::Once::new();
+impl TestCase {
+    /// The locale of the test case
+    ///
+    /// # Errors
+    ///
+    /// If the `ISO 3166-2` code is malformed
+    pub fn locale(&self) -> Result<Locale, RoundtripError> {
+        if let Some(code) = self.iso_3166_2.as_deref() {
+            if !is_iso_3166(code) {
+                return Err(RoundtripError::Iso3166(code.to_owned()));
+            }
+        }
+        Ok(Locale::builder()
+            .driving_side(self.driving_side)
+            .iso_3166_option(self.iso_3166_2.as_deref())
+            .build())
+    }
 
-    trait EqExpected<Exp: ?Sized = Self> {
-        fn eq_exp(&self, expected: &Exp) -> bool;
+    /// Convert the tags to lanes, checking them against the expected road,
+    /// then convert the lanes back to tags and those tags to lanes again,
+    /// checking that both conversions to lanes agree.
+    ///
+    /// Separators and warnings are checked as configured by the `rust` options of the test case.
+    ///
+    /// # Errors
+    ///
+    /// If the locale is malformed, either conversion fails,
+    /// the lanes differ from the expected road or after the roundtrip,
+    /// or the warnings are not as expected
+    pub fn roundtrip(&self) -> Result<(), RoundtripError> {
+        let locale = self.locale()?;
+        let config = TagsToLanesConfig {
+            include_separators: self.test_include_separators() && self.expected_has_separators(),
+            ..TagsToLanesConfig::default()
+        };
+        let (road, warnings) = tags_to_lanes(&self.tags, &locale, &config)
+            .map_err(RoundtripError::TagsToLanes)?
+            .into_filtered_road(self);
+        let expected_road = self.expected_road();
+        let expected = if road.eq_exp(&expected_road) {
+            None
+        } else {
+            Some(differences(
+                &road.lanes,
+                &expected_road.lanes,
+                EqExpected::eq_exp,
+            ))
+        };
+        let tags = lanes_to_tags(&road, &locale, &LanesToTagsConfig::new(false))
+            .map_err(RoundtripError::LanesToTags)?;
+        let roundtrip = tags_to_lanes(&tags, &locale, &config)
+            .map_err(RoundtripError::Roundtrip)?
+            .road;
+        let roundtrip = differences(&roundtrip.lanes, &road.lanes, PartialEq::eq);
+        if expected.is_some() || !roundtrip.is_empty() {
+            return Err(RoundtripError::Lanes {
+                tags,
+                expected,
+                roundtrip,
+            });
+        }
+        if self.test_expects_warnings() == warnings.is_empty() {
+            return Err(RoundtripError::Warnings {
+                expected: self.test_expects_warnings(),
+                warnings,
+            });
+        }
+        Ok(())
     }
 
-    impl<T: EqExpected> EqExpected for Option<T> {
-        fn eq_exp(&self, expected: &Self) -> bool {
-            match (self, expected) {
-                (None, None) | (Some(_), None) => true,
-                (None, Some(_)) => false,
-                (Some(actual), Some(expected)) => actual.eq_exp(expected),
-            }
+    /// Whether the lane is compared, separators only where expected and enabled
+    fn is_lane_enabled(&self, lane: &Lane) -> bool {
+        match lane {
+            Lane::Separator { .. } => {
+                self.test_include_separators() && self.expected_has_separators()
+            },
+            _ => true,
         }
     }
 
-    impl EqExpected for Road {
-        fn eq_exp(&self, expected: &Self) -> bool {
-            if self.lanes.len() != expected.lanes.len() {
-                return false;
-            }
-            self.lanes
+    /// The expected road, with the lanes that are not compared filtered out
+    fn expected_road(&self) -> Road {
+        Road {
+            name: None,
+            r#ref: None,
+            highway: osm_tag_schemes::Highway::from_tags(&self.tags)
+                .ok()
+                .flatten()
+                .unwrap_or_else(|| self.road.highway.clone()),
+            lit: None,
+            tracktype: None,
+            smoothness: None,
+            cycling_infrastructure: self.road.cycling_infrastructure,
+            separately_mapped: self.road.separately_mapped.clone(),
+            placement: self.road.placement,
+            roundabout: self.road.roundabout,
+            dual_carriageway: self.road.dual_carriageway,
+            steps: self.road.steps.clone(),
+            role: self.road.role,
+            overtaking: self.road.overtaking,
+            priority_road: self.road.priority_road,
+            passing_places: self.road.passing_places,
+            lanes: self
+                .road
+                .lanes
                 .iter()
-                .zip(expected.lanes.iter())
-                .all(|(actual, expected)| actual.eq_exp(expected))
+                .filter(|lane| self.is_lane_enabled(lane))
+                .cloned()
+                .collect(),
         }
     }
+}
 
-    impl EqExpected for Lane {
-        fn eq_exp(&self, expected: &Self) -> bool {
-            #[allow(clippy::unnested_or_patterns)]
-            match (self, expected) {
-                (
-                    Lane::Separator {
-                        markings: markings_actual,
-                        semantic: semantic_actual,
-                    },
-                    Lane::Separator {
-                        markings: markings_expected,
-                        semantic: semantic_expected,
-                    },
-                ) => {
-                    markings_actual.eq_exp(&markings_expected)
-                        && semantic_actual.eq_exp(&semantic_expected)
-                },
-                (
-                    Lane::Travel {
-                        designated: actual_designated,
-                        direction: actual_direction,
-                        width: actual_width,
-                        max_speed: actual_max_speed,
-                        access: actual_access,
-                    },
-                    Lane::Travel {
-                        designated: expected_designated,
-                        direction: expected_direction,
-                        width: expected_width,
-                        max_speed: expected_max_speed,
-                        access: expected_access,
-                    },
-                ) => {
-                    actual_designated == expected_designated
-                        && actual_direction == expected_direction
-                        && actual_width.eq_exp(&expected_width)
-                        && actual_max_speed.eq_exp(&expected_max_speed)
-                        && actual_access.eq_exp(&expected_access)
-                },
-                (
-                    Lane::Parking {
-                        designated: actual_designated,
-                        direction: actual_direction,
-                        width: actual_width,
-                    },
-                    Lane::Parking {
-                        designated: expected_designated,
-                        direction: expected_direction,
-                        width: expected_width,
-                    },
-                ) => {
-                    actual_designated == expected_designated
-                        && actual_direction == expected_direction
-                        && actual_width.eq_exp(&expected_width)
-                },
-                (
-                    Lane::Shoulder {
-                        width: actual_width,
-                    },
-                    Lane::Shoulder {
-                        width: expected_width,
+impl RoadFromTags {
+    /// Return a Road based upon a `RoadFromTags` with irrelevant parts filtered out.
+    fn into_filtered_road(self, test: &TestCase) -> (Road, RoadWarnings) {
+        (
+            Road {
+                name: None,
+                r#ref: None,
+                highway: self.road.highway,
+                lit: None,
+                tracktype: None,
+                smoothness: None,
+                cycling_infrastructure: self.road.cycling_infrastructure,
+                separately_mapped: self.road.separately_mapped,
+                placement: self.road.placement,
+                roundabout: self.road.roundabout,
+                dual_carriageway: self.road.dual_carriageway,
+                steps: self.road.steps,
+                role: self.road.role,
+                overtaking: self.road.overtaking,
+                priority_road: self.road.priority_road,
+                passing_places: self.road.passing_places,
+                lanes: self
+                    .road
+                    .lanes
+                    .into_iter()
+                    .filter(|lane| test.is_lane_enabled(lane))
+                    .collect(),
+            },
+            self.warnings,
+        )
+    }
+}
+
+/// Whether the code is an ISO 3166-1 alpha-2 or alpha-3 code, or an ISO 3166-2 code
+fn is_iso_3166(code: &str) -> bool {
+    let is_alpha = |code: &str| code.chars().all(|c| c.is_ascii_uppercase());
+    match code.split_once('-') {
+        Some((country, subdivision)) => {
+            country.len() == 2
+                && is_alpha(country)
+                && (1..=3).contains(&subdivision.len())
+                && subdivision.chars().all(|c| c.is_ascii_alphanumeric())
+        },
+        None => matches!(code.len(), 2 | 3) && is_alpha(code),
+    }
+}
+
+/// The lanes at each position that are not equal, by position from the left
+fn differences(
+    actual: &[Lane],
+    expected: &[Lane],
+    eq: impl Fn(&Lane, &Lane) -> bool,
+) -> Vec<LaneDifference> {
+    (0..actual.len().max(expected.len()))
+        .map(|index| LaneDifference {
+            index,
+            actual: actual.get(index).cloned(),
+            expected: expected.get(index).cloned(),
+        })
+        .filter(
+            |difference| match (&difference.actual, &difference.expected) {
+                (Some(actual), Some(expected)) => !eq(actual, expected),
+                _ => true,
+            },
+        )
+        .collect()
+}
+
+/// A lane that differs from the one expected at the same position
+#[derive(Debug)]
+pub struct LaneDifference {
+    /// Position from the left
+    pub index: usize,
+    /// The lane converted from tags, if any
+    pub actual: Option<Lane>,
+    /// The expected lane, if any
+    pub expected: Option<Lane>,
+}
+
+/// Failure to roundtrip the tags of a test case, see [`TestCase::roundtrip`]
+#[derive(Debug)]
+pub enum RoundtripError {
+    /// The `ISO 3166-2` code is malformed
+    Iso3166(String),
+    /// The tags could not be converted to lanes
+    TagsToLanes(RoadError),
+    /// The lanes could not be converted back to tags
+    LanesToTags(LanesToTagsMsg),
+    /// The tags of the lanes could not be converted to lanes
+    Roundtrip(RoadError),
+    /// The tags convert to lanes other than expected, or to other lanes after the roundtrip
+    Lanes {
+        /// The tags of the lanes
+        tags: Tags,
+        /// The differences from the expected road, if it differs
+        expected: Option<Vec<LaneDifference>>,
+        /// The differences after the roundtrip, from the lanes of the original tags
+        roundtrip: Vec<LaneDifference>,
+    },
+    /// Warnings where none are expected, or none where they are
+    Warnings {
+        expected: bool,
+        warnings: RoadWarnings,
+    },
+}
+
+impl std::error::Error for RoundtripError {}
+
+impl std::fmt::Display for RoundtripError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fn write_differences(
+            f: &mut std::fmt::Formatter<'_>,
+            differences: &[LaneDifference],
+        ) -> std::fmt::Result {
+            let lane = |lane: &Option<Lane>| {
+                lane.as_ref().map_or_else(
+                    || "none".to_owned(),
+                    |lane| {
+                        serde_yaml::to_string(lane)
+                            .unwrap_or_default()
+                            .trim_end()
+                            .replace('\n', "\n      ")
                     },
-                ) => actual_width.eq_exp(&expected_width),
-                (actual, expected) => actual == expected,
+                )
+            };
+            for difference in differences {
+                writeln!(f, "  lane {}:", difference.index)?;
+                writeln!(f, "    - {}", lane(&difference.expected))?;
+                writeln!(f, "    + {}", lane(&difference.actual))?;
             }
+            Ok(())
         }
-    }
 
-    impl EqExpected for Markings {
-        fn eq_exp(&self, expected: &Self) -> bool {
-            self.iter()
-                .zip(expected.iter())
-                .all(|(actual, expected)| actual.eq_exp(expected))
+        match self {
+            Self::Iso3166(code) => write!(f, "malformed ISO 3166-2 code {code}"),
+            Self::TagsToLanes(e) => write!(f, "tags to lanes: {e}"),
+            Self::LanesToTags(e) => write!(f, "lanes to tags: {e}"),
+            Self::Roundtrip(e) => write!(f, "roundtrip tags to lanes: {e}"),
+            Self::Lanes {
+                tags,
+                expected,
+                roundtrip,
+            } => {
+                if let Some(expected) = expected {
+                    writeln!(f, "lanes differ from the expected road:")?;
+                    if expected.is_empty() {
+                        writeln!(f, "  road properties differ")?;
+                    }
+                    write_differences(f, expected)?;
+                }
+                if !roundtrip.is_empty() {
+                    writeln!(f, "roundtrip lanes differ, from tags:")?;
+                    for (key, value) in tags.to_str_pairs() {
+                        writeln!(f, "    {key}={value}")?;
+                    }
+                    write_differences(f, roundtrip)?;
+                }
+                Ok(())
+            },
+            Self::Warnings {
+                expected: true,
+                warnings: _,
+            } => write!(f, "expected warnings, try removing `expect_warnings`"),
+            Self::Warnings {
+                expected: false,
+                warnings,
+            } => write!(f, "unexpected warnings: {warnings}"),
         }
     }
+}
+
+trait EqExpected<Exp: ?Sized = Self> {
+    fn eq_exp(&self, expected: &Exp) -> bool;
+}
 
-    impl EqExpected for Marking {
-        fn eq_exp(&self, expected: &Self) -> bool {
-            self.style == expected.style
-                && self.color.eq_exp(&expected.color)
-                && self.width.eq_exp(&expected.width)
+impl<T: EqExpected> EqExpected for Option<T> {
+    fn eq_exp(&self, expected: &Self) -> bool {
+        match (self, expected) {
+            (None | Some(_), None) => true,
+            (None, Some(_)) => false,
+            (Some(actual), Some(expected)) => actual.eq_exp(expected),
         }
     }
+}
 
-    impl EqExpected for Semantic {
-        fn eq_exp(&self, expected: &Self) -> bool {
-            self == expected
+impl EqExpected for Road {
+    fn eq_exp(&self, expected: &Self) -> bool {
+        if self.lanes.len() != expected.lanes.len() {
+            return false;
+        }
+        if !self
+            .cycling_infrastructure
+            .eq_exp(&expected.cycling_infrastructure)
+        {
+            return false;
+        }
+        if !expected.separately_mapped.is_empty()
+            && self.separately_mapped != expected.separately_mapped
+        {
+            return false;
+        }
+        if expected.placement.is_some() && self.placement != expected.placement {
+            return false;
+        }
+        if self.highway.lifecycle() != expected.highway.lifecycle() {
+            return false;
+        }
+        if !self.roundabout.eq_exp(&expected.roundabout) {
+            return false;
+        }
+        if !self.dual_carriageway.eq_exp(&expected.dual_carriageway) {
+            return false;
+        }
+        if expected.steps.is_some() && self.steps != expected.steps {
+            return false;
+        }
+        if expected.role.is_some() && self.role != expected.role {
+            return false;
         }
+        if expected.overtaking.is_some() && self.overtaking != expected.overtaking {
+            return false;
+        }
+        if expected.priority_road.is_some() && self.priority_road != expected.priority_road {
+            return false;
+        }
+        if !self.passing_places.eq_exp(&expected.passing_places) {
+            return false;
+        }
+        self.lanes
+            .iter()
+            .zip(expected.lanes.iter())
+            .all(|(actual, expected)| actual.eq_exp(expected))
     }
+}
 
-    impl EqExpected for Metre {
-        fn eq_exp(&self, expected: &Self) -> bool {
-            self == expected
+impl EqExpected for Lane {
+    #[allow(
+        clippy::too_many_lines,
+        clippy::unnested_or_patterns,
+        clippy::similar_names
+    )]
+    fn eq_exp(&self, expected: &Self) -> bool {
+        match (self, expected) {
+            (
+                Lane::Separator {
+                    markings: markings_actual,
+                    semantic: semantic_actual,
+                },
+                Lane::Separator {
+                    markings: markings_expected,
+                    semantic: semantic_expected,
+                },
+            ) => {
+                markings_actual.eq_exp(&markings_expected)
+                    && semantic_actual.eq_exp(&semantic_expected)
+            },
+            (
+                Lane::Travel {
+                    designated: actual_designated,
+                    direction: actual_direction,
+                    width: actual_width,
+                    max_speed: actual_max_speed,
+                    max_height: actual_max_height,
+                    max_weight: actual_max_weight,
+                    access: actual_access,
+                    elevation: actual_elevation,
+                    turn: actual_turn,
+                    change: actual_change,
+                    destinations: actual_destinations,
+                    conditions: actual_conditions,
+                    surface: actual_surface,
+                    surface_details: actual_surface_details,
+                    smoothness: actual_smoothness,
+                    lit: actual_lit,
+                    embedded_rail: actual_embedded_rail,
+                    centre_turn: actual_centre_turn,
+                },
+                Lane::Travel {
+                    designated: expected_designated,
+                    direction: expected_direction,
+                    width: expected_width,
+                    max_speed: expected_max_speed,
+                    max_height: expected_max_height,
+                    max_weight: expected_max_weight,
+                    access: expected_access,
+                    elevation: expected_elevation,
+                    turn: expected_turn,
+                    change: expected_change,
+                    destinations: expected_destinations,
+                    conditions: expected_conditions,
+                    surface: expected_surface,
+                    surface_details: expected_surface_details,
+                    smoothness: expected_smoothness,
+                    lit: expected_lit,
+                    embedded_rail: expected_embedded_rail,
+                    centre_turn: expected_centre_turn,
+                },
+            ) => {
+                actual_designated == expected_designated
+                    && actual_direction == expected_direction
+                    && actual_width.eq_exp(&expected_width)
+                    && actual_max_speed.eq_exp(&expected_max_speed)
+                    && actual_max_height.eq_exp(&expected_max_height)
+                    && actual_max_weight.eq_exp(&expected_max_weight)
+                    && actual_access.eq_exp(&expected_access)
+                    && actual_elevation.eq_exp(&expected_elevation)
+                    && actual_turn.eq_exp(&expected_turn)
+                    && actual_change.eq_exp(expected_change)
+                    && actual_destinations.eq_exp(expected_destinations)
+                    && actual_conditions.eq_exp(expected_conditions)
+                    && actual_surface.eq_exp(&expected_surface)
+                    && actual_surface_details.eq_exp(expected_surface_details)
+                    && actual_smoothness.eq_exp(&expected_smoothness)
+                    && actual_lit.eq_exp(expected_lit)
+                    && actual_embedded_rail.eq_exp(expected_embedded_rail)
+                    && actual_centre_turn.eq_exp(expected_centre_turn)
+            },
+            (
+                Lane::Parking {
+                    designated: actual_designated,
+                    direction: actual_direction,
+                    orientation: actual_orientation,
+                    width: actual_width,
+                    surface: actual_surface,
+                    surface_details: actual_surface_details,
+                    smoothness: actual_smoothness,
+                },
+                Lane::Parking {
+                    designated: expected_designated,
+                    direction: expected_direction,
+                    orientation: expected_orientation,
+                    width: expected_width,
+                    surface: expected_surface,
+                    surface_details: expected_surface_details,
+                    smoothness: expected_smoothness,
+                },
+            ) => {
+                actual_designated == expected_designated
+                    && actual_direction == expected_direction
+                    && actual_orientation.eq_exp(expected_orientation)
+                    && actual_width.eq_exp(&expected_width)
+                    && actual_surface.eq_exp(&expected_surface)
+                    && actual_surface_details.eq_exp(expected_surface_details)
+                    && actual_smoothness.eq_exp(&expected_smoothness)
+            },
+            (
+                Lane::Shoulder {
+                    direction: actual_direction,
+                    width: actual_width,
+                    surface: actual_surface,
+                    surface_details: actual_surface_details,
+                    smoothness: actual_smoothness,
+                },
+                Lane::Shoulder {
+                    direction: expected_direction,
+                    width: expected_width,
+                    surface: expected_surface,
+                    surface_details: expected_surface_details,
+                    smoothness: expected_smoothness,
+                },
+            ) => {
+                actual_direction.eq_exp(&expected_direction)
+                    && actual_width.eq_exp(&expected_width)
+                    && actual_surface.eq_exp(&expected_surface)
+                    && actual_surface_details.eq_exp(expected_surface_details)
+                    && actual_smoothness.eq_exp(&expected_smoothness)
+            },
+            (
+                Lane::Verge {
+                    width: actual_width,
+                },
+                Lane::Verge {
+                    width: expected_width,
+                },
+            ) => actual_width.eq_exp(expected_width),
+            (actual, expected) => actual == expected,
         }
     }
+}
 
-    impl EqExpected for Speed {
-        fn eq_exp(&self, expected: &Self) -> bool {
-            self == expected
-        }
+impl EqExpected for Markings {
+    fn eq_exp(&self, expected: &Self) -> bool {
+        self.iter()
+            .zip(expected.iter())
+            .all(|(actual, expected)| actual.eq_exp(expected))
     }
+}
 
-    impl EqExpected for AccessByType {
-        fn eq_exp(&self, expected: &Self) -> bool {
-            self == expected
-        }
+impl EqExpected for Marking {
+    fn eq_exp(&self, expected: &Self) -> bool {
+        self.style == expected.style
+            && self.color.eq_exp(&expected.color)
+            && self.width.eq_exp(&expected.width)
+            && self.kerb.eq_exp(&expected.kerb)
     }
+}
 
-    impl EqExpected for Color {
-        fn eq_exp(&self, expected: &Self) -> bool {
-            self == expected
-        }
+impl EqExpected for KerbProfile {
+    fn eq_exp(&self, expected: &Self) -> bool {
+        self.kerb == expected.kerb && self.height.eq_exp(&expected.height)
     }
+}
+
+impl EqExpected for Semantic {
+    fn eq_exp(&self, expected: &Self) -> bool {
+        self == expected
+    }
+}
+
+impl EqExpected for Surface {
+    fn eq_exp(&self, expected: &Self) -> bool {
+        self == expected
+    }
+}
+
+impl EqExpected for ParkingOrientation {
+    fn eq_exp(&self, expected: &Self) -> bool {
+        self == expected
+    }
+}
+
+impl EqExpected for Smoothness {
+    fn eq_exp(&self, expected: &Self) -> bool {
+        self == expected
+    }
+}
+
+impl EqExpected for Change {
+    fn eq_exp(&self, expected: &Self) -> bool {
+        self == expected
+    }
+}
+
+impl EqExpected for Lit {
+    fn eq_exp(&self, expected: &Self) -> bool {
+        self == expected
+    }
+}
+
+impl EqExpected for Vec<TurnIndication> {
+    fn eq_exp(&self, expected: &Self) -> bool {
+        self == expected
+    }
+}
+
+impl EqExpected for bool {
+    fn eq_exp(&self, expected: &Self) -> bool {
+        self == expected
+    }
+}
+
+impl EqExpected for Direction {
+    fn eq_exp(&self, expected: &Self) -> bool {
+        self == expected
+    }
+}
+
+impl EqExpected for Elevation {
+    fn eq_exp(&self, expected: &Self) -> bool {
+        self == expected
+    }
+}
+
+impl EqExpected for Metre {
+    fn eq_exp(&self, expected: &Self) -> bool {
+        self == expected
+    }
+}
+
+impl EqExpected for Tonne {
+    fn eq_exp(&self, expected: &Self) -> bool {
+        self == expected
+    }
+}
+
+impl EqExpected for Speed {
+    fn eq_exp(&self, expected: &Self) -> bool {
+        self == expected
+    }
+}
+
+impl EqExpected for SurfaceDetails {
+    fn eq_exp(&self, expected: &Self) -> bool {
+        self == expected
+    }
+}
+
+impl EqExpected for Conditions {
+    fn eq_exp(&self, expected: &Self) -> bool {
+        self == expected
+    }
+}
+
+impl EqExpected for Destinations {
+    fn eq_exp(&self, expected: &Self) -> bool {
+        self == expected
+    }
+}
+
+impl EqExpected for AccessByType {
+    fn eq_exp(&self, expected: &Self) -> bool {
+        self == expected
+    }
+}
+
+impl EqExpected for Color {
+    fn eq_exp(&self, expected: &Self) -> bool {
+        self == expected
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use assert_json_diff::assert_json_eq;
+
+    use super::*;
+    use crate::road::Printable;
+
+    static LOG_INIT: std::sync::Once = std::sync::Once::new();
 
     impl DrivingSide {
         /// Three-letter abbreviation
@@ -304,58 +824,6 @@ mod tests {
                 println!("        Comment: {}", comment);
             }
         }
-
-        fn is_lane_enabled(&self, lane: &Lane) -> bool {
-            match lane {
-                Lane::Separator { .. } => {
-                    self.test_include_separators() && self.expected_has_separators()
-                },
-                _ => true,
-            }
-        }
-
-        fn expected_road(&self) -> Road {
-            Road {
-                name: None,
-                r#ref: None,
-                highway: osm_tag_schemes::Highway::from_tags(&self.tags)
-                    .unwrap()
-                    .unwrap(),
-                lit: None,
-                tracktype: None,
-                smoothness: None,
-                lanes: self
-                    .road
-                    .lanes
-                    .iter()
-                    .filter(|lane| self.is_lane_enabled(lane))
-                    .cloned()
-                    .collect(),
-            }
-        }
-    }
-
-    impl RoadFromTags {
-        /// Return a Road based upon a `RoadFromTags` with irrelevant parts filtered out.
-        fn into_filtered_road(self, test: &TestCase) -> (Road, RoadWarnings) {
-            (
-                Road {
-                    name: None,
-                    r#ref: None,
-                    highway: self.road.highway,
-                    lit: None,
-                    tracktype: None,
-                    smoothness: None,
-                    lanes: self
-                        .road
-                        .lanes
-                        .into_iter()
-                        .filter(|lane| test.is_lane_enabled(lane))
-                        .collect(),
-                },
-                self.warnings,
-            )
-        }
     }
 
     fn stringify_lane_types(road: &Road) -> String {
@@ -462,16 +930,70 @@ mod tests {
         serde_json::to_string(&tests).expect("can't serialize test cases");
     }
 
+    #[test]
+    fn test_name() {
+        let tests = get_tests();
+        for test in &tests {
+            assert!(!test.name().is_empty(), "{test} has no name");
+        }
+        let mut test = tests.into_iter().next().unwrap();
+        test.way_id = None;
+        test.link = None;
+        test.description = Some("Bus lanes, both ways (UK)".to_owned());
+        assert_eq!(test.name(), "bus_lanes_both_ways_uk");
+        test.way_id = Some(12345);
+        assert_eq!(test.name(), "way_12345");
+    }
+
+    #[test]
+    fn test_parse_and_roundtrip() {
+        let tests = parse_tests(
+            r#"[{"description": "Two lanes", "driving_side": "right",
+                "tags": {"highway": "residential", "lanes": "2"},
+                "road": {"highway": "residential", "lanes": [
+                    {"type": "travel", "direction": "backward", "designated": "motor_vehicle"},
+                    {"type": "travel", "direction": "forward", "designated": "motor_vehicle"}
+                ]}}]"#,
+        )
+        .unwrap();
+        let test = tests.first().unwrap();
+        assert_eq!(test.name(), "two_lanes");
+        test.roundtrip().unwrap();
+    }
+
+    #[test]
+    fn test_roundtrip_errors() {
+        let tests = parse_tests(
+            r#"[{"description": "One lane", "driving_side": "right",
+                "tags": {"highway": "residential", "lanes": "2"},
+                "road": {"highway": "residential", "lanes": [
+                    {"type": "travel", "direction": "both", "designated": "motor_vehicle"}
+                ]}},
+                {"description": "Malformed", "driving_side": "right", "ISO 3166-2": "USA-WA",
+                "tags": {"highway": "residential", "lanes": "2"},
+                "road": {"highway": "residential", "lanes": []}}]"#,
+        )
+        .unwrap();
+        assert!(matches!(
+            tests[0].roundtrip(),
+            Err(RoundtripError::Lanes {
+                expected: Some(_),
+                ..
+            })
+        ));
+        assert!(matches!(
+            tests[1].roundtrip(),
+            Err(RoundtripError::Iso3166(_))
+        ));
+    }
+
     #[test]
     fn test_from_data() {
         env_logger_init();
         let tests = get_tests();
 
         for test in &tests {
-            let locale = Locale::builder()
-                .driving_side(test.driving_side)
-                .iso_3166_option(test.iso_3166_2.as_deref())
-                .build();
+            let locale = test.locale().unwrap();
             let road_from_tags = tags_to_lanes(
                 &test.tags,
                 &locale,
@@ -540,19 +1062,9 @@ mod tests {
         let tests = get_tests();
 
         for test in &tests {
-            let locale = Locale::builder()
-                .driving_side(test.driving_side)
-                .iso_3166_option(test.iso_3166_2.as_deref())
-                .build();
+            let locale = test.locale().unwrap();
             let input_road = test.expected_road();
-            let tags = lanes_to_tags(
-                &test.road,
-                &locale,
-                &LanesToTagsConfig {
-                    check_roundtrip: false,
-                },
-            )
-            .unwrap();
+            let tags = lanes_to_tags(&test.road, &locale, &LanesToTagsConfig::new(false)).unwrap();
             let output_lanes = tags_to_lanes(
                 &tags,
                 &locale,
@@ -0,0 +1,108 @@
+use std::borrow::Borrow;
+
+use osm_tags::{Tags, TagsRef};
+
+use super::{tags_to_lanes, RoadError, RoadFromTags, TagsToLanesConfig};
+use crate::locale::Locale;
+
+/// Converts many ways sharing a locale and config, such as in a map import.
+/// A convenience holding both for every call to [`tags_to_lanes`],
+/// which logs the warnings of the locale once.
+///
+/// ```
+/// use osm2lanes::locale::Locale;
+/// use osm2lanes::transform::{Converter, TagsToLanesConfig};
+///
+/// let converter = Converter::new(Locale::builder().build(), TagsToLanesConfig::default());
+/// let road = converter
+///     .convert_pairs(&[("highway", "residential"), ("lanes", "2")])
+///     .unwrap();
+/// assert!(!road.road.lanes.is_empty());
+/// ```
+pub struct Converter {
+    locale: Locale,
+    config: TagsToLanesConfig,
+}
+
+impl Converter {
+    #[must_use]
+    pub fn new(locale: Locale, config: TagsToLanesConfig) -> Self {
+        for warning in locale.warnings() {
+            log::warn!("{warning}");
+        }
+        Self { locale, config }
+    }
+
+    #[must_use]
+    pub fn locale(&self) -> &Locale {
+        &self.locale
+    }
+
+    #[must_use]
+    pub fn config(&self) -> &TagsToLanesConfig {
+        &self.config
+    }
+
+    /// Convert the tags of a way, see [`tags_to_lanes`]
+    ///
+    /// # Errors
+    ///
+    /// As [`tags_to_lanes`]
+    pub fn convert(&self, tags: &TagsRef<'_>) -> Result<RoadFromTags, RoadError> {
+        tags_to_lanes(tags, &self.locale, &self.config)
+    }
+
+    /// Convert the tags of a way, borrowed as key and value pairs such as from a PBF reader,
+    /// without copying them, see [`TagsRef`].
+    ///
+    /// # Errors
+    ///
+    /// As [`tags_to_lanes`], or if a key is repeated
+    pub fn convert_pairs(&self, pairs: &[(&str, &str)]) -> Result<RoadFromTags, RoadError> {
+        let tags = TagsRef::from_ref_pairs(pairs.iter().copied())?;
+        self.convert(&tags)
+    }
+
+    /// Convert the tags of each way as it is iterated
+    pub fn convert_all<'conv, I>(
+        &'conv self,
+        ways: I,
+    ) -> impl Iterator<Item = Result<RoadFromTags, RoadError>> + 'conv
+    where
+        I: IntoIterator + 'conv,
+        I::Item: Borrow<Tags>,
+    {
+        ways.into_iter().map(|tags| self.convert(tags.borrow()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use osm_tags::Tags;
+
+    use super::Converter;
+    use crate::locale::{DrivingSide, Locale};
+    use crate::transform::{tags_to_lanes, TagsToLanesConfig};
+
+    #[test]
+    fn converter() {
+        let locale = Locale::builder().driving_side(DrivingSide::Right).build();
+        let converter = Converter::new(locale.clone(), TagsToLanesConfig::default());
+        let pairs = [("highway", "secondary"), ("lanes", "3"), ("oneway", "yes")];
+        let tags = Tags::from_pairs(pairs).unwrap();
+        let expected = tags_to_lanes(&tags, &locale, &TagsToLanesConfig::default())
+            .unwrap()
+            .road;
+
+        assert_eq!(converter.convert(&tags).unwrap().road, expected);
+        assert_eq!(converter.convert_pairs(&pairs).unwrap().road, expected);
+        let ways = vec![tags.clone(), tags];
+        let roads: Vec<_> = converter.convert_all(&ways).collect();
+        assert_eq!(roads.len(), 2);
+        assert!(roads.into_iter().all(|road| road.unwrap().road == expected));
+
+        assert!(converter
+            .convert_pairs(&[("highway", "residential"), ("highway", "service")])
+            .is_err());
+    }
+}
@@ -1,7 +1,7 @@
-use osm_tags::DuplicateKeyError;
+use osm_tags::{DuplicateKeyError, Tags};
 
 use super::TagsToLanesMsg;
-use crate::road::Road;
+use crate::road::{LaneConnectivity, ProvenancedRoad, Road};
 
 #[derive(Clone, Debug, Default)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize))]
@@ -45,10 +45,16 @@ impl std::fmt::Display for RoadWarnings {
 /// Error for transformation
 /// ```
 /// use osm2lanes::transform::{TagsToLanesMsg, RoadError};
-/// let msg: TagsToLanesMsg = TagsToLanesMsg::deprecated_tag("foo", "bar");
-/// assert_eq!("\"deprecated: 'foo=bar' - src/transform/error.rs:5:27\"", serde_json::to_string(&msg).unwrap());
+/// let msg: TagsToLanesMsg = TagsToLanesMsg::deprecated_tag("foo", "bar", "foo", "baz");
+/// assert_eq!(
+///     "{\"code\":\"deprecated\",\"message\":\"deprecated: replace 'foo=bar' with 'foo=baz' - src/transform/error.rs:5:27\",\"related_tags\":{\"foo\":\"bar\"},\"suggestion\":{\"foo\":\"baz\"}}",
+///     serde_json::to_string(&msg).unwrap()
+/// );
 /// let err: RoadError = msg.into();
-/// assert_eq!("{\"error\":\"deprecated: 'foo=bar' - src/transform/error.rs:5:27\"}", serde_json::to_string(&err).unwrap());
+/// assert_eq!(
+///     "{\"error\":{\"code\":\"deprecated\",\"message\":\"deprecated: replace 'foo=bar' with 'foo=baz' - src/transform/error.rs:5:27\",\"related_tags\":{\"foo\":\"bar\"},\"suggestion\":{\"foo\":\"baz\"}}}",
+///     serde_json::to_string(&err).unwrap()
+/// );
 /// ```
 #[allow(clippy::module_name_repetitions)]
 #[derive(Debug, Clone)]
@@ -78,7 +84,7 @@ impl std::fmt::Display for RoadError {
 impl From<DuplicateKeyError> for RoadError {
     #[track_caller]
     fn from(e: DuplicateKeyError) -> Self {
-        e.into()
+        Self::Msg(e.into())
     }
 }
 
@@ -99,4 +105,16 @@ impl From<RoadWarnings> for RoadError {
 pub struct RoadFromTags {
     pub road: Road,
     pub warnings: RoadWarnings,
+    /// The road with the provenance of each lane property,
+    /// only if `TagsToLanesConfig::include_provenance`
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub provenance: Option<ProvenancedRoad>,
+    /// For each lane, the minimal set of tags to reproduce just that lane,
+    /// only if `TagsToLanesConfig::include_lane_tags`
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub lane_tags: Option<Vec<Tags>>,
+    /// Plausible exits of each forward lane,
+    /// only if `TagsToLanesConfig::include_connectivity`
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub connectivity: Option<Vec<LaneConnectivity>>,
 }
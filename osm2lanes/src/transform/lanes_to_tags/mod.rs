@@ -1,24 +1,65 @@
 #![allow(clippy::module_name_repetitions)] // TODO: fix upstream
 
 use celes::Country;
-use osm_tag_schemes::Access;
+use osm_tag_schemes::{
+    Access, Change, Conditional, Highway, HighwayType, Lifecycle, ParkingOrientation, Separation,
+    Smoothness, Surface, TurnIndication,
+};
 use osm_tags::Tags;
 
 pub use self::error::LanesToTagsMsg;
-use super::{tags_to_lanes, TagsToLanesConfig};
-use crate::locale::{DrivingSide, Locale};
-use crate::metric::Speed;
-use crate::road::{AccessByType, Color, Designated, Direction, Lane, Marking, Road};
+#[cfg(feature = "taginfo")]
+pub use self::taginfo::{rare_tags, RareTag};
+use super::{tags_to_lanes, Divider, TagsToLanesConfig};
+use crate::locale::{highway_implies_oneway, DrivingSide, Locale};
+use crate::metric::{Metre, Speed};
+use crate::road::{
+    AccessAndDirection, AccessByType, Conditions, Designated, Destinations, Direction, Elevation,
+    FootwayRole, Lane, Marking, Markings, Road, SeparatelyMapped, Side, Steps, Style,
+    SurfaceDetails,
+};
+
+/// The tagging scheme parking is written in
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ParkingScheme {
+    /// `parking:lane:<side>=<orientation>`
+    #[default]
+    Lane,
+    /// `parking:<side>=lane` and `parking:<side>:orientation=<orientation>`
+    Side,
+}
+
+impl ParkingScheme {
+    /// Prefix of the parking keys, e.g. for `<prefix>:<side>:width`
+    fn prefix(self) -> &'static str {
+        match self {
+            Self::Lane => "parking:lane",
+            Self::Side => "parking",
+        }
+    }
+}
+
+#[cfg(feature = "taginfo")]
+mod taginfo;
 
 #[non_exhaustive]
 pub struct Config {
     pub check_roundtrip: bool,
+    pub parking_scheme: ParkingScheme,
+    /// Log a warning for each generated tag that is rare or unknown, see [`rare_tags`]
+    #[cfg(feature = "taginfo")]
+    pub check_taginfo: bool,
 }
 
 impl Config {
     #[must_use]
     pub fn new(check_roundtrip: bool) -> Self {
-        Config { check_roundtrip }
+        Config {
+            check_roundtrip,
+            parking_scheme: ParkingScheme::default(),
+            #[cfg(feature = "taginfo")]
+            check_taginfo: false,
+        }
     }
 }
 
@@ -26,6 +67,9 @@ impl Default for Config {
     fn default() -> Self {
         Self {
             check_roundtrip: true,
+            parking_scheme: ParkingScheme::default(),
+            #[cfg(feature = "taginfo")]
+            check_taginfo: false,
         }
     }
 }
@@ -35,6 +79,78 @@ impl Lane {
         matches!(self, Lane::Shoulder { .. })
     }
 
+    fn is_verge(&self) -> bool {
+        matches!(self, Lane::Verge { .. })
+    }
+
+    fn elevation(&self) -> Option<Elevation> {
+        match self {
+            Self::Travel { elevation, .. } => *elevation,
+            _ => None,
+        }
+    }
+
+    fn surface(&self) -> Option<Surface> {
+        match self {
+            Self::Travel { surface, .. }
+            | Self::Parking { surface, .. }
+            | Self::Shoulder { surface, .. } => *surface,
+            Self::Verge { .. } | Self::Separator { .. } => None,
+        }
+    }
+
+    fn smoothness(&self) -> Option<Smoothness> {
+        match self {
+            Self::Travel { smoothness, .. }
+            | Self::Parking { smoothness, .. }
+            | Self::Shoulder { smoothness, .. } => *smoothness,
+            Self::Verge { .. } | Self::Separator { .. } => None,
+        }
+    }
+
+    fn surface_details(&self) -> Option<&SurfaceDetails> {
+        match self {
+            Self::Travel {
+                surface_details, ..
+            }
+            | Self::Parking {
+                surface_details, ..
+            }
+            | Self::Shoulder {
+                surface_details, ..
+            } => surface_details.as_ref(),
+            Self::Verge { .. } | Self::Separator { .. } => None,
+        }
+    }
+
+    fn turn(&self) -> Option<&[TurnIndication]> {
+        match self {
+            Self::Travel { turn, .. } => turn.as_deref(),
+            _ => None,
+        }
+    }
+
+    fn change(&self) -> Option<Change> {
+        match self {
+            Self::Travel { change, .. } => *change,
+            _ => None,
+        }
+    }
+
+    fn destinations(&self) -> Option<&Destinations> {
+        match self {
+            Self::Travel { destinations, .. } => destinations.as_ref(),
+            _ => None,
+        }
+    }
+
+    fn conditions(&self) -> Option<&Conditions> {
+        match self {
+            Self::Travel { conditions, .. } => conditions.as_ref(),
+            _ => None,
+        }
+    }
+
     fn access(&self) -> Option<&AccessByType> {
         match self {
             Self::Travel { access, .. } => access.as_ref(),
@@ -148,63 +264,162 @@ pub fn lanes_to_tags(
         .iter()
         .any(|lane| lane.is_motor() || lane.is_bus())
     {
-        tags.checked_insert("highway", "path")?;
+        if let Some(steps) = &road.steps {
+            tags.checked_insert("highway", "steps")?;
+            set_steps(steps, &mut tags)?;
+        } else if road.highway.r#type() == HighwayType::Footway {
+            tags.checked_insert("highway", "footway")?;
+            if let Some(role) = road.role {
+                tags.checked_insert(
+                    "footway",
+                    match role {
+                        FootwayRole::Sidewalk => "sidewalk",
+                        FootwayRole::Crossing => "crossing",
+                    },
+                )?;
+            }
+        } else {
+            tags.checked_insert("highway", "path")?;
+        }
         return Ok(tags);
     }
 
-    tags.checked_insert("highway", road.highway.r#type().to_string())?;
-    if road.highway.is_construction() {
-        return Err(LanesToTagsMsg::unimplemented("construction=*"));
-    }
-    if road.highway.is_proposed() {
-        return Err(LanesToTagsMsg::unimplemented("construction=*"));
-    }
+    set_highway(&road.highway, &mut tags)?;
 
     let lanes = &road.lanes;
 
-    let lane_count = set_lanes(lanes, &mut tags)?;
-    let oneway = set_oneway(lanes, &mut tags, locale, lane_count)?;
+    set_lanes(lanes, &mut tags)?;
+    let oneway = set_oneway(lanes, &mut tags, road.roundabout, road.highway.r#type())?;
+    if let Some(placement) = road.placement {
+        tags.checked_insert("placement", placement.to_string())?;
+    }
+    if road.dual_carriageway {
+        tags.checked_insert("dual_carriageway", "yes")?;
+    }
+    if road.passing_places {
+        tags.checked_insert("passing_places", "yes")?;
+    }
+    // Before the divider, whose markings are compared with those implied by overtaking
+    if let Some(overtaking) = road.overtaking {
+        tags.checked_insert("overtaking", <&str>::from(overtaking))?;
+    }
+    if let Some(priority_road) = road.priority_road {
+        tags.checked_insert("priority_road", <&str>::from(priority_road))?;
+    }
 
     set_shoulder(lanes, &mut tags)?;
-    set_pedestrian(lanes, &mut tags)?;
-    set_parking(lanes, &mut tags)?;
-    set_cycleway(lanes, &mut tags, oneway, locale)?;
+    set_verge(lanes, &mut tags)?;
+    set_pedestrian(lanes, &mut tags, &road.separately_mapped)?;
+    set_parking(lanes, &mut tags, locale, config.parking_scheme)?;
+    set_bus_bay(lanes, &mut tags)?;
+    set_cycleway(lanes, &mut tags, oneway, locale, &road.separately_mapped)?;
+    if road.cycling_infrastructure == Some(false) && !lanes.iter().any(Lane::is_bicycle) {
+        tags.checked_insert("cycleway:both", "no")?;
+    }
     set_busway(lanes, &mut tags, oneway)?;
+    set_hov(lanes, &mut tags, oneway)?;
+    set_surface(lanes, &mut tags, config.parking_scheme)?;
+    set_separation(lanes, &mut tags, "cycleway", Lane::is_bicycle)?;
+    set_separation(lanes, &mut tags, "sidewalk", Lane::is_foot)?;
+    set_sidewalk_kerb(lanes, &mut tags, locale)?;
+    set_turn(lanes, &mut tags, oneway)?;
+    set_change(lanes, &mut tags, oneway)?;
+    set_destinations(lanes, &mut tags, oneway)?;
+    set_conditions(lanes, &mut tags, locale)?;
+    set_restrictions(lanes, &mut tags, oneway)?;
+    set_embedded_rail(lanes, &mut tags, oneway)?;
+    set_access(lanes, &mut tags, oneway)?;
+    set_width(lanes, &mut tags, oneway, config.parking_scheme)?;
 
-    let max_speed = get_max_speed(lanes, &mut tags)?;
+    let max_speed = get_max_speed(lanes, &mut tags, oneway, locale)?;
 
     locale_additions(max_speed, locale, &mut tags)?;
 
+    // Last, as it compares with the lanes inferred from all the other tags
+    set_divider(lanes, &mut tags, locale)?;
+
     check_roundtrip(config, &tags, locale, lanes)?;
 
+    #[cfg(feature = "taginfo")]
+    if config.check_taginfo {
+        for rare in rare_tags(&tags) {
+            log::warn!("{rare}");
+        }
+    }
+
     Ok(tags)
 }
 
-fn set_lanes(lanes: &[Lane], tags: &mut Tags) -> Result<usize, LanesToTagsMsg> {
-    let lane_count = lanes
-        .iter()
-        .filter(|lane| {
-            matches!(
-                lane,
-                Lane::Travel {
-                    designated: Designated::Motor | Designated::Bus,
-                    ..
-                }
-            )
-        })
-        .count();
+fn set_steps(steps: &Steps, tags: &mut Tags) -> Result<(), LanesToTagsMsg> {
+    if let Some(incline) = steps.incline {
+        tags.checked_insert("incline", <&str>::from(incline))?;
+    }
+    if let Some(handrail) = steps.handrail {
+        tags.checked_insert("handrail", if handrail { "yes" } else { "no" })?;
+    }
+    if let Some(step_count) = steps.step_count {
+        tags.checked_insert("step_count", step_count.to_string())?;
+    }
+    Ok(())
+}
+
+/// `highway=*`, or for a highway that is not yet built,
+/// `highway=construction` or `highway=proposed` with its type in `construction=*` or `proposed=*`
+fn set_highway(highway: &Highway, tags: &mut Tags) -> Result<(), LanesToTagsMsg> {
+    match highway.lifecycle() {
+        Lifecycle::Active => tags.checked_insert("highway", highway.r#type().to_string())?,
+        Lifecycle::Construction => {
+            tags.checked_insert("highway", "construction")?;
+            tags.checked_insert("construction", highway.r#type().to_string())?;
+        },
+        Lifecycle::Proposed => {
+            tags.checked_insert("highway", "proposed")?;
+            tags.checked_insert("proposed", highway.r#type().to_string())?;
+        },
+    }
+    Ok(())
+}
+
+fn set_lanes(lanes: &[Lane], tags: &mut Tags) -> Result<(), LanesToTagsMsg> {
+    let lane_count = lanes.iter().filter(|lane| lane.is_carriageway()).count();
     tags.checked_insert("lanes", lane_count.to_string())?;
-    Ok(lane_count)
+    Ok(())
+}
+
+/// A single lane with motor traffic in both directions, that is not a centre turn lane
+fn is_single_track(lanes: &[Lane]) -> bool {
+    matches!(
+        lanes
+            .iter()
+            .filter(|lane| lane.is_carriageway())
+            .collect::<Vec<_>>()
+            .as_slice(),
+        [lane] if lane.is_motor()
+            && lane.direction() == Some(Direction::Both)
+            && !lane.is_centre_turn()
+    )
 }
 
-/// Returns oneway
+/// Returns oneway, which is implied by `junction=roundabout`,
+/// tagging `oneway=no` where the highway type implies oneway
 fn set_oneway(
     lanes: &[Lane],
     tags: &mut Tags,
-    locale: &Locale,
-    lane_count: usize,
+    roundabout: bool,
+    highway: HighwayType,
 ) -> Result<bool, LanesToTagsMsg> {
-    if lanes.iter().filter(|lane| lane.is_motor()).all(|lane| {
+    if roundabout {
+        tags.checked_insert("junction", "roundabout")?;
+    }
+    if lanes.iter().any(Lane::is_motor)
+        && lanes
+            .iter()
+            .filter(|lane| lane.is_motor())
+            .all(|lane| lane.direction() == Some(Direction::Reversible))
+    {
+        tags.checked_insert("oneway", "reversible")?;
+        Ok(true)
+    } else if lanes.iter().filter(|lane| lane.is_motor()).all(|lane| {
         matches!(
             lane,
             Lane::Travel {
@@ -213,9 +428,17 @@ fn set_oneway(
             }
         )
     }) {
-        tags.checked_insert("oneway", "yes")?;
+        if !roundabout {
+            tags.checked_insert("oneway", "yes")?;
+        }
         Ok(true)
+    } else if is_single_track(lanes) {
+        // Implied by `lanes=1` without a direction
+        Ok(false)
     } else {
+        if highway_implies_oneway(highway) {
+            tags.checked_insert("oneway", "no")?;
+        }
         // Forward
         let forward_lanes = lanes
             .iter()
@@ -223,7 +446,7 @@ fn set_oneway(
                 matches!(
                     lane,
                     Lane::Travel {
-                        designated: Designated::Motor | Designated::Bus,
+                        designated: Designated::Motor | Designated::Bus | Designated::Hov,
                         direction: Some(Direction::Forward),
                         ..
                     }
@@ -238,7 +461,7 @@ fn set_oneway(
                 matches!(
                     lane,
                     Lane::Travel {
-                        designated: Designated::Motor | Designated::Bus,
+                        designated: Designated::Motor | Designated::Bus | Designated::Hov,
                         direction: Some(Direction::Backward),
                         ..
                     }
@@ -247,23 +470,21 @@ fn set_oneway(
             .count();
         tags.checked_insert("lanes:backward", backward_lanes.to_string())?;
         // Both ways
-        if lanes.iter().any(|lane| {
-            matches!(
-                lane,
-                Lane::Travel {
-                    designated: Designated::Motor,
-                    direction: Some(Direction::Both),
-                    ..
-                }
-            )
-        }) {
+        if lanes.iter().any(Lane::is_centre_turn) {
             tags.checked_insert("lanes:both_ways", "1")?;
-            if lane_count >= 3 {
-                tags.checked_insert(
-                    "turn:lanes:both_ways",
-                    locale.driving_side.opposite().to_string(),
-                )?;
-            }
+        }
+        if let Some(turn) = lanes
+            .iter()
+            .filter(|lane| lane.is_centre_turn())
+            .find_map(Lane::turn)
+        {
+            tags.checked_insert(
+                "turn:lanes:both_ways",
+                turn.iter()
+                    .map(|&turn| turn.into())
+                    .collect::<Vec<&str>>()
+                    .join(";"),
+            )?;
         }
         Ok(false)
     }
@@ -289,49 +510,178 @@ fn set_shoulder(lanes: &[Lane], tags: &mut Tags) -> Result<(), LanesToTagsMsg> {
     Ok(())
 }
 
-fn set_pedestrian(lanes: &[Lane], tags: &mut Tags) -> Result<(), LanesToTagsMsg> {
+/// Set `verge=*` from the verges outside the carriageway on each side
+fn set_verge(lanes: &[Lane], tags: &mut Tags) -> Result<(), LanesToTagsMsg> {
+    let outside = |lane: &&Lane| !lane.is_motor() && !lane.is_bus();
     match (
-        lanes.first().unwrap().is_foot(),
-        lanes.last().unwrap().is_foot(),
+        lanes.iter().take_while(outside).any(Lane::is_verge),
+        lanes.iter().rev().take_while(outside).any(Lane::is_verge),
     ) {
-        (false, false) => {
+        (false, false) => {},
+        (true, false) => tags.checked_insert("verge", "left")?,
+        (false, true) => tags.checked_insert("verge", "right")?,
+        (true, true) => tags.checked_insert("verge", "both")?,
+    }
+    Ok(())
+}
+
+fn set_pedestrian(
+    lanes: &[Lane],
+    tags: &mut Tags,
+    separately_mapped: &[SeparatelyMapped],
+) -> Result<(), LanesToTagsMsg> {
+    let sidewalk = |lane: Option<&Lane>, side: Side| {
+        if lane.map(Lane::is_foot) == Some(true) {
+            if lane.and_then(Lane::elevation) == Some(Elevation::Carriageway) {
+                "lane"
+            } else {
+                "yes"
+            }
+        } else if separately_mapped.contains(&SeparatelyMapped {
+            designated: Designated::Foot,
+            side,
+        }) {
+            "separate"
+        } else {
+            "no"
+        }
+    };
+    match (
+        sidewalk(lanes.first(), Side::Left),
+        sidewalk(lanes.last(), Side::Right),
+    ) {
+        ("no", "no") => {
             // TODO do we want to always be explicit about this?
             tags.checked_insert("sidewalk", "no")?;
         },
-        (true, false) => tags.checked_insert("sidewalk", "left")?,
-        (false, true) => tags.checked_insert("sidewalk", "right")?,
-        (true, true) => tags.checked_insert("sidewalk", "both")?,
+        ("yes", "no") => tags.checked_insert("sidewalk", "left")?,
+        ("no", "yes") => tags.checked_insert("sidewalk", "right")?,
+        ("yes", "yes") => tags.checked_insert("sidewalk", "both")?,
+        ("separate", "separate") => tags.checked_insert("sidewalk", "separate")?,
+        (left, right) => {
+            tags.checked_insert("sidewalk:left", left)?;
+            tags.checked_insert("sidewalk:right", right)?;
+        },
+    }
+    let lit = |lane: Option<&Lane>| match lane {
+        Some(Lane::Travel {
+            designated: Designated::Foot,
+            lit,
+            ..
+        }) => *lit,
+        _ => None,
+    };
+    match (lit(lanes.first()), lit(lanes.last())) {
+        (Some(left), Some(right)) if left == right => {
+            tags.checked_insert("sidewalk:both:lit", <&str>::from(left))?;
+        },
+        (left, right) => {
+            if let Some(left) = left {
+                tags.checked_insert("sidewalk:left:lit", <&str>::from(left))?;
+            }
+            if let Some(right) = right {
+                tags.checked_insert("sidewalk:right:lit", <&str>::from(right))?;
+            }
+        },
     }
     Ok(())
 }
 
-fn set_parking(lanes: &[Lane], tags: &mut Tags) -> Result<(), LanesToTagsMsg> {
+fn set_parking(
+    lanes: &[Lane],
+    tags: &mut Tags,
+    locale: &Locale,
+    scheme: ParkingScheme,
+) -> Result<(), LanesToTagsMsg> {
+    // Parallel, unless the orientation is known
+    fn orientation<'lane>(
+        mut side: impl Iterator<Item = &'lane Lane>,
+    ) -> Option<ParkingOrientation> {
+        side.find_map(|lane| match lane {
+            Lane::Parking { orientation, .. } if !lane.is_bus_bay() => {
+                Some(orientation.unwrap_or(ParkingOrientation::Parallel))
+            },
+            _ => None,
+        })
+    }
     match (
-        lanes
-            .iter()
-            .take_while(|lane| !lane.is_motor())
-            .any(|lane| matches!(lane, Lane::Parking { .. })),
-        lanes
-            .iter()
-            .skip_while(|lane| !lane.is_motor())
-            .any(|lane| matches!(lane, Lane::Parking { .. })),
+        orientation(lanes.iter().take_while(|lane| !lane.is_motor())),
+        orientation(lanes.iter().skip_while(|lane| !lane.is_motor())),
     ) {
-        (false, false) => {},
-        (true, false) => tags.checked_insert("parking:lane:left", "parallel")?,
-        (false, true) => tags.checked_insert("parking:lane:right", "parallel")?,
-        (true, true) => tags.checked_insert("parking:lane:both", "parallel")?,
+        (None, None) => {},
+        (Some(left), Some(right)) if left == right => {
+            set_parking_side(tags, scheme, "both", left)?;
+        },
+        (left, right) => {
+            if let Some(left) = left {
+                set_parking_side(tags, scheme, "left", left)?;
+            }
+            if let Some(right) = right {
+                set_parking_side(tags, scheme, "right", right)?;
+            }
+        },
     }
 
-    if let Some(Lane::Separator { markings, .. }) = lanes.first() {
-        if let Some(Marking {
-            color: Some(Color::Red),
+    // Painted along the edges, the times are not known
+    let condition = |lane: Option<&Lane>| match lane {
+        Some(Lane::Separator {
+            markings: Some(markings),
             ..
-        }) = markings.as_ref().and_then(|m| m.first())
-        {
-            tags.checked_insert("parking:condition:both", "no_stopping")?;
-        }
+        }) => locale
+            .parking_condition_from_markings(markings)
+            .map(|(condition, _at_times)| condition),
+        _ => None,
+    };
+    match (condition(lanes.first()), condition(lanes.last())) {
+        (None, None) => {},
+        (Some(left), Some(right)) if left == right => {
+            tags.checked_insert("parking:condition:both", <&str>::from(left))?;
+        },
+        (left, right) => {
+            if let Some(left) = left {
+                tags.checked_insert("parking:condition:left", <&str>::from(left))?;
+            }
+            if let Some(right) = right {
+                tags.checked_insert("parking:condition:right", <&str>::from(right))?;
+            }
+        },
+    }
+
+    Ok(())
+}
+
+/// Set `bus_bay=*` from the bus bays outside the carriageway
+fn set_bus_bay(lanes: &[Lane], tags: &mut Tags) -> Result<(), LanesToTagsMsg> {
+    let outside = |lane: &&Lane| !lane.is_motor() && !lane.is_bus();
+    let left = lanes.iter().take_while(outside).any(Lane::is_bus_bay);
+    let right = lanes.iter().rev().take_while(outside).any(Lane::is_bus_bay);
+    match (left, right) {
+        (true, true) => tags.checked_insert("bus_bay", "both")?,
+        (true, false) => tags.checked_insert("bus_bay", "left")?,
+        (false, true) => tags.checked_insert("bus_bay", "right")?,
+        (false, false) => {},
     }
+    Ok(())
+}
 
+fn set_parking_side(
+    tags: &mut Tags,
+    scheme: ParkingScheme,
+    side: &str,
+    orientation: ParkingOrientation,
+) -> Result<(), LanesToTagsMsg> {
+    match scheme {
+        ParkingScheme::Lane => {
+            tags.checked_insert(format!("parking:lane:{side}"), <&str>::from(orientation))?;
+        },
+        ParkingScheme::Side => {
+            tags.checked_insert(format!("parking:{side}"), "lane")?;
+            tags.checked_insert(
+                format!("parking:{side}:orientation"),
+                <&str>::from(orientation),
+            )?;
+        },
+    }
     Ok(())
 }
 
@@ -340,6 +690,7 @@ fn set_cycleway(
     tags: &mut Tags,
     oneway: bool,
     locale: &Locale,
+    separately_mapped: &[SeparatelyMapped],
 ) -> Result<(), LanesToTagsMsg> {
     let left_cycle_lane: Option<&Lane> = lanes
         .iter()
@@ -350,11 +701,67 @@ fn set_cycleway(
         .rev()
         .take_while(|lane| !lane.is_motor())
         .find(|lane| lane.is_bicycle());
-    match (left_cycle_lane.is_some(), right_cycle_lane.is_some()) {
-        (false, false) => {},
-        (true, false) => tags.checked_insert("cycleway:left", "lane")?,
-        (false, true) => tags.checked_insert("cycleway:right", "lane")?,
-        (true, true) => tags.checked_insert("cycleway:both", "lane")?,
+    // Only tracks can be raised, so a known elevation implies a track
+    let variant = |lane: &Lane| {
+        if lane.elevation().is_some() {
+            "track"
+        } else {
+            "lane"
+        }
+    };
+    // Otherwise bicycles may share the outside lane of the carriageway
+    let shared = |lane: Option<&Lane>| {
+        let lane = lane?;
+        let bicycle = lane.access()?.bicycle.as_ref()?;
+        if bicycle.access != Access::Designated {
+            return None;
+        }
+        if lane.is_bus() {
+            Some("share_busway")
+        } else {
+            Some("shared_lane")
+        }
+    };
+    let carriageway = |lane: &&Lane| lane.is_motor() || lane.is_bus();
+    // Or there is a cycleway mapped as a way of its own
+    let separate = |side: Side| {
+        separately_mapped
+            .contains(&SeparatelyMapped {
+                designated: Designated::Bicycle,
+                side,
+            })
+            .then_some("separate")
+    };
+    let left = left_cycle_lane
+        .map(variant)
+        .or_else(|| shared(lanes.iter().find(carriageway)))
+        .or_else(|| separate(Side::Left));
+    let right = right_cycle_lane
+        .map(variant)
+        .or_else(|| shared(lanes.iter().rev().find(carriageway)))
+        .or_else(|| separate(Side::Right));
+    match (left, right) {
+        (None, None) => {},
+        (Some(left), None) => tags.checked_insert("cycleway:left", left)?,
+        (None, Some(right)) => tags.checked_insert("cycleway:right", right)?,
+        (Some(left), Some(right)) if left == right => {
+            tags.checked_insert("cycleway:both", left)?;
+        },
+        (Some(left), Some(right)) => {
+            tags.checked_insert("cycleway:left", left)?;
+            tags.checked_insert("cycleway:right", right)?;
+        },
+    }
+    for (side, lane) in [("left", left_cycle_lane), ("right", right_cycle_lane)] {
+        match lane.and_then(Lane::elevation) {
+            Some(Elevation::Raised) => {
+                tags.checked_insert(format!("cycleway:{side}:kerb"), "raised")?;
+            },
+            Some(Elevation::Carriageway) => {
+                tags.checked_insert(format!("cycleway:{side}:kerb"), "flush")?;
+            },
+            None => {},
+        }
     }
 
     // if the way has oneway=yes and you are allowed to cycle against that oneway flow
@@ -383,6 +790,9 @@ fn set_cycleway(
             tags.checked_insert("cycleway:left:oneway", "-1")?;
         },
         Some(Direction::Both) => tags.checked_insert("cycleway:left:oneway", "no")?,
+        Some(Direction::Reversible) => {
+            return Err(LanesToTagsMsg::unimplemented("reversible cycle lane"))
+        },
         None => {},
     }
     match right_cycle_lane.and_then(Lane::direction) {
@@ -393,6 +803,9 @@ fn set_cycleway(
             tags.checked_insert("cycleway:right:oneway", "-1")?;
         },
         Some(Direction::Both) => tags.checked_insert("cycleway:right:oneway", "no")?,
+        Some(Direction::Reversible) => {
+            return Err(LanesToTagsMsg::unimplemented("reversible cycle lane"))
+        },
         None => {},
     }
 
@@ -427,6 +840,243 @@ fn set_cycleway(
     Ok(())
 }
 
+fn set_surface(
+    lanes: &[Lane],
+    tags: &mut Tags,
+    scheme: ParkingScheme,
+) -> Result<(), LanesToTagsMsg> {
+    // Only if all motor lanes agree
+    fn agreed<T: PartialEq>(mut values: impl Iterator<Item = Option<T>>) -> Option<T> {
+        let first = values.next()??;
+        values.all(|v| v.as_ref() == Some(&first)).then_some(first)
+    }
+    let carriageway = || lanes.iter().filter(|lane| lane.is_motor() || lane.is_bus());
+    let surface = agreed(carriageway().map(Lane::surface));
+    let smoothness = agreed(carriageway().map(Lane::smoothness));
+    if let Some(surface) = surface {
+        tags.checked_insert("surface", <&str>::from(surface))?;
+    }
+    if let Some(smoothness) = smoothness {
+        tags.checked_insert("smoothness", <&str>::from(smoothness))?;
+    }
+    let details = agreed(carriageway().map(Lane::surface_details));
+    if let Some(details) = details {
+        set_surface_details("surface", details, None, tags)?;
+    }
+
+    let left: Vec<&Lane> = lanes.iter().take_while(|lane| !lane.is_motor()).collect();
+    let right: Vec<&Lane> = lanes
+        .iter()
+        .rev()
+        .take_while(|lane| !lane.is_motor())
+        .collect();
+    for (side, side_lanes) in [("left", left), ("right", right)] {
+        for lane in side_lanes {
+            let prefix = match lane {
+                Lane::Parking { .. } if lane.is_bus_bay() => continue,
+                Lane::Parking { .. } => scheme.prefix(),
+                _ if lane.is_foot() => "sidewalk",
+                _ if lane.is_bicycle() => "cycleway",
+                _ => continue,
+            };
+            // Sidewalks and cycle tracks are separate from the carriageway
+            let separate = lane.is_foot() || lane.elevation().is_some();
+            if let Some(lane_surface) = lane.surface() {
+                if separate || surface != Some(lane_surface) {
+                    tags.checked_insert(
+                        format!("{prefix}:{side}:surface"),
+                        <&str>::from(lane_surface),
+                    )?;
+                }
+            }
+            if let Some(lane_smoothness) = lane.smoothness() {
+                if separate || smoothness != Some(lane_smoothness) {
+                    tags.checked_insert(
+                        format!("{prefix}:{side}:smoothness"),
+                        <&str>::from(lane_smoothness),
+                    )?;
+                }
+            }
+            if let Some(lane_details) = lane.surface_details() {
+                if separate || details != Some(lane_details) {
+                    set_surface_details(
+                        &format!("{prefix}:{side}:surface"),
+                        lane_details,
+                        details.filter(|_| !separate),
+                        tags,
+                    )?;
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+/// The `separation=*` value of the marking closest to a lane,
+/// kerbs and physical objects taking precedence over painted lines
+fn separation<'marking>(
+    mut closest_first: impl Iterator<Item = &'marking Marking> + Clone,
+) -> Option<&'static str> {
+    let has = |style| closest_first.clone().any(|marking| marking.style == style);
+    match (has(Style::KerbUp), has(Style::KerbDown)) {
+        (true, true) => return Some("separation_kerb"),
+        (true, false) | (false, true) => return Some("kerb"),
+        (false, false) => {},
+    }
+    if let Some(object) = closest_first
+        .clone()
+        .find_map(|marking| object_separation(marking.style))
+    {
+        return Some(object.into());
+    }
+    if buffer(closest_first.clone()).is_none()
+        && closest_first
+            .clone()
+            .filter(|marking| marking.style == Style::SolidLine)
+            .count()
+            == 2
+    {
+        return Some("double_solid_line");
+    }
+    closest_first.find_map(|marking| match marking.style {
+        Style::SolidLine => Some("solid_line"),
+        Style::BrokenLine | Style::DashedLine | Style::DottedLine => Some("dashed_line"),
+        _ => None,
+    })
+}
+
+/// The `separation=*` value of a physical object
+fn object_separation(style: Style) -> Option<Separation> {
+    match style {
+        Style::Bollard => Some(Separation::Bollard),
+        Style::FlexPost => Some(Separation::FlexPost),
+        Style::VerticalPanel => Some(Separation::VerticalPanel),
+        Style::Planter => Some(Separation::Planter),
+        Style::Fence => Some(Separation::Fence),
+        Style::TreeRow => Some(Separation::TreeRow),
+        Style::SolidLine
+        | Style::BrokenLine
+        | Style::DashedLine
+        | Style::DottedLine
+        | Style::NoFill
+        | Style::KerbUp
+        | Style::KerbDown => None,
+    }
+}
+
+/// The `buffer=*` width of the space between the markings,
+/// if wider than the space between a double line
+fn buffer<'marking>(markings: impl Iterator<Item = &'marking Marking>) -> Option<Metre> {
+    let space = markings
+        .filter(|marking| marking.style == Style::NoFill)
+        .map(|marking| marking.width.unwrap_or(Marking::DEFAULT_SPACE))
+        .sum::<Metre>();
+    (space.val() > Marking::DEFAULT_SPACE.val()).then_some(space)
+}
+
+/// The markings of the separator on the inside of the outermost lane of a part,
+/// given the lanes of one side from the outside in
+fn inside_markings<'lane>(
+    mut outside_in: impl Iterator<Item = &'lane Lane>,
+    is_part: fn(&Lane) -> bool,
+) -> Option<&'lane Markings> {
+    outside_in
+        .by_ref()
+        .take_while(|lane| !lane.is_motor() && !lane.is_bus())
+        .find(|lane| is_part(lane))?;
+    match outside_in.next() {
+        Some(Lane::Separator {
+            markings: Some(markings),
+            ..
+        }) => Some(markings),
+        _ => None,
+    }
+}
+
+/// Set `PREFIX:SIDE:separation:INSIDE=*` and `PREFIX:SIDE:buffer:INSIDE=*`
+/// from the markings of the separator
+/// on the inside of the outermost lane of that part on each side
+fn set_separation(
+    lanes: &[Lane],
+    tags: &mut Tags,
+    prefix: &str,
+    is_part: fn(&Lane) -> bool,
+) -> Result<(), LanesToTagsMsg> {
+    if let Some(markings) = inside_markings(lanes.iter(), is_part) {
+        if let Some(separation) = separation(markings.iter()) {
+            tags.checked_insert(format!("{prefix}:left:separation:right"), separation)?;
+        }
+        if let Some(buffer) = buffer(markings.iter()) {
+            tags.checked_insert(
+                format!("{prefix}:left:buffer:right"),
+                buffer.val().to_string(),
+            )?;
+        }
+    }
+    if let Some(markings) = inside_markings(lanes.iter().rev(), is_part) {
+        if let Some(separation) = separation(markings.iter().rev()) {
+            tags.checked_insert(format!("{prefix}:right:separation:left"), separation)?;
+        }
+        if let Some(buffer) = buffer(markings.iter()) {
+            tags.checked_insert(
+                format!("{prefix}:right:buffer:left"),
+                buffer.val().to_string(),
+            )?;
+        }
+    }
+    Ok(())
+}
+
+/// Set `sidewalk:SIDE:kerb=*` and `sidewalk:SIDE:kerb:height=*`
+/// from the kerb marking on the inside of each sidewalk, unless the locale default
+fn set_sidewalk_kerb(
+    lanes: &[Lane],
+    tags: &mut Tags,
+    locale: &Locale,
+) -> Result<(), LanesToTagsMsg> {
+    for (side, markings) in [
+        ("left", inside_markings(lanes.iter(), Lane::is_foot)),
+        ("right", inside_markings(lanes.iter().rev(), Lane::is_foot)),
+    ] {
+        let profile = markings
+            .into_iter()
+            .flat_map(|markings| markings.iter())
+            .find_map(|marking| marking.kerb);
+        if let Some(profile) = profile {
+            if profile.kerb != locale.kerb() {
+                tags.checked_insert(format!("sidewalk:{side}:kerb"), <&str>::from(profile.kerb))?;
+            }
+            if let Some(height) = profile.height {
+                tags.checked_insert(
+                    format!("sidewalk:{side}:kerb:height"),
+                    height.val().to_string(),
+                )?;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// `KEY:winter=*` and `KEY:date=*`, unless the same as the less specific details
+fn set_surface_details(
+    key: &str,
+    details: &SurfaceDetails,
+    other: Option<&SurfaceDetails>,
+    tags: &mut Tags,
+) -> Result<(), LanesToTagsMsg> {
+    if let Some(winter) = details.winter {
+        if other.and_then(|other| other.winter) != Some(winter) {
+            tags.checked_insert(format!("{key}:winter"), <&str>::from(winter))?;
+        }
+    }
+    if let Some(date) = &details.date {
+        if other.and_then(|other| other.date.as_ref()) != Some(date) {
+            tags.checked_insert(format!("{key}:date"), date.as_str())?;
+        }
+    }
+    Ok(())
+}
+
 fn set_busway(lanes: &[Lane], tags: &mut Tags, oneway: bool) -> Result<(), LanesToTagsMsg> {
     let left_bus_lane = lanes
         .iter()
@@ -465,31 +1115,411 @@ fn set_busway(lanes: &[Lane], tags: &mut Tags, oneway: bool) -> Result<(), Lanes
     Ok(())
 }
 
-fn get_max_speed(lanes: &[Lane], tags: &mut Tags) -> Result<Option<Speed>, LanesToTagsMsg> {
-    let max_speeds: Vec<Speed> = lanes
+/// Set `KEY:lanes` from a value per motor lane, `unset` for lanes without a value,
+/// unless no lane has a value
+fn set_by_lane(
+    lanes: &[Lane],
+    tags: &mut Tags,
+    oneway: bool,
+    key: &'static str,
+    unset: &str,
+    value: impl Fn(&Lane) -> Option<String>,
+) -> Result<(), LanesToTagsMsg> {
+    let carriageway = |lane: &&Lane| {
+        (lane.is_motor() || lane.is_bus()) && lane.direction() != Some(Direction::Both)
+    };
+    if !lanes
         .iter()
-        .filter_map(|lane| match lane {
-            Lane::Travel { max_speed, .. } => *max_speed,
-            _ => None,
+        .filter(carriageway)
+        .any(|lane| value(lane).is_some())
+    {
+        return Ok(());
+    }
+    let values = |direction: Direction| -> Vec<String> {
+        lanes
+            .iter()
+            .filter(carriageway)
+            .filter(|lane| lane.direction() == Some(direction))
+            .map(|lane| value(lane).unwrap_or_else(|| unset.to_owned()))
+            .collect()
+    };
+    if oneway {
+        tags.checked_insert(key, values(Direction::Forward).join("|"))?;
+    } else {
+        let mut backward = values(Direction::Backward);
+        backward.reverse();
+        tags.checked_insert(
+            format!("{key}:forward"),
+            values(Direction::Forward).join("|"),
+        )?;
+        tags.checked_insert(format!("{key}:backward"), backward.join("|"))?;
+    }
+    Ok(())
+}
+
+/// High occupancy vehicle lanes, as `hov:lanes=*`
+fn set_hov(lanes: &[Lane], tags: &mut Tags, oneway: bool) -> Result<(), LanesToTagsMsg> {
+    set_by_lane(lanes, tags, oneway, "hov:lanes", "", |lane| {
+        lane.is_hov().then(|| "designated".to_owned())
+    })
+}
+
+fn set_turn(lanes: &[Lane], tags: &mut Tags, oneway: bool) -> Result<(), LanesToTagsMsg> {
+    set_by_lane(lanes, tags, oneway, "turn:lanes", "", |lane| {
+        lane.turn().map(|turn| {
+            turn.iter()
+                .map(|&turn| turn.into())
+                .collect::<Vec<&str>>()
+                .join(";")
         })
+    })
+}
+
+fn set_change(lanes: &[Lane], tags: &mut Tags, oneway: bool) -> Result<(), LanesToTagsMsg> {
+    set_by_lane(lanes, tags, oneway, "change:lanes", "yes", |lane| {
+        lane.change().map(|change| <&str>::from(change).to_owned())
+    })
+}
+
+fn set_destinations(lanes: &[Lane], tags: &mut Tags, oneway: bool) -> Result<(), LanesToTagsMsg> {
+    let set = |tags: &mut Tags, key: &'static str, field: fn(&Destinations) -> &Vec<String>| {
+        set_by_lane(lanes, tags, oneway, key, "", |lane| {
+            lane.destinations()
+                .map(field)
+                .filter(|values| !values.is_empty())
+                .map(|values| values.join(";"))
+        })
+    };
+    set(tags, "destination:lanes", |d| &d.names)?;
+    set(tags, "destination:ref:lanes", |d| &d.refs)?;
+    set(tags, "destination:symbol:lanes", |d| &d.symbols)
+}
+
+/// `;` separated conditional restrictions, `None` if there are none
+fn join_conditional<T>(
+    conditionals: &[Conditional<T>],
+    value: impl Fn(&T) -> String,
+) -> Option<String> {
+    (!conditionals.is_empty()).then(|| {
+        conditionals
+            .iter()
+            .map(|c| format!("{} @ ({})", value(&c.value), c.condition))
+            .collect::<Vec<_>>()
+            .join("; ")
+    })
+}
+
+fn set_conditions(lanes: &[Lane], tags: &mut Tags, locale: &Locale) -> Result<(), LanesToTagsMsg> {
+    let carriageway: Vec<&Lane> = lanes
+        .iter()
+        .filter(|lane| lane.is_motor() || lane.is_bus())
         .collect();
-    if let Some(max_speed) = max_speeds.first() {
-        // Check if all are the same
-        // See benches/benchmark_all_same.rs
-        if max_speeds.windows(2).all(|w| {
-            let speeds: &[Speed; 2] = w.try_into().unwrap();
-            speeds[0] == speeds[1]
-        }) {
-            tags.checked_insert("maxspeed", max_speed.to_string())?;
-            Ok(Some(*max_speed))
-        } else {
-            Err(LanesToTagsMsg::unimplemented(
-                "different max speeds per lane",
-            ))
+    // The whole carriageway shares a single value
+    let shared = |value: &dyn Fn(&Conditions) -> Option<String>| -> Option<String> {
+        let mut values = carriageway
+            .iter()
+            .map(|lane| lane.conditions().and_then(value));
+        let first = values.next()??;
+        values
+            .all(|other| other.as_deref() == Some(first.as_str()))
+            .then_some(first)
+    };
+    if let Some(max_speed) = shared(&|c| {
+        join_conditional(&c.max_speed, |speed| {
+            speed.to_string_in(locale.speed_unit())
+        })
+    }) {
+        tags.checked_insert("maxspeed:conditional", max_speed)?;
+    }
+    if let Some(access) = shared(&|c| join_conditional(&c.access, ToString::to_string)) {
+        tags.checked_insert("access:conditional", access)?;
+    }
+
+    let bus = |lane: Option<&&Lane>| {
+        lane.and_then(|lane| lane.conditions()).and_then(|c| {
+            join_conditional(&c.bus, |access| match access {
+                Access::Designated => "lane".to_owned(),
+                access => access.to_string(),
+            })
+        })
+    };
+    match (bus(carriageway.first()), bus(carriageway.last())) {
+        (Some(left), Some(right)) if left == right => {
+            tags.checked_insert("busway:both:conditional", left)?;
+        },
+        (left, right) => {
+            if let Some(left) = left {
+                tags.checked_insert("busway:left:conditional", left)?;
+            }
+            if let Some(right) = right {
+                tags.checked_insert("busway:right:conditional", right)?;
+            }
+        },
+    }
+    Ok(())
+}
+
+fn set_restrictions(lanes: &[Lane], tags: &mut Tags, oneway: bool) -> Result<(), LanesToTagsMsg> {
+    set_by_lane(
+        lanes,
+        tags,
+        oneway,
+        "maxheight:lanes",
+        "none",
+        |lane| match lane {
+            Lane::Travel { max_height, .. } => max_height.map(|height| height.val().to_string()),
+            _ => None,
+        },
+    )?;
+    set_by_lane(
+        lanes,
+        tags,
+        oneway,
+        "maxweight:lanes",
+        "none",
+        |lane| match lane {
+            Lane::Travel { max_weight, .. } => max_weight.map(|weight| weight.val().to_string()),
+            _ => None,
+        },
+    )
+}
+
+fn set_embedded_rail(lanes: &[Lane], tags: &mut Tags, oneway: bool) -> Result<(), LanesToTagsMsg> {
+    set_by_lane(
+        lanes,
+        tags,
+        oneway,
+        "embedded_rails:lanes",
+        "",
+        |lane| match lane {
+            Lane::Travel {
+                embedded_rail: true,
+                ..
+            } => Some("tram".to_owned()),
+            _ => None,
+        },
+    )
+}
+
+fn set_access(lanes: &[Lane], tags: &mut Tags, oneway: bool) -> Result<(), LanesToTagsMsg> {
+    type Mode = fn(&AccessByType) -> Option<&AccessAndDirection>;
+    let foot: Mode = |a| a.foot.as_ref();
+    // Designated is written as a cycleway sharing the lane
+    let bicycle: Mode = |a| {
+        a.bicycle
+            .as_ref()
+            .filter(|bicycle| bicycle.access != Access::Designated)
+    };
+    let taxi: Mode = |a| a.taxi.as_ref();
+    let bus: Mode = |a| a.bus.as_ref();
+    let motor: Mode = |a| a.motor.as_ref();
+
+    let carriageway: Vec<&Lane> = lanes
+        .iter()
+        .filter(|lane| lane.is_motor() || lane.is_bus())
+        .collect();
+    // Access in a different direction from the lane cannot be written
+    let access = |lane: &Lane, mode: Mode| {
+        lane.access()
+            .and_then(mode)
+            .filter(|access| access.direction.is_none())
+            .map(|access| access.access.clone())
+    };
+    // The whole carriageway shares a single value
+    let shared = |mode: Mode| -> Option<Access> {
+        let mut values = carriageway.iter().map(|lane| access(lane, mode));
+        let first = values.next()??;
+        values
+            .all(|other| other.as_ref() == Some(&first))
+            .then_some(first)
+    };
+
+    // `access=*` covers every mode, motor vehicles cover taxis and buses
+    let motor_vehicle = shared(motor);
+    let general = (shared(foot) == motor_vehicle && shared(bicycle) == motor_vehicle)
+        .then_some(motor_vehicle)
+        .flatten();
+    if let Some(general) = &general {
+        tags.checked_insert("access", general.to_string())?;
+    }
+    for (key, lanes_key, mode, parent) in [
+        ("foot", Some("foot:lanes"), foot, None),
+        ("bicycle", Some("bicycle:lanes"), bicycle, None),
+        ("motor_vehicle", Some("motor_vehicle:lanes"), motor, None),
+        ("taxi", Some("taxi:lanes"), taxi, Some(motor)),
+        // Bus lanes are written as `busway=*` or `bus:lanes=*`
+        ("bus", None, bus, Some(motor)),
+    ] {
+        // The access of a lane, unless covered by a more general key
+        let value = |lane: &Lane| {
+            let covered = parent
+                .and_then(|parent| access(lane, parent))
+                .or_else(|| general.clone());
+            access(lane, mode).filter(|access| Some(access) != covered.as_ref())
+        };
+        if carriageway.iter().all(|lane| value(lane).is_none()) {
+            continue;
+        }
+        match (shared(mode), lanes_key) {
+            (Some(shared), _) => tags.checked_insert(key, shared.to_string())?,
+            (None, Some(lanes_key)) => {
+                set_by_lane(lanes, tags, oneway, lanes_key, "", |lane| {
+                    value(lane).map(|access| access.to_string())
+                })?;
+            },
+            (None, None) => {},
+        }
+    }
+    Ok(())
+}
+
+fn lane_width(lane: &Lane) -> Option<Metre> {
+    match lane {
+        Lane::Travel { width, .. }
+        | Lane::Parking { width, .. }
+        | Lane::Shoulder { width, .. }
+        | Lane::Verge { width } => *width,
+        Lane::Separator { .. } => None,
+    }
+}
+
+/// Set `PREFIX:SIDE:width` from the outermost lane of that part on each side
+fn set_side_width(
+    lanes: &[Lane],
+    tags: &mut Tags,
+    prefix: &str,
+    is_part: fn(&Lane) -> bool,
+) -> Result<(), LanesToTagsMsg> {
+    let outside = |lane: &&Lane| !lane.is_motor() && !lane.is_bus();
+    let left = lanes.iter().take_while(outside).find(|lane| is_part(lane));
+    let right = lanes
+        .iter()
+        .rev()
+        .take_while(outside)
+        .find(|lane| is_part(lane));
+    for (side, lane) in [("left", left), ("right", right)] {
+        if let Some(width) = lane.and_then(lane_width) {
+            tags.checked_insert(format!("{prefix}:{side}:width"), width.val().to_string())?;
+        }
+    }
+    Ok(())
+}
+
+fn set_width(
+    lanes: &[Lane],
+    tags: &mut Tags,
+    oneway: bool,
+    scheme: ParkingScheme,
+) -> Result<(), LanesToTagsMsg> {
+    set_by_lane(lanes, tags, oneway, "width:lanes", "", |lane| {
+        lane_width(lane).map(|width| width.val().to_string())
+    })?;
+    set_side_width(lanes, tags, "sidewalk", Lane::is_foot)?;
+    set_side_width(lanes, tags, scheme.prefix(), |lane| {
+        matches!(lane, Lane::Parking { .. }) && !lane.is_bus_bay()
+    })?;
+    set_side_width(lanes, tags, "bus_bay", Lane::is_bus_bay)?;
+    set_side_width(lanes, tags, "shoulder", Lane::is_shoulder)?;
+    set_side_width(lanes, tags, "verge", Lane::is_verge)
+}
+
+/// Set `maxspeed=*` if every lane shares a speed limit, otherwise per direction or per lane.
+/// Returns the speed limit shared by every lane.
+fn get_max_speed(
+    lanes: &[Lane],
+    tags: &mut Tags,
+    oneway: bool,
+    locale: &Locale,
+) -> Result<Option<Speed>, LanesToTagsMsg> {
+    fn max_speed(lane: &Lane) -> Option<Speed> {
+        match lane {
+            Lane::Travel { max_speed, .. } => *max_speed,
+            _ => None,
+        }
+    }
+    let max_speeds: Vec<Speed> = lanes.iter().filter_map(max_speed).collect();
+    let first = match max_speeds.first() {
+        Some(first) => *first,
+        None => return Ok(None),
+    };
+    // Check if all are the same, and that no lane of the carriageway is without one
+    // See benches/benchmark_all_same.rs
+    if max_speeds.windows(2).all(|w| {
+        let speeds: &[Speed; 2] = w.try_into().unwrap();
+        speeds[0] == speeds[1]
+    }) && lanes
+        .iter()
+        .filter(|lane| lane.is_carriageway())
+        .all(|lane| max_speed(lane).is_some())
+    {
+        tags.checked_insert("maxspeed", first.to_string_in(locale.speed_unit()))?;
+        return Ok(Some(first));
+    }
+    // The speed limit shared by the carriageway in one direction,
+    // `Some(None)` if it has none, and `None` if it differs between lanes
+    let direction_max_speed = |direction: Direction| {
+        let mut max_speeds = lanes
+            .iter()
+            .filter(|lane| lane.is_carriageway() && lane.direction() == Some(direction))
+            .map(max_speed);
+        let first = max_speeds.next().flatten();
+        max_speeds.all(|other| other == first).then_some(first)
+    };
+    if !oneway {
+        if let (Some(forward), Some(backward)) = (
+            direction_max_speed(Direction::Forward),
+            direction_max_speed(Direction::Backward),
+        ) {
+            for (key, max_speed) in [
+                ("maxspeed:forward", forward),
+                ("maxspeed:backward", backward),
+            ] {
+                if let Some(max_speed) = max_speed {
+                    tags.checked_insert(key, max_speed.to_string_in(locale.speed_unit()))?;
+                }
+            }
+            return Ok(None);
+        }
+    }
+    set_by_lane(lanes, tags, oneway, "maxspeed:lanes", "", |lane| {
+        max_speed(lane).map(|max_speed| max_speed.to_string_in(locale.speed_unit()))
+    })?;
+    Ok(None)
+}
+
+/// The markings of the separator between the two directions
+fn centre_markings(lanes: &[Lane]) -> Option<&Option<Markings>> {
+    lanes.windows(3).find_map(|window| match window {
+        [inside, Lane::Separator { markings, .. }, outside]
+            if matches!(
+                (inside.direction(), outside.direction()),
+                (Some(Direction::Forward), Some(Direction::Backward))
+                    | (Some(Direction::Backward), Some(Direction::Forward))
+            ) =>
+        {
+            Some(markings)
+        },
+        _ => None,
+    })
+}
+
+/// Set `divider=*` if the separator between the two directions
+/// is not the one inferred from the other tags
+fn set_divider(lanes: &[Lane], tags: &mut Tags, locale: &Locale) -> Result<(), LanesToTagsMsg> {
+    if let Some(markings) = centre_markings(lanes) {
+        let inferred = tags_to_lanes(tags, locale, &TagsToLanesConfig::default())
+            .ok()
+            .map(|inferred| centre_markings(&inferred.road.lanes) == Some(markings));
+        if inferred != Some(true) {
+            if let Some(divider) = Divider::ALL
+                .into_iter()
+                .find(|divider| &divider.markings(locale) == markings)
+            {
+                tags.checked_insert("divider", divider.as_str())?;
+            }
         }
-    } else {
-        Ok(None)
     }
+    Ok(())
 }
 
 fn locale_additions(
@@ -524,3 +1554,108 @@ fn check_roundtrip(
     }
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use osm_tags::Tags;
+
+    use super::{lanes_to_tags, Config, ParkingScheme};
+    use crate::locale::{DrivingSide, Locale};
+    use crate::transform::{tags_to_lanes, TagsToLanesConfig};
+
+    #[test]
+    fn sides() {
+        let tags = Tags::from_pairs([
+            ("highway", "residential"),
+            ("lanes", "2"),
+            ("sidewalk", "right"),
+            ("sidewalk:right:width", "2"),
+            ("cycleway:right", "lane"),
+            ("cycleway:right:surface", "asphalt"),
+            ("parking:lane:left", "diagonal"),
+        ])
+        .unwrap();
+        let locale = Locale::builder().driving_side(DrivingSide::Right).build();
+        let road = tags_to_lanes(&tags, &locale, &TagsToLanesConfig::default())
+            .unwrap()
+            .road;
+        let tags = lanes_to_tags(&road, &locale, &Config::new(false)).unwrap();
+        assert_eq!(tags.get("sidewalk:right:width"), Some("2"));
+        assert_eq!(tags.get("cycleway:right:surface"), Some("asphalt"));
+        assert_eq!(tags.get("parking:lane:left"), Some("diagonal"));
+        assert_eq!(
+            tags.get("cycleway:right:separation:left"),
+            Some("solid_line")
+        );
+        assert_eq!(tags.get("sidewalk:right:separation:left"), Some("kerb"));
+    }
+
+    #[test]
+    fn parking_scheme() {
+        let tags = Tags::from_pairs([
+            ("highway", "residential"),
+            ("lanes", "2"),
+            ("parking:both", "lane"),
+            ("parking:left:orientation", "diagonal"),
+            ("parking:right:width", "2.5"),
+        ])
+        .unwrap();
+        let locale = Locale::builder().driving_side(DrivingSide::Right).build();
+        let road = tags_to_lanes(&tags, &locale, &TagsToLanesConfig::default())
+            .unwrap()
+            .road;
+
+        let legacy = lanes_to_tags(&road, &locale, &Config::new(false)).unwrap();
+        assert_eq!(legacy.get("parking:lane:left"), Some("diagonal"));
+        assert_eq!(legacy.get("parking:lane:right"), Some("parallel"));
+        assert_eq!(legacy.get("parking:lane:right:width"), Some("2.5"));
+
+        let config = Config {
+            parking_scheme: ParkingScheme::Side,
+            ..Config::new(false)
+        };
+        let side = lanes_to_tags(&road, &locale, &config).unwrap();
+        assert_eq!(side.get("parking:left"), Some("lane"));
+        assert_eq!(side.get("parking:left:orientation"), Some("diagonal"));
+        assert_eq!(side.get("parking:right"), Some("lane"));
+        assert_eq!(side.get("parking:right:orientation"), Some("parallel"));
+        assert_eq!(side.get("parking:right:width"), Some("2.5"));
+        assert_eq!(side.get("parking:lane:right"), None);
+    }
+
+    #[test]
+    fn max_speed_per_direction() {
+        let locale = Locale::builder().driving_side(DrivingSide::Right).build();
+        for (maxspeed, expected) in [
+            (
+                vec![("maxspeed:forward", "50"), ("maxspeed:backward", "30")],
+                [None, Some("50"), Some("30")],
+            ),
+            (vec![("maxspeed:forward", "50")], [None, Some("50"), None]),
+            (
+                vec![("maxspeed", "50"), ("maxspeed:backward", "30")],
+                [None, Some("50"), Some("30")],
+            ),
+            (vec![("maxspeed", "50")], [Some("50"), None, None]),
+        ] {
+            let tags = Tags::from_pairs(
+                [("highway", "secondary"), ("lanes", "2")]
+                    .into_iter()
+                    .chain(maxspeed),
+            )
+            .unwrap();
+            let road = tags_to_lanes(&tags, &locale, &TagsToLanesConfig::default())
+                .unwrap()
+                .road;
+            let tags = lanes_to_tags(&road, &locale, &Config::new(false)).unwrap();
+            assert_eq!(
+                [
+                    tags.get("maxspeed"),
+                    tags.get("maxspeed:forward"),
+                    tags.get("maxspeed:backward"),
+                ],
+                expected,
+            );
+        }
+    }
+}
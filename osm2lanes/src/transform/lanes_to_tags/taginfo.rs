@@ -0,0 +1,107 @@
+//! Checking generated tags against a snapshot of their popularity on
+//! [taginfo](https://taginfo.openstreetmap.org), bundled from `data/taginfo.tsv`
+
+use osm_tags::TagsRef;
+
+const SNAPSHOT: &str = include_str!("../../../../data/taginfo.tsv");
+
+/// A tag that is rare or unknown in OpenStreetMap,
+/// which may have been invented rather than following the tagging in use
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum RareTag {
+    /// The key is rarely used
+    Key(String),
+    /// The key is in common use, but not with this value
+    Value { key: String, value: String },
+}
+
+impl std::fmt::Display for RareTag {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Key(key) => write!(f, "rare key {key}=*"),
+            Self::Value { key, value } => write!(f, "rare value {key}={value}"),
+        }
+    }
+}
+
+/// Values in common use for the key, `None` if the key is rare
+fn snapshot_values(key: &str) -> Option<Vec<&'static str>> {
+    let values: Vec<&str> = SNAPSHOT
+        .lines()
+        .filter(|line| !line.starts_with('#'))
+        .filter_map(|line| {
+            // The count, if any, was already compared to the thresholds when generated
+            let mut fields = line.split('\t');
+            Some((fields.next()?, fields.next()?))
+        })
+        .filter(|(snapshot_key, _value)| *snapshot_key == key)
+        .map(|(_key, value)| value)
+        .collect();
+    (!values.is_empty()).then_some(values)
+}
+
+/// The tags that are rare or unknown according to the bundled snapshot of taginfo.
+///
+/// Lane values, `|` separated, and multiple values, `;` separated, are checked separately,
+/// where the value of a lane may be empty.
+#[must_use]
+pub fn rare_tags(tags: &TagsRef<'_>) -> Vec<RareTag> {
+    let mut rare = Vec::new();
+    for (key, value) in tags.to_str_pairs() {
+        if let Some(values) = snapshot_values(key) {
+            if !values.contains(&"*") && !is_common_value(key, value, &values) {
+                rare.push(RareTag::Value {
+                    key: key.to_owned(),
+                    value: value.to_owned(),
+                });
+            }
+        } else {
+            rare.push(RareTag::Key(key.to_owned()));
+        }
+    }
+    rare
+}
+
+/// Whether each lane value and each multiple value is in common use for the key
+fn is_common_value(key: &str, value: &str, values: &[&str]) -> bool {
+    let is_lanes = key.split(':').any(|part| part == "lanes");
+    let lanes: Vec<&str> = if is_lanes {
+        value.split('|').collect()
+    } else {
+        vec![value]
+    };
+    lanes.into_iter().all(|lane| {
+        (is_lanes && lane.is_empty()) || lane.split(';').all(|part| values.contains(&part))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use osm_tags::Tags;
+
+    use super::{rare_tags, RareTag};
+
+    #[test]
+    fn rare() {
+        let tags = Tags::from_pairs([
+            ("highway", "secondary"),
+            ("lanes", "3"),
+            ("turn:lanes:forward", "left|through;right"),
+            ("bus:lanes:backward", "|designated"),
+            ("cycleway:right", "lane"),
+            ("sidewalk", "sometimes"),
+            ("cycleway:right:colour", "green"),
+        ])
+        .unwrap();
+        assert_eq!(
+            rare_tags(&tags),
+            vec![
+                RareTag::Key("cycleway:right:colour".to_owned()),
+                RareTag::Value {
+                    key: "sidewalk".to_owned(),
+                    value: "sometimes".to_owned(),
+                },
+            ]
+        );
+    }
+}
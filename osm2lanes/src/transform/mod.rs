@@ -1,16 +1,32 @@
 use osm_tags::TagKey;
 
-use crate::locale::DrivingSide;
-use crate::road::{Designated, Direction, Lane};
+use crate::locale::{DrivingSide, Locale};
+use crate::road::{Designated, Direction, Lane, Marking, Markings, Style};
 
 mod error;
 pub use error::{RoadError, RoadFromTags, RoadWarnings};
 
 mod tags_to_lanes;
-pub use tags_to_lanes::{tags_to_lanes, Config as TagsToLanesConfig, Infer, TagsToLanesMsg};
+#[cfg(feature = "osmchange")]
+pub(crate) use tags_to_lanes::lane_keys;
+pub use tags_to_lanes::{
+    tags_to_lanes, Config as TagsToLanesConfig, Deprecation, DualCarriagewayHints, Infer,
+    InferenceLevel, LaneBuilder, LaneBuilderAccess, LaneBuilderWidth, LaneModePass, LaneType,
+    ObservedLanes, RoadBuilder, TagsToLanesMsg, WarningCode, WidthReconciliation, DEPRECATIONS,
+};
+
+mod converter;
+pub use converter::Converter;
+
+mod normalize;
+pub use normalize::{normalize_tags, Applied};
 
 mod lanes_to_tags;
-pub use lanes_to_tags::{lanes_to_tags, Config as LanesToTagsConfig, LanesToTagsMsg};
+pub use lanes_to_tags::{
+    lanes_to_tags, Config as LanesToTagsConfig, LanesToTagsMsg, ParkingScheme,
+};
+#[cfg(feature = "taginfo")]
+pub use lanes_to_tags::{rare_tags, RareTag};
 
 pub mod tags {
     use osm_tags::TagKey;
@@ -18,6 +34,7 @@ pub mod tags {
     pub const CYCLEWAY: TagKey = TagKey::from_static("cycleway");
     pub const SIDEWALK: TagKey = TagKey::from_static("sidewalk");
     pub const SHOULDER: TagKey = TagKey::from_static("shoulder");
+    pub const VERGE: TagKey = TagKey::from_static("verge");
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -67,18 +84,87 @@ impl DrivingSide {
     }
 }
 
+/// The separator between the two directions of a way, `divider=*`
+/// <https://wiki.openstreetmap.org/wiki/Key:divider>
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Divider {
+    No,
+    DashedLine,
+    SolidLine,
+    DoubleSolidLine,
+}
+
+impl Divider {
+    const ALL: [Self; 4] = [
+        Self::No,
+        Self::DashedLine,
+        Self::SolidLine,
+        Self::DoubleSolidLine,
+    ];
+
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::No => "no",
+            Self::DashedLine => "dashed_line",
+            Self::SolidLine => "solid_line",
+            Self::DoubleSolidLine => "double_solid_line",
+        }
+    }
+
+    fn parse(s: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|divider| divider.as_str() == s)
+    }
+
+    /// Painted in the colour and width of the locale, `None` without markings
+    fn markings(self, locale: &Locale) -> Option<Markings> {
+        let line = |style| Marking {
+            style,
+            color: Some(locale.separator_motor_color()),
+            width: Some(locale.separator_motor_width()),
+            kerb: None,
+        };
+        match self {
+            Self::No => None,
+            Self::DashedLine => Some(Markings::new(vec![line(Style::BrokenLine)])),
+            Self::SolidLine => Some(Markings::new(vec![line(Style::SolidLine)])),
+            Self::DoubleSolidLine => Some(Markings::new(vec![
+                line(Style::SolidLine),
+                Marking {
+                    style: Style::NoFill,
+                    color: None,
+                    width: Some(Marking::DEFAULT_SPACE),
+                    kerb: None,
+                },
+                line(Style::SolidLine),
+            ])),
+        }
+    }
+}
+
 impl Lane {
     #[must_use]
     pub fn is_separator(&self) -> bool {
         matches!(self, Lane::Separator { .. })
     }
 
+    /// Motor lanes, including those for high occupancy vehicles
     #[must_use]
     fn is_motor(&self) -> bool {
         matches!(
             self,
             Lane::Travel {
-                designated: Designated::Motor,
+                designated: Designated::Motor | Designated::Hov,
+                ..
+            }
+        )
+    }
+
+    #[must_use]
+    fn is_hov(&self) -> bool {
+        matches!(
+            self,
+            Lane::Travel {
+                designated: Designated::Hov,
                 ..
             }
         )
@@ -117,6 +203,17 @@ impl Lane {
         )
     }
 
+    #[must_use]
+    fn is_bus_bay(&self) -> bool {
+        matches!(
+            self,
+            Lane::Parking {
+                designated: Designated::Bus,
+                ..
+            }
+        )
+    }
+
     #[must_use]
     fn direction(&self) -> Option<Direction> {
         match self {
@@ -0,0 +1,288 @@
+use osm_tags::{TagKey, Tags};
+
+use crate::locale::{DrivingSide, Locale};
+use crate::transform::DEPRECATIONS;
+
+const PARKING_LANE: TagKey = TagKey::from_static("parking:lane");
+const PARKING: TagKey = TagKey::from_static("parking");
+
+/// A rewrite of deprecated tags by `normalize_tags`
+#[derive(Clone, Debug)]
+pub struct Applied {
+    /// The deprecated tags, removed
+    pub removed: Tags,
+    /// The tags replacing them, empty if they were only removed
+    pub added: Tags,
+}
+
+impl Applied {
+    fn new<'val>(
+        removed: &[(TagKey, &str)],
+        added: impl IntoIterator<Item = (TagKey, &'val str)>,
+    ) -> Self {
+        Self {
+            removed: Tags::from_pairs(removed.iter().cloned()).unwrap_or_default(),
+            added: Tags::from_pairs(added).unwrap_or_default(),
+        }
+    }
+}
+
+/// Rewrite deprecated tags to the current recommended schemes,
+/// working on the tags alone rather than going through the lanes,
+/// so that editors can offer it as a cleanup.
+///
+/// These are rewritten:
+/// - the tags of `DEPRECATIONS`
+/// - the legacy parking scheme, `parking:lane:<side>=*` and `parking:lane:<side>:width=*`,
+///   to `parking:<side>=*`, `parking:<side>:orientation=*`, `parking:<side>:restriction=*`
+///   and `parking:<side>:width=*`, leaving `parking:condition:<side>=*` as it is
+///
+/// A rewrite is skipped if it would change a tag that is already present.
+#[must_use]
+pub fn normalize_tags(tags: &Tags, locale: &Locale) -> (Tags, Vec<Applied>) {
+    let mut candidates = deprecations(tags, locale);
+    for side in ["left", "right", "both"] {
+        candidates.extend(legacy_parking(tags, side));
+    }
+
+    let mut applied: Vec<Applied> = Vec::new();
+    for candidate in candidates {
+        let removed = |key: &str| {
+            candidate.removed.get(key).is_some()
+                || applied
+                    .iter()
+                    .any(|applied| applied.removed.get(key).is_some())
+        };
+        let conflicts = candidate
+            .added
+            .to_str_pairs()
+            .into_iter()
+            .any(|(key, val)| {
+                let existing = applied
+                    .iter()
+                    .find_map(|applied| applied.added.get(key))
+                    .or_else(|| tags.get(key).filter(|_| !removed(key)));
+                matches!(existing, Some(existing) if existing != val)
+            });
+        if !conflicts {
+            applied.push(candidate);
+        }
+    }
+
+    let mut normalized = Tags::default();
+    let kept = tags.to_str_pairs().into_iter().filter(|(key, _)| {
+        applied
+            .iter()
+            .all(|applied| applied.removed.get(*key).is_none())
+    });
+    let added = applied
+        .iter()
+        .flat_map(|applied| applied.added.to_str_pairs());
+    for (key, val) in kept.chain(added) {
+        if normalized.get(key).is_none() {
+            // The keys are unique
+            let _ignored = normalized.checked_insert(key.to_owned(), val);
+        }
+    }
+    (normalized, applied)
+}
+
+/// The tags of `DEPRECATIONS`, with the sides of the suggestions swapped when driving on the left
+fn deprecations(tags: &Tags, locale: &Locale) -> Vec<Applied> {
+    let oneway = tags.is("oneway", "yes");
+    DEPRECATIONS
+        .iter()
+        .filter(|deprecation| {
+            let (key, val) = deprecation.deprecated;
+            tags.is(key, val) && (oneway || !deprecation.oneway)
+        })
+        .map(|deprecation| {
+            let (key, val) = deprecation.deprecated;
+            Applied::new(
+                &[(TagKey::from(key), val)],
+                deprecation
+                    .suggested
+                    .iter()
+                    .map(|(key, val)| (swap_sides(key, locale.driving_side), *val)),
+            )
+        })
+        .collect()
+}
+
+/// Swap `left` and `right` in a key when driving on the left
+fn swap_sides(key: &str, driving_side: DrivingSide) -> TagKey {
+    match driving_side {
+        DrivingSide::Right => TagKey::from(key.to_owned()),
+        DrivingSide::Left => TagKey::from(
+            key.split(':')
+                .map(|part| match part {
+                    "left" => "right",
+                    "right" => "left",
+                    part => part,
+                })
+                .collect::<Vec<_>>()
+                .join(":"),
+        ),
+    }
+}
+
+/// `parking:lane:<side>=*` to the `parking:<side>=*` scheme,
+/// see <https://wiki.openstreetmap.org/wiki/Street_parking>
+fn legacy_parking(tags: &Tags, side: &str) -> Vec<Applied> {
+    let mut applied = Vec::new();
+    let key = PARKING_LANE + side;
+    if let Some(val) = tags.get(&key) {
+        match val {
+            "parallel" | "diagonal" | "perpendicular" => {
+                let position_key = PARKING_LANE + side + val;
+                let position = tags.get(&position_key);
+                let mut removed = vec![(key.clone(), val)];
+                if let Some(position) = position {
+                    removed.push((position_key, position));
+                }
+                let position = match position {
+                    Some("on_kerb") => "on_kerb",
+                    Some("half_on_kerb") => "half_on_kerb",
+                    Some("street_side" | "lay_by") => "street_side",
+                    Some("shoulder") => "shoulder",
+                    _ => "lane",
+                };
+                applied.push(Applied::new(
+                    &removed,
+                    [
+                        (PARKING + side, position),
+                        (PARKING + side + "orientation", val),
+                    ],
+                ));
+            },
+            "no" => applied.push(Applied::new(
+                &[(key.clone(), val)],
+                [(PARKING + side, "no")],
+            )),
+            restriction @ ("no_parking" | "no_stopping") => applied.push(Applied::new(
+                &[(key.clone(), val)],
+                [
+                    (PARKING + side, "no"),
+                    (PARKING + side + "restriction", restriction),
+                ],
+            )),
+            "separate" => applied.push(Applied::new(
+                &[(key.clone(), val)],
+                [(PARKING + side, "separate")],
+            )),
+            _ => {},
+        }
+    }
+    let width_key = key + "width";
+    if let Some(width) = tags.get(&width_key) {
+        applied.push(Applied::new(
+            &[(width_key, width)],
+            [(PARKING + side + "width", width)],
+        ));
+    }
+    applied
+}
+
+#[cfg(test)]
+mod tests {
+    use osm_tags::Tags;
+
+    use super::normalize_tags;
+    use crate::locale::{DrivingSide, Locale};
+    use crate::transform::{tags_to_lanes, TagsToLanesConfig, WarningCode};
+
+    fn normalize(
+        tags: &[(&'static str, &'static str)],
+        driving_side: DrivingSide,
+    ) -> (Vec<String>, usize) {
+        let locale = Locale::builder().driving_side(driving_side).build();
+        let tags = Tags::from_pairs(tags.iter().copied()).unwrap();
+        let (normalized, applied) = normalize_tags(&tags, &locale);
+
+        // The lanes are the same, without the deprecation warnings
+        let config = TagsToLanesConfig::default();
+        let before = tags_to_lanes(&tags, &locale, &config).unwrap();
+        let after = tags_to_lanes(&normalized, &locale, &config).unwrap();
+        assert_eq!(before.road, after.road);
+        assert!(after
+            .warnings
+            .as_slice()
+            .iter()
+            .all(|warning| warning.code() != WarningCode::DeprecatedTag));
+
+        (normalized.to_vec(), applied.len())
+    }
+
+    #[test]
+    fn deprecations() {
+        assert_eq!(
+            normalize(
+                &[("highway", "residential"), ("sidewalk", "none")],
+                DrivingSide::Right
+            ),
+            (
+                vec!["highway=residential".to_owned(), "sidewalk=no".to_owned()],
+                1
+            )
+        );
+        assert_eq!(
+            normalize(
+                &[
+                    ("highway", "residential"),
+                    ("oneway", "yes"),
+                    ("cycleway", "opposite_lane"),
+                ],
+                DrivingSide::Left
+            ),
+            (
+                vec![
+                    "cycleway:right=lane".to_owned(),
+                    "cycleway:right:oneway=-1".to_owned(),
+                    "highway=residential".to_owned(),
+                    "oneway=yes".to_owned(),
+                ],
+                1
+            )
+        );
+    }
+
+    #[test]
+    fn legacy_parking() {
+        assert_eq!(
+            normalize(
+                &[
+                    ("highway", "residential"),
+                    ("parking:lane:right", "parallel"),
+                    ("parking:lane:right:parallel", "half_on_kerb"),
+                    ("parking:lane:left", "no_stopping"),
+                ],
+                DrivingSide::Right
+            ),
+            (
+                vec![
+                    "highway=residential".to_owned(),
+                    "parking:left=no".to_owned(),
+                    "parking:left:restriction=no_stopping".to_owned(),
+                    "parking:right=half_on_kerb".to_owned(),
+                    "parking:right:orientation=parallel".to_owned(),
+                ],
+                2
+            )
+        );
+    }
+
+    #[test]
+    fn conflict() {
+        // Both schemes are tagged, so the legacy scheme is left for a mapper to resolve
+        let locale = Locale::builder().driving_side(DrivingSide::Right).build();
+        let tags = Tags::from_pairs([
+            ("highway", "residential"),
+            ("parking:lane:right", "parallel"),
+            ("parking:right", "no"),
+        ])
+        .unwrap();
+        let (normalized, applied) = normalize_tags(&tags, &locale);
+        assert!(applied.is_empty());
+        assert_eq!(normalized.to_vec(), tags.to_vec());
+    }
+}
@@ -1,5 +1,5 @@
-use osm_tag_schemes::Highway;
-use osm_tags::{TagKey, Tags};
+use osm_tag_schemes::{Highway, TurnIndication};
+use osm_tags::{TagKey, Tags, TagsRef};
 
 use super::{Infer, Oneway, TagsNumeric};
 use crate::locale::Locale;
@@ -28,7 +28,7 @@ impl Counts {
         clippy::too_many_lines
     )]
     pub(super) fn new(
-        tags: &Tags,
+        tags: &TagsRef<'_>,
         oneway: Oneway,
         highway: &Highway,
         centre_turn_lane: &CentreTurnLaneScheme, // TODO prefer TurnLanesScheme
@@ -37,6 +37,7 @@ impl Counts {
         warnings: &mut RoadWarnings,
     ) -> Self {
         let lanes = LanesDirectionScheme::from_tags(tags, oneway, locale, warnings);
+        lanes_transition(tags, lanes.total, warnings);
 
         let centre_turn_lane = match (lanes.both_ways, centre_turn_lane.some()) {
             (Some(()), None | Some(true)) => Infer::Direct(true),
@@ -100,8 +101,8 @@ impl Counts {
                     centre_turn_lane,
                 }
             } else {
-                // Assume 1 lane, but guess 1 normal lane plus bus lanes.
-                let assumed_forward = 1; // TODO depends on highway tag
+                // Assume the locale's lanes per direction, plus bus lanes.
+                let assumed_forward = locale.default_lanes_per_direction(highway.r#type());
                 Self::Directional {
                     forward: Infer::Default(assumed_forward + bus.forward),
                     backward: Infer::Default(0),
@@ -162,21 +163,21 @@ impl Counts {
                         let half = (remaining_lanes + 1) / 2; // usize division rounded up.
                         Self::Directional {
                             forward: Infer::Default(half + bus.forward),
-                            backward: Infer::Default(
-                                remaining_lanes - half - both_ways + bus.backward,
-                            ),
+                            backward: Infer::Default(remaining_lanes - half + bus.backward),
                             centre_turn_lane,
                         }
                     }
                 },
                 (None, None, None) => {
-                    if locale.has_split_lanes(highway.r#type())
-                        || bus.forward > 0
-                        || bus.backward > 0
-                    {
+                    let default_lanes = locale.default_lanes(highway.r#type());
+                    if default_lanes > 1 || bus.forward > 0 || bus.backward > 0 {
+                        let half = (default_lanes + 1) / 2; // usize division rounded up.
                         Self::Directional {
-                            forward: Infer::Default(1 + bus.forward),
-                            backward: Infer::Default(1 + bus.backward),
+                            forward: Infer::Default(half + bus.forward),
+                            backward: Infer::Default(
+                                // At least one lane in each direction when split by bus lanes.
+                                default_lanes.saturating_sub(half).max(1) + bus.backward,
+                            ),
                             centre_turn_lane,
                         }
                     } else {
@@ -203,6 +204,136 @@ impl Counts {
     }
 }
 
+/// Lanes for motor vehicle traffic observed outside of the tags, such as from imagery,
+/// to constrain the lane counts that are otherwise assumed.
+/// Counts tagged on the way take precedence, disagreements are warned about.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct ObservedLanes {
+    /// Lanes in the direction of the way, including bus lanes
+    pub forward: Option<usize>,
+    /// Lanes against the direction of the way, including bus lanes
+    pub backward: Option<usize>,
+}
+
+impl ObservedLanes {
+    fn is_empty(&self) -> bool {
+        self.forward.is_none() && self.backward.is_none()
+    }
+}
+
+impl Counts {
+    /// A single lane with travel in both directions instead of the assumed lane in each direction,
+    /// where the carriageway is too narrow for them, see `Locale::single_track_width`
+    pub(super) fn single_track(self, narrow: bool, tags: &TagsRef<'_>) -> Self {
+        match self {
+            Self::Directional {
+                forward: Infer::Default(1),
+                backward: Infer::Default(1),
+                centre_turn_lane,
+            } if narrow
+                && tags.get(&LANES).is_none()
+                && !centre_turn_lane.some().unwrap_or(false) =>
+            {
+                Self::One
+            },
+            counts => counts,
+        }
+    }
+
+    /// Only the lane counts that are tagged, or calculated from tags, when strict.
+    /// Counts assumed without any `lanes=*` tag are reduced to a single lane of unknown direction,
+    /// with a warning.
+    pub(super) fn strict(
+        self,
+        strict: bool,
+        tags: &TagsRef<'_>,
+        warnings: &mut RoadWarnings,
+    ) -> Self {
+        match self {
+            Self::Directional {
+                forward: Infer::Default(_) | Infer::None,
+                backward: Infer::Default(_) | Infer::None,
+                ..
+            } if strict && tags.get(&LANES).is_none() => {
+                warnings.push(TagsToLanesMsg::inference_omitted_road(vec!["lane count"]));
+                Self::One
+            },
+            counts => counts,
+        }
+    }
+
+    /// Constrain the counts by the observed lanes,
+    /// replacing assumed counts and warning about tagged or calculated counts that disagree
+    pub(super) fn observe(
+        self,
+        observed: ObservedLanes,
+        tags: &TagsRef<'_>,
+        oneway: Oneway,
+        warnings: &mut RoadWarnings,
+    ) -> Self {
+        if observed.is_empty() {
+            return self;
+        }
+        let mut conflict = false;
+        let mut observe = |count: Infer<usize>, observed: Option<usize>| match (count, observed) {
+            (count, None) => count,
+            (count @ (Infer::Direct(_) | Infer::Calculated(_)), Some(observed)) => {
+                conflict |= count.some() != Some(observed);
+                count
+            },
+            (Infer::Default(_) | Infer::None, Some(observed)) => Infer::Calculated(observed),
+        };
+        let counts = match self {
+            Self::Directional {
+                forward,
+                backward,
+                centre_turn_lane,
+            } => Self::Directional {
+                forward: observe(forward, observed.forward),
+                backward: observe(backward, observed.backward),
+                centre_turn_lane,
+            },
+            // A single lane is only tagged as `lanes=1`, otherwise it is assumed
+            Self::One if tags.get(&LANES).is_some() => {
+                conflict = observed.forward.unwrap_or(0) + observed.backward.unwrap_or(0) > 1;
+                Self::One
+            },
+            Self::One => {
+                // An unobserved direction keeps a lane, unless oneway
+                let other = usize::from(!bool::from(oneway));
+                Self::Directional {
+                    forward: Infer::Calculated(observed.forward.unwrap_or(other)),
+                    backward: Infer::Calculated(observed.backward.unwrap_or(other)),
+                    centre_turn_lane: Infer::Default(false),
+                }
+            },
+        };
+        if conflict {
+            warnings.push(TagsToLanesMsg::ambiguous(
+                format!(
+                    "observed {} forward and {} backward lanes disagree with the tags",
+                    observed
+                        .forward
+                        .map_or_else(|| "?".to_owned(), |c| c.to_string()),
+                    observed
+                        .backward
+                        .map_or_else(|| "?".to_owned(), |c| c.to_string()),
+                ),
+                tags.subset(&[
+                    LANES,
+                    LANES + "forward",
+                    LANES + "backward",
+                    LANES + "both_ways",
+                    Oneway::KEY,
+                ]),
+            ));
+        }
+        counts
+    }
+}
+
 const LANES: TagKey = TagKey::from_static("lanes");
 
 /// `lanes` and directional `lanes:*` scheme, see <https://wiki.openstreetmap.org/wiki/Key:lanes>
@@ -214,7 +345,7 @@ pub(in crate::transform::tags_to_lanes) struct LanesDirectionScheme {
 }
 impl LanesDirectionScheme {
     pub(crate) fn from_tags(
-        tags: &Tags,
+        tags: &TagsRef<'_>,
         _oneway: Oneway,
         _locale: &Locale,
         warnings: &mut RoadWarnings,
@@ -242,37 +373,182 @@ impl LanesDirectionScheme {
     }
 }
 
+const TAPER: TagKey = TagKey::from_static("taper");
+
+/// Proposed `lanes:start=*`, `lanes:end=*` and `taper=*` tagging,
+/// for ways where the lane count changes along the way.
+/// A single cross-section cannot represent this, so a warning carrying both counts is emitted.
+fn lanes_transition(tags: &TagsRef<'_>, total: Option<usize>, warnings: &mut RoadWarnings) {
+    let start: Option<usize> = tags.get_parsed(&(LANES + "start"), warnings);
+    let end: Option<usize> = tags.get_parsed(&(LANES + "end"), warnings);
+    if start.is_none() && end.is_none() && tags.get(&TAPER).is_none() {
+        return;
+    }
+    warnings.push(TagsToLanesMsg::lanes_transition(
+        start.or(total),
+        end.or(total),
+        tags.subset(&[LANES, LANES + "start", LANES + "end", TAPER]),
+    ));
+}
+
 const CENTRE_TURN_LANE: TagKey = TagKey::from_static("centre_turn_lane");
-pub(in crate::transform::tags_to_lanes) struct CentreTurnLaneScheme(pub(crate) Option<bool>);
+const TURN_LANES_BOTH_WAYS: TagKey = TagKey::from_static("turn:lanes:both_ways");
+
+/// Centre two-way turn lane, from `turn:lanes:both_ways=*` or the deprecated `centre_turn_lane=*`
+pub(in crate::transform::tags_to_lanes) struct CentreTurnLaneScheme {
+    centre_turn_lane: Option<bool>,
+    /// Turn indications of the centre lane
+    pub(crate) turn: Option<Vec<TurnIndication>>,
+}
 impl CentreTurnLaneScheme {
-    /// Parses and validates the `centre_turn_lane` tag and emits a deprecation warning.
+    /// Parses and validates the `centre_turn_lane` tag and emits a deprecation warning,
+    /// and the `turn:lanes:both_ways` tag of the single centre lane.
     /// See <https://wiki.openstreetmap.org/wiki/Key:centre_turn_lane>.
     pub(crate) fn from_tags(
-        tags: &Tags,
+        tags: &TagsRef<'_>,
         _oneway: Oneway,
         _locale: &Locale,
         warnings: &mut RoadWarnings,
     ) -> Self {
-        if let Some(v) = tags.get(&CENTRE_TURN_LANE) {
-            warnings.push(TagsToLanesMsg::deprecated_tags(
+        let centre_turn_lane = if let Some(v) = tags.get(&CENTRE_TURN_LANE) {
+            let suggested = if v == "yes" {
+                Tags::from_pairs([("lanes:both_ways", "1"), ("turn:lanes:both_ways", "left")])
+                    .unwrap()
+            } else {
+                Tags::default()
+            };
+            warnings.push(TagsToLanesMsg::deprecated(
                 tags.subset(&[CENTRE_TURN_LANE]),
+                suggested,
             ));
             match v {
-                "yes" => Self(Some(true)),
-                "no" => Self(Some(false)),
+                "yes" => Some(true),
+                "no" => Some(false),
                 _ => {
                     warnings.push(TagsToLanesMsg::unsupported_tags(
                         tags.subset([&CENTRE_TURN_LANE]),
                     ));
-                    Self(None)
+                    None
                 },
             }
         } else {
-            Self(None)
+            None
+        };
+        let turn = tags.get(&TURN_LANES_BOTH_WAYS).and_then(|v| {
+            if v.contains('|') {
+                warnings.push(TagsToLanesMsg::unsupported(
+                    "turn:lanes:both_ways must be of a single lane",
+                    tags.subset(&[TURN_LANES_BOTH_WAYS]),
+                ));
+                return None;
+            }
+            let turn = TurnIndication::split(v).ok();
+            if turn.is_none() {
+                warnings.push(TagsToLanesMsg::unsupported_tag(TURN_LANES_BOTH_WAYS, v));
+            }
+            turn
+        });
+        Self {
+            centre_turn_lane,
+            turn,
         }
     }
 
+    /// Whether there is a centre turn lane, `turn:lanes:both_ways=*` implying one
     pub(crate) fn some(&self) -> Option<bool> {
-        self.0
+        self.centre_turn_lane
+            .or_else(|| self.turn.as_ref().map(|_turn| true))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use osm_tags::Tags;
+
+    use super::{lanes_transition, ObservedLanes};
+    use crate::road::{Direction, Lane};
+    use crate::transform::tags_to_lanes::error::TagsToLanesIssue;
+    use crate::transform::tags_to_lanes::tests::road_from_pairs;
+    use crate::transform::{RoadWarnings, TagsToLanesConfig};
+
+    #[test]
+    fn transition() {
+        let tags = Tags::from_pairs([("lanes", "2"), ("lanes:end", "3")]).unwrap();
+        let mut warnings = RoadWarnings::default();
+        lanes_transition(&tags, Some(2), &mut warnings);
+        assert_eq!(warnings.as_slice().len(), 1);
+        assert!(matches!(
+            warnings.as_slice().first().unwrap().issue,
+            TagsToLanesIssue::LanesTransition {
+                start: Some(2),
+                end: Some(3),
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn no_transition() {
+        let tags = Tags::from_pair("lanes", "2");
+        let mut warnings = RoadWarnings::default();
+        lanes_transition(&tags, Some(2), &mut warnings);
+        assert!(warnings.is_empty());
+    }
+
+    #[test]
+    fn observed() {
+        let convert = |tags: &[(&'static str, &'static str)], observed: ObservedLanes| {
+            let config = TagsToLanesConfig {
+                include_separators: false,
+                observed_lanes: observed,
+                ..TagsToLanesConfig::default()
+            };
+            let road_from_tags = road_from_pairs(tags, &config);
+            let count = |direction| {
+                road_from_tags
+                    .road
+                    .lanes
+                    .iter()
+                    .filter(|lane| {
+                        lane.is_motor()
+                            && matches!(lane, Lane::Travel { direction: Some(d), .. } if *d == direction)
+                    })
+                    .count()
+            };
+            (
+                count(Direction::Forward),
+                count(Direction::Backward),
+                road_from_tags.warnings.as_slice().len(),
+            )
+        };
+        let three_forward = ObservedLanes {
+            forward: Some(3),
+            ..ObservedLanes::default()
+        };
+
+        // Assumed counts are replaced
+        assert_eq!(
+            convert(&[("highway", "primary"), ("oneway", "yes")], three_forward),
+            (3, 0, 0)
+        );
+        assert_eq!(
+            convert(&[("highway", "residential")], three_forward),
+            (3, 1, 0)
+        );
+        // Tagged counts are kept, with a warning
+        assert_eq!(
+            convert(
+                &[("highway", "primary"), ("oneway", "yes"), ("lanes", "2")],
+                three_forward
+            ),
+            (2, 0, 1)
+        );
+        assert_eq!(
+            convert(
+                &[("highway", "primary"), ("oneway", "yes"), ("lanes", "3")],
+                three_forward
+            ),
+            (3, 0, 0)
+        );
     }
 }
@@ -0,0 +1,82 @@
+/// A deprecated tag that is still read, warned about with `TagsToLanesMsg::suggestion`
+/// so that editors can replace it
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Deprecation {
+    /// The deprecated tag
+    pub deprecated: (&'static str, &'static str),
+    /// The tags replacing it, empty if it is to be removed.
+    /// Sides are given for driving on the right, `left` and `right` swap when driving on the left.
+    pub suggested: &'static [(&'static str, &'static str)],
+    /// Only deprecated on roads tagged `oneway=yes`
+    pub oneway: bool,
+}
+
+/// Every deprecation warned about by `tags_to_lanes`
+pub const DEPRECATIONS: &[Deprecation] = &[
+    // https://wiki.openstreetmap.org/wiki/Key:centre_turn_lane
+    Deprecation {
+        deprecated: ("centre_turn_lane", "yes"),
+        suggested: &[("lanes:both_ways", "1"), ("turn:lanes:both_ways", "left")],
+        oneway: false,
+    },
+    Deprecation {
+        deprecated: ("centre_turn_lane", "no"),
+        suggested: &[],
+        oneway: false,
+    },
+    // https://wiki.openstreetmap.org/wiki/Key:sidewalk
+    Deprecation {
+        deprecated: ("sidewalk", "none"),
+        suggested: &[("sidewalk", "no")],
+        oneway: false,
+    },
+    // https://wiki.openstreetmap.org/wiki/Key:cycleway
+    Deprecation {
+        deprecated: ("cycleway", "opposite_lane"),
+        suggested: &[("cycleway:left", "lane"), ("cycleway:left:oneway", "-1")],
+        oneway: true,
+    },
+    Deprecation {
+        deprecated: ("cycleway", "opposite_track"),
+        suggested: &[("cycleway:left", "track"), ("cycleway:left:oneway", "-1")],
+        oneway: true,
+    },
+];
+
+#[cfg(test)]
+mod tests {
+    use osm_tags::Tags;
+
+    use super::DEPRECATIONS;
+    use crate::locale::{DrivingSide, Locale};
+    use crate::transform::{tags_to_lanes, TagsToLanesConfig};
+
+    #[test]
+    fn deprecations_are_warned() {
+        let locale = Locale::builder().driving_side(DrivingSide::Right).build();
+        for deprecation in DEPRECATIONS {
+            let mut tags =
+                Tags::from_pairs([("highway", "tertiary"), ("lanes", "1"), ("oneway", "yes")])
+                    .unwrap();
+            tags.checked_insert(deprecation.deprecated.0, deprecation.deprecated.1)
+                .unwrap();
+            let warnings = tags_to_lanes(&tags, &locale, &TagsToLanesConfig::default())
+                .unwrap()
+                .warnings;
+            let deprecated =
+                Tags::from_pair(deprecation.deprecated.0, deprecation.deprecated.1).to_vec();
+            let suggested = Tags::from_pairs(deprecation.suggested.iter().copied())
+                .unwrap()
+                .to_vec();
+            assert!(
+                warnings.as_slice().iter().any(|warning| {
+                    warning.related_tags().map(Tags::to_vec).as_ref() == Some(&deprecated)
+                        && warning.suggestion().map(Tags::to_vec).as_ref() == Some(&suggested)
+                }),
+                "{}={}: {warnings}",
+                deprecation.deprecated.0,
+                deprecation.deprecated.1,
+            );
+        }
+    }
+}
@@ -0,0 +1,131 @@
+use osm_tag_schemes::{keys, HighwayImportance, HighwayType};
+use osm_tags::{TagKey, TagsRef};
+
+use super::oneway::{is_roundabout, Oneway};
+use super::TagsToLanesMsg;
+use crate::transform::RoadWarnings;
+
+const DUAL_CARRIAGEWAY: TagKey = TagKey::from_static("dual_carriageway");
+
+/// Hints for recognising a oneway way as one half of a dual carriageway,
+/// see `Road::dual_carriageway`.
+/// Without hints, only `dual_carriageway=yes` is recognised.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct DualCarriagewayHints {
+    /// Oneway ways of this highway class or more important are halves,
+    /// such as `Some(HighwayImportance::Trunk)` for oneway motorways and trunk roads
+    pub min_importance: Option<HighwayImportance>,
+    /// The names and refs of oneway ways found by the caller running the opposite way nearby,
+    /// a oneway way sharing its `name=*` or `ref=*` with one of them is a half
+    pub opposite_names: Vec<String>,
+}
+
+impl DualCarriagewayHints {
+    /// Oneway ways of the highway class are halves
+    fn is_important(&self, highway: HighwayType) -> bool {
+        match (highway, self.min_importance) {
+            (HighwayType::Classified(importance), Some(min_importance)) => {
+                importance <= min_importance
+            },
+            _ => false,
+        }
+    }
+
+    /// Oneway ways with the name or ref are halves
+    fn is_opposite_named(&self, name: Option<&str>, r#ref: Option<&str>) -> bool {
+        [name, r#ref]
+            .into_iter()
+            .flatten()
+            .any(|name| self.opposite_names.iter().any(|opposite| opposite == name))
+    }
+}
+
+/// Whether the way is one half of a dual carriageway,
+/// from `dual_carriageway=*` or else from the hints for oneway ways.
+/// Roundabouts are oneway, but are never halves.
+pub(super) fn dual_carriageway(
+    tags: &TagsRef<'_>,
+    highway: HighwayType,
+    oneway: Oneway,
+    hints: &DualCarriagewayHints,
+    warnings: &mut RoadWarnings,
+) -> bool {
+    match tags.get(&DUAL_CARRIAGEWAY) {
+        Some("yes") => return true,
+        Some("no") => return false,
+        Some(value) => warnings.push(TagsToLanesMsg::unsupported_tag(DUAL_CARRIAGEWAY, value)),
+        None => {},
+    }
+    oneway == Oneway::Yes
+        && !is_roundabout(tags)
+        && (hints.is_important(highway)
+            || hints.is_opposite_named(tags.get(&keys::NAME), tags.get(&keys::REF)))
+}
+
+#[cfg(test)]
+mod tests {
+    use osm_tag_schemes::HighwayImportance;
+
+    use super::DualCarriagewayHints;
+    use crate::transform::tags_to_lanes::tests::road_from_pairs;
+    use crate::transform::TagsToLanesConfig;
+
+    #[test]
+    fn dual_carriageway() {
+        let is_half = |tags: &[(&'static str, &'static str)], hints: DualCarriagewayHints| {
+            let config = TagsToLanesConfig {
+                dual_carriageway_hints: hints,
+                ..TagsToLanesConfig::default()
+            };
+            road_from_pairs(tags, &config).road.dual_carriageway
+        };
+        let trunk = DualCarriagewayHints {
+            min_importance: Some(HighwayImportance::Trunk),
+            ..DualCarriagewayHints::default()
+        };
+        let named = DualCarriagewayHints {
+            opposite_names: vec!["A1".to_owned()],
+            ..DualCarriagewayHints::default()
+        };
+
+        let motorway = [("highway", "motorway"), ("lanes", "2"), ("oneway", "yes")];
+        assert!(!is_half(&motorway, DualCarriagewayHints::default()));
+        assert!(is_half(&motorway, trunk.clone()));
+        assert!(!is_half(
+            &[("highway", "primary"), ("lanes", "2"), ("oneway", "yes")],
+            trunk.clone(),
+        ));
+        assert!(!is_half(
+            &[("highway", "trunk"), ("lanes", "2")],
+            trunk.clone()
+        ));
+        assert!(!is_half(
+            &[
+                ("highway", "trunk"),
+                ("lanes", "2"),
+                ("junction", "roundabout")
+            ],
+            trunk,
+        ));
+
+        let named_primary = [
+            ("highway", "primary"),
+            ("lanes", "2"),
+            ("oneway", "yes"),
+            ("ref", "A1"),
+        ];
+        assert!(is_half(&named_primary, named.clone()));
+        assert!(!is_half(&named_primary, DualCarriagewayHints::default()));
+
+        assert!(!is_half(
+            &[
+                ("highway", "primary"),
+                ("lanes", "2"),
+                ("oneway", "yes"),
+                ("ref", "A1"),
+                ("dual_carriageway", "no"),
+            ],
+            named,
+        ));
+    }
+}
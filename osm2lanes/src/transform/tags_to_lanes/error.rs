@@ -1,15 +1,22 @@
 use std::panic::Location;
 
-use osm_tags::{DuplicateKeyError, TagKey, Tags};
+use osm_tags::{DuplicateKeyError, TagKey, Tags, TagsRef};
 
 use crate::transform::tags_to_lanes::LaneBuilder;
 
 /// Tags to Lanes Transformation Logic Issue
 ///
 /// ```
-/// use osm2lanes::transform::TagsToLanesMsg;
-/// let _ = TagsToLanesMsg::deprecated_tag("foo", "bar");
-/// let _ = TagsToLanesMsg::unsupported_tag("foo", "bar");
+/// use osm2lanes::transform::{TagsToLanesMsg, WarningCode};
+/// use osm_tags::Tags;
+/// let _ = TagsToLanesMsg::deprecated_tag("foo", "bar", "foo", "baz");
+/// let msg = TagsToLanesMsg::unsupported_tag("foo", "bar");
+/// assert_eq!(msg.code(), WarningCode::UnsupportedTag);
+/// assert_eq!(msg.code().as_str(), "unsupported");
+/// assert_eq!(msg.related_tags().map(Tags::to_vec), Some(vec![String::from("foo=bar")]));
+/// assert!(msg.suggestion().is_none());
+/// let msg = TagsToLanesMsg::ambiguous_tag("foo", "yes").with_suggestion(Tags::from_pair("foo", "both"));
+/// assert_eq!(msg.suggestion().map(Tags::to_vec), Some(vec![String::from("foo=both")]));
 /// let _ = TagsToLanesMsg::unsupported_str(String::from("foo=bar because x and y"));
 /// let _ = TagsToLanesMsg::ambiguous_str("foo=bar because x and y");
 /// ```
@@ -17,14 +24,68 @@ use crate::transform::tags_to_lanes::LaneBuilder;
 pub struct TagsToLanesMsg {
     location: &'static Location<'static>,
     pub(in crate::transform) issue: TagsToLanesIssue,
+    /// Tags to replace the related tags with, see `TagsToLanesMsg::suggestion`
+    suggestion: Option<Tags>,
+}
+
+/// Stable machine readable kind of a `TagsToLanesMsg`,
+/// for aggregating and filtering warnings, serialized as `as_str`
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum WarningCode {
+    #[cfg_attr(feature = "serde", serde(rename = "deprecated"))]
+    DeprecatedTag,
+    #[cfg_attr(feature = "serde", serde(rename = "unsupported"))]
+    UnsupportedTag,
+    #[cfg_attr(feature = "serde", serde(rename = "unimplemented"))]
+    UnimplementedTag,
+    #[cfg_attr(feature = "serde", serde(rename = "ambiguous"))]
+    Ambiguous,
+    #[cfg_attr(feature = "serde", serde(rename = "separator_locale_unused"))]
+    SeparatorLocaleUnused,
+    #[cfg_attr(feature = "serde", serde(rename = "separator_unknown"))]
+    SeparatorUnknown,
+    #[cfg_attr(feature = "serde", serde(rename = "lanes_transition"))]
+    LanesTransition,
+    #[cfg_attr(feature = "serde", serde(rename = "inference_omitted"))]
+    InferenceOmitted,
+    #[cfg_attr(feature = "serde", serde(rename = "tags_duplicate_key"))]
+    TagsDuplicateKey,
+    #[cfg_attr(feature = "serde", serde(rename = "internal"))]
+    Internal,
+}
+
+impl WarningCode {
+    /// The code as it is serialized, which does not change between releases
+    #[must_use]
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::DeprecatedTag => "deprecated",
+            Self::UnsupportedTag => "unsupported",
+            Self::UnimplementedTag => "unimplemented",
+            Self::Ambiguous => "ambiguous",
+            Self::SeparatorLocaleUnused => "separator_locale_unused",
+            Self::SeparatorUnknown => "separator_unknown",
+            Self::LanesTransition => "lanes_transition",
+            Self::InferenceOmitted => "inference_omitted",
+            Self::TagsDuplicateKey => "tags_duplicate_key",
+            Self::Internal => "internal",
+        }
+    }
+}
+
+impl std::fmt::Display for WarningCode {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
 }
 
 #[derive(Clone, Debug)]
 pub(crate) enum TagsToLanesIssue {
-    /// Deprecated OSM tags, with suggested alternative
+    /// Deprecated OSM tags, with the tags replacing them as the suggestion, see `DEPRECATIONS`
     Deprecated {
         deprecated_tags: Tags,
-        suggested_tags: Option<Tags>,
     },
     /// Tag combination that is unsupported, and may never be supported
     Unsupported {
@@ -51,69 +112,78 @@ pub(crate) enum TagsToLanesIssue {
         inside: LaneBuilder,
         outside: LaneBuilder,
     },
+    /// Lane count changes along the way
+    LanesTransition {
+        start: Option<usize>,
+        end: Option<usize>,
+        tags: Tags,
+    },
+    /// Inferred lane properties omitted by a strict inference level,
+    /// of the whole road if there is no lane
+    InferenceOmitted {
+        lane: Option<usize>,
+        properties: Vec<&'static str>,
+    },
     /// Internal errors
     TagsDuplicateKey(DuplicateKeyError),
     Internal(&'static str),
 }
 
 impl TagsToLanesMsg {
+    /// Deprecated tags and the tags replacing them, empty if the tags are to be removed
     #[must_use]
     #[track_caller]
-    pub fn deprecated(deprecated: Tags, suggested: Tags) -> Self {
+    pub fn deprecated(deprecated: TagsRef<'_>, suggested: TagsRef<'_>) -> Self {
         TagsToLanesMsg {
             location: Location::caller(),
             issue: TagsToLanesIssue::Deprecated {
-                deprecated_tags: deprecated,
-                suggested_tags: Some(suggested),
+                deprecated_tags: deprecated.into_owned(),
             },
+            suggestion: Some(suggested.into_owned()),
         }
     }
 
+    /// A deprecated tag and the tag replacing it
     #[must_use]
     #[track_caller]
-    pub fn deprecated_tags(tags: Tags) -> Self {
-        TagsToLanesMsg {
-            location: Location::caller(),
-            issue: TagsToLanesIssue::Deprecated {
-                deprecated_tags: tags,
-                suggested_tags: None,
-            },
-        }
-    }
-
-    #[must_use]
-    #[track_caller]
-    pub fn deprecated_tag<K: Into<TagKey>>(key: K, val: &str) -> Self {
+    pub fn deprecated_tag<K: Into<TagKey>, S: Into<TagKey>>(
+        key: K,
+        val: &str,
+        suggested_key: S,
+        suggested_val: &str,
+    ) -> Self {
         TagsToLanesMsg {
             location: Location::caller(),
             issue: TagsToLanesIssue::Deprecated {
                 deprecated_tags: Tags::from_pair(key, val),
-                suggested_tags: None,
             },
+            suggestion: Some(Tags::from_pair(suggested_key, suggested_val)),
         }
     }
 
     #[must_use]
     #[track_caller]
-    pub fn unsupported(description: impl Into<String>, tags: Tags) -> Self {
+    pub fn unsupported(description: impl Into<String>, tags: TagsRef<'_>) -> Self {
         TagsToLanesMsg {
             location: Location::caller(),
             issue: TagsToLanesIssue::Unsupported {
                 description: Some(description.into()),
-                tags: Some(tags),
+                tags: Some(tags.into_owned()),
             },
+            suggestion: None,
         }
     }
 
     #[must_use]
     #[track_caller]
-    pub fn unsupported_tags(tags: Tags) -> Self {
+    pub fn unsupported_tags(tags: TagsRef<'_>) -> Self {
         TagsToLanesMsg {
             location: Location::caller(),
             issue: TagsToLanesIssue::Unsupported {
                 description: None,
-                tags: Some(tags),
+                tags: Some(tags.into_owned()),
             },
+            suggestion: None,
         }
     }
 
@@ -126,6 +196,7 @@ impl TagsToLanesMsg {
                 description: None,
                 tags: Some(Tags::from_pair(key, val)),
             },
+            suggestion: None,
         }
     }
 
@@ -138,18 +209,20 @@ impl TagsToLanesMsg {
                 description: Some(description.into()),
                 tags: None,
             },
+            suggestion: None,
         }
     }
 
     #[must_use]
     #[track_caller]
-    pub fn unimplemented(description: impl Into<String>, tags: Tags) -> Self {
+    pub fn unimplemented(description: impl Into<String>, tags: TagsRef<'_>) -> Self {
         TagsToLanesMsg {
             location: Location::caller(),
             issue: TagsToLanesIssue::Unimplemented {
                 description: Some(description.into()),
-                tags: Some(tags),
+                tags: Some(tags.into_owned()),
             },
+            suggestion: None,
         }
     }
 
@@ -162,18 +235,33 @@ impl TagsToLanesMsg {
                 description: None,
                 tags: Some(Tags::from_pair(key, val)),
             },
+            suggestion: None,
         }
     }
 
     #[must_use]
     #[track_caller]
-    pub fn unimplemented_tags(tags: Tags) -> Self {
+    pub fn unimplemented_tags(tags: TagsRef<'_>) -> Self {
         TagsToLanesMsg {
             location: Location::caller(),
             issue: TagsToLanesIssue::Unimplemented {
                 description: None,
-                tags: Some(tags),
+                tags: Some(tags.into_owned()),
             },
+            suggestion: None,
+        }
+    }
+
+    #[must_use]
+    #[track_caller]
+    pub fn ambiguous(description: impl Into<String>, tags: TagsRef<'_>) -> Self {
+        TagsToLanesMsg {
+            location: Location::caller(),
+            issue: TagsToLanesIssue::Ambiguous {
+                description: Some(description.into()),
+                tags: Some(tags.into_owned()),
+            },
+            suggestion: None,
         }
     }
 
@@ -186,18 +274,20 @@ impl TagsToLanesMsg {
                 description: None,
                 tags: Some(Tags::from_pair(key, val)),
             },
+            suggestion: None,
         }
     }
 
     #[must_use]
     #[track_caller]
-    pub fn ambiguous_tags(tags: Tags) -> Self {
+    pub fn ambiguous_tags(tags: TagsRef<'_>) -> Self {
         TagsToLanesMsg {
             location: Location::caller(),
             issue: TagsToLanesIssue::Ambiguous {
                 description: None,
-                tags: Some(tags),
+                tags: Some(tags.into_owned()),
             },
+            suggestion: None,
         }
     }
 
@@ -210,6 +300,7 @@ impl TagsToLanesMsg {
                 description: Some(description.into()),
                 tags: None,
             },
+            suggestion: None,
         }
     }
 
@@ -219,6 +310,7 @@ impl TagsToLanesMsg {
         TagsToLanesMsg {
             location: Location::caller(),
             issue: TagsToLanesIssue::SeparatorLocaleUnused { inside, outside },
+            suggestion: None,
         }
     }
 
@@ -228,6 +320,48 @@ impl TagsToLanesMsg {
         TagsToLanesMsg {
             location: Location::caller(),
             issue: TagsToLanesIssue::SeparatorUnknown { inside, outside },
+            suggestion: None,
+        }
+    }
+
+    #[must_use]
+    #[track_caller]
+    pub fn lanes_transition(start: Option<usize>, end: Option<usize>, tags: TagsRef<'_>) -> Self {
+        TagsToLanesMsg {
+            location: Location::caller(),
+            issue: TagsToLanesIssue::LanesTransition {
+                start,
+                end,
+                tags: tags.into_owned(),
+            },
+            suggestion: None,
+        }
+    }
+
+    #[must_use]
+    #[track_caller]
+    pub fn inference_omitted(lane: usize, properties: Vec<&'static str>) -> Self {
+        TagsToLanesMsg {
+            location: Location::caller(),
+            issue: TagsToLanesIssue::InferenceOmitted {
+                lane: Some(lane),
+                properties,
+            },
+            suggestion: None,
+        }
+    }
+
+    /// Inferred properties of the road, rather than of any one lane, omitted when strict
+    #[must_use]
+    #[track_caller]
+    pub fn inference_omitted_road(properties: Vec<&'static str>) -> Self {
+        TagsToLanesMsg {
+            location: Location::caller(),
+            issue: TagsToLanesIssue::InferenceOmitted {
+                lane: None,
+                properties,
+            },
+            suggestion: None,
         }
     }
 
@@ -237,8 +371,63 @@ impl TagsToLanesMsg {
         TagsToLanesMsg {
             location: Location::caller(),
             issue: TagsToLanesIssue::Internal(e),
+            suggestion: None,
+        }
+    }
+}
+
+impl TagsToLanesMsg {
+    /// Stable machine readable identifier for the kind of issue
+    #[must_use]
+    pub fn code(&self) -> WarningCode {
+        match self.issue {
+            TagsToLanesIssue::Deprecated { .. } => WarningCode::DeprecatedTag,
+            TagsToLanesIssue::Unsupported { .. } => WarningCode::UnsupportedTag,
+            TagsToLanesIssue::Unimplemented { .. } => WarningCode::UnimplementedTag,
+            TagsToLanesIssue::Ambiguous { .. } => WarningCode::Ambiguous,
+            TagsToLanesIssue::SeparatorLocaleUnused { .. } => WarningCode::SeparatorLocaleUnused,
+            TagsToLanesIssue::SeparatorUnknown { .. } => WarningCode::SeparatorUnknown,
+            TagsToLanesIssue::LanesTransition { .. } => WarningCode::LanesTransition,
+            TagsToLanesIssue::InferenceOmitted { .. } => WarningCode::InferenceOmitted,
+            TagsToLanesIssue::TagsDuplicateKey(_) => WarningCode::TagsDuplicateKey,
+            TagsToLanesIssue::Internal(_) => WarningCode::Internal,
+        }
+    }
+
+    /// Suggest tags to replace the related tags with, empty if they are to be removed,
+    /// for tag editors to offer as a fix
+    #[must_use]
+    pub fn with_suggestion(mut self, suggestion: Tags) -> Self {
+        self.suggestion = Some(suggestion);
+        self
+    }
+
+    /// The tags that triggered the issue, with their values, if known
+    #[must_use]
+    pub fn related_tags(&self) -> Option<&Tags> {
+        match &self.issue {
+            TagsToLanesIssue::Deprecated {
+                deprecated_tags, ..
+            } => Some(deprecated_tags),
+            TagsToLanesIssue::Unsupported { tags, .. }
+            | TagsToLanesIssue::Unimplemented { tags, .. }
+            | TagsToLanesIssue::Ambiguous { tags, .. } => tags.as_ref(),
+            TagsToLanesIssue::LanesTransition { tags, .. } => Some(tags),
+            TagsToLanesIssue::SeparatorLocaleUnused { .. }
+            | TagsToLanesIssue::SeparatorUnknown { .. }
+            | TagsToLanesIssue::InferenceOmitted { .. }
+            | TagsToLanesIssue::TagsDuplicateKey(_)
+            | TagsToLanesIssue::Internal(_) => None,
         }
     }
+
+    /// The tags to replace the related tags of the issue with,
+    /// empty if they are to be removed,
+    /// `None` if there is no known fix
+    #[must_use]
+    pub fn suggestion(&self) -> Option<&Tags> {
+        self.suggestion.as_ref()
+    }
 }
 
 impl From<DuplicateKeyError> for TagsToLanesMsg {
@@ -247,6 +436,7 @@ impl From<DuplicateKeyError> for TagsToLanesMsg {
         TagsToLanesMsg {
             location: Location::caller(),
             issue: TagsToLanesIssue::TagsDuplicateKey(e),
+            suggestion: None,
         }
     }
 }
@@ -255,23 +445,21 @@ impl std::fmt::Display for TagsToLanesMsg {
     #[allow(clippy::panic_in_result_fn)]
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         match &self.issue {
-            TagsToLanesIssue::Deprecated {
-                deprecated_tags,
-                suggested_tags,
-            } => {
-                if let Some(suggested_tags) = suggested_tags {
+            TagsToLanesIssue::Deprecated { deprecated_tags } => {
+                let suggested_tags = self.suggestion.clone().unwrap_or_default();
+                if suggested_tags.is_empty() {
                     write!(
                         f,
-                        "deprecated: replace '{}' with '{}' - {}",
+                        "deprecated: remove '{}' - {}",
                         deprecated_tags.to_vec().as_slice().join(" "),
-                        suggested_tags.to_vec().as_slice().join(" "),
                         self.location,
                     )
                 } else {
                     write!(
                         f,
-                        "deprecated: '{}' - {}",
+                        "deprecated: replace '{}' with '{}' - {}",
                         deprecated_tags.to_vec().as_slice().join(" "),
+                        suggested_tags.to_vec().as_slice().join(" "),
                         self.location,
                     )
                 }
@@ -322,6 +510,40 @@ impl std::fmt::Display for TagsToLanesMsg {
                     inside, outside, self.location
                 )
             },
+            TagsToLanesIssue::LanesTransition { start, end, tags } => {
+                let count = |c: &Option<usize>| c.map_or_else(|| "?".to_owned(), |c| c.to_string());
+                write!(
+                    f,
+                    "lane count changes from {} to {}: '{}' - {}",
+                    count(start),
+                    count(end),
+                    tags.to_vec().as_slice().join(" "),
+                    self.location
+                )
+            },
+            TagsToLanesIssue::InferenceOmitted {
+                lane: Some(lane),
+                properties,
+            } => {
+                write!(
+                    f,
+                    "omitted inferred {} of lane {} - {}",
+                    properties.join(", "),
+                    lane,
+                    self.location
+                )
+            },
+            TagsToLanesIssue::InferenceOmitted {
+                lane: None,
+                properties,
+            } => {
+                write!(
+                    f,
+                    "omitted inferred {} of the road - {}",
+                    properties.join(", "),
+                    self.location
+                )
+            },
             TagsToLanesIssue::TagsDuplicateKey(e) => write!(f, "{} - {}", e, self.location),
             TagsToLanesIssue::Internal(e) => write!(f, "{} - {}", e, self.location),
         }
@@ -336,6 +558,25 @@ impl serde::Serialize for TagsToLanesMsg {
     where
         S: serde::Serializer,
     {
-        serializer.collect_str(self)
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("TagsToLanesMsg", 6)?;
+        state.serialize_field("code", &self.code())?;
+        state.serialize_field("message", &self.to_string())?;
+        match self.related_tags() {
+            Some(tags) => state.serialize_field("related_tags", tags)?,
+            None => state.skip_field("related_tags")?,
+        }
+        match self.suggestion() {
+            Some(tags) => state.serialize_field("suggestion", tags)?,
+            None => state.skip_field("suggestion")?,
+        }
+        if let TagsToLanesIssue::LanesTransition { start, end, .. } = &self.issue {
+            state.serialize_field("lanes_start", start)?;
+            state.serialize_field("lanes_end", end)?;
+        } else {
+            state.skip_field("lanes_start")?;
+            state.skip_field("lanes_end")?;
+        }
+        state.end()
     }
 }
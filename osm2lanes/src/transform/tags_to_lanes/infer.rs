@@ -1,4 +1,5 @@
 use super::TagsToLanesMsg;
+use crate::road::{Provenance, Provenanced};
 
 #[derive(Debug)]
 pub struct InferConflict;
@@ -109,6 +110,22 @@ where
         }
     }
 
+    /// The provenance of any non-`Infer::None` value
+    pub fn provenance(&self) -> Option<Provenance> {
+        match self {
+            Self::None => None,
+            Self::Default(_) => Some(Provenance::Default),
+            Self::Calculated(_) => Some(Provenance::Calculated),
+            Self::Direct(_) => Some(Provenance::Direct),
+        }
+    }
+
+    /// Convert any non-`Infer::None` value into `Option::Some`, keeping its provenance
+    pub fn provenanced(self) -> Option<Provenanced<T>> {
+        let provenance = self.provenance()?;
+        self.some().map(|value| Provenanced::new(value, provenance))
+    }
+
     /// If `Infer::None`, replaces with `Infer::Default(d)`
     #[must_use]
     pub fn or_default(self, d: T) -> Self {
@@ -119,6 +136,20 @@ where
     }
 }
 
+/// How much `tags_to_lanes` may guess beyond what is tagged
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum InferenceLevel {
+    /// Only directly tagged lane properties, everything else is omitted with a warning
+    Strict,
+    /// Directly tagged lane properties and commonly agreed defaults
+    #[default]
+    Consensus,
+    /// Every lane property, filling gaps with plausible locale defaults
+    Fanciful,
+}
+
 impl<T> Default for Infer<T> {
     fn default() -> Self {
         Self::None
@@ -0,0 +1,273 @@
+use osm_tags::{Tags, TagsRef};
+
+use crate::road::{Designated, Direction, Lane};
+use crate::transform::WaySide;
+
+const HIGHWAY: &str = "highway";
+
+/// Tags of the carriageway, of the lanes in the direction of the lane
+const CARRIAGEWAY: [&str; 12] = [
+    "lanes",
+    "oneway",
+    "turn:lanes",
+    "destination",
+    "width:lanes",
+    "maxspeed",
+    "access:lanes",
+    "bus:lanes",
+    "psv:lanes",
+    "busway",
+    "surface",
+    "smoothness",
+];
+
+/// Tag stems of the lane, `None` for separators
+fn stems(lane: &Lane) -> Option<&'static [&'static str]> {
+    match lane {
+        Lane::Travel {
+            designated: Designated::Foot,
+            ..
+        } => Some(&["sidewalk", "footway"]),
+        Lane::Travel {
+            designated: Designated::Bicycle,
+            ..
+        } => Some(&["cycleway"]),
+        Lane::Travel { .. } => Some(&CARRIAGEWAY),
+        Lane::Parking {
+            designated: Designated::Bus,
+            ..
+        } => Some(&["bus_bay"]),
+        Lane::Parking { .. } => Some(&["parking"]),
+        Lane::Shoulder { .. } => Some(&["shoulder"]),
+        Lane::Verge { .. } => Some(&["verge"]),
+        Lane::Separator { .. } => None,
+    }
+}
+
+/// The key is the stem, or a more specific key that does not name another side of the way
+/// or another direction of the lane
+fn matches_stem(
+    key: &str,
+    stem: &str,
+    side: Option<&WaySide>,
+    direction: Option<Direction>,
+) -> bool {
+    match key.strip_prefix(stem) {
+        Some("") => true,
+        Some(rest) => match rest.strip_prefix(':') {
+            Some(rest) => rest.split(':').all(|part| match (part, side, direction) {
+                ("left" | "right", Some(side), _) => part == side.as_str(),
+                ("forward", _, Some(direction)) => direction == Direction::Forward,
+                ("backward", _, Some(direction)) => direction == Direction::Backward,
+                ("both_ways", _, Some(direction)) => direction == Direction::Both,
+                _ => true,
+            }),
+            None => false,
+        },
+        None => false,
+    }
+}
+
+/// The tag for one side of the way,
+/// e.g. `cycleway=lane` to `cycleway:right=lane` or `sidewalk=both` to `sidewalk:right=yes`
+fn sided(key: &str, val: &str, stem: &str, side: &WaySide) -> (String, String) {
+    // `bus_bay=*` has no keys per side, only values
+    if stem == "bus_bay" {
+        let val = if val == "both" { side.as_str() } else { val };
+        return (key.to_owned(), val.to_owned());
+    }
+    let mut parts: Vec<&str> = match key
+        .strip_prefix(stem)
+        .and_then(|rest| rest.strip_prefix(':'))
+    {
+        Some(rest) => rest.split(':').collect(),
+        None => Vec::new(),
+    };
+    if let Some(part) = parts.iter_mut().find(|part| **part == "both") {
+        *part = side.as_str();
+    } else if !parts.iter().any(|part| matches!(*part, "left" | "right")) {
+        // `parking:lane:<side>=*`
+        let index = usize::from(parts.first() == Some(&"lane"));
+        parts.insert(index, side.as_str());
+    }
+    let val = if key == stem && matches!(val, "both" | "left" | "right") {
+        "yes"
+    } else {
+        val
+    };
+    (
+        [stem]
+            .into_iter()
+            .chain(parts)
+            .collect::<Vec<_>>()
+            .join(":"),
+        val.to_owned(),
+    )
+}
+
+/// The keys of the tags of the lane, with the stem each matches
+fn keys<'tags>(
+    tags: &'tags TagsRef<'_>,
+    lane: &Lane,
+    side: Option<&WaySide>,
+) -> Vec<(&'tags str, Option<&'static str>)> {
+    let stems = stems(lane).unwrap_or_default();
+    let direction = if lane.is_motor() || lane.is_bus() {
+        lane.direction()
+    } else {
+        None
+    };
+    tags.to_str_pairs()
+        .into_iter()
+        .map(|(key, _val)| key)
+        .filter_map(|key| {
+            if key == HIGHWAY {
+                return Some((key, None));
+            }
+            stems
+                .iter()
+                .find(|stem| matches_stem(key, stem, side, direction))
+                .map(|stem| (key, Some(*stem)))
+        })
+        .collect()
+}
+
+/// The side of the way of each lane left to right, `None` within the carriageway
+fn sides(lanes: &[Lane]) -> Vec<Option<WaySide>> {
+    let carriageway = |lane: &Lane| lane.is_motor() || lane.is_bus();
+    let first = lanes.iter().position(carriageway);
+    let last = lanes.iter().rposition(carriageway);
+    (0..lanes.len())
+        .map(|index| match (first, last) {
+            (Some(first), _) if index < first => Some(WaySide::Left),
+            (_, Some(last)) if index > last => Some(WaySide::Right),
+            _ => None,
+        })
+        .collect()
+}
+
+/// For each lane left to right, the minimal set of tags to reproduce just that lane,
+/// with the tags of both sides or no side of the way given for the side of the lane
+pub(super) fn lane_tags(tags: &TagsRef<'_>, lanes: &[Lane]) -> Vec<Tags> {
+    lanes
+        .iter()
+        .zip(sides(lanes))
+        .map(|(lane, side)| {
+            if stems(lane).is_none() {
+                return Tags::default();
+            }
+            let mut sided_tags: Vec<(bool, String, String)> = keys(tags, lane, side.as_ref())
+                .into_iter()
+                .map(|(key, stem)| {
+                    let val = tags.get(key).unwrap_or_default();
+                    let (sided_key, val) = match (stem, side.as_ref()) {
+                        (Some(stem), Some(side)) if !CARRIAGEWAY.contains(&stem) => {
+                            sided(key, val, stem, side)
+                        },
+                        _ => (key.to_owned(), val.to_owned()),
+                    };
+                    (sided_key != key, sided_key, val)
+                })
+                .collect();
+            // A key tagged for the side takes precedence over one given for it
+            sided_tags.sort_by_key(|(given, _key, _val)| *given);
+            let mut lane_tags = Tags::default();
+            for (_given, key, val) in sided_tags {
+                if lane_tags.get(&key).is_none() {
+                    lane_tags.checked_insert(key, val).unwrap();
+                }
+            }
+            lane_tags
+        })
+        .collect()
+}
+
+/// The keys of the tags that the lanes are from, as tagged
+#[cfg(feature = "osmchange")]
+pub(crate) fn lane_keys(tags: &TagsRef<'_>, lanes: &[Lane]) -> std::collections::BTreeSet<String> {
+    lanes
+        .iter()
+        .zip(sides(lanes))
+        .filter(|(lane, _side)| stems(lane).is_some())
+        .flat_map(|(lane, side)| {
+            keys(tags, lane, side.as_ref())
+                .into_iter()
+                .map(|(key, _stem)| key.to_owned())
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{matches_stem, sided};
+    use crate::road::Direction;
+    use crate::transform::WaySide;
+
+    #[test]
+    fn test_matches_stem() {
+        let right = Some(&WaySide::Right);
+        assert!(matches_stem("cycleway", "cycleway", right, None));
+        assert!(matches_stem("cycleway:right", "cycleway", right, None));
+        assert!(matches_stem(
+            "cycleway:right:width",
+            "cycleway",
+            right,
+            None
+        ));
+        assert!(matches_stem("cycleway:both", "cycleway", right, None));
+        assert!(!matches_stem("cycleway:left", "cycleway", right, None));
+        assert!(!matches_stem(
+            "cycleway:left:width",
+            "cycleway",
+            right,
+            None
+        ));
+        assert!(!matches_stem("cyclewayfoo", "cycleway", right, None));
+        assert!(matches_stem("cycleway:left", "cycleway", None, None));
+        let forward = Some(Direction::Forward);
+        assert!(matches_stem("lanes:forward", "lanes", None, forward));
+        assert!(matches_stem("lanes", "lanes", None, forward));
+        assert!(!matches_stem("lanes:backward", "lanes", None, forward));
+        assert!(!matches_stem("lanes:both_ways", "lanes", None, forward));
+        assert!(!matches_stem(
+            "turn:lanes:backward",
+            "turn:lanes",
+            None,
+            forward
+        ));
+    }
+
+    #[test]
+    fn test_sided() {
+        let sided = |key, val, stem| sided(key, val, stem, &WaySide::Right);
+        let pair = |key: &str, val: &str| (key.to_owned(), val.to_owned());
+        assert_eq!(
+            sided("cycleway", "lane", "cycleway"),
+            pair("cycleway:right", "lane")
+        );
+        assert_eq!(
+            sided("cycleway:both:width", "1.5", "cycleway"),
+            pair("cycleway:right:width", "1.5")
+        );
+        assert_eq!(
+            sided("cycleway:right", "lane", "cycleway"),
+            pair("cycleway:right", "lane")
+        );
+        assert_eq!(
+            sided("sidewalk", "both", "sidewalk"),
+            pair("sidewalk:right", "yes")
+        );
+        assert_eq!(
+            sided("parking:lane:both", "parallel", "parking"),
+            pair("parking:lane:right", "parallel")
+        );
+        assert_eq!(
+            sided("parking:lane", "parallel", "parking"),
+            pair("parking:lane:right", "parallel")
+        );
+        assert_eq!(
+            sided("bus_bay", "both", "bus_bay"),
+            pair("bus_bay", "right")
+        );
+    }
+}
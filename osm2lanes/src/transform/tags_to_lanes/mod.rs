@@ -1,20 +1,22 @@
 #![allow(clippy::module_name_repetitions)] // TODO: fix upstream
 
-use std::borrow::Borrow;
-use std::hash::Hash;
-
-use osm_tag_schemes::Schemes;
-use osm_tags::Tags;
+use osm_tag_schemes::{keys, Schemes};
+use osm_tags::TagsRef;
 
 use crate::locale::Locale;
-use crate::road::Road;
+use crate::metric::Metre;
+use crate::road::{Designated, Direction, Lane, ProvenancedLane, ProvenancedRoad, Road};
 use crate::transform::error::{RoadError, RoadWarnings};
 use crate::transform::RoadFromTags;
 
 mod error;
-pub use error::TagsToLanesMsg;
+pub use error::{TagsToLanesMsg, WarningCode};
+
+mod deprecation;
+pub use deprecation::{Deprecation, DEPRECATIONS};
 
 mod counts;
+pub use counts::ObservedLanes;
 
 mod modes;
 use modes::BuswayScheme;
@@ -23,39 +25,69 @@ mod separator;
 
 mod road;
 use osm_tags::TagKey;
-use road::{LaneBuilder, LaneBuilderError, LaneType, RoadBuilder};
+use road::LaneBuilderError;
+pub use road::{
+    Access as LaneBuilderAccess, LaneBuilder, LaneType, RoadBuilder, Width as LaneBuilderWidth,
+};
+
+mod pass;
+pub use pass::LaneModePass;
 
 mod unsupported;
 use unsupported::unsupported;
 
 mod infer;
-pub use infer::Infer;
+pub use infer::{Infer, InferenceLevel};
 
 mod oneway;
-use oneway::Oneway;
+use oneway::{is_roundabout, reversible, Oneway};
+
+mod lane_tags;
+#[cfg(feature = "osmchange")]
+pub(crate) use lane_tags::lane_keys;
+use lane_tags::lane_tags;
+
+mod placement;
+use placement::placement;
+
+mod dual_carriageway;
+use dual_carriageway::dual_carriageway;
+pub use dual_carriageway::DualCarriagewayHints;
+
+mod steps;
+use steps::steps;
+
+mod overtaking;
+use overtaking::overtaking;
+
+mod single_track;
+use single_track::passing_places;
+
+mod widths;
+pub use widths::WidthReconciliation;
+use widths::{reconcile_width, widths};
 
 trait TagsNumeric {
-    fn get_parsed<Q, T, O>(&self, key: &Q, warnings: &mut RoadWarnings) -> Option<T>
+    fn get_parsed<Q, T>(&self, key: &Q, warnings: &mut RoadWarnings) -> Option<T>
     where
-        TagKey: Borrow<Q>,
-        Q: Ord + Hash + Eq + ?Sized + ToOwned<Owned = O>,
-        O: Into<TagKey>,
+        Q: AsRef<str> + ?Sized,
         T: std::str::FromStr;
 }
 
-impl TagsNumeric for Tags {
-    fn get_parsed<Q, T, O>(&self, key: &Q, warnings: &mut RoadWarnings) -> Option<T>
+impl TagsNumeric for TagsRef<'_> {
+    fn get_parsed<Q, T>(&self, key: &Q, warnings: &mut RoadWarnings) -> Option<T>
     where
-        TagKey: Borrow<Q>,
-        Q: Ord + Hash + Eq + ?Sized + ToOwned<Owned = O>,
-        O: Into<TagKey>,
+        Q: AsRef<str> + ?Sized,
         T: std::str::FromStr,
     {
         self.get(key).and_then(|val| {
             if let Ok(w) = val.parse::<T>() {
                 Some(w)
             } else {
-                warnings.push(TagsToLanesMsg::unsupported_tag(key.to_owned(), val));
+                warnings.push(TagsToLanesMsg::unsupported_tag(
+                    TagKey::from_ref(key.as_ref()),
+                    val,
+                ));
                 None
             }
         })
@@ -66,6 +98,35 @@ impl TagsNumeric for Tags {
 pub struct Config {
     pub error_on_warnings: bool,
     pub include_separators: bool,
+    pub inference: InferenceLevel,
+    /// Also output the road with the provenance of each lane property,
+    /// distinguishing tagged values from calculated values and defaults
+    pub include_provenance: bool,
+    /// Also output the tags of each lane, to show or edit a single lane in isolation
+    pub include_lane_tags: bool,
+    /// Also output the plausible exits of each forward lane, see `Road::connectivity`
+    pub include_connectivity: bool,
+    /// How lane widths are fitted to `width=*` or `width:carriageway=*`
+    pub width_reconciliation: WidthReconciliation,
+    /// Carriageway width measured outside of the tags, such as from an `area:highway=*` polygon
+    /// or imagery, excluding sidewalks.
+    /// The lane widths are fitted to it in preference to the tagged widths,
+    /// warning if they disagree.
+    pub measured_width: Option<Metre>,
+    /// Lanes observed outside of the tags, such as from imagery,
+    /// constraining the lane counts that are otherwise assumed
+    pub observed_lanes: ObservedLanes,
+    /// Only keep separator markings that are tagged, such as `divider=*`,
+    /// silently omitting those inferred, which strict inference also does with a warning
+    pub only_tagged_markings: bool,
+    /// Treat unknown `highway=*` values as `highway=road` with a warning, instead of an error,
+    /// so that bulk conversions keep ways of new or regional highway values
+    pub unknown_highway_as_road: bool,
+    /// Hints for recognising a oneway way as one half of a dual carriageway,
+    /// beyond `dual_carriageway=yes`, see `Road::dual_carriageway`
+    pub dual_carriageway_hints: DualCarriagewayHints,
+    /// Additional passes over the tags, run in order after the built-in modes
+    pub passes: Vec<Box<dyn LaneModePass>>,
 }
 
 impl Config {
@@ -74,8 +135,16 @@ impl Config {
         Self {
             error_on_warnings,
             include_separators,
+            ..Self::default()
         }
     }
+
+    /// Register an additional pass over the tags, see [`LaneModePass`]
+    #[must_use]
+    pub fn with_pass(mut self, pass: impl LaneModePass + 'static) -> Self {
+        self.passes.push(Box::new(pass));
+        self
+    }
 }
 
 impl Default for Config {
@@ -83,25 +152,45 @@ impl Default for Config {
         Self {
             error_on_warnings: false,
             include_separators: true,
+            inference: InferenceLevel::default(),
+            include_provenance: false,
+            include_lane_tags: false,
+            include_connectivity: false,
+            width_reconciliation: WidthReconciliation::default(),
+            measured_width: None,
+            observed_lanes: ObservedLanes::default(),
+            only_tagged_markings: false,
+            unknown_highway_as_road: false,
+            dual_carriageway_hints: DualCarriagewayHints::default(),
+            passes: Vec::new(),
         }
     }
 }
 
 pub(in crate::transform::tags_to_lanes) struct TagSchemes {
-    oneway: Oneway,
+    /// `Infer::Default` if implied by the highway type
+    oneway: Infer<Oneway>,
     busway: BuswayScheme,
 }
 
 impl TagSchemes {
     pub(crate) fn from_tags(
-        tags: &Tags,
+        tags: &TagsRef<'_>,
         locale: &Locale,
         warnings: &mut RoadWarnings,
     ) -> Result<Self, TagsToLanesMsg> {
-        let oneway = Oneway::from_tags(tags, locale, warnings)?;
-        let busway = BuswayScheme::from_tags(tags, oneway, locale, warnings)?;
+        let oneway = Oneway::from_tags(tags, warnings)?;
+        let busway = BuswayScheme::from_tags(tags, Self::oneway_of(oneway), locale, warnings)?;
         Ok(Self { oneway, busway })
     }
+
+    pub(crate) fn oneway(&self) -> Oneway {
+        Self::oneway_of(self.oneway)
+    }
+
+    fn oneway_of(oneway: Infer<Oneway>) -> Oneway {
+        oneway.some().unwrap_or(Oneway::No)
+    }
 }
 
 /// From an OpenStreetMap way's tags,
@@ -119,7 +208,7 @@ impl TagSchemes {
 /// A config option allows all warnings to be treated as errors.
 ///
 pub fn tags_to_lanes(
-    tags: &Tags,
+    tags: &TagsRef<'_>,
     locale: &Locale,
     config: &Config,
 ) -> Result<RoadFromTags, RoadError> {
@@ -139,6 +228,7 @@ pub fn tags_to_lanes(
         &crate_schemes,
         tags,
         locale,
+        config,
         &mut warnings,
     )?;
 
@@ -152,26 +242,161 @@ pub fn tags_to_lanes(
         &mut warnings,
     )?;
 
-    modes::bicycle(tags, locale, &mut road, &mut warnings)?;
+    modes::hov(tags, locale, &mut road, &mut warnings)?;
+
+    modes::turn(tags, locale, &mut road, &mut warnings)?;
+
+    modes::change(tags, locale, &mut road, &mut warnings)?;
+
+    modes::destination(tags, locale, &mut road, &mut warnings)?;
 
-    modes::parking(tags, locale, &mut road)?;
+    modes::restrictions(tags, locale, &mut road, &mut warnings)?;
+
+    modes::embedded_rail(tags, locale, &mut road, &mut warnings)?;
+
+    modes::access(tags, locale, &mut road, &mut warnings)?;
+
+    modes::conditional(tags, locale, &mut road, &mut warnings)?;
+
+    let cycling_infrastructure = modes::bicycle(tags, locale, &mut road, &mut warnings)?;
+
+    modes::parking(tags, locale, &mut road, &mut warnings)?;
 
     modes::foot_and_shoulder(tags, locale, &mut road, &mut warnings)?;
 
-    let (lanes, highway, _oneway) =
-        road.into_ltr(tags, locale, config.include_separators, &mut warnings)?;
+    modes::surface(tags, locale, &mut road, &mut warnings)?;
+
+    modes::lit(tags, locale, &mut road, &mut warnings)?;
+
+    widths(tags, locale, &mut road, &mut warnings)?;
+
+    for pass in &config.passes {
+        pass.apply(tags, locale, &mut road, &mut warnings)?;
+    }
+
+    reversible(tags, locale, &mut road);
+
+    let omitted = road.apply_inference(config.inference, locale);
+
+    // Tagged widths are all that is kept when strict
+    if config.inference != InferenceLevel::Strict {
+        reconcile_width(
+            tags,
+            locale,
+            &mut road,
+            config.width_reconciliation,
+            config.measured_width,
+            &mut warnings,
+        );
+    }
+
+    let provenanced = config
+        .include_provenance
+        .then(|| road.provenanced_ltr(locale));
+
+    let separately_mapped = std::mem::take(&mut road.separately_mapped);
+
+    let (lanes, highway, oneway) = road.into_ltr(
+        tags,
+        locale,
+        config.include_separators,
+        config.only_tagged_markings || config.inference == InferenceLevel::Strict,
+        config.inference == InferenceLevel::Strict,
+        &mut warnings,
+    )?;
+
+    if config.inference == InferenceLevel::Strict {
+        // Separator markings are omitted as they are built
+        let mut omitted = omitted.into_iter();
+        for (index, lane) in lanes.iter().enumerate() {
+            if matches!(lane, Lane::Separator { .. }) {
+                continue;
+            }
+            let properties = omitted.next().unwrap_or_default();
+            if !properties.is_empty() {
+                warnings.push(TagsToLanesMsg::inference_omitted(index, properties));
+            }
+        }
+    }
+
+    let dual_carriageway = dual_carriageway(
+        tags,
+        highway.r#type(),
+        oneway,
+        &config.dual_carriageway_hints,
+        &mut warnings,
+    );
+    let steps = steps(tags, highway.r#type(), &mut warnings);
+    let role = modes::footway_role(tags, highway.r#type(), &mut warnings);
+
+    let road = Road {
+        name: generic_schemes.name,
+        r#ref: generic_schemes.r#ref,
+        highway,
+        lit: generic_schemes.lit.unwrap_or(None),
+        tracktype: generic_schemes.tracktype.unwrap_or(None),
+        smoothness: generic_schemes.smoothness.unwrap_or(None),
+        cycling_infrastructure,
+        separately_mapped,
+        placement: placement(tags, &lanes, &mut warnings),
+        roundabout: is_roundabout(tags),
+        dual_carriageway,
+        steps,
+        role,
+        overtaking: overtaking(tags, &mut warnings),
+        priority_road: tags.get_parsed(&keys::PRIORITY_ROAD, &mut warnings),
+        passing_places: passing_places(tags, &lanes, &mut warnings),
+        lanes,
+    };
+
+    // Sanity check, every road must be passable by some mode
+    if !road.highway.is_construction()
+        && !road.highway.is_proposed()
+        && ![
+            Designated::Foot,
+            Designated::Bicycle,
+            Designated::Bus,
+            Designated::Motor,
+            Designated::Hov,
+        ]
+        .into_iter()
+        .any(|mode| {
+            road.passable(mode, Direction::Forward) || road.passable(mode, Direction::Backward)
+        })
+    {
+        warnings.push(TagsToLanesMsg::unsupported(
+            "road is not passable by any mode",
+            tags.subset(["highway", "access"]),
+        ));
+    }
+
+    // Separators are interleaved with the provenanced lanes, in the same order
+    let provenance = provenanced.map(|provenanced| {
+        let mut provenanced = provenanced.into_iter();
+        let lanes = road
+            .lanes
+            .iter()
+            .filter_map(|lane| match lane {
+                Lane::Separator { semantic, markings } => {
+                    Some(ProvenancedLane::separator(*semantic, markings.clone()))
+                },
+                _ => provenanced.next(),
+            })
+            .collect();
+        ProvenancedRoad::new(&road, lanes)
+    });
+
+    let lane_tags = config
+        .include_lane_tags
+        .then(|| lane_tags(tags, &road.lanes));
+    let connectivity = config.include_connectivity.then(|| road.connectivity());
 
     let road_from_tags = RoadFromTags {
-        road: Road {
-            name: generic_schemes.name,
-            r#ref: generic_schemes.r#ref,
-            highway,
-            lit: generic_schemes.lit.unwrap_or(None),
-            tracktype: generic_schemes.tracktype.unwrap_or(None),
-            smoothness: generic_schemes.smoothness.unwrap_or(None),
-            lanes,
-        },
+        road,
         warnings,
+        provenance,
+        lane_tags,
+        connectivity,
     };
 
     if config.error_on_warnings && !road_from_tags.warnings.is_empty() {
@@ -180,3 +405,351 @@ pub fn tags_to_lanes(
 
     Ok(road_from_tags)
 }
+
+#[cfg(test)]
+mod tests {
+    use osm_tag_schemes::{Highway, HighwayType};
+    use osm_tags::Tags;
+
+    use super::{tags_to_lanes, Config, InferenceLevel};
+    use crate::locale::{DrivingSide, Locale};
+    use crate::metric::Metre;
+    use crate::road::{Designated, Direction, Lane, Provenance, ProvenancedLane, Road};
+    use crate::transform::RoadFromTags;
+
+    /// Convert the tags in right-hand traffic, for the cases that need a config,
+    /// the others are in `data/tests.yml`
+    pub(super) fn road_from_pairs(
+        tags: &[(&'static str, &'static str)],
+        config: &Config,
+    ) -> RoadFromTags {
+        let locale = Locale::builder().driving_side(DrivingSide::Right).build();
+        let tags = Tags::from_pairs(tags.iter().copied()).unwrap();
+        tags_to_lanes(&tags, &locale, config).unwrap()
+    }
+
+    fn lanes(tags: &Tags, inference: InferenceLevel) -> (Vec<Lane>, usize) {
+        let locale = Locale::builder().driving_side(DrivingSide::Right).build();
+        let road_from_tags = tags_to_lanes(
+            tags,
+            &locale,
+            &Config {
+                inference,
+                ..Config::default()
+            },
+        )
+        .unwrap();
+        (
+            road_from_tags.road.lanes,
+            road_from_tags.warnings.as_slice().len(),
+        )
+    }
+
+    #[test]
+    fn strict() {
+        let tags = Tags::from_pairs([
+            ("highway", "residential"),
+            ("lanes", "2"),
+            ("cycleway:right", "lane"),
+            ("cycleway:right:width", "1.5"),
+        ])
+        .unwrap();
+        let (lanes, warnings) = lanes(&tags, InferenceLevel::Strict);
+        for lane in &lanes {
+            match lane {
+                Lane::Travel {
+                    designated: Designated::Motor,
+                    direction,
+                    ..
+                } => assert_eq!(direction, &None),
+                Lane::Travel {
+                    designated: Designated::Bicycle,
+                    direction,
+                    width,
+                    ..
+                } => {
+                    assert_eq!(direction, &Some(Direction::Forward));
+                    assert_eq!(width, &Some(Metre::new(1.5_f64)));
+                },
+                Lane::Separator { markings, .. } => assert!(markings.is_none()),
+                _ => {},
+            }
+        }
+        assert!(warnings > 0);
+    }
+
+    #[test]
+    fn not_passable() {
+        let locale = Locale::builder().driving_side(DrivingSide::Right).build();
+        let tags = Tags::from_pairs([("highway", "service"), ("access", "no")]).unwrap();
+        let road_from_tags = tags_to_lanes(&tags, &locale, &Config::default()).unwrap();
+        let warning = road_from_tags
+            .warnings
+            .as_slice()
+            .iter()
+            .find(|warning| warning.to_string().contains("not passable"))
+            .unwrap();
+        assert_eq!(
+            warning.related_tags().map(Tags::to_vec),
+            Some(tags.to_vec())
+        );
+    }
+
+    #[test]
+    fn strict_untagged() {
+        let tags = Tags::from_pairs([("highway", "residential")]).unwrap();
+        // The assumed lane in each direction and the separators between them
+        let (consensus, _warnings) = lanes(&tags, InferenceLevel::Consensus);
+        assert_eq!(consensus.iter().filter(|lane| lane.is_motor()).count(), 2);
+        assert!(consensus.iter().any(Lane::is_separator));
+
+        // A single lane of unknown direction, without separators
+        let (lanes, warnings) = lanes(&tags, InferenceLevel::Strict);
+        assert_eq!(lanes.len(), 1);
+        assert!(matches!(
+            lanes.first(),
+            Some(Lane::Travel {
+                direction: None,
+                designated: Designated::Motor,
+                ..
+            })
+        ));
+        // The lane count, the centre separator, and the direction and width of the lane
+        assert_eq!(warnings, 3);
+    }
+
+    #[test]
+    fn only_tagged_markings() {
+        let locale = Locale::builder().driving_side(DrivingSide::Right).build();
+        let config = Config {
+            only_tagged_markings: true,
+            ..Config::default()
+        };
+        let markings = |tags: &Tags| -> Vec<bool> {
+            tags_to_lanes(tags, &locale, &config)
+                .unwrap()
+                .road
+                .lanes
+                .iter()
+                .filter_map(|lane| match lane {
+                    Lane::Separator { markings, .. } => Some(markings.is_some()),
+                    _ => None,
+                })
+                .collect()
+        };
+
+        let tags = Tags::from_pairs([
+            ("highway", "residential"),
+            ("lanes", "2"),
+            ("sidewalk", "both"),
+        ])
+        .unwrap();
+        assert_eq!(markings(&tags), vec![false, false, false]);
+        // Dropped silently, unlike with strict inference
+        let warnings = |config: &Config| {
+            tags_to_lanes(&tags, &locale, config)
+                .unwrap()
+                .warnings
+                .as_slice()
+                .len()
+        };
+        assert_eq!(warnings(&config), warnings(&Config::default()));
+
+        // Only the tagged centre line is kept
+        let tags = Tags::from_pairs([
+            ("highway", "residential"),
+            ("lanes", "2"),
+            ("sidewalk", "both"),
+            ("divider", "solid_line"),
+        ])
+        .unwrap();
+        assert_eq!(markings(&tags), vec![false, true, false]);
+
+        // No centre line, overriding the one inferred
+        let tags = Tags::from_pairs([
+            ("highway", "residential"),
+            ("lanes", "2"),
+            ("divider", "no"),
+        ])
+        .unwrap();
+        let (lanes, _warnings) = lanes(&tags, InferenceLevel::default());
+        assert!(lanes.iter().all(
+            |lane| matches!(lane, Lane::Separator { markings: None, .. }) || !lane.is_separator()
+        ));
+    }
+
+    #[test]
+    fn fanciful() {
+        let tags = Tags::from_pairs([
+            ("highway", "residential"),
+            ("lanes", "2"),
+            ("cycleway:right", "track"),
+        ])
+        .unwrap();
+        let (consensus, _) = lanes(&tags, InferenceLevel::Consensus);
+        let (fanciful, _) = lanes(&tags, InferenceLevel::Fanciful);
+        assert_eq!(consensus.len(), fanciful.len());
+        for lane in &fanciful {
+            if let Lane::Travel {
+                designated,
+                width,
+                elevation,
+                ..
+            } = lane
+            {
+                assert!(width.is_some());
+                if designated == &Designated::Bicycle {
+                    assert!(elevation.is_some());
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn provenance() {
+        let tags = Tags::from_pairs([
+            ("highway", "residential"),
+            ("lanes", "2"),
+            ("cycleway:right", "lane"),
+            ("cycleway:right:width", "1.5"),
+        ])
+        .unwrap();
+        let locale = Locale::builder().driving_side(DrivingSide::Right).build();
+
+        let road_from_tags = tags_to_lanes(&tags, &locale, &Config::default()).unwrap();
+        assert!(road_from_tags.provenance.is_none());
+
+        let road_from_tags = tags_to_lanes(
+            &tags,
+            &locale,
+            &Config {
+                include_provenance: true,
+                ..Config::default()
+            },
+        )
+        .unwrap();
+        let provenance = road_from_tags.provenance.unwrap();
+        assert_eq!(provenance.lanes.len(), road_from_tags.road.lanes.len());
+        assert!(provenance.lanes.iter().any(|lane| matches!(
+            lane,
+            ProvenancedLane::Travel {
+                width: Some(width),
+                ..
+            } if width.provenance == Provenance::Direct
+        )));
+        assert!(provenance.lanes.iter().any(|lane| matches!(
+            lane,
+            ProvenancedLane::Travel {
+                direction: Some(direction),
+                ..
+            } if direction.provenance != Provenance::Direct
+        )));
+        assert_eq!(Road::from(provenance), road_from_tags.road);
+    }
+
+    #[test]
+    fn lane_tags() {
+        let tags = Tags::from_pairs([
+            ("highway", "residential"),
+            ("lanes", "2"),
+            ("lanes:forward", "1"),
+            ("lanes:backward", "1"),
+            ("sidewalk", "both"),
+            ("cycleway:left", "track"),
+            ("cycleway", "lane"),
+            ("cycleway:right:width", "1.5"),
+        ])
+        .unwrap();
+        let locale = Locale::builder().driving_side(DrivingSide::Right).build();
+        let road_from_tags = tags_to_lanes(
+            &tags,
+            &locale,
+            &Config {
+                include_separators: false,
+                include_lane_tags: true,
+                ..Config::default()
+            },
+        )
+        .unwrap();
+        let lane_tags = road_from_tags.lane_tags.unwrap();
+        assert_eq!(lane_tags.len(), road_from_tags.road.lanes.len());
+        let (cycle_lane, tags) = road_from_tags
+            .road
+            .lanes
+            .iter()
+            .zip(lane_tags.iter())
+            .rfind(|(lane, _tags)| lane.is_bicycle())
+            .unwrap();
+        assert!(matches!(cycle_lane, Lane::Travel { width: Some(_), .. }));
+        assert_eq!(
+            tags.to_str_pairs(),
+            vec![
+                ("cycleway:right", "lane"),
+                ("cycleway:right:width", "1.5"),
+                ("highway", "residential"),
+            ]
+        );
+        assert_eq!(
+            lane_tags.first().unwrap().to_str_pairs(),
+            vec![("highway", "residential"), ("sidewalk:left", "yes")]
+        );
+        let (_lane, tags) = road_from_tags
+            .road
+            .lanes
+            .iter()
+            .zip(lane_tags.iter())
+            .find(|(lane, _tags)| lane.is_bicycle())
+            .unwrap();
+        assert_eq!(
+            tags.to_str_pairs(),
+            vec![("cycleway:left", "track"), ("highway", "residential")]
+        );
+        let motor: Vec<_> = road_from_tags
+            .road
+            .lanes
+            .iter()
+            .zip(lane_tags.iter())
+            .filter(|(lane, _tags)| lane.is_motor())
+            .map(|(_lane, tags)| tags.to_str_pairs())
+            .collect();
+        assert_eq!(
+            motor,
+            vec![
+                vec![
+                    ("highway", "residential"),
+                    ("lanes", "2"),
+                    ("lanes:backward", "1")
+                ],
+                vec![
+                    ("highway", "residential"),
+                    ("lanes", "2"),
+                    ("lanes:forward", "1")
+                ],
+            ]
+        );
+    }
+
+    #[test]
+    fn unknown_highway_as_road() {
+        let tags = Tags::from_pairs([("highway", "regional_road"), ("lanes", "2")]).unwrap();
+        let locale = Locale::builder().driving_side(DrivingSide::Right).build();
+        assert!(tags_to_lanes(&tags, &locale, &Config::default()).is_err());
+
+        let road_from_tags = tags_to_lanes(
+            &tags,
+            &locale,
+            &Config {
+                include_separators: false,
+                unknown_highway_as_road: true,
+                ..Config::default()
+            },
+        )
+        .unwrap();
+        assert_eq!(
+            road_from_tags.road.highway,
+            Highway::active(HighwayType::UnknownRoad)
+        );
+        assert_eq!(road_from_tags.road.lanes.len(), 2);
+        assert!(!road_from_tags.warnings.is_empty());
+    }
+}
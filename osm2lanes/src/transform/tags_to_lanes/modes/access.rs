@@ -0,0 +1,133 @@
+use std::str::FromStr;
+
+use osm_tag_schemes::{Access, LaneDependent};
+use osm_tags::{TagKey, TagsRef};
+
+use super::{is_carriageway, set_lanes};
+use crate::locale::Locale;
+use crate::road::AccessAndDirection;
+use crate::transform::tags_to_lanes::{Infer, LaneBuilder, RoadBuilder, TagsToLanesMsg};
+use crate::transform::RoadWarnings;
+
+/// The modes of `AccessByType`
+#[derive(Clone, Copy)]
+enum Mode {
+    Foot,
+    Bicycle,
+    Taxi,
+    Bus,
+    Motor,
+}
+
+impl Mode {
+    fn access(self, lane: &mut LaneBuilder) -> &mut Infer<AccessAndDirection> {
+        match self {
+            Self::Foot => &mut lane.access.foot,
+            Self::Bicycle => &mut lane.access.bicycle,
+            Self::Taxi => &mut lane.access.taxi,
+            Self::Bus => &mut lane.access.bus,
+            Self::Motor => &mut lane.access.motor,
+        }
+    }
+}
+
+/// Access keys from least to most specific, and the modes they apply to.
+/// <https://wiki.openstreetmap.org/wiki/Key:access#Transport_mode_restrictions>
+const KEYS: [(TagKey, &[Mode]); 8] = [
+    (
+        TagKey::from_static("access"),
+        &[
+            Mode::Foot,
+            Mode::Bicycle,
+            Mode::Taxi,
+            Mode::Bus,
+            Mode::Motor,
+        ],
+    ),
+    (
+        TagKey::from_static("vehicle"),
+        &[Mode::Bicycle, Mode::Taxi, Mode::Bus, Mode::Motor],
+    ),
+    (
+        TagKey::from_static("motor_vehicle"),
+        &[Mode::Taxi, Mode::Bus, Mode::Motor],
+    ),
+    (TagKey::from_static("psv"), &[Mode::Taxi, Mode::Bus]),
+    (TagKey::from_static("bus"), &[Mode::Bus]),
+    (TagKey::from_static("taxi"), &[Mode::Taxi]),
+    (TagKey::from_static("bicycle"), &[Mode::Bicycle]),
+    (TagKey::from_static("foot"), &[Mode::Foot]),
+];
+
+/// The access of a single lane, `None` if not given
+fn parse_lane(lane: &str) -> Result<Option<Access>, <Access as FromStr>::Err> {
+    match lane {
+        "" => Ok(None),
+        lane => lane.parse().map(Some),
+    }
+}
+
+/// The access from a single tag, warning if the value is not known
+fn get_access(tags: &TagsRef<'_>, key: &TagKey, warnings: &mut RoadWarnings) -> Option<Access> {
+    let value = tags.get(key)?;
+    if let Ok(access) = value.parse() {
+        Some(access)
+    } else {
+        warnings.push(TagsToLanesMsg::unsupported_tag(key.clone(), value));
+        None
+    }
+}
+
+fn set_access(lane: &mut LaneBuilder, modes: &[Mode], access: &Access) {
+    for mode in modes {
+        // More specific keys override less specific keys
+        *mode.access(lane) = Infer::Direct(AccessAndDirection {
+            access: access.clone(),
+            direction: None,
+        });
+    }
+}
+
+/// Access of each motor and bus lane, from `KEY=*`, `KEY:forward=*`, `KEY:backward=*`
+/// and `KEY:lanes=*`, for the common transport mode keys
+pub(in crate::transform::tags_to_lanes) fn access(
+    tags: &TagsRef<'_>,
+    locale: &Locale,
+    road: &mut RoadBuilder,
+    warnings: &mut RoadWarnings,
+) -> Result<(), TagsToLanesMsg> {
+    for (key, modes) in &KEYS {
+        let whole = get_access(tags, key, warnings);
+        let forward = get_access(tags, &(key + "forward"), warnings).or_else(|| whole.clone());
+        let backward = get_access(tags, &(key + "backward"), warnings).or(whole);
+        if let Some(forward) = forward {
+            for lane in road.forward_ltr_mut(locale).filter(is_carriageway) {
+                set_access(lane, modes, &forward);
+            }
+        }
+        if let Some(backward) = backward {
+            for lane in road.backward_ltr_mut(locale).filter(is_carriageway) {
+                set_access(lane, modes, &backward);
+            }
+        }
+
+        // Bus lanes are designated from `bus:lanes=*` and `psv:lanes=*` by the bus mode
+        if key.as_str() == "bus" || key.as_str() == "psv" {
+            continue;
+        }
+        let key = key + "lanes";
+        match LaneDependent::from_tags_with(tags, &key, parse_lane) {
+            Ok(Some(lanes)) => {
+                set_lanes(&key, lanes, tags, locale, road, warnings, |lane, access| {
+                    if let Some(access) = access {
+                        set_access(lane, modes, &access);
+                    }
+                    Ok(())
+                })?;
+            },
+            Ok(None) => {},
+            Err(e) => warnings.push(e.into()),
+        }
+    }
+    Ok(())
+}
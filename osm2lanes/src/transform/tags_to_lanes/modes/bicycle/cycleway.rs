@@ -1,16 +1,12 @@
-use std::borrow::Borrow;
 use std::fmt::Display;
-use std::hash::Hash;
 
-use osm_tags::{TagKey, Tags};
+use osm_tags::{TagKey, Tags, TagsRef};
 
 use crate::locale::Locale;
-use crate::metric::Metre;
 use crate::road::Direction;
 use crate::transform::tags::CYCLEWAY;
 use crate::transform::tags_to_lanes::oneway::Oneway;
-use crate::transform::tags_to_lanes::road::Width;
-use crate::transform::tags_to_lanes::{Infer, TagsNumeric, TagsToLanesMsg};
+use crate::transform::tags_to_lanes::TagsToLanesMsg;
 use crate::transform::{RoadWarnings, WaySide};
 
 #[derive(Debug)]
@@ -31,10 +27,15 @@ impl From<VariantError> for TagsToLanesMsg {
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub(in crate::transform::tags_to_lanes) enum Variant {
     SharedMotor,
-    // SharedBus,
+    /// Sharing the motor vehicle lane, marked with sharrows
+    SharedLane,
+    /// Sharing the bus lane
+    ShareBusway,
     // OptionalLane,
     Lane,
     Track,
+    /// Mapped as a way of its own
+    Separate,
 }
 
 impl Display for Variant {
@@ -45,8 +46,11 @@ impl Display for Variant {
             "{}",
             match self {
                 Self::SharedMotor => todo!(),
+                Self::SharedLane => "shared_lane",
+                Self::ShareBusway => "share_busway",
                 Self::Lane => "lane",
                 Self::Track => "track",
+                Self::Separate => "separate",
             }
         )
     }
@@ -70,14 +74,12 @@ impl<T> OptionNo<T> {
 
 pub(in crate::transform::tags_to_lanes) struct Opposite;
 
-pub(in crate::transform::tags_to_lanes) fn get_variant<Q, O>(
-    tags: &Tags,
+pub(in crate::transform::tags_to_lanes) fn get_variant<Q>(
+    tags: &TagsRef<'_>,
     k: &Q,
 ) -> Result<OptionNo<(Variant, Option<Opposite>)>, VariantError>
 where
-    TagKey: Borrow<Q>,
-    Q: Ord + Hash + Eq + ?Sized + ToOwned<Owned = O>,
-    O: Into<TagKey>,
+    Q: AsRef<str> + ?Sized,
 {
     match tags.get(k) {
         Some("lane") => Ok(OptionNo::Some((Variant::Lane, None))),
@@ -85,20 +87,17 @@ where
         Some("opposite_lane") => Ok(OptionNo::Some((Variant::Lane, Some(Opposite)))),
         Some("opposite_track") => Ok(OptionNo::Some((Variant::Track, Some(Opposite)))),
         Some("opposite") => Ok(OptionNo::Some((Variant::SharedMotor, Some(Opposite)))),
+        Some("shared_lane") => Ok(OptionNo::Some((Variant::SharedLane, None))),
+        Some("share_busway") => Ok(OptionNo::Some((Variant::ShareBusway, None))),
+        Some("opposite_share_busway") => Ok(OptionNo::Some((Variant::ShareBusway, Some(Opposite)))),
+        Some("separate") => Ok(OptionNo::Some((Variant::Separate, None))),
         Some("no") => Ok(OptionNo::No),
-        Some(
-            v @ ("shared_lane"
-            | "share_busway"
-            | "opposite_share_busway"
-            | "shared"
-            | "shoulder"
-            | "separate"),
-        ) => Err(VariantError::UnimplementedVariant(
-            k.to_owned().into(),
+        Some(v @ ("shared" | "shoulder")) => Err(VariantError::UnimplementedVariant(
+            TagKey::from_ref(k.as_ref()),
             v.to_owned(),
         )),
         Some(v) => Err(VariantError::UnknownVariant(
-            k.to_owned().into(),
+            TagKey::from_ref(k.as_ref()),
             v.to_owned(),
         )),
         None => Ok(OptionNo::None),
@@ -114,7 +113,7 @@ where
 ///     `Err` if the variant is not known
 type VariantWithMetadata = Result<(OptionNo<(Variant, Option<Opposite>)>, TagKey), VariantError>;
 
-fn cycleway_variant(tags: &Tags, side: Option<WaySide>) -> VariantWithMetadata {
+fn cycleway_variant(tags: &TagsRef<'_>, side: Option<WaySide>) -> VariantWithMetadata {
     let key = if let Some(side) = side {
         CYCLEWAY + side.as_str()
     } else {
@@ -128,7 +127,6 @@ fn cycleway_variant(tags: &Tags, side: Option<WaySide>) -> VariantWithMetadata {
 pub(in crate::transform::tags_to_lanes) struct Way {
     pub(crate) variant: Variant,
     pub(crate) direction: Direction,
-    pub(crate) width: Option<Width>,
 }
 
 #[derive(Debug, PartialEq)]
@@ -148,7 +146,7 @@ pub(in crate::transform::tags_to_lanes) struct Scheme {
 
 impl Scheme {
     pub(in crate::transform::tags_to_lanes) fn from_tags(
-        tags: &Tags,
+        tags: &TagsRef<'_>,
         locale: &Locale,
         road_oneway: Oneway,
         warnings: &mut RoadWarnings,
@@ -260,20 +258,20 @@ impl Scheme {
     /// `None` if unknown
     #[allow(clippy::unnecessary_wraps, clippy::panic_in_result_fn)]
     pub(in crate::transform::tags_to_lanes) fn from_tags_cycleway(
-        tags: &Tags,
+        tags: &TagsRef<'_>,
         locale: &Locale,
         road_oneway: Oneway,
         warnings: &mut RoadWarnings,
     ) -> Result<Option<Self>, TagsToLanesMsg> {
         match cycleway_variant(tags, None) {
             Ok((OptionNo::Some((variant, opposite)), key)) => {
-                if road_oneway.into() {
+                // A separately mapped cycleway is no lane, so oneway roads can have one either side
+                if road_oneway.into() && variant != Variant::Separate {
                     if opposite.is_none() {
                         Ok(Some(Self {
                             location: Location::Forward(Way {
                                 variant,
                                 direction: Direction::Forward,
-                                width: None,
                             }),
                             keys: vec![key],
                         }))
@@ -298,28 +296,31 @@ impl Scheme {
                             location: Location::Backward(Way {
                                 variant,
                                 direction: Direction::Backward,
-                                width: None,
                             }),
                             keys: vec![key],
                         }))
                     }
                 } else {
                     if opposite.is_some() {
-                        return Err(TagsToLanesMsg::unsupported_tags(
-                            tags.subset(["oneway", "cycleway"]),
-                        ));
+                        let msg =
+                            TagsToLanesMsg::unsupported_tags(tags.subset(["oneway", "cycleway"]));
+                        // The lane or track is on both sides of a two-way road
+                        if variant == Variant::SharedMotor {
+                            return Err(msg);
+                        }
+                        let mut suggestion = tags.subset(["oneway"]).into_owned();
+                        suggestion.checked_insert(CYCLEWAY, variant.to_string())?;
+                        return Err(msg.with_suggestion(suggestion));
                     }
                     Ok(Some(Self {
                         location: Location::Both {
                             forward: Way {
                                 variant,
                                 direction: Direction::Forward,
-                                width: None,
                             },
                             backward: Way {
                                 variant,
                                 direction: Direction::Backward,
-                                width: None,
                             },
                         },
                         keys: vec![key],
@@ -343,7 +344,7 @@ impl Scheme {
     /// `None` if unknown
     #[allow(clippy::unnecessary_wraps, clippy::panic_in_result_fn)]
     pub(in crate::transform::tags_to_lanes) fn from_tags_cycleway_both(
-        tags: &Tags,
+        tags: &TagsRef<'_>,
         _locale: &Locale,
         _road_oneway: Oneway,
         warnings: &mut RoadWarnings,
@@ -351,21 +352,22 @@ impl Scheme {
         match cycleway_variant(tags, Some(WaySide::Both)) {
             Ok((OptionNo::Some((variant, opposite)), key)) => {
                 if let Some(Opposite) = opposite {
-                    warnings.push(TagsToLanesMsg::unsupported_tags(
-                        tags.subset(["cycleway:both"]),
-                    ));
+                    let msg = TagsToLanesMsg::unsupported_tags(tags.subset(["cycleway:both"]));
+                    warnings.push(if variant == Variant::SharedMotor {
+                        msg
+                    } else {
+                        msg.with_suggestion(Tags::from_pair(CYCLEWAY + "both", variant.to_string()))
+                    });
                 }
                 Some(Self {
                     location: Location::Both {
                         forward: Way {
                             variant,
                             direction: Direction::Forward,
-                            width: None,
                         },
                         backward: Way {
                             variant,
                             direction: Direction::Backward,
-                            width: None,
                         },
                     },
                     keys: vec![key],
@@ -388,19 +390,13 @@ impl Scheme {
     /// `None` if unknown
     #[allow(clippy::unnecessary_wraps, clippy::panic_in_result_fn)]
     pub(in crate::transform::tags_to_lanes) fn from_tags_cycleway_forward(
-        tags: &Tags,
+        tags: &TagsRef<'_>,
         locale: &Locale,
         _road_oneway: Oneway,
         warnings: &mut RoadWarnings,
     ) -> Result<Option<Self>, TagsToLanesMsg> {
         match cycleway_variant(tags, Some(locale.driving_side.into())) {
             Ok((OptionNo::Some((variant, _opposite)), key)) => {
-                let width = tags
-                    .get_parsed(&(CYCLEWAY + locale.driving_side.tag() + "width"), warnings)
-                    .map(|w| Width {
-                        target: Infer::Direct(Metre::new(w)),
-                        ..Default::default()
-                    });
                 if tags.is(&(CYCLEWAY + locale.driving_side.tag() + "oneway"), "no")
                     || tags.is("oneway:bicycle", "no")
                 {
@@ -408,7 +404,6 @@ impl Scheme {
                         location: Location::Forward(Way {
                             variant,
                             direction: Direction::Both,
-                            width,
                         }),
                         keys: vec![key],
                     }));
@@ -417,7 +412,6 @@ impl Scheme {
                     location: Location::Forward(Way {
                         variant,
                         direction: Direction::Forward,
-                        width,
                     }),
                     keys: vec![key],
                 }))
@@ -439,7 +433,7 @@ impl Scheme {
     /// `None` if unknown
     #[allow(clippy::unnecessary_wraps, clippy::panic_in_result_fn)]
     pub(in crate::transform::tags_to_lanes) fn from_tags_cycleway_backward(
-        tags: &Tags,
+        tags: &TagsRef<'_>,
         locale: &Locale,
         road_oneway: Oneway,
         warnings: &mut RoadWarnings,
@@ -447,17 +441,12 @@ impl Scheme {
         match cycleway_variant(tags, Some(locale.driving_side.opposite().into())) {
             Ok((OptionNo::Some((variant, _opposite)), root_key)) => {
                 let width_key = CYCLEWAY + locale.driving_side.opposite().tag() + "width";
-                let width = tags.get_parsed(&width_key, warnings).map(|w| Width {
-                    target: Infer::Direct(Metre::new(w)),
-                    ..Default::default()
-                });
                 let oneway_key = CYCLEWAY + locale.driving_side.opposite().tag() + "oneway";
                 Ok(Some(if tags.is(&oneway_key, "yes") {
                     Self {
                         location: Location::Backward(Way {
                             variant,
                             direction: Direction::Forward,
-                            width,
                         }),
                         keys: vec![root_key, width_key, oneway_key],
                     }
@@ -466,7 +455,6 @@ impl Scheme {
                         location: Location::Backward(Way {
                             variant,
                             direction: Direction::Backward,
-                            width,
                         }),
                         keys: vec![root_key, width_key, oneway_key],
                     }
@@ -475,7 +463,6 @@ impl Scheme {
                         location: Location::Backward(Way {
                             variant,
                             direction: Direction::Both,
-                            width,
                         }),
                         keys: vec![root_key, width_key, oneway_key],
                     }
@@ -485,7 +472,6 @@ impl Scheme {
                         location: Location::Backward(Way {
                             variant,
                             direction: Direction::Forward,
-                            width,
                         }),
                         keys: vec![root_key, width_key, oneway_key, Oneway::KEY],
                     }
@@ -495,7 +481,6 @@ impl Scheme {
                         location: Location::Backward(Way {
                             variant,
                             direction: Direction::Backward,
-                            width,
                         }),
                         keys: vec![root_key, width_key, oneway_key, Oneway::KEY],
                     }
@@ -543,12 +528,10 @@ mod tests {
                 forward: Way {
                     variant: Variant::Lane,
                     direction: Direction::Forward,
-                    width: None,
                 },
                 backward: Way {
                     variant: Variant::Lane,
                     direction: Direction::Backward,
-                    width: None,
                 }
             }
         )
@@ -570,7 +553,6 @@ mod tests {
             Location::Backward(Way {
                 variant: Variant::Track,
                 direction: Direction::Backward,
-                width: None,
             })
         );
     }
@@ -591,7 +573,6 @@ mod tests {
             Location::Forward(Way {
                 variant: Variant::Lane,
                 direction: Direction::Forward,
-                width: None,
             })
         );
     }
@@ -612,7 +593,6 @@ mod tests {
             Location::Backward(Way {
                 variant: Variant::Track,
                 direction: Direction::Backward,
-                width: None,
             })
         );
     }
@@ -633,7 +613,6 @@ mod tests {
             Location::Backward(Way {
                 variant: Variant::Track,
                 direction: Direction::Backward,
-                width: None,
             })
         );
     }
@@ -655,7 +634,6 @@ mod tests {
             Location::Backward(Way {
                 variant: Variant::Track,
                 direction: Direction::Backward,
-                width: None,
             })
         );
     }
@@ -677,7 +655,6 @@ mod tests {
             Location::Backward(Way {
                 variant: Variant::SharedMotor,
                 direction: Direction::Backward,
-                width: None,
             })
         );
     }
@@ -692,6 +669,18 @@ mod tests {
             &mut RoadWarnings::default(),
         );
         assert!(scheme.is_err());
+
+        // The lane is on both sides
+        let scheme = Scheme::from_tags(
+            &Tags::from_pairs([("cycleway", "opposite_lane"), ("oneway", "no")]).unwrap(),
+            &Locale::builder().build(),
+            Oneway::No,
+            &mut RoadWarnings::default(),
+        );
+        assert_eq!(
+            scheme.unwrap_err().suggestion().map(Tags::to_vec),
+            Some(vec!["cycleway=lane".to_owned(), "oneway=no".to_owned()])
+        );
     }
 
     #[test]
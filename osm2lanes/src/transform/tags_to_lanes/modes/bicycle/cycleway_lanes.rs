@@ -1,5 +1,5 @@
 use osm_tag_schemes::LaneDependentAccess;
-use osm_tags::{TagKey, Tags};
+use osm_tags::{TagKey, TagsRef};
 
 use crate::locale::Locale;
 use crate::transform::{RoadWarnings, TagsToLanesMsg};
@@ -8,7 +8,7 @@ pub(in crate::transform::tags_to_lanes) struct Scheme(Option<LaneDependentAccess
 
 impl Scheme {
     pub(crate) fn from_tags(
-        tags: &Tags,
+        tags: &TagsRef<'_>,
         _locale: &Locale,
         _warnings: &mut RoadWarnings,
     ) -> Result<Self, TagsToLanesMsg> {
@@ -1,8 +1,10 @@
-use osm_tag_schemes::Access;
-use osm_tags::Tags;
+use osm_tag_schemes::{Access, Separation};
+use osm_tags::TagsRef;
 
-use crate::locale::Locale;
-use crate::road::{AccessAndDirection, Designated, Direction};
+use crate::locale::{DrivingSide, Locale};
+use crate::metric::Metre;
+use crate::road::{AccessAndDirection, Designated, Direction, Elevation, SeparatelyMapped};
+use crate::transform::tags::CYCLEWAY;
 use crate::transform::tags_to_lanes::road::LaneType;
 use crate::transform::tags_to_lanes::{Infer, LaneBuilder, RoadBuilder, TagsToLanesMsg};
 use crate::transform::RoadWarnings;
@@ -12,40 +14,183 @@ pub(in crate::transform::tags_to_lanes) mod cycleway;
 mod cycleway_lanes;
 
 impl LaneBuilder {
-    fn cycle(way: cycleway::Way) -> Self {
+    fn cycle(way: cycleway::Way, elevation: Infer<Elevation>) -> Self {
         Self {
             r#type: Infer::Direct(LaneType::Travel),
             direction: Infer::Direct(way.direction),
             designated: Infer::Direct(Designated::Bicycle),
-            width: way.width.unwrap_or_default(),
+            elevation,
             cycleway_variant: Some(way.variant),
             ..Default::default()
         }
     }
 }
 
-pub(in crate::transform::tags_to_lanes) fn bicycle(
-    tags: &Tags,
+/// Elevation of a cycle track from `cycleway:SIDE:kerb=*`, otherwise the locale default.
+/// Cycle lanes are always on the carriageway, so are left unset.
+fn track_elevation(
+    way: &cycleway::Way,
+    side: DrivingSide,
+    tags: &TagsRef<'_>,
     locale: &Locale,
+    warnings: &mut RoadWarnings,
+) -> Infer<Elevation> {
+    if way.variant != cycleway::Variant::Track {
+        return Infer::None;
+    }
+    let default = match locale.cycle_track_elevation() {
+        Some(elevation) => Infer::Default(elevation),
+        None => Infer::None,
+    };
+    let kerb = [CYCLEWAY + side.tag() + "kerb", CYCLEWAY + "both" + "kerb"]
+        .into_iter()
+        .find_map(|key| tags.get(&key).map(|value| (key, value)));
+    match kerb {
+        Some((_, "raised" | "rolled")) => Infer::Direct(Elevation::Raised),
+        Some((_, "lowered" | "flush" | "no")) => Infer::Direct(Elevation::Carriageway),
+        Some((key, value)) => {
+            warnings.push(TagsToLanesMsg::unsupported_tag(key, value));
+            default
+        },
+        None => default,
+    }
+}
+
+/// Width of a buffer tagged `=yes`
+const DEFAULT_BUFFER: Metre = Metre::new(0.5);
+
+/// Separation of a cycle lane or track from the lane inside it,
+/// from `cycleway:SIDE:separation:INSIDE=*` and the width of `cycleway:SIDE:buffer:INSIDE=*`
+fn inside_separation(
+    side: DrivingSide,
+    tags: &TagsRef<'_>,
+    warnings: &mut RoadWarnings,
+) -> (Option<Separation>, Option<Metre>) {
+    // https://wiki.openstreetmap.org/wiki/Proposal:Separation
+    let inside = side.opposite().tag();
+    let key = CYCLEWAY + side.tag() + "separation" + inside.clone();
+    let separation = tags.get(&key).and_then(|value| {
+        let separation = value.parse().ok();
+        if separation.is_none() {
+            warnings.push(TagsToLanesMsg::unsupported_tag(key.clone(), value));
+        }
+        separation
+    });
+    let key = CYCLEWAY + side.tag() + "buffer" + inside;
+    let buffer = match tags.get(&key) {
+        None | Some("no") => None,
+        Some("yes") => Some(DEFAULT_BUFFER),
+        Some(value) => {
+            let buffer = value.parse().ok();
+            if buffer.is_none() {
+                warnings.push(TagsToLanesMsg::unsupported_tag(key, value));
+            }
+            buffer
+        },
+    };
+    (separation, buffer)
+}
+
+/// Bicycles share the outside lane of one direction, with sharrows or signage.
+fn share_lane(
     road: &mut RoadBuilder,
+    way: &cycleway::Way,
+    forward: bool,
+) -> Result<(), TagsToLanesMsg> {
+    let lane = if forward {
+        road.forward_outside_mut()
+    } else {
+        road.backward_outside_mut()
+    }
+    .ok_or_else(|| TagsToLanesMsg::unsupported_str("no lanes to share with bicycles"))?;
+    if way.variant == cycleway::Variant::ShareBusway
+        && lane.designated.some() != Some(Designated::Bus)
+    {
+        return Err(TagsToLanesMsg::unsupported_str(
+            "no bus lane to share with bicycles",
+        ));
+    }
+    lane.access.bicycle = Infer::Direct(AccessAndDirection {
+        access: Access::Designated,
+        direction: (lane.direction.some() != Some(way.direction)).then_some(way.direction),
+    });
+    Ok(())
+}
+
+/// Add a cycle lane or track to the road, share an existing lane,
+/// or record a cycleway mapped separately.
+fn push_way(
+    road: &mut RoadBuilder,
+    way: cycleway::Way,
+    forward: bool,
+    tags: &TagsRef<'_>,
+    locale: &Locale,
     warnings: &mut RoadWarnings,
 ) -> Result<(), TagsToLanesMsg> {
+    match way.variant {
+        cycleway::Variant::Lane | cycleway::Variant::Track => {
+            let side = if forward {
+                locale.driving_side
+            } else {
+                locale.driving_side.opposite()
+            };
+            let elevation = track_elevation(&way, side, tags, locale, warnings);
+            let (separation, buffer) = inside_separation(side, tags, warnings);
+            let lane = LaneBuilder {
+                separation,
+                buffer,
+                ..LaneBuilder::cycle(way, elevation)
+            };
+            if forward {
+                road.push_forward_outside(lane);
+            } else {
+                road.push_backward_outside(lane);
+            }
+        },
+        cycleway::Variant::SharedLane | cycleway::Variant::ShareBusway => {
+            share_lane(road, &way, forward)?;
+        },
+        cycleway::Variant::Separate => {
+            let side = if forward {
+                locale.driving_side
+            } else {
+                locale.driving_side.opposite()
+            };
+            road.separately_mapped.push(SeparatelyMapped {
+                designated: Designated::Bicycle,
+                side: side.into(),
+            });
+        },
+        cycleway::Variant::SharedMotor => {},
+    }
+    Ok(())
+}
+
+/// Add cycle lanes and tracks to the road.
+/// Returns whether there is cycling infrastructure, or `None` if it is not tagged.
+pub(in crate::transform::tags_to_lanes) fn bicycle(
+    tags: &TagsRef<'_>,
+    locale: &Locale,
+    road: &mut RoadBuilder,
+    warnings: &mut RoadWarnings,
+) -> Result<Option<bool>, TagsToLanesMsg> {
     let cycleway_scheme = cycleway::Scheme::from_tags(tags, locale, road.oneway, warnings)?;
     log::trace!("cycleway=* scheme: {cycleway_scheme:?}");
     let _cycleway_lanes_scheme = cycleway_lanes::Scheme::from_tags(tags, locale, warnings)?;
     log::trace!("cycleway:lanes=* scheme: {cycleway_scheme:?}");
+    let cycling_infrastructure = match cycleway_scheme.location {
+        // Keys are only recorded for a location of none if tagged `=no`
+        cycleway::Location::None if cycleway_scheme.keys.is_empty() => None,
+        cycleway::Location::None => Some(false),
+        _ => Some(true),
+    };
     match cycleway_scheme.location {
         cycleway::Location::None => {},
         cycleway::Location::Forward(way) => {
-            if let cycleway::Variant::Lane | cycleway::Variant::Track = way.variant {
-                road.push_forward_outside(LaneBuilder::cycle(way));
-            }
             // TODO: Do nothing if forward sharing the lane? What if we are on a bus-only road?
+            push_way(road, way, true, tags, locale, warnings)?;
         },
         cycleway::Location::Backward(way) => match way.variant {
-            cycleway::Variant::Lane | cycleway::Variant::Track => {
-                road.push_backward_outside(LaneBuilder::cycle(way));
-            },
             cycleway::Variant::SharedMotor => {
                 road.forward_outside_mut()
                     .ok_or_else(|| {
@@ -57,11 +202,12 @@ pub(in crate::transform::tags_to_lanes) fn bicycle(
                     direction: Some(Direction::Both),
                 });
             },
+            _ => push_way(road, way, false, tags, locale, warnings)?,
         },
         cycleway::Location::Both { forward, backward } => {
-            road.push_forward_outside(LaneBuilder::cycle(forward));
-            road.push_backward_outside(LaneBuilder::cycle(backward));
+            push_way(road, forward, true, tags, locale, warnings)?;
+            push_way(road, backward, false, tags, locale, warnings)?;
         },
     }
-    Ok(())
+    Ok(cycling_infrastructure)
 }
@@ -1,7 +1,4 @@
-use std::borrow::Borrow;
-use std::hash::Hash;
-
-use osm_tags::{TagKey, Tags};
+use osm_tags::{TagKey, Tags, TagsRef};
 
 use crate::locale::Locale;
 use crate::road::Direction;
@@ -45,18 +42,19 @@ enum Lane {
     Opposite,
 }
 
-fn get_bus_lane<Q, O>(tags: &Tags, key: &Q, warnings: &mut RoadWarnings) -> Lane
+fn get_bus_lane<Q>(tags: &TagsRef<'_>, key: &Q, warnings: &mut RoadWarnings) -> Lane
 where
-    TagKey: Borrow<Q>,
-    Q: Ord + Hash + Eq + ?Sized + ToOwned<Owned = O>,
-    O: Into<TagKey>,
+    Q: AsRef<str> + ?Sized,
 {
     match tags.get(key) {
         None => Lane::None,
         Some("lane") => Lane::Lane,
         Some("opposite_lane") => Lane::Opposite,
         Some(v) => {
-            warnings.push(TagsToLanesMsg::unsupported_tag(key.to_owned().into(), v));
+            warnings.push(TagsToLanesMsg::unsupported_tag(
+                TagKey::from_ref(key.as_ref()),
+                v,
+            ));
             Lane::None
         },
     }
@@ -65,7 +63,7 @@ where
 impl Scheme {
     #[allow(clippy::unnecessary_wraps)]
     pub(in crate::transform::tags_to_lanes) fn from_tags(
-        tags: &Tags,
+        tags: &TagsRef<'_>,
         road_oneway: Oneway,
         locale: &Locale,
         warnings: &mut RoadWarnings,
@@ -127,11 +125,15 @@ impl Scheme {
 
         if let Variant::Both = busway_both {
             if let Variant::Forward | Variant::Backward = busway_forward_backward {
-                warnings.push(TagsToLanesMsg::ambiguous_tags(tags.subset([
-                    &busway_both_key,
-                    &busway_forward_key,
-                    &busway_backward_key,
-                ])));
+                // `busway:both=*` is used
+                warnings.push(
+                    TagsToLanesMsg::ambiguous_tags(tags.subset([
+                        &busway_both_key,
+                        &busway_forward_key,
+                        &busway_backward_key,
+                    ]))
+                    .with_suggestion(Tags::from_pair(busway_both_key.clone(), "lane")),
+                );
             }
             if let Variant::Forward | Variant::Backward = busway_root {
                 warnings.push(TagsToLanesMsg::ambiguous_tags(tags.subset(&[
@@ -1,10 +1,10 @@
 use osm_tag_schemes::{LaneAccess, LaneDependentAccess};
-use osm_tags::{TagKey, Tags};
+use osm_tags::{TagKey, TagsRef};
 
 use crate::locale::Locale;
 use crate::road::Designated;
 use crate::transform::tags_to_lanes::{
-    Infer, LaneBuilder, LaneBuilderError, RoadBuilder, TagsNumeric, TagsToLanesMsg,
+    Infer, LaneBuilder, LaneBuilderError, Oneway, RoadBuilder, TagsNumeric, TagsToLanesMsg,
 };
 use crate::transform::RoadWarnings;
 
@@ -13,6 +13,7 @@ use busway::apply_busway;
 pub(in crate::transform::tags_to_lanes) use busway::Scheme as BuswayScheme;
 
 const LANES: TagKey = TagKey::from_static("lanes");
+const ONEWAY: TagKey = TagKey::from_static("oneway");
 
 impl LaneBuilder {
     #[allow(clippy::unnecessary_wraps)]
@@ -34,19 +35,52 @@ pub(in crate::transform::tags_to_lanes) struct BusLaneCount {
     pub(crate) backward: usize,
 }
 
+/// Bus lanes per direction from `lanes:bus=*` or `lanes:psv=*`, `None` if untagged.
+/// Without a direction, all bus lanes are forward on a oneway, or split evenly otherwise.
+#[allow(clippy::integer_arithmetic, clippy::integer_division)]
+fn lanes_bus_count(
+    tags: &TagsRef<'_>,
+    oneway: Oneway,
+    warnings: &mut RoadWarnings,
+) -> (Option<usize>, Option<usize>) {
+    let mut count = |suffix: Option<&str>| {
+        let key = |mode: &str| match suffix {
+            Some(suffix) => LANES + mode + suffix,
+            None => LANES + mode,
+        };
+        let bus: Option<usize> = tags.get_parsed(&key("bus"), warnings);
+        let psv: Option<usize> = tags.get_parsed(&key("psv"), warnings);
+        bus.or(psv)
+    };
+    let (forward, backward) = (count(Some("forward")), count(Some("backward")));
+    let (total_forward, total_backward) = match (count(None), oneway) {
+        (Some(total), Oneway::Yes) => (Some(total), None),
+        (Some(total), Oneway::No) => {
+            if total % 2 != 0 {
+                warnings.push(TagsToLanesMsg::ambiguous_tags(tags.subset(&[
+                    LANES + "bus",
+                    LANES + "psv",
+                    ONEWAY,
+                ])));
+            }
+            (Some(total - total / 2), Some(total / 2))
+        },
+        (None, _) => (None, None),
+    };
+    (forward.or(total_forward), backward.or(total_backward))
+}
+
 impl BusLaneCount {
     pub(crate) fn from_tags(
         busway: &BuswayScheme,
-        tags: &Tags,
+        tags: &TagsRef<'_>,
+        oneway: Oneway,
         _locale: &Locale,
         warnings: &mut RoadWarnings,
     ) -> Self {
-        let forward = tags
-            .get_parsed("lanes:bus:forward", warnings)
-            .unwrap_or_else(|| if busway.forward() { 1 } else { 0 });
-        let backward = tags
-            .get_parsed("lanes:bus:backward", warnings)
-            .unwrap_or_else(|| if busway.backward() { 1 } else { 0 });
+        let (forward, backward) = lanes_bus_count(tags, oneway, warnings);
+        let forward = forward.unwrap_or_else(|| usize::from(busway.forward()));
+        let backward = backward.unwrap_or_else(|| usize::from(busway.backward()));
         Self { forward, backward }
     }
 }
@@ -54,7 +88,7 @@ impl BusLaneCount {
 #[allow(clippy::unnecessary_wraps)]
 pub(in crate::transform::tags_to_lanes) fn bus(
     busway: &BuswayScheme,
-    tags: &Tags,
+    tags: &TagsRef<'_>,
     locale: &Locale,
     road: &mut RoadBuilder,
     warnings: &mut RoadWarnings,
@@ -82,30 +116,54 @@ pub(in crate::transform::tags_to_lanes) fn bus(
     Ok(())
 }
 
-#[allow(clippy::unnecessary_wraps)]
+/// Designate the outermost lanes in each direction as bus lanes,
+/// they are already included in the lane counts, see `BusLaneCount`
 fn lanes_bus(
-    tags: &Tags,
-    _locale: &Locale,
-    _road: &mut RoadBuilder,
+    tags: &TagsRef<'_>,
+    locale: &Locale,
+    road: &mut RoadBuilder,
     warnings: &mut RoadWarnings,
 ) -> Result<(), TagsToLanesMsg> {
-    warnings.push(TagsToLanesMsg::unimplemented_tags(tags.subset(&[
-        LANES + "psv",
-        LANES + "psv" + "forward",
-        LANES + "psv" + "backward",
+    let sided = tags.subset(&[
         LANES + "psv" + "left",
         LANES + "psv" + "right",
-        LANES + "bus",
-        LANES + "bus" + "forward",
-        LANES + "bus" + "backward",
         LANES + "bus" + "left",
         LANES + "bus" + "right",
-    ])));
+    ]);
+    if !sided.is_empty() {
+        warnings.push(TagsToLanesMsg::unimplemented_tags(sided));
+    }
+
+    // Warnings were already raised when counting the lanes
+    let (forward, backward) = lanes_bus_count(tags, road.oneway, &mut RoadWarnings::default());
+    let (forward, backward) = (forward.unwrap_or(0), backward.unwrap_or(0));
+    if forward > road.forward_len() || backward > road.backward_len() {
+        return Err(TagsToLanesMsg::unsupported(
+            "lane count mismatch",
+            tags.subset(&[
+                LANES,
+                LANES + "forward",
+                LANES + "backward",
+                LANES + "bus",
+                LANES + "bus" + "forward",
+                LANES + "bus" + "backward",
+                LANES + "psv",
+                LANES + "psv" + "forward",
+                LANES + "psv" + "backward",
+            ]),
+        ));
+    }
+    for lane in road.forward_outside_in_mut().take(forward) {
+        lane.set_bus(locale)?;
+    }
+    for lane in road.backward_outside_in_mut().take(backward) {
+        lane.set_bus(locale)?;
+    }
     Ok(())
 }
 
 fn bus_lanes(
-    tags: &Tags,
+    tags: &TagsRef<'_>,
     locale: &Locale,
     road: &mut RoadBuilder,
     _warnings: &mut RoadWarnings,
@@ -0,0 +1,35 @@
+use osm_tag_schemes::{Change, LaneDependent};
+use osm_tags::{TagKey, TagsRef};
+
+use super::set_lanes;
+use crate::locale::Locale;
+use crate::transform::tags_to_lanes::{Infer, RoadBuilder, TagsToLanesMsg};
+use crate::transform::RoadWarnings;
+
+const CHANGE_LANES: TagKey = TagKey::from_static("change:lanes");
+
+/// Whether vehicles may change out of each lane, from `change:lanes=*`
+pub(in crate::transform::tags_to_lanes) fn change(
+    tags: &TagsRef<'_>,
+    locale: &Locale,
+    road: &mut RoadBuilder,
+    warnings: &mut RoadWarnings,
+) -> Result<(), TagsToLanesMsg> {
+    // https://wiki.openstreetmap.org/wiki/Key:change
+    match LaneDependent::<Change>::from_tags(tags, &CHANGE_LANES) {
+        Ok(Some(change_lanes)) => set_lanes(
+            &CHANGE_LANES,
+            change_lanes,
+            tags,
+            locale,
+            road,
+            warnings,
+            |lane, change| Ok(lane.change.set(Infer::Direct(change))?),
+        ),
+        Ok(None) => Ok(()),
+        Err(e) => {
+            warnings.push(e.into());
+            Ok(())
+        },
+    }
+}
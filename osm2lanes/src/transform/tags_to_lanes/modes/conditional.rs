@@ -0,0 +1,147 @@
+use osm_tag_schemes::{Access, Conditional};
+use osm_tags::{TagKey, TagsRef};
+
+use super::is_carriageway;
+use crate::locale::Locale;
+use crate::metric::Speed;
+use crate::road::Conditions;
+use crate::transform::tags_to_lanes::{Infer, LaneBuilder, Oneway, RoadBuilder, TagsToLanesMsg};
+use crate::transform::RoadWarnings;
+
+const MAXSPEED_CONDITIONAL: TagKey = TagKey::from_static("maxspeed:conditional");
+const ACCESS_CONDITIONAL: TagKey = TagKey::from_static("access:conditional");
+const BUSWAY: TagKey = TagKey::from_static("busway");
+
+/// The bus access of a `busway=*` value
+fn parse_busway(value: &str) -> Result<Access, ()> {
+    match value {
+        "lane" | "opposite_lane" => Ok(Access::Designated),
+        "no" => Ok(Access::No),
+        _ => Err(()),
+    }
+}
+
+/// Parse a `*:conditional` tag, warning if it is invalid
+fn from_tags<T, E>(
+    tags: &TagsRef<'_>,
+    key: &TagKey,
+    parse: impl Fn(&str) -> Result<T, E>,
+    warnings: &mut RoadWarnings,
+) -> Option<Vec<Conditional<T>>> {
+    let value = tags.get(key)?;
+    if let Ok(conditionals) = Conditional::split(value, parse) {
+        Some(conditionals)
+    } else {
+        warnings.push(TagsToLanesMsg::unsupported_tag(key.clone(), value));
+        None
+    }
+}
+
+fn update(lane: &mut LaneBuilder, f: impl FnOnce(&mut Conditions)) {
+    let mut conditions = lane.conditions.clone().some().unwrap_or_default();
+    f(&mut conditions);
+    lane.conditions = Infer::Direct(conditions);
+}
+
+/// Conditional restrictions of the carriageway, from `maxspeed:conditional=*`,
+/// `access:conditional=*` and `busway:*:conditional=*`
+#[allow(clippy::unnecessary_wraps)]
+pub(in crate::transform::tags_to_lanes) fn conditional(
+    tags: &TagsRef<'_>,
+    locale: &Locale,
+    road: &mut RoadBuilder,
+    warnings: &mut RoadWarnings,
+) -> Result<(), TagsToLanesMsg> {
+    // https://wiki.openstreetmap.org/wiki/Conditional_restrictions
+    if let Some(max_speed) = from_tags(
+        tags,
+        &MAXSPEED_CONDITIONAL,
+        |speed| Speed::parse_in(speed, locale.speed_unit()),
+        warnings,
+    ) {
+        for lane in road.lanes_ltr_mut(locale).filter(is_carriageway) {
+            update(lane, |conditions| {
+                conditions.max_speed.clone_from(&max_speed);
+            });
+        }
+    }
+    if let Some(access) = from_tags(tags, &ACCESS_CONDITIONAL, str::parse::<Access>, warnings) {
+        for lane in road.lanes_ltr_mut(locale).filter(is_carriageway) {
+            update(lane, |conditions| conditions.access.clone_from(&access));
+        }
+    }
+
+    // The bus lane times apply to the outermost carriageway lane of each side
+    let forward_key = BUSWAY + locale.driving_side.tag() + "conditional";
+    let backward_key = BUSWAY + locale.driving_side.opposite().tag() + "conditional";
+    let both_key = BUSWAY + "both" + "conditional";
+    let root_key = BUSWAY + "conditional";
+    let root = from_tags(tags, &root_key, parse_busway, warnings);
+    let both = from_tags(tags, &both_key, parse_busway, warnings);
+    let forward = from_tags(tags, &forward_key, parse_busway, warnings)
+        .or_else(|| both.clone())
+        .or_else(|| root.clone());
+    let backward = from_tags(tags, &backward_key, parse_busway, warnings)
+        .or(both)
+        .or_else(|| root.filter(|_| road.oneway != Oneway::Yes));
+    if let Some(bus) = forward {
+        match road.forward_outside_in_mut().find(is_carriageway) {
+            Some(lane) => update(lane, |conditions| conditions.bus = bus),
+            None => warnings.push(TagsToLanesMsg::unsupported(
+                "no forward lanes for busway",
+                tags.subset([&forward_key, &both_key, &root_key]),
+            )),
+        }
+    }
+    if let Some(bus) = backward {
+        let lane = if road
+            .backward_outside_in_mut()
+            .any(|lane| is_carriageway(&lane))
+        {
+            road.backward_outside_in_mut().find(is_carriageway)
+        } else {
+            // The other side of a oneway road
+            road.forward_outside_in_mut().filter(is_carriageway).last()
+        };
+        match lane {
+            Some(lane) => update(lane, |conditions| conditions.bus = bus),
+            None => warnings.push(TagsToLanesMsg::unsupported(
+                "no backward lanes for busway",
+                tags.subset([&backward_key, &both_key, &root_key]),
+            )),
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use osm_tag_schemes::{Access, Conditional};
+
+    use super::parse_busway;
+
+    #[test]
+    fn test_split() {
+        let conditionals = Conditional::split(
+            "no @ (Mo-Fr 07:00-09:00; Sa 10:00-12:00); destination @ snow",
+            str::parse::<Access>,
+        )
+        .unwrap();
+        assert_eq!(
+            conditionals,
+            vec![
+                Conditional {
+                    value: Access::No,
+                    condition: "Mo-Fr 07:00-09:00; Sa 10:00-12:00".to_owned(),
+                },
+                Conditional {
+                    value: Access::Destination,
+                    condition: "snow".to_owned(),
+                },
+            ]
+        );
+        assert!(Conditional::split("no", str::parse::<Access>).is_err());
+        assert!(Conditional::split("lane @ (Mo-Fr)", parse_busway).is_ok());
+        assert!(Conditional::split("share_busway @ (Mo-Fr)", parse_busway).is_err());
+    }
+}
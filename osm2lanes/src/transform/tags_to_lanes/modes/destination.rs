@@ -0,0 +1,102 @@
+use std::convert::Infallible;
+
+use osm_tag_schemes::LaneDependent;
+use osm_tags::{TagKey, TagsRef};
+
+use super::set_lanes;
+use crate::locale::Locale;
+use crate::road::Destinations;
+use crate::transform::tags_to_lanes::{Infer, RoadBuilder, TagsToLanesMsg};
+use crate::transform::RoadWarnings;
+
+const DESTINATION_LANES: TagKey = TagKey::from_static("destination:lanes");
+const DESTINATION_REF_LANES: TagKey = TagKey::from_static("destination:ref:lanes");
+const DESTINATION_SYMBOL_LANES: TagKey = TagKey::from_static("destination:symbol:lanes");
+
+/// The semicolon separated destinations of a single lane, empty if unsigned
+#[allow(clippy::unnecessary_wraps)]
+fn parse_destinations(lane: &str) -> Result<Vec<String>, Infallible> {
+    Ok(match lane {
+        "" | "none" => Vec::new(),
+        lane => lane.split(';').map(|d| d.trim().to_owned()).collect(),
+    })
+}
+
+/// Destination signs of each lane, from `destination:lanes=*`,
+/// `destination:ref:lanes=*` and `destination:symbol:lanes=*`
+pub(in crate::transform::tags_to_lanes) fn destination(
+    tags: &TagsRef<'_>,
+    locale: &Locale,
+    road: &mut RoadBuilder,
+    warnings: &mut RoadWarnings,
+) -> Result<(), TagsToLanesMsg> {
+    // https://wiki.openstreetmap.org/wiki/Key:destination#Lane-specific_destinations
+    set_destinations(
+        &DESTINATION_LANES,
+        tags,
+        locale,
+        road,
+        warnings,
+        |d, names| {
+            d.names = names;
+        },
+    )?;
+    set_destinations(
+        &DESTINATION_REF_LANES,
+        tags,
+        locale,
+        road,
+        warnings,
+        |d, refs| {
+            d.refs = refs;
+        },
+    )?;
+    set_destinations(
+        &DESTINATION_SYMBOL_LANES,
+        tags,
+        locale,
+        road,
+        warnings,
+        |d, symbols| d.symbols = symbols,
+    )
+}
+
+fn set_destinations(
+    key: &TagKey,
+    tags: &TagsRef<'_>,
+    locale: &Locale,
+    road: &mut RoadBuilder,
+    warnings: &mut RoadWarnings,
+    set_field: fn(&mut Destinations, Vec<String>),
+) -> Result<(), TagsToLanesMsg> {
+    match LaneDependent::from_tags_with(tags, key, parse_destinations) {
+        Ok(Some(lanes)) => set_lanes(key, lanes, tags, locale, road, warnings, |lane, values| {
+            if !values.is_empty() {
+                let mut destinations = lane.destinations.clone().some().unwrap_or_default();
+                set_field(&mut destinations, values);
+                lane.destinations = Infer::Direct(destinations);
+            }
+            Ok(())
+        }),
+        Ok(None) => Ok(()),
+        Err(e) => {
+            warnings.push(e.into());
+            Ok(())
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse_destinations;
+
+    #[test]
+    fn test_parse_destinations() {
+        assert!(parse_destinations("").unwrap().is_empty());
+        assert!(parse_destinations("none").unwrap().is_empty());
+        assert_eq!(
+            parse_destinations("Paris; Lyon").unwrap(),
+            vec!["Paris".to_owned(), "Lyon".to_owned()]
+        );
+    }
+}
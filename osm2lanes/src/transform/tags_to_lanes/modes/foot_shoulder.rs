@@ -1,34 +1,36 @@
-use celes::Country;
-use osm_tags::Tags;
+use osm_tag_schemes::Kerb;
+use osm_tags::{TagKey, Tags, TagsRef};
 
-use crate::locale::Locale;
+use crate::locale::{DrivingSide, Locale};
 use crate::metric::Metre;
-use crate::road::Designated;
-use crate::transform::tags::{SHOULDER, SIDEWALK};
-use crate::transform::tags_to_lanes::road::Width;
+use crate::road::{Designated, Direction, Elevation, SeparatelyMapped};
+use crate::transform::tags::{SHOULDER, SIDEWALK, VERGE};
+use crate::transform::tags_to_lanes::separator::edge_parking_restriction;
 use crate::transform::tags_to_lanes::{Infer, LaneBuilder, LaneType, RoadBuilder, TagsToLanesMsg};
 use crate::transform::{RoadError, RoadWarnings};
 
 impl LaneBuilder {
-    fn shoulder(locale: &Locale) -> Self {
-        let mut shoulder = Self {
+    /// Shoulder, with the direction of the traffic it is adjacent to
+    fn shoulder(direction: Direction) -> Self {
+        Self {
             r#type: Infer::Direct(LaneType::Shoulder),
+            direction: Infer::Calculated(direction),
             ..Default::default()
-        };
-        if let Some(c) = &locale.country {
-            if c == &Country::the_netherlands() {
-                shoulder.width = Width {
-                    target: Infer::Default(Metre::new(0.6)),
-                    ..Default::default()
-                }
-            }
         }
-        shoulder
     }
-    fn foot(_locale: &Locale) -> Self {
+    fn foot(side: DrivingSide, tags: &TagsRef<'_>, warnings: &mut RoadWarnings) -> Self {
+        let (kerb, kerb_height) = sidewalk_kerb(side, tags, warnings);
         Self {
             r#type: Infer::Direct(LaneType::Travel),
             designated: Infer::Direct(Designated::Foot),
+            kerb,
+            kerb_height,
+            ..Default::default()
+        }
+    }
+    fn verge() -> Self {
+        Self {
+            r#type: Infer::Direct(LaneType::Verge),
             ..Default::default()
         }
     }
@@ -37,12 +39,57 @@ impl LaneBuilder {
     }
 }
 
+const KERB: TagKey = TagKey::from_static("kerb");
+
+/// The first of `sidewalk:SIDE:KEY=*`, `sidewalk:both:KEY=*` and `KEY=*` that is tagged
+fn sidewalk_tag<'tags>(
+    side: DrivingSide,
+    key: &TagKey,
+    tags: &'tags TagsRef<'_>,
+) -> Option<(TagKey, &'tags str)> {
+    [
+        SIDEWALK + side.tag() + key.clone(),
+        SIDEWALK + "both" + key.clone(),
+        key.clone(),
+    ]
+    .into_iter()
+    .find_map(|key| tags.get(&key).map(|value| (key, value)))
+}
+
+/// Kind and height of the kerb between a sidewalk and the carriageway,
+/// from `sidewalk:SIDE:kerb=*` and `sidewalk:SIDE:kerb:height=*`
+/// or their `sidewalk:both` and way-wide equivalents
+fn sidewalk_kerb(
+    side: DrivingSide,
+    tags: &TagsRef<'_>,
+    warnings: &mut RoadWarnings,
+) -> (Option<Kerb>, Option<Metre>) {
+    // https://wiki.openstreetmap.org/wiki/Key:kerb
+    let kerb = sidewalk_tag(side, &KERB, tags).and_then(|(key, value)| {
+        let kerb = value.parse().ok();
+        if kerb.is_none() {
+            warnings.push(TagsToLanesMsg::unsupported_tag(key, value));
+        }
+        kerb
+    });
+    let height = sidewalk_tag(side, &(KERB + "height"), tags).and_then(|(key, value)| {
+        let height = value.parse().ok();
+        if height.is_none() {
+            warnings.push(TagsToLanesMsg::unsupported_tag(key, value));
+        }
+        height
+    });
+    (kerb, height)
+}
+
 enum Sidewalk {
     /// No information. This variant isn't called `None`, because that could be confused with
     /// `sidewalk=none`.
     Unknown,
     No,
     Yes,
+    /// Part of the carriageway set aside for pedestrians, `sidewalk:SIDE=lane`
+    Lane,
     Separate,
 }
 
@@ -55,7 +102,7 @@ impl Sidewalk {
     /// We catch any tag combinations that violate the OSM spec
     #[allow(clippy::unnested_or_patterns)]
     fn from_tags(
-        tags: &Tags,
+        tags: &TagsRef<'_>,
         locale: &Locale,
         warnings: &mut RoadWarnings,
     ) -> Result<(Self, Self), TagsToLanesMsg> {
@@ -64,24 +111,32 @@ impl Sidewalk {
             SIDEWALK + locale.driving_side.tag(),
             SIDEWALK + locale.driving_side.opposite().tag(),
         ])));
+        // A sidewalk painted on the carriageway is otherwise a sidewalk
+        let get = |key: TagKey| match tags.get(&key) {
+            Some("lane") => Some("yes"),
+            value => value,
+        };
         let sidewalks = match (
             tags.get(&SIDEWALK),
-            tags.get(&(SIDEWALK + "both")),
+            get(SIDEWALK + "both"),
             (
-                tags.get(&(SIDEWALK + locale.driving_side.tag())),
-                tags.get(&(SIDEWALK + locale.driving_side.opposite().tag())),
+                get(SIDEWALK + locale.driving_side.tag()),
+                get(SIDEWALK + locale.driving_side.opposite().tag()),
             ),
         ) {
             (Some(v), None, (None, None)) => match v {
                 "none" => {
-                    warnings.push(TagsToLanesMsg::deprecated_tags(tags.subset(&[SIDEWALK])));
+                    warnings.push(TagsToLanesMsg::deprecated_tag(
+                        SIDEWALK, "none", SIDEWALK, "no",
+                    ));
                     (Sidewalk::No, Sidewalk::No)
                 },
                 "no" => (Sidewalk::No, Sidewalk::No),
                 "yes" => {
-                    warnings.push(TagsToLanesMsg::ambiguous_tags(
-                        tags.subset(&[SIDEWALK, SIDEWALK + "both"]),
-                    ));
+                    warnings.push(
+                        TagsToLanesMsg::ambiguous_tags(tags.subset(&[SIDEWALK, SIDEWALK + "both"]))
+                            .with_suggestion(Tags::from_pair(SIDEWALK, "both")),
+                    );
                     (Sidewalk::Yes, Sidewalk::Yes)
                 },
                 "both" => (Sidewalk::Yes, Sidewalk::Yes),
@@ -109,8 +164,11 @@ impl Sidewalk {
                 (Some("yes"), None | Some("no")) => (Sidewalk::Yes, Sidewalk::No),
                 (None | Some("no"), Some("yes")) => (Sidewalk::No, Sidewalk::Yes),
 
-                (Some("separate"), None) => (Sidewalk::Separate, Sidewalk::No),
-                (None, Some("separate")) => (Sidewalk::No, Sidewalk::Separate),
+                (Some("separate"), Some("separate")) => (Sidewalk::Separate, Sidewalk::Separate),
+                (Some("separate"), Some("yes")) => (Sidewalk::Separate, Sidewalk::Yes),
+                (Some("yes"), Some("separate")) => (Sidewalk::Yes, Sidewalk::Separate),
+                (Some("separate"), None | Some("no")) => (Sidewalk::Separate, Sidewalk::No),
+                (None | Some("no"), Some("separate")) => (Sidewalk::No, Sidewalk::Separate),
                 (Some(_), None) | (None, Some(_)) | (Some(_), Some(_)) => {
                     return err;
                 },
@@ -121,7 +179,19 @@ impl Sidewalk {
                 return err;
             },
         };
-        Ok(sidewalks)
+        let painted = |sidewalk, side: DrivingSide| match sidewalk {
+            Sidewalk::Yes
+                if tags.is(&(SIDEWALK + "both"), "lane")
+                    || tags.is(&(SIDEWALK + side.tag()), "lane") =>
+            {
+                Sidewalk::Lane
+            },
+            sidewalk => sidewalk,
+        };
+        Ok((
+            painted(sidewalks.0, locale.driving_side),
+            painted(sidewalks.1, locale.driving_side.opposite()),
+        ))
     }
 }
 
@@ -135,7 +205,7 @@ enum Shoulder {
 
 impl Shoulder {
     fn from_tags(
-        tags: &Tags,
+        tags: &TagsRef<'_>,
         locale: &Locale,
         _warnings: &mut RoadWarnings,
     ) -> Result<(Self, Self), TagsToLanesMsg> {
@@ -152,9 +222,24 @@ impl Shoulder {
     }
 }
 
+/// Whether there is a verge on the forward and backward sides, from `verge=*`
+fn verge(tags: &TagsRef<'_>, locale: &Locale, warnings: &mut RoadWarnings) -> (bool, bool) {
+    // https://wiki.openstreetmap.org/wiki/Key:verge
+    match tags.get(&VERGE) {
+        None | Some("no") => (false, false),
+        Some("both") => (true, true),
+        Some(s) if s == locale.driving_side.tag().as_str() => (true, false),
+        Some(s) if s == locale.driving_side.opposite().tag().as_str() => (false, true),
+        Some(s) => {
+            warnings.push(TagsToLanesMsg::unsupported_tag(VERGE, s));
+            (false, false)
+        },
+    }
+}
+
 #[allow(clippy::items_after_statements, clippy::unnested_or_patterns)]
 pub(in crate::transform::tags_to_lanes) fn foot_and_shoulder(
-    tags: &Tags,
+    tags: &TagsRef<'_>,
     locale: &Locale,
     road: &mut RoadBuilder,
     warnings: &mut RoadWarnings,
@@ -165,6 +250,8 @@ pub(in crate::transform::tags_to_lanes) fn foot_and_shoulder(
     // https://wiki.openstreetmap.org/wiki/Key:shoulder
     let shoulder: (Shoulder, Shoulder) = Shoulder::from_tags(tags, locale, warnings)?;
 
+    let verge: (bool, bool) = verge(tags, locale, warnings);
+
     impl RoadBuilder {
         fn lane_outside(&self, forward: bool) -> Option<&LaneBuilder> {
             if forward {
@@ -173,6 +260,14 @@ pub(in crate::transform::tags_to_lanes) fn foot_and_shoulder(
                 self.backward_outside()
             }
         }
+        /// On oneway roads, both sides are adjacent to forward traffic
+        fn shoulder_direction(&self, forward: bool) -> Direction {
+            if forward || bool::from(self.oneway) {
+                Direction::Forward
+            } else {
+                Direction::Backward
+            }
+        }
         fn push_outside(&mut self, lane: LaneBuilder, forward: bool) {
             if forward {
                 self.push_forward_outside(lane);
@@ -181,13 +276,20 @@ pub(in crate::transform::tags_to_lanes) fn foot_and_shoulder(
             }
         }
         #[allow(clippy::match_same_arms)]
+        #[allow(clippy::too_many_arguments)]
         fn add_sidewalk_shoulder(
             &mut self,
             (sidewalk, shoulder): (Sidewalk, Shoulder),
+            verge: bool,
             forward: bool,
-            tags: &Tags,
+            tags: &TagsRef<'_>,
             locale: &Locale,
+            warnings: &mut RoadWarnings,
         ) -> Result<(), RoadError> {
+            // Between the carriageway and the sidewalk or shoulder
+            if verge {
+                self.push_outside(LaneBuilder::verge(), forward);
+            }
             match (sidewalk, shoulder) {
                 (Sidewalk::No | Sidewalk::Unknown, Shoulder::Unknown) => {
                     // We assume a shoulder if there is no bike lane.
@@ -195,21 +297,33 @@ pub(in crate::transform::tags_to_lanes) fn foot_and_shoulder(
                     let has_bicycle_lane = self
                         .lane_outside(forward)
                         .map_or(false, LaneBuilder::is_bicycle);
-                    if !has_bicycle_lane
+                    if !verge
+                        && !has_bicycle_lane
                         && locale.has_shoulder(self.highway.r#type())
                         && (forward || !bool::from(self.oneway))
-                        && !tags.is("parking:condition:both", "no_stopping")
+                        && edge_parking_restriction(tags, locale, forward).is_none()
                     {
-                        self.push_outside(LaneBuilder::shoulder(locale), forward);
+                        let direction = self.shoulder_direction(forward);
+                        self.push_outside(LaneBuilder::shoulder(direction), forward);
                     }
                 },
-                (Sidewalk::Yes, Shoulder::No | Shoulder::Unknown) => {
-                    self.push_outside(LaneBuilder::foot(locale), forward);
+                (sidewalk @ (Sidewalk::Yes | Sidewalk::Lane), Shoulder::No | Shoulder::Unknown) => {
+                    let side = if forward {
+                        locale.driving_side
+                    } else {
+                        locale.driving_side.opposite()
+                    };
+                    let mut lane = LaneBuilder::foot(side, tags, warnings);
+                    if let Sidewalk::Lane = sidewalk {
+                        lane.elevation = Infer::Direct(Elevation::Carriageway);
+                    }
+                    self.push_outside(lane, forward);
                 },
                 (Sidewalk::No | Sidewalk::Unknown, Shoulder::Yes) => {
-                    self.push_outside(LaneBuilder::shoulder(locale), forward);
+                    let direction = self.shoulder_direction(forward);
+                    self.push_outside(LaneBuilder::shoulder(direction), forward);
                 },
-                (Sidewalk::Yes, Shoulder::Yes) => {
+                (Sidewalk::Yes | Sidewalk::Lane, Shoulder::Yes) => {
                     return Err(TagsToLanesMsg::unsupported(
                         "shoulder and sidewalk on same side",
                         tags.subset(&[SIDEWALK, SHOULDER]),
@@ -217,15 +331,39 @@ pub(in crate::transform::tags_to_lanes) fn foot_and_shoulder(
                     .into());
                 },
                 (Sidewalk::No | Sidewalk::Unknown, Shoulder::No) => {},
-                // Treat the same as Sidewalk::No
-                (Sidewalk::Separate, _) => {},
+                // No lane, but the sidewalk is remembered
+                (Sidewalk::Separate, _) => {
+                    let side = if forward {
+                        locale.driving_side
+                    } else {
+                        locale.driving_side.opposite()
+                    };
+                    self.separately_mapped.push(SeparatelyMapped {
+                        designated: Designated::Foot,
+                        side: side.into(),
+                    });
+                },
             }
             Ok(())
         }
     }
 
-    road.add_sidewalk_shoulder((sidewalk.0, shoulder.0), true, tags, locale)?;
-    road.add_sidewalk_shoulder((sidewalk.1, shoulder.1), false, tags, locale)?;
+    road.add_sidewalk_shoulder(
+        (sidewalk.0, shoulder.0),
+        verge.0,
+        true,
+        tags,
+        locale,
+        warnings,
+    )?;
+    road.add_sidewalk_shoulder(
+        (sidewalk.1, shoulder.1),
+        verge.1,
+        false,
+        tags,
+        locale,
+        warnings,
+    )?;
 
     Ok(())
 }
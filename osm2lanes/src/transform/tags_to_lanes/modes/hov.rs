@@ -0,0 +1,135 @@
+use osm_tag_schemes::{Access, LaneDependent};
+use osm_tags::{TagKey, TagsRef};
+
+use super::{is_directional_carriageway, set_lanes};
+use crate::locale::Locale;
+use crate::road::Designated;
+use crate::transform::tags_to_lanes::road::LaneType;
+use crate::transform::tags_to_lanes::{
+    Infer, LaneBuilder, Oneway, RoadBuilder, TagsNumeric, TagsToLanesMsg,
+};
+use crate::transform::RoadWarnings;
+
+const HOV: TagKey = TagKey::from_static("hov");
+const HOV_LANES: TagKey = TagKey::from_static("hov:lanes");
+const LANES: TagKey = TagKey::from_static("lanes");
+const ONEWAY: TagKey = TagKey::from_static("oneway");
+
+impl LaneBuilder {
+    /// Designate a motor travel lane for high occupancy vehicles, leaving bus lanes as they are
+    fn set_hov(&mut self) {
+        if self.r#type.some() == Some(LaneType::Travel)
+            && self.designated.some() == Some(Designated::Motor)
+        {
+            self.designated = Infer::Direct(Designated::Hov);
+        }
+    }
+}
+
+/// Whether a single lane is designated for high occupancy vehicles
+fn parse_lane(lane: &str) -> Result<bool, <Access as std::str::FromStr>::Err> {
+    match lane {
+        "" => Ok(false),
+        lane => lane
+            .parse()
+            .map(|access: Access| access == Access::Designated),
+    }
+}
+
+/// High occupancy vehicle lanes per direction from `lanes:hov=*`, `None` if untagged.
+/// Without a direction, all are forward on a oneway, or split evenly otherwise.
+#[allow(clippy::integer_arithmetic, clippy::integer_division)]
+fn lanes_hov_count(
+    tags: &TagsRef<'_>,
+    oneway: Oneway,
+    warnings: &mut RoadWarnings,
+) -> (Option<usize>, Option<usize>) {
+    let forward: Option<usize> = tags.get_parsed(&(LANES + "hov" + "forward"), warnings);
+    let backward: Option<usize> = tags.get_parsed(&(LANES + "hov" + "backward"), warnings);
+    let total: Option<usize> = tags.get_parsed(&(LANES + "hov"), warnings);
+    let (total_forward, total_backward) = match (total, oneway) {
+        (Some(total), Oneway::Yes) => (Some(total), None),
+        (Some(total), Oneway::No) => {
+            if total % 2 != 0 {
+                warnings.push(TagsToLanesMsg::ambiguous_tags(
+                    tags.subset(&[LANES + "hov", ONEWAY]),
+                ));
+            }
+            (Some(total - total / 2), Some(total / 2))
+        },
+        (None, _) => (None, None),
+    };
+    (forward.or(total_forward), backward.or(total_backward))
+}
+
+/// Designate the inner-most lanes of one direction, from the outside in,
+/// returning whether there were enough lanes
+fn set_inside<'lane>(lanes: impl Iterator<Item = &'lane mut LaneBuilder>, count: usize) -> bool {
+    let mut lanes: Vec<&mut LaneBuilder> = lanes.filter(is_directional_carriageway).collect();
+    if count > lanes.len() {
+        return false;
+    }
+    for lane in lanes.iter_mut().rev().take(count) {
+        lane.set_hov();
+    }
+    true
+}
+
+/// High occupancy vehicle lanes, from `hov=designated` for the whole road,
+/// `hov:lanes=*` for each lane, or `lanes:hov=*` for the inner-most lanes of each direction,
+/// see <https://wiki.openstreetmap.org/wiki/Key:hov>
+pub(in crate::transform::tags_to_lanes) fn hov(
+    tags: &TagsRef<'_>,
+    locale: &Locale,
+    road: &mut RoadBuilder,
+    warnings: &mut RoadWarnings,
+) -> Result<(), TagsToLanesMsg> {
+    match tags.get(&HOV) {
+        Some("designated") => {
+            for lane in road.lanes_ltr_mut(locale) {
+                lane.set_hov();
+            }
+        },
+        Some(value) => warnings.push(TagsToLanesMsg::unsupported_tag(HOV, value)),
+        None => {},
+    }
+
+    let (forward, backward) = lanes_hov_count(tags, road.oneway, warnings);
+    if !set_inside(road.forward_outside_in_mut(), forward.unwrap_or(0))
+        || !set_inside(road.backward_outside_in_mut(), backward.unwrap_or(0))
+    {
+        warnings.push(TagsToLanesMsg::unsupported(
+            "lane count mismatch",
+            tags.subset(&[
+                LANES,
+                LANES + "forward",
+                LANES + "backward",
+                LANES + "hov",
+                LANES + "hov" + "forward",
+                LANES + "hov" + "backward",
+            ]),
+        ));
+    }
+
+    match LaneDependent::from_tags_with(tags, &HOV_LANES, parse_lane) {
+        Ok(Some(lanes)) => set_lanes(
+            &HOV_LANES,
+            lanes,
+            tags,
+            locale,
+            road,
+            warnings,
+            |lane, designated| {
+                if designated {
+                    lane.set_hov();
+                }
+                Ok(())
+            },
+        ),
+        Ok(None) => Ok(()),
+        Err(e) => {
+            warnings.push(e.into());
+            Ok(())
+        },
+    }
+}
@@ -0,0 +1,62 @@
+use osm_tag_schemes::{keys, Lit};
+use osm_tags::{TagKey, TagsRef};
+
+use crate::locale::{DrivingSide, Locale};
+use crate::road::Designated;
+use crate::transform::tags_to_lanes::road::LaneType;
+use crate::transform::tags_to_lanes::{Infer, LaneBuilder, RoadBuilder, TagsToLanesMsg};
+use crate::transform::RoadWarnings;
+
+const SIDEWALK: TagKey = TagKey::from_static("sidewalk");
+
+/// Lighting of the sidewalk on a side, from the most specific of
+/// `sidewalk:<side>:lit`, `sidewalk:both:lit`, `sidewalk:lit`, `lit:<side>` and `lit:both`.
+/// `None` if lighting is only tagged for the whole way, by `lit=*`.
+fn sidewalk_lit(tags: &TagsRef<'_>, side: DrivingSide, warnings: &mut RoadWarnings) -> Option<Lit> {
+    let side = side.tag();
+    [
+        SIDEWALK + side.clone() + keys::LIT,
+        SIDEWALK + "both" + keys::LIT,
+        SIDEWALK + keys::LIT,
+        keys::LIT + side,
+        keys::LIT + "both",
+    ]
+    .into_iter()
+    .find_map(|key| {
+        let val = tags.get(&key)?;
+        let lit = val.parse().ok();
+        if lit.is_none() {
+            warnings.push(TagsToLanesMsg::unsupported_tag(key, val));
+        }
+        lit
+    })
+}
+
+fn set_sidewalk_lit<'lane>(
+    lanes: impl Iterator<Item = &'lane mut LaneBuilder>,
+    lit: Option<Lit>,
+) -> Result<(), TagsToLanesMsg> {
+    for lane in lanes {
+        if lane.r#type.some() == Some(LaneType::Travel)
+            && lane.designated.some() == Some(Designated::Foot)
+        {
+            lane.lit.set(Infer::direct(lit))?;
+        }
+    }
+    Ok(())
+}
+
+/// Street lighting of the sidewalks, where tagged per side,
+/// see <https://wiki.openstreetmap.org/wiki/Key:lit>
+pub(in crate::transform::tags_to_lanes) fn lit(
+    tags: &TagsRef<'_>,
+    locale: &Locale,
+    road: &mut RoadBuilder,
+    warnings: &mut RoadWarnings,
+) -> Result<(), TagsToLanesMsg> {
+    let forward = sidewalk_lit(tags, locale.driving_side, warnings);
+    let backward = sidewalk_lit(tags, locale.driving_side.opposite(), warnings);
+    set_sidewalk_lit(road.forward_ltr_mut(locale), forward)?;
+    set_sidewalk_lit(road.backward_ltr_mut(locale), backward)?;
+    Ok(())
+}
@@ -1,7 +1,12 @@
 /// Modes of travel
-use osm_tag_schemes::LaneDependentAccessError;
+use osm_tag_schemes::{LaneDependent, LaneDependentAccessError};
+use osm_tags::{TagKey, TagsRef};
 
-use super::TagsToLanesMsg;
+use super::oneway::Oneway;
+use super::{LaneBuilder, LaneType, RoadBuilder, TagsToLanesMsg};
+use crate::locale::Locale;
+use crate::road::{Designated, Direction};
+use crate::transform::RoadWarnings;
 
 mod bicycle;
 pub(super) use bicycle::bicycle;
@@ -13,12 +18,42 @@ pub(super) use bus::{bus, BusLaneCount, BuswayScheme};
 mod foot_shoulder;
 pub(super) use foot_shoulder::foot_and_shoulder;
 
+mod hov;
+pub(super) use hov::hov;
+
 mod parking;
 
 pub(super) use parking::parking;
 
 mod non_motorized;
-pub(super) use non_motorized::non_motorized;
+pub(super) use non_motorized::{footway_role, non_motorized};
+
+mod surface;
+pub(super) use surface::surface;
+
+mod lit;
+pub(super) use lit::lit;
+
+mod turn;
+pub(super) use turn::turn;
+
+mod change;
+pub(super) use change::change;
+
+mod destination;
+pub(super) use destination::destination;
+
+mod conditional;
+pub(super) use conditional::conditional;
+
+mod restriction;
+pub(super) use restriction::restrictions;
+
+mod rail;
+pub(super) use rail::embedded_rail;
+
+mod access;
+pub(super) use access::access;
 
 impl From<LaneDependentAccessError<'_>> for TagsToLanesMsg {
     fn from(e: LaneDependentAccessError) -> Self {
@@ -33,3 +68,81 @@ impl From<LaneDependentAccessError<'_>> for TagsToLanesMsg {
         }
     }
 }
+
+/// Motor, bus and high occupancy vehicle lanes, those counted by `lanes=*`
+fn is_carriageway(lane: &&mut LaneBuilder) -> bool {
+    lane.r#type.some() == Some(LaneType::Travel)
+        && matches!(
+            lane.designated.some(),
+            Some(Designated::Motor | Designated::Bus | Designated::Hov)
+        )
+}
+
+/// Motor and bus lanes in one direction, those counted by `lanes:{forward,backward}=*`,
+/// excluding the centre turn lane of `*:lanes:both_ways=*`
+fn is_directional_carriageway(lane: &&mut LaneBuilder) -> bool {
+    is_carriageway(lane) && lane.direction.some() != Some(Direction::Both)
+}
+
+/// Set the value of each motor lane from a `*:lanes` scheme,
+/// warning instead if the number of values does not match the number of lanes
+pub(super) fn set_lanes<T>(
+    key: &TagKey,
+    lanes: LaneDependent<T>,
+    tags: &TagsRef<'_>,
+    locale: &Locale,
+    road: &mut RoadBuilder,
+    warnings: &mut RoadWarnings,
+    set: impl Fn(&mut LaneBuilder, T) -> Result<(), TagsToLanesMsg>,
+) -> Result<(), TagsToLanesMsg> {
+    let keys = [key.clone(), key + "forward", key + "backward"];
+    let mismatch = || TagsToLanesMsg::unsupported("lane count mismatch", tags.subset(&keys));
+    let forward_len = road
+        .forward_ltr_mut(locale)
+        .filter(is_directional_carriageway)
+        .count();
+    let backward_len = road
+        .backward_ltr_mut(locale)
+        .filter(is_directional_carriageway)
+        .count();
+    let (forward, backward) = match lanes {
+        LaneDependent::LeftToRight(lanes) => {
+            if road.oneway != Oneway::Yes {
+                warnings.push(TagsToLanesMsg::unsupported(
+                    "lanes without direction on a two-way road",
+                    tags.subset(&keys),
+                ));
+                return Ok(());
+            }
+            (Some(lanes), None)
+        },
+        LaneDependent::Forward(forward) => (Some(forward), None),
+        LaneDependent::Backward(backward) => (None, Some(backward)),
+        LaneDependent::ForwardBackward { forward, backward } => (Some(forward), Some(backward)),
+    };
+    if matches!(&forward, Some(lanes) if lanes.len() != forward_len)
+        || matches!(&backward, Some(lanes) if lanes.len() != backward_len)
+    {
+        warnings.push(mismatch());
+        return Ok(());
+    }
+    if let Some(forward) = forward {
+        for (lane, value) in road
+            .forward_ltr_mut(locale)
+            .filter(is_directional_carriageway)
+            .zip(forward)
+        {
+            set(lane, value)?;
+        }
+    }
+    if let Some(backward) = backward {
+        for (lane, value) in road
+            .backward_ltr_mut(locale)
+            .filter(is_directional_carriageway)
+            .zip(backward)
+        {
+            set(lane, value)?;
+        }
+    }
+    Ok(())
+}
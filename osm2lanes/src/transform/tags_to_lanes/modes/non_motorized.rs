@@ -1,21 +1,21 @@
 use osm_tag_schemes::keys::HIGHWAY;
-use osm_tag_schemes::Access;
-use osm_tags::Tags;
+use osm_tag_schemes::{Access, HighwayType};
+use osm_tags::{TagKey, TagsRef};
 
 use crate::locale::Locale;
-use crate::road::{AccessAndDirection, Designated, Direction};
+use crate::road::{AccessAndDirection, Designated, Direction, FootwayRole};
 use crate::transform::tags_to_lanes::{RoadBuilder, TagsToLanesMsg};
 use crate::transform::{Infer, RoadWarnings};
 
 #[allow(clippy::unnecessary_wraps, clippy::restriction)]
 pub(in crate::transform::tags_to_lanes) fn non_motorized(
-    tags: &Tags,
+    tags: &TagsRef<'_>,
     _locale: &Locale,
     road: &mut RoadBuilder,
-    warnings: &mut RoadWarnings,
+    _warnings: &mut RoadWarnings,
 ) -> Result<(), TagsToLanesMsg> {
     // Easy special cases first.
-    if let Some(v @ ("steps" | "path")) = tags.get(&HIGHWAY) {
+    if let Some("steps" | "path" | "footway") = tags.get(&HIGHWAY) {
         // TODO: how to avoid making this assumption?
         assert_eq!(road.len(), 1);
         let lane = road.forward_outside_mut().unwrap();
@@ -29,13 +29,30 @@ pub(in crate::transform::tags_to_lanes) fn non_motorized(
             access: Access::No,
             direction: None,
         }))?;
-        if v == "steps" {
-            warnings.push(TagsToLanesMsg::unimplemented(
-                "steps becomes sidewalk",
-                tags.subset(&[HIGHWAY]),
-            ));
-        }
     }
 
     Ok(())
 }
+
+const FOOTWAY: TagKey = TagKey::from_static("footway");
+
+/// What a `highway=footway` way is part of, from `footway=*`, `None` for other highways
+pub(in crate::transform::tags_to_lanes) fn footway_role(
+    tags: &TagsRef<'_>,
+    highway: HighwayType,
+    warnings: &mut RoadWarnings,
+) -> Option<FootwayRole> {
+    if highway != HighwayType::Footway {
+        return None;
+    }
+    // https://wiki.openstreetmap.org/wiki/Key:footway
+    match tags.get(&FOOTWAY) {
+        None => None,
+        Some("sidewalk") => Some(FootwayRole::Sidewalk),
+        Some("crossing") => Some(FootwayRole::Crossing),
+        Some(value) => {
+            warnings.push(TagsToLanesMsg::unsupported_tag(FOOTWAY, value));
+            None
+        },
+    }
+}
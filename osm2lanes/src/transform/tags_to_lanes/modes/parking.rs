@@ -1,45 +1,167 @@
-use osm_tags::Tags;
+use osm_tag_schemes::ParkingOrientation;
+use osm_tags::{TagKey, Tags, TagsRef};
 
 use crate::locale::Locale;
 use crate::road::{Designated, Direction};
-use crate::transform::tags_to_lanes::{Infer, LaneBuilder, LaneType, RoadBuilder};
-use crate::transform::RoadError;
+use crate::transform::tags_to_lanes::{Infer, LaneBuilder, LaneType, RoadBuilder, TagsToLanesMsg};
+use crate::transform::{RoadError, RoadWarnings};
+
+const PARKING_LANE: TagKey = TagKey::from_static("parking:lane");
+const PARKING: TagKey = TagKey::from_static("parking");
+const BUS_BAY: TagKey = TagKey::from_static("bus_bay");
 
 impl LaneBuilder {
-    fn parking_forward(_locale: &Locale) -> Self {
+    fn parking(direction: Direction, orientation: ParkingOrientation) -> Self {
         Self {
             r#type: Infer::Direct(LaneType::Parking),
-            direction: Infer::Direct(Direction::Forward),
+            direction: Infer::Direct(direction),
             designated: Infer::Direct(Designated::Motor),
+            parking_orientation: Some(orientation),
             ..Default::default()
         }
     }
-    fn parking_backward(_locale: &Locale) -> Self {
+
+    /// Bus bay, set back from the carriageway so stopping buses do not block it
+    fn bus_bay(direction: Direction) -> Self {
         Self {
             r#type: Infer::Direct(LaneType::Parking),
-            direction: Infer::Direct(Direction::Backward),
-            designated: Infer::Direct(Designated::Motor),
+            direction: Infer::Direct(direction),
+            designated: Infer::Direct(Designated::Bus),
             ..Default::default()
         }
     }
 }
 
+/// Parking on one side of the way, in one of the tagging schemes
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Tagged {
+    Untagged,
+    No,
+    Yes(ParkingOrientation),
+}
+
+impl Tagged {
+    fn orientation(self) -> Option<ParkingOrientation> {
+        match self {
+            Self::Untagged | Self::No => None,
+            Self::Yes(orientation) => Some(orientation),
+        }
+    }
+
+    /// The parking in the `parking:<side>=*` scheme
+    fn side_scheme_tags(self, side: &str) -> Tags {
+        match self {
+            Self::Untagged => Tags::default(),
+            Self::No => Tags::from_pair(PARKING + side, "no"),
+            Self::Yes(orientation) => Tags::from_pairs([
+                (PARKING + side, "lane".to_owned()),
+                (
+                    PARKING + side + "orientation",
+                    <&str>::from(orientation).to_owned(),
+                ),
+            ])
+            .unwrap_or_default(),
+        }
+    }
+}
+
+/// `parking:lane:<side>=<orientation>`
+fn legacy_scheme(tags: &TagsRef<'_>, side: &str) -> Tagged {
+    match tags
+        .get(&(PARKING_LANE + side))
+        .or_else(|| tags.get(&(PARKING_LANE + "both")))
+    {
+        None => Tagged::Untagged,
+        Some(value) => value
+            .parse::<ParkingOrientation>()
+            .map_or(Tagged::No, Tagged::Yes),
+    }
+}
+
+/// `parking:<side>=<position>` and `parking:<side>:orientation=<orientation>`,
+/// where the side overrides both
+fn side_scheme(tags: &TagsRef<'_>, side: &str) -> Tagged {
+    match tags
+        .get(&(PARKING + side))
+        .or_else(|| tags.get(&(PARKING + "both")))
+    {
+        None => Tagged::Untagged,
+        Some("lane" | "street_side" | "on_kerb" | "half_on_kerb" | "shoulder") => Tagged::Yes(
+            tags.get(&(PARKING + side + "orientation"))
+                .or_else(|| tags.get(&(PARKING + "both" + "orientation")))
+                .and_then(|orientation| orientation.parse::<ParkingOrientation>().ok())
+                .unwrap_or(ParkingOrientation::Parallel),
+        ),
+        Some(_) => Tagged::No,
+    }
+}
+
 #[allow(clippy::unnecessary_wraps)]
 pub(in crate::transform::tags_to_lanes) fn parking(
-    tags: &Tags,
+    tags: &TagsRef<'_>,
     locale: &Locale,
     road: &mut RoadBuilder,
+    warnings: &mut RoadWarnings,
 ) -> Result<(), RoadError> {
-    let has_parking = vec!["parallel", "diagonal", "perpendicular"];
-    let parking_lane_fwd = tags.is_any("parking:lane:right", &has_parking)
-        || tags.is_any("parking:lane:both", &has_parking);
-    let parking_lane_back = tags.is_any("parking:lane:left", &has_parking)
-        || tags.is_any("parking:lane:both", &has_parking);
-    if parking_lane_fwd {
-        road.push_forward_outside(LaneBuilder::parking_forward(locale));
+    // The legacy `parking:lane:<side>=*` scheme wins if both are tagged
+    let mut side_orientation =
+        |side: &str| match (legacy_scheme(tags, side), side_scheme(tags, side)) {
+            (Tagged::Untagged, tagged) | (tagged, Tagged::Untagged) => tagged.orientation(),
+            (legacy, new) => {
+                if legacy != new {
+                    warnings.push(
+                        TagsToLanesMsg::ambiguous_tags(tags.subset(&[
+                            PARKING_LANE + side,
+                            PARKING_LANE + "both",
+                            PARKING + side,
+                            PARKING + "both",
+                            PARKING + side + "orientation",
+                            PARKING + "both" + "orientation",
+                        ]))
+                        .with_suggestion(legacy.side_scheme_tags(side)),
+                    );
+                }
+                legacy.orientation()
+            },
+        };
+    if let Some(orientation) = side_orientation("right") {
+        road.push_forward_outside(LaneBuilder::parking(Direction::Forward, orientation));
     }
-    if parking_lane_back {
-        road.push_backward_outside(LaneBuilder::parking_backward(locale));
+    if let Some(orientation) = side_orientation("left") {
+        road.push_backward_outside(LaneBuilder::parking(Direction::Backward, orientation));
     }
+    bus_bay(tags, locale, road, warnings);
     Ok(())
 }
+
+/// Bus bays at stops, from `bus_bay=*`.
+/// On a oneway road, buses stop in the forward direction on either side.
+fn bus_bay(
+    tags: &TagsRef<'_>,
+    locale: &Locale,
+    road: &mut RoadBuilder,
+    warnings: &mut RoadWarnings,
+) {
+    // https://wiki.openstreetmap.org/wiki/Key:bus_bay
+    let (forward, backward) = match tags.get(&BUS_BAY) {
+        None | Some("no") => return,
+        Some("both") => (true, true),
+        Some(side) if side == locale.driving_side.tag().as_str() => (true, false),
+        Some(side) if side == locale.driving_side.opposite().tag().as_str() => (false, true),
+        Some(value) => {
+            warnings.push(TagsToLanesMsg::unsupported_tag(BUS_BAY, value));
+            return;
+        },
+    };
+    if forward {
+        road.push_forward_outside(LaneBuilder::bus_bay(Direction::Forward));
+    }
+    if backward {
+        let direction = if bool::from(road.oneway) {
+            Direction::Forward
+        } else {
+            Direction::Backward
+        };
+        road.push_backward_outside(LaneBuilder::bus_bay(direction));
+    }
+}
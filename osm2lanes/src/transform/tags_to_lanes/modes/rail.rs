@@ -0,0 +1,89 @@
+use osm_tag_schemes::LaneDependent;
+use osm_tags::{TagKey, TagsRef};
+
+use super::{is_directional_carriageway, set_lanes};
+use crate::locale::Locale;
+use crate::transform::tags_to_lanes::{Infer, RoadBuilder, TagsToLanesMsg};
+use crate::transform::RoadWarnings;
+
+const EMBEDDED_RAILS: TagKey = TagKey::from_static("embedded_rails");
+const EMBEDDED_RAILS_LANES: TagKey = TagKey::from_static("embedded_rails:lanes");
+const RAILWAY: TagKey = TagKey::from_static("railway");
+
+/// Whether a railway is embedded in a single lane
+fn parse_embedded_rail(lane: &str) -> Result<bool, ()> {
+    match lane {
+        "" | "no" => Ok(false),
+        "yes" | "tram" | "light_rail" => Ok(true),
+        _ => Err(()),
+    }
+}
+
+/// Whether the way carries rails embedded in the carriageway,
+/// from `embedded_rails=*` or a tram line mapped on the road itself with `railway=tram`
+fn embedded_rails(tags: &TagsRef<'_>, warnings: &mut RoadWarnings) -> bool {
+    let embedded = match tags.get(&EMBEDDED_RAILS) {
+        None => false,
+        Some(value) => parse_embedded_rail(value).unwrap_or_else(|()| {
+            warnings.push(TagsToLanesMsg::unsupported_tag(EMBEDDED_RAILS, value));
+            false
+        }),
+    };
+    let railway = match tags.get(&RAILWAY) {
+        None => false,
+        Some("tram" | "light_rail") => true,
+        Some(value) => {
+            warnings.push(TagsToLanesMsg::unsupported_tag(RAILWAY, value));
+            false
+        },
+    };
+    embedded || railway
+}
+
+/// Rails embedded in the carriageway and shared with trams,
+/// from `embedded_rails:lanes=*`, otherwise in the inner-most lane of each direction
+/// from `embedded_rails=*` or `railway=tram`
+pub(in crate::transform::tags_to_lanes) fn embedded_rail(
+    tags: &TagsRef<'_>,
+    locale: &Locale,
+    road: &mut RoadBuilder,
+    warnings: &mut RoadWarnings,
+) -> Result<(), TagsToLanesMsg> {
+    // https://wiki.openstreetmap.org/wiki/Key:embedded_rails
+    match LaneDependent::from_tags_with(tags, &EMBEDDED_RAILS_LANES, parse_embedded_rail) {
+        Ok(Some(lanes)) => {
+            return set_lanes(
+                &EMBEDDED_RAILS_LANES,
+                lanes,
+                tags,
+                locale,
+                road,
+                warnings,
+                |lane, embedded_rail| Ok(lane.embedded_rail.set(Infer::Direct(embedded_rail))?),
+            );
+        },
+        Ok(None) => {},
+        Err(e) => {
+            warnings.push(e.into());
+            return Ok(());
+        },
+    }
+    if embedded_rails(tags, warnings) {
+        // Which lanes the rails are in is not tagged, trams usually run nearest the centre
+        if let Some(lane) = road
+            .forward_outside_in_mut()
+            .filter(is_directional_carriageway)
+            .last()
+        {
+            lane.embedded_rail = Infer::Calculated(true);
+        }
+        if let Some(lane) = road
+            .backward_outside_in_mut()
+            .filter(is_directional_carriageway)
+            .last()
+        {
+            lane.embedded_rail = Infer::Calculated(true);
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,120 @@
+use std::str::FromStr;
+
+use osm_tag_schemes::LaneDependent;
+use osm_tags::{TagKey, TagsRef};
+
+use super::{is_carriageway, set_lanes};
+use crate::locale::Locale;
+use crate::metric::{Metre, Speed, SpeedError, SpeedUnit, Tonne};
+use crate::transform::tags_to_lanes::{Infer, RoadBuilder, TagsToLanesMsg};
+use crate::transform::RoadWarnings;
+
+const MAXSPEED: TagKey = TagKey::from_static("maxspeed");
+const MAXSPEED_LANES: TagKey = TagKey::from_static("maxspeed:lanes");
+const MAXHEIGHT_LANES: TagKey = TagKey::from_static("maxheight:lanes");
+const MAXWEIGHT_LANES: TagKey = TagKey::from_static("maxweight:lanes");
+
+/// A physical restriction of a single lane, `None` if unrestricted
+fn parse_restriction<T: FromStr>(lane: &str) -> Result<Option<T>, T::Err> {
+    match lane {
+        "" | "none" | "default" => Ok(None),
+        lane => lane.parse().map(Some),
+    }
+}
+
+/// The speed limit of a single lane, `None` if not given.
+/// Unlike other restrictions, `none` is a value, for roads without a speed limit.
+fn parse_speed(lane: &str, unit: SpeedUnit) -> Result<Option<Speed>, SpeedError> {
+    match lane {
+        "" => Ok(None),
+        lane => Speed::parse_in(lane, unit).map(Some),
+    }
+}
+
+/// Maximum speed, height and weight of each lane,
+/// from `maxspeed:forward=*`, `maxspeed:backward=*`, `maxspeed:lanes=*`,
+/// `maxheight:lanes=*` and `maxweight:lanes=*`
+pub(in crate::transform::tags_to_lanes) fn restrictions(
+    tags: &TagsRef<'_>,
+    locale: &Locale,
+    road: &mut RoadBuilder,
+    warnings: &mut RoadWarnings,
+) -> Result<(), TagsToLanesMsg> {
+    // https://wiki.openstreetmap.org/wiki/Key:maxspeed
+    // `maxspeed=*` is already set on every lane, the more specific keys override it
+    for (forward, key) in [(true, MAXSPEED + "forward"), (false, MAXSPEED + "backward")] {
+        let max_speed = match tags
+            .get(&key)
+            .map(|speed| Speed::parse_in(speed, locale.speed_unit()))
+            .transpose()
+        {
+            Ok(Some(max_speed)) => max_speed,
+            Ok(None) => continue,
+            Err(e) => {
+                warnings.push(TagsToLanesMsg::unsupported(
+                    e.to_string(),
+                    tags.subset(&[key]),
+                ));
+                continue;
+            },
+        };
+        if forward {
+            for lane in road.forward_ltr_mut(locale).filter(is_carriageway) {
+                lane.max_speed = Infer::Direct(max_speed);
+            }
+        } else {
+            for lane in road.backward_ltr_mut(locale).filter(is_carriageway) {
+                lane.max_speed = Infer::Direct(max_speed);
+            }
+        }
+    }
+    match LaneDependent::from_tags_with(tags, &MAXSPEED_LANES, |lane| {
+        parse_speed(lane, locale.speed_unit())
+    }) {
+        Ok(Some(lanes)) => set_lanes(
+            &MAXSPEED_LANES,
+            lanes,
+            tags,
+            locale,
+            road,
+            warnings,
+            |lane, max_speed| {
+                if let Some(max_speed) = max_speed {
+                    lane.max_speed = Infer::Direct(max_speed);
+                }
+                Ok(())
+            },
+        )?,
+        Ok(None) => {},
+        Err(e) => warnings.push(e.into()),
+    }
+    // https://wiki.openstreetmap.org/wiki/Key:maxheight
+    match LaneDependent::from_tags_with(tags, &MAXHEIGHT_LANES, parse_restriction::<Metre>) {
+        Ok(Some(lanes)) => set_lanes(
+            &MAXHEIGHT_LANES,
+            lanes,
+            tags,
+            locale,
+            road,
+            warnings,
+            |lane, max_height| Ok(lane.max_height.set(Infer::direct(max_height))?),
+        )?,
+        Ok(None) => {},
+        Err(e) => warnings.push(e.into()),
+    }
+    // https://wiki.openstreetmap.org/wiki/Key:maxweight
+    match LaneDependent::from_tags_with(tags, &MAXWEIGHT_LANES, parse_restriction::<Tonne>) {
+        Ok(Some(lanes)) => set_lanes(
+            &MAXWEIGHT_LANES,
+            lanes,
+            tags,
+            locale,
+            road,
+            warnings,
+            |lane, max_weight| Ok(lane.max_weight.set(Infer::direct(max_weight))?),
+        )?,
+        Ok(None) => {},
+        Err(e) => warnings.push(e.into()),
+    }
+    Ok(())
+}
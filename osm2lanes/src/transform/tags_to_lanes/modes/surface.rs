@@ -0,0 +1,174 @@
+use osm_tag_schemes::{Smoothness, Surface, SurfaceScheme, TagError};
+use osm_tags::{TagKey, TagsRef};
+
+use crate::locale::{DrivingSide, Locale};
+use crate::road::{Designated, SurfaceDetails};
+use crate::transform::tags_to_lanes::modes::CyclewayVariant;
+use crate::transform::tags_to_lanes::road::LaneType;
+use crate::transform::tags_to_lanes::{Infer, LaneBuilder, RoadBuilder, TagsToLanesMsg};
+use crate::transform::RoadWarnings;
+
+const SIDEWALK: TagKey = TagKey::from_static("sidewalk");
+const CYCLEWAY: TagKey = TagKey::from_static("cycleway");
+const PARKING_LANE: TagKey = TagKey::from_static("parking:lane");
+const PARKING: TagKey = TagKey::from_static("parking");
+
+#[derive(Clone, Copy, Default)]
+struct Surfacing<'tag> {
+    surface: Option<Surface>,
+    smoothness: Option<Smoothness>,
+    winter: Option<Surface>,
+    date: Option<&'tag str>,
+}
+
+impl<'tag> Surfacing<'tag> {
+    fn from_tags(
+        tags: &'tag TagsRef<'_>,
+        prefix: Option<&TagKey>,
+        warnings: &mut RoadWarnings,
+    ) -> Self {
+        fn ok_or_warn<T>(
+            tagged: Result<Option<T>, TagError>,
+            warnings: &mut RoadWarnings,
+        ) -> Option<T> {
+            tagged.unwrap_or_else(|e| {
+                warnings.push(TagsToLanesMsg::unsupported_str(e.to_string()));
+                None
+            })
+        }
+        let scheme = SurfaceScheme::from_tags(tags, prefix);
+        Self {
+            surface: ok_or_warn(scheme.surface, warnings),
+            smoothness: ok_or_warn(scheme.smoothness, warnings),
+            winter: ok_or_warn(scheme.winter, warnings),
+            date: scheme.date,
+        }
+    }
+
+    /// Fill the gaps from another, less specific, surfacing
+    fn or(self, other: Self) -> Self {
+        Self {
+            surface: self.surface.or(other.surface),
+            smoothness: self.smoothness.or(other.smoothness),
+            winter: self.winter.or(other.winter),
+            date: self.date.or(other.date),
+        }
+    }
+
+    /// `None` if neither the winter surface nor the date are tagged
+    fn details(&self) -> Option<SurfaceDetails> {
+        (self.winter.is_some() || self.date.is_some()).then(|| SurfaceDetails {
+            winter: self.winter,
+            date: self.date.map(ToOwned::to_owned),
+        })
+    }
+}
+
+/// Surfacing of the parts of a way, `PREFIX:SIDE`, then `PREFIX:both`, then `PREFIX`.
+struct Parts<'tag> {
+    sidewalk: Surfacing<'tag>,
+    cycleway: Surfacing<'tag>,
+    parking: Surfacing<'tag>,
+}
+
+impl<'tag> Parts<'tag> {
+    fn from_tags(tags: &'tag TagsRef<'_>, side: Option<&str>, warnings: &mut RoadWarnings) -> Self {
+        let mut part = |prefix: &TagKey| {
+            let key = side.map(|side| prefix + side);
+            Surfacing::from_tags(tags, Some(key.as_ref().unwrap_or(prefix)), warnings)
+        };
+        Self {
+            sidewalk: part(&SIDEWALK),
+            cycleway: part(&CYCLEWAY),
+            parking: part(&PARKING_LANE).or(part(&PARKING)),
+        }
+    }
+
+    fn or(self, other: &Self) -> Self {
+        Self {
+            sidewalk: self.sidewalk.or(other.sidewalk),
+            cycleway: self.cycleway.or(other.cycleway),
+            parking: self.parking.or(other.parking),
+        }
+    }
+
+    /// The part of the way the lane belongs to, `None` if on the carriageway
+    fn get(&self, lane: &LaneBuilder) -> Option<Surfacing<'tag>> {
+        match (lane.r#type.some(), lane.designated.some()) {
+            (Some(LaneType::Travel), Some(Designated::Foot)) => Some(self.sidewalk),
+            (Some(LaneType::Travel), Some(Designated::Bicycle)) => Some(self.cycleway),
+            // Bus bays are paved as part of the carriageway
+            (Some(LaneType::Parking), Some(Designated::Bus)) => None,
+            (Some(LaneType::Parking), _) => Some(self.parking),
+            _ => None,
+        }
+    }
+}
+
+/// Lane is part of the carriageway, rather than separated from it
+fn on_carriageway(lane: &LaneBuilder) -> bool {
+    match (lane.r#type.some(), lane.designated.some()) {
+        (Some(LaneType::Travel), Some(Designated::Foot)) => false,
+        (Some(LaneType::Travel), Some(Designated::Bicycle)) => {
+            lane.cycleway_variant != Some(CyclewayVariant::Track)
+        },
+        _ => true,
+    }
+}
+
+fn set_surfacing(
+    lane: &mut LaneBuilder,
+    part: Option<Surfacing>,
+    carriageway: Surfacing,
+    default: Option<Surface>,
+) -> Result<(), TagsToLanesMsg> {
+    if let Some(part) = part {
+        lane.surface.set(Infer::direct(part.surface))?;
+        lane.smoothness.set(Infer::direct(part.smoothness))?;
+        lane.surface_details.set(Infer::direct(part.details()))?;
+    }
+    if on_carriageway(lane) {
+        lane.surface
+            .set(carriageway.surface.map_or(Infer::None, Infer::Calculated))?;
+        lane.smoothness.set(
+            carriageway
+                .smoothness
+                .map_or(Infer::None, Infer::Calculated),
+        )?;
+        lane.surface_details
+            .set(carriageway.details().map_or(Infer::None, Infer::Calculated))?;
+        lane.surface
+            .set(default.map_or(Infer::None, Infer::Default))?;
+    }
+    Ok(())
+}
+
+/// Surface and smoothness of each lane,
+/// from the part of the way it belongs to or otherwise from the carriageway.
+pub(in crate::transform::tags_to_lanes) fn surface(
+    tags: &TagsRef<'_>,
+    locale: &Locale,
+    road: &mut RoadBuilder,
+    warnings: &mut RoadWarnings,
+) -> Result<(), TagsToLanesMsg> {
+    let carriageway = Surfacing::from_tags(tags, None, warnings);
+    let default = locale.surface(road.highway.r#type());
+
+    let any = Parts::from_tags(tags, None, warnings);
+    let both = Parts::from_tags(tags, Some("both"), warnings).or(&any);
+    let side = |side: DrivingSide, warnings: &mut RoadWarnings| {
+        Parts::from_tags(tags, Some(side.tag().as_str()), warnings).or(&both)
+    };
+    let forward = side(locale.driving_side, warnings);
+    let backward = side(locale.driving_side.opposite(), warnings);
+
+    for lane in road.forward_ltr_mut(locale) {
+        let part = forward.get(lane);
+        set_surfacing(lane, part, carriageway, default)?;
+    }
+    for lane in road.backward_ltr_mut(locale) {
+        let part = backward.get(lane);
+        set_surfacing(lane, part, carriageway, default)?;
+    }
+    Ok(())
+}
@@ -0,0 +1,35 @@
+use osm_tag_schemes::{LaneDependent, TurnIndication};
+use osm_tags::{TagKey, TagsRef};
+
+use super::set_lanes;
+use crate::locale::Locale;
+use crate::transform::tags_to_lanes::{Infer, RoadBuilder, TagsToLanesMsg};
+use crate::transform::RoadWarnings;
+
+const TURN_LANES: TagKey = TagKey::from_static("turn:lanes");
+
+/// Turn indications of each lane, from `turn:lanes=*`
+pub(in crate::transform::tags_to_lanes) fn turn(
+    tags: &TagsRef<'_>,
+    locale: &Locale,
+    road: &mut RoadBuilder,
+    warnings: &mut RoadWarnings,
+) -> Result<(), TagsToLanesMsg> {
+    // https://wiki.openstreetmap.org/wiki/Key:turn
+    match LaneDependent::from_tags_with(tags, &TURN_LANES, TurnIndication::split) {
+        Ok(Some(turn_lanes)) => set_lanes(
+            &TURN_LANES,
+            turn_lanes,
+            tags,
+            locale,
+            road,
+            warnings,
+            |lane, turn| Ok(lane.turn.set(Infer::Direct(turn))?),
+        ),
+        Ok(None) => Ok(()),
+        Err(e) => {
+            warnings.push(e.into());
+            Ok(())
+        },
+    }
+}
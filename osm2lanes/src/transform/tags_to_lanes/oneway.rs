@@ -1,8 +1,11 @@
-use osm_tag_schemes::keys::ONEWAY;
-use osm_tags::{TagKey, Tags};
+use osm_tag_schemes::keys::{HIGHWAY, ONEWAY};
+use osm_tag_schemes::Highway;
+use osm_tags::{TagKey, TagsRef};
 
-use super::TagsToLanesMsg;
-use crate::locale::Locale;
+use super::{RoadBuilder, TagsToLanesMsg};
+use crate::locale::{highway_implies_oneway, Locale};
+use crate::road::{Designated, Direction};
+use crate::transform::tags_to_lanes::Infer;
 use crate::transform::RoadWarnings;
 
 #[derive(Clone, Copy, PartialEq, Eq)]
@@ -32,26 +35,79 @@ impl From<Oneway> for bool {
 
 impl Oneway {
     pub const KEY: TagKey = TagKey::from_static("oneway");
+    const CONDITIONAL: TagKey = TagKey::from_static("oneway:conditional");
 
+    /// Oneway from `oneway=*` or `junction=*`,
+    /// else the default of the highway type, see [`highway_implies_oneway`]
     pub fn from_tags(
-        tags: &Tags,
-        _locale: &Locale,
-        _warnings: &mut RoadWarnings,
-    ) -> Result<Self, TagsToLanesMsg> {
-        Ok(
-            match (tags.get(&ONEWAY), tags.is("junction", "roundabout")) {
-                (Some("yes"), _) => Self::Yes,
-                (Some("no"), false) => Self::No,
-                (Some("no"), true) => {
-                    return Err(TagsToLanesMsg::ambiguous_tags(
-                        tags.subset(["oneway", "junction"]),
+        tags: &TagsRef<'_>,
+        warnings: &mut RoadWarnings,
+    ) -> Result<Infer<Self>, TagsToLanesMsg> {
+        if tags.get(&Self::CONDITIONAL).is_some() && !is_reversible(tags) {
+            warnings.push(TagsToLanesMsg::unimplemented_tags(
+                tags.subset([&ONEWAY, &Self::CONDITIONAL]),
+            ));
+        }
+        let implied = match Highway::from_tags(tags) {
+            Ok(Some(highway)) => highway_implies_oneway(highway.r#type()),
+            _ => false,
+        };
+        Ok(match (tags.get(&ONEWAY), is_roundabout(tags)) {
+            // All lanes run the same way at any one time
+            (Some("yes" | "reversible"), _) | (None, true) => Infer::Direct(Self::Yes),
+            (Some("no"), false) => {
+                if implied {
+                    warnings.push(TagsToLanesMsg::ambiguous_tags(
+                        tags.subset(&[ONEWAY, HIGHWAY]),
                     ));
-                },
-                (Some(value), _) => {
-                    return Err(TagsToLanesMsg::unimplemented_tag(ONEWAY, value));
-                },
-                (None, roundabout) => Self::from(roundabout),
+                }
+                Infer::Direct(Self::No)
+            },
+            (Some("no"), true) => {
+                return Err(TagsToLanesMsg::ambiguous_tags(
+                    tags.subset(["oneway", "junction"]),
+                ));
             },
-        )
+            (Some(value), _) => {
+                return Err(TagsToLanesMsg::unimplemented_tag(ONEWAY, value));
+            },
+            (None, false) => Infer::Default(Self::from(implied)),
+        })
+    }
+}
+
+/// `junction=roundabout` or `junction=circular`, implying `oneway=yes`
+pub(in crate::transform::tags_to_lanes) fn is_roundabout(tags: &TagsRef<'_>) -> bool {
+    tags.is_any("junction", &["roundabout", "circular"])
+}
+
+/// `oneway=reversible`, or a oneway that is reversed by `oneway:conditional=-1 @ (...)`
+fn is_reversible(tags: &TagsRef<'_>) -> bool {
+    match tags.get(&ONEWAY) {
+        Some("reversible") => true,
+        Some("yes") => matches!(
+            tags.get(&Oneway::CONDITIONAL),
+            Some(conditional) if conditional.trim_start().starts_with("-1")
+        ),
+        _ => false,
+    }
+}
+
+/// Motor lanes of a reversible road alternate direction
+pub(in crate::transform::tags_to_lanes) fn reversible(
+    tags: &TagsRef<'_>,
+    locale: &Locale,
+    road: &mut RoadBuilder,
+) {
+    if !is_reversible(tags) {
+        return;
+    }
+    for lane in road.forward_ltr_mut(locale) {
+        if matches!(
+            lane.designated.some(),
+            Some(Designated::Motor | Designated::Bus | Designated::Hov)
+        ) {
+            lane.direction = Infer::Direct(Direction::Reversible);
+        }
     }
 }
@@ -0,0 +1,38 @@
+use osm_tag_schemes::{keys, Overtaking};
+use osm_tags::TagsRef;
+
+use super::{TagsNumeric, TagsToLanesMsg};
+use crate::transform::RoadWarnings;
+
+/// Which directions of traffic may overtake, from `overtaking=*`,
+/// or else from `overtaking:forward=*` and `overtaking:backward=*`,
+/// as tagged along no-overtaking zones
+pub(in crate::transform::tags_to_lanes) fn overtaking(
+    tags: &TagsRef<'_>,
+    warnings: &mut RoadWarnings,
+) -> Option<Overtaking> {
+    match tags.get(&keys::OVERTAKING) {
+        Some("both") => return Some(Overtaking::Yes),
+        Some(_) => return tags.get_parsed(&keys::OVERTAKING, warnings),
+        None => {},
+    }
+    let mut permitted = |direction| {
+        let key = keys::OVERTAKING + direction;
+        match tags.get(&key) {
+            Some("yes") => Some(true),
+            Some("no") => Some(false),
+            Some(value) => {
+                warnings.push(TagsToLanesMsg::unsupported_tag(key, value));
+                None
+            },
+            None => None,
+        }
+    };
+    match (permitted("forward"), permitted("backward")) {
+        (None, None) => None,
+        (Some(false), Some(false)) => Some(Overtaking::No),
+        (Some(false), _) => Some(Overtaking::Backward),
+        (_, Some(false)) => Some(Overtaking::Forward),
+        _ => Some(Overtaking::Yes),
+    }
+}
@@ -0,0 +1,59 @@
+use osm_tags::TagsRef;
+
+use super::RoadBuilder;
+use crate::locale::Locale;
+use crate::transform::{RoadError, RoadWarnings};
+
+/// An additional pass over the tags, run after the built-in modes and before inference,
+/// to prototype support for further tagging schemes without changing this crate.
+///
+/// Register passes with [`Config::with_pass`](super::Config::with_pass).
+///
+/// ```
+/// use osm2lanes::locale::Locale;
+/// use osm2lanes::transform::{
+///     tags_to_lanes, Infer, LaneModePass, RoadBuilder, RoadError, RoadWarnings,
+///     TagsToLanesConfig, TagsToLanesMsg,
+/// };
+/// use osm_tag_schemes::Surface;
+/// use osm_tags::{Tags, TagsRef};
+///
+/// struct WinterRoad;
+///
+/// impl LaneModePass for WinterRoad {
+///     fn apply(
+///         &self,
+///         tags: &TagsRef<'_>,
+///         locale: &Locale,
+///         road: &mut RoadBuilder,
+///         warnings: &mut RoadWarnings,
+///     ) -> Result<(), RoadError> {
+///         if tags.is("winter_road", "yes") {
+///             for lane in road.lanes_ltr_mut(locale) {
+///                 lane.surface = Infer::Direct(Surface::Ice);
+///             }
+///             warnings.push(TagsToLanesMsg::unsupported_str("only passable in winter"));
+///         }
+///         Ok(())
+///     }
+/// }
+///
+/// let tags = Tags::from_pairs([("highway", "track"), ("winter_road", "yes")]).unwrap();
+/// let config = TagsToLanesConfig::default().with_pass(WinterRoad);
+/// let road = tags_to_lanes(&tags, &Locale::builder().build(), &config).unwrap();
+/// assert_eq!(road.warnings.as_slice().len(), 1);
+/// ```
+pub trait LaneModePass: Send + Sync {
+    /// Parse the tags and apply them to the lanes of the road, pushing any warnings
+    ///
+    /// # Errors
+    ///
+    /// If the tags cannot be supported, stopping the transformation
+    fn apply(
+        &self,
+        tags: &TagsRef<'_>,
+        locale: &Locale,
+        road: &mut RoadBuilder,
+        warnings: &mut RoadWarnings,
+    ) -> Result<(), RoadError>;
+}
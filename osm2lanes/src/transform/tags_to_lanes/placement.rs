@@ -0,0 +1,84 @@
+use osm_tag_schemes::{keys, Placement};
+use osm_tags::TagsRef;
+
+use super::{TagsNumeric, TagsToLanesMsg};
+use crate::road::{Direction, Lane};
+use crate::transform::RoadWarnings;
+
+/// Renumber a placement on the `numbers` of the carriageway lanes it counts,
+/// which are reversed when counted against the direction of the way
+fn renumber(placement: Placement, numbers: &[usize], reversed: bool) -> Option<Placement> {
+    let nth = |lane: usize| {
+        let index = lane.checked_sub(1)?;
+        if reversed {
+            numbers.iter().rev().nth(index).copied()
+        } else {
+            numbers.get(index).copied()
+        }
+    };
+    Some(match (placement, reversed) {
+        (Placement::Transition, _) => Placement::Transition,
+        (Placement::MiddleOf(lane), _) => Placement::MiddleOf(nth(lane)?),
+        (Placement::LeftOf(lane), false) | (Placement::RightOf(lane), true) => {
+            Placement::LeftOf(nth(lane)?)
+        },
+        (Placement::RightOf(lane), false) | (Placement::LeftOf(lane), true) => {
+            Placement::RightOf(nth(lane)?)
+        },
+    })
+}
+
+/// Position of the way from `placement=*`,
+/// or from `placement:forward=*` or `placement:backward=*`,
+/// which only count the lanes in that direction, in that direction.
+/// The result counts all carriageway lanes in the direction of the way.
+pub(super) fn placement(
+    tags: &TagsRef<'_>,
+    lanes: &[Lane],
+    warnings: &mut RoadWarnings,
+) -> Option<Placement> {
+    let keys = [
+        keys::PLACEMENT,
+        keys::PLACEMENT + "forward",
+        keys::PLACEMENT + "backward",
+    ];
+    let [whole, forward, backward] = keys
+        .clone()
+        .map(|key| tags.get_parsed::<_, Placement>(&key, warnings));
+
+    let carriageway = || {
+        lanes
+            .iter()
+            .filter(|lane| lane.is_carriageway())
+            .enumerate()
+    };
+    let numbers = |direction: Direction| -> Vec<usize> {
+        carriageway()
+            .filter(|(_, lane)| lane.direction() == Some(direction))
+            .map(|(index, _)| index + 1)
+            .collect()
+    };
+    let renumbered = match (whole, forward, backward) {
+        (None, None, None) => return None,
+        (Some(placement), None, None) => {
+            let all: Vec<usize> = carriageway().map(|(index, _)| index + 1).collect();
+            renumber(placement, &all, false)
+        },
+        (None, Some(placement), None) => renumber(placement, &numbers(Direction::Forward), false),
+        (None, None, Some(placement)) => renumber(placement, &numbers(Direction::Backward), true),
+        _ => {
+            warnings.push(TagsToLanesMsg::unsupported(
+                "more than one placement",
+                tags.subset(&keys),
+            ));
+            return None;
+        },
+    };
+    if renumbered.is_none() {
+        warnings.push(TagsToLanesMsg::unsupported(
+            "placement on a lane that does not exist",
+            tags.subset(&keys),
+        ));
+    }
+    renumbered
+}
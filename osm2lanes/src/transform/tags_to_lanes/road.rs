@@ -1,21 +1,26 @@
 use std::collections::VecDeque;
 use std::iter;
 
-use osm_tag_schemes::{keys, Highway, HighwayError, Schemes};
-use osm_tags::{TagKey, Tags};
+use osm_tag_schemes::{
+    keys, Change, Highway, HighwayError, HighwayType, Kerb, Lit, ParkingOrientation, Schemes,
+    Separation, Smoothness, Surface, TurnIndication,
+};
+use osm_tags::{TagKey, Tags, TagsRef};
 
-use super::infer::Infer;
+use super::infer::{Infer, InferenceLevel};
 use super::oneway::Oneway;
 use super::separator::{
     lane_pair_to_semantic_separator, lane_to_inner_edge_separator, outer_edge_semantic_separator,
-    semantic_edge_separator_to_lane, semantic_separator_to_lane,
+    semantic_edge_separator_to_lane, semantic_separator_to_lane, tagged_centre_separator,
 };
-use super::{TagSchemes, TagsToLanesMsg};
+use super::single_track::is_narrow;
+use super::{Config, TagSchemes, TagsToLanesMsg};
 use crate::locale::{DrivingSide, Locale};
-use crate::metric::{Metre, Speed};
+use crate::metric::{LenientMetre, Metre, Speed, Tonne};
 use crate::road::{
-    AccessAndDirection as LaneAccessAndDirection, AccessByType as LaneAccessByType, Designated,
-    Direction, Lane,
+    AccessAndDirection as LaneAccessAndDirection, AccessByType as LaneAccessByType, Conditions,
+    Designated, Destinations, Direction, Elevation, Lane, Provenance, Provenanced, ProvenancedLane,
+    SeparatelyMapped, SurfaceDetails,
 };
 use crate::transform::error::{RoadError, RoadWarnings};
 use crate::transform::tags_to_lanes::counts::{CentreTurnLaneScheme, Counts};
@@ -43,6 +48,7 @@ pub enum LaneType {
     Travel,
     Parking,
     Shoulder,
+    Verge,
 }
 
 #[derive(Clone, Default, Debug, PartialEq)]
@@ -52,6 +58,27 @@ pub struct Width {
     pub max: Infer<Metre>,
 }
 
+/// Exact values are direct, approximations and range midpoints are calculated.
+impl From<LenientMetre> for Width {
+    fn from(lenient: LenientMetre) -> Self {
+        match lenient {
+            LenientMetre::Exact(target) => Self {
+                target: Infer::Direct(target),
+                ..Default::default()
+            },
+            LenientMetre::Approximate(target) => Self {
+                target: Infer::Calculated(target),
+                ..Default::default()
+            },
+            LenientMetre::Range { min, max } => Self {
+                min: Infer::Direct(min),
+                target: Infer::Calculated(lenient.target()),
+                max: Infer::Direct(max),
+            },
+        }
+    }
+}
+
 #[derive(Clone, Default, Debug)]
 pub struct Access {
     pub foot: Infer<LaneAccessAndDirection>,
@@ -61,6 +88,22 @@ pub struct Access {
     pub motor: Infer<LaneAccessAndDirection>,
 }
 
+impl Access {
+    /// The least certain provenance of any access
+    fn provenance(&self) -> Option<Provenance> {
+        [
+            self.foot.provenance(),
+            self.bicycle.provenance(),
+            self.taxi.provenance(),
+            self.bus.provenance(),
+            self.motor.provenance(),
+        ]
+        .into_iter()
+        .flatten()
+        .min()
+    }
+}
+
 impl From<Access> for Option<LaneAccessByType> {
     fn from(inferred: Access) -> Self {
         if inferred.foot.is_none()
@@ -89,8 +132,29 @@ pub struct LaneBuilder {
     pub designated: Infer<Designated>,
     pub width: Width,
     pub max_speed: Infer<Speed>,
+    pub max_height: Infer<Metre>,
+    pub max_weight: Infer<Tonne>,
     pub access: Access,
+    pub elevation: Infer<Elevation>,
+    pub turn: Infer<Vec<TurnIndication>>,
+    pub change: Infer<Change>,
+    pub destinations: Infer<Destinations>,
+    pub conditions: Infer<Conditions>,
+    pub surface: Infer<Surface>,
+    pub surface_details: Infer<SurfaceDetails>,
+    pub smoothness: Infer<Smoothness>,
+    pub lit: Infer<Lit>,
+    pub embedded_rail: Infer<bool>,
+    pub centre_turn: Infer<bool>,
     pub(super) cycleway_variant: Option<CyclewayVariant>,
+    pub(super) parking_orientation: Option<ParkingOrientation>,
+    /// What separates a cycle lane from the lane inside it
+    pub(super) separation: Option<Separation>,
+    /// Width of the buffer between a cycle lane and the lane inside it
+    pub(super) buffer: Option<Metre>,
+    /// Kerb between a sidewalk and the lane inside it, if tagged
+    pub(super) kerb: Option<Kerb>,
+    pub(super) kerb_height: Option<Metre>,
 }
 
 impl LaneBuilder {
@@ -118,19 +182,194 @@ impl LaneBuilder {
                     designated: self.designated.some().unwrap(),
                     width,
                     max_speed: self.max_speed.some(),
+                    max_height: self.max_height.some(),
+                    max_weight: self.max_weight.some(),
                     access: self.access.into(),
+                    elevation: self.elevation.some(),
+                    turn: self.turn.some(),
+                    change: self.change.some(),
+                    destinations: self.destinations.some(),
+                    conditions: self.conditions.some(),
+                    surface: self.surface.some(),
+                    surface_details: self.surface_details.some(),
+                    smoothness: self.smoothness.some(),
+                    lit: self.lit.some(),
+                    embedded_rail: self.embedded_rail.some().unwrap_or_default(),
+                    centre_turn: self.centre_turn.some().unwrap_or_default(),
                 }
             },
             Some(LaneType::Parking) => Lane::Parking {
                 direction: self.direction.some().unwrap(),
                 designated: self.designated.some().unwrap(),
+                orientation: self.parking_orientation,
+                width,
+                surface: self.surface.some(),
+                surface_details: self.surface_details.some(),
+                smoothness: self.smoothness.some(),
+            },
+            Some(LaneType::Shoulder) => Lane::Shoulder {
+                direction: self.direction.some(),
                 width,
+                surface: self.surface.some(),
+                surface_details: self.surface_details.some(),
+                smoothness: self.smoothness.some(),
+            },
+            Some(LaneType::Verge) => Lane::Verge { width },
+            None => panic!(),
+        }
+    }
+
+    /// Like `build`, keeping the provenance of each property
+    #[allow(clippy::panic)]
+    #[must_use]
+    fn build_provenanced(self) -> ProvenancedLane {
+        match self.r#type.some() {
+            Some(LaneType::Travel) => {
+                let direction = if let Some(Designated::Foot) = self.designated.some() {
+                    None
+                } else {
+                    self.direction.provenanced()
+                };
+                let access = self.access.provenance().and_then(|provenance| {
+                    Option::<LaneAccessByType>::from(self.access)
+                        .map(|access| Provenanced::new(access, provenance))
+                });
+                ProvenancedLane::Travel {
+                    direction,
+                    designated: self.designated.provenanced().unwrap(),
+                    width: self.width.target.provenanced(),
+                    max_speed: self.max_speed.provenanced(),
+                    max_height: self.max_height.provenanced(),
+                    max_weight: self.max_weight.provenanced(),
+                    access,
+                    elevation: self.elevation.provenanced(),
+                    turn: self.turn.provenanced(),
+                    change: self.change.provenanced(),
+                    destinations: self.destinations.provenanced(),
+                    conditions: self.conditions.provenanced(),
+                    surface: self.surface.provenanced(),
+                    surface_details: self.surface_details.provenanced(),
+                    smoothness: self.smoothness.provenanced(),
+                    lit: self.lit.provenanced(),
+                    embedded_rail: self.embedded_rail.provenanced(),
+                    centre_turn: self.centre_turn.provenanced(),
+                }
+            },
+            Some(LaneType::Parking) => ProvenancedLane::Parking {
+                direction: self.direction.provenanced().unwrap(),
+                designated: self.designated.provenanced().unwrap(),
+                // Only ever tagged, from `parking:lane:<side>=*`
+                orientation: self
+                    .parking_orientation
+                    .map(|orientation| Provenanced::new(orientation, Provenance::Direct)),
+                width: self.width.target.provenanced(),
+                surface: self.surface.provenanced(),
+                surface_details: self.surface_details.provenanced(),
+                smoothness: self.smoothness.provenanced(),
+            },
+            Some(LaneType::Shoulder) => ProvenancedLane::Shoulder {
+                direction: self.direction.provenanced(),
+                width: self.width.target.provenanced(),
+                surface: self.surface.provenanced(),
+                surface_details: self.surface_details.provenanced(),
+                smoothness: self.smoothness.provenanced(),
+            },
+            Some(LaneType::Verge) => ProvenancedLane::Verge {
+                width: self.width.target.provenanced(),
             },
-            Some(LaneType::Shoulder) => Lane::Shoulder { width },
             None => panic!(),
         }
     }
 
+    /// Drop every optional property that is not directly tagged,
+    /// returning the names of those omitted
+    fn keep_direct(&mut self) -> Vec<&'static str> {
+        fn keep<T: PartialEq>(
+            value: &mut Infer<T>,
+            name: &'static str,
+            omitted: &mut Vec<&'static str>,
+        ) {
+            if !value.is_none() && !matches!(value, Infer::Direct(_)) {
+                *value = Infer::None;
+                if !omitted.contains(&name) {
+                    omitted.push(name);
+                }
+            }
+        }
+        let mut omitted = Vec::new();
+        // Parking lanes cannot be built without a direction
+        if self.r#type.some() != Some(LaneType::Parking) {
+            keep(&mut self.direction, "direction", &mut omitted);
+        }
+        // A width range is only kept alongside a direct target width
+        if !matches!(self.width.target, Infer::Direct(_)) && self.width != Width::default() {
+            self.width = Width::default();
+            omitted.push("width");
+        }
+        keep(&mut self.max_speed, "max_speed", &mut omitted);
+        keep(&mut self.max_height, "max_height", &mut omitted);
+        keep(&mut self.max_weight, "max_weight", &mut omitted);
+        keep(&mut self.access.foot, "access", &mut omitted);
+        keep(&mut self.access.bicycle, "access", &mut omitted);
+        keep(&mut self.access.taxi, "access", &mut omitted);
+        keep(&mut self.access.bus, "access", &mut omitted);
+        keep(&mut self.access.motor, "access", &mut omitted);
+        keep(&mut self.elevation, "elevation", &mut omitted);
+        keep(&mut self.turn, "turn", &mut omitted);
+        keep(&mut self.change, "change", &mut omitted);
+        keep(&mut self.destinations, "destinations", &mut omitted);
+        keep(&mut self.conditions, "conditions", &mut omitted);
+        keep(&mut self.surface, "surface", &mut omitted);
+        keep(&mut self.surface_details, "surface_details", &mut omitted);
+        keep(&mut self.smoothness, "smoothness", &mut omitted);
+        keep(&mut self.lit, "lit", &mut omitted);
+        keep(&mut self.embedded_rail, "embedded_rail", &mut omitted);
+        keep(&mut self.centre_turn, "centre_turn", &mut omitted);
+        omitted
+    }
+
+    /// Fill the gaps in the optional properties with plausible locale defaults
+    fn fill_defaults(&mut self, highway: HighwayType, locale: &Locale) {
+        match (self.r#type.some(), self.designated.some()) {
+            (Some(LaneType::Travel), Some(designated))
+                if self.embedded_rail.some() == Some(true) =>
+            {
+                self.width.target = self
+                    .width
+                    .target
+                    .or_default(locale.embedded_rail_width(&designated, highway));
+            },
+            (Some(LaneType::Travel | LaneType::Parking), Some(designated)) => {
+                self.width.target = self
+                    .width
+                    .target
+                    .or_default(locale.travel_width(&designated, highway));
+            },
+            (Some(LaneType::Shoulder), _) => {
+                self.width.target = self.width.target.or_default(Lane::DEFAULT_WIDTH);
+            },
+            _ => {},
+        }
+        if self.designated.some() == Some(Designated::Bicycle) {
+            let elevation = match self.cycleway_variant {
+                Some(CyclewayVariant::Track) => {
+                    Some(locale.cycle_track_elevation().unwrap_or(Elevation::Raised))
+                },
+                Some(CyclewayVariant::Lane) => Some(Elevation::Carriageway),
+                Some(
+                    CyclewayVariant::SharedMotor
+                    | CyclewayVariant::SharedLane
+                    | CyclewayVariant::ShareBusway
+                    | CyclewayVariant::Separate,
+                )
+                | None => None,
+            };
+            if let Some(elevation) = elevation {
+                self.elevation = self.elevation.or_default(elevation);
+            }
+        }
+    }
+
     /// Create a mirrored version of the lane
     #[must_use]
     fn mirror(&self) -> &Self {
@@ -140,11 +379,12 @@ impl LaneBuilder {
     }
 }
 
-pub(in crate::transform::tags_to_lanes) struct RoadBuilder {
+pub struct RoadBuilder {
     forward_lanes: VecDeque<LaneBuilder>,
     backward_lanes: VecDeque<LaneBuilder>,
     pub(crate) highway: Highway,
     pub(crate) oneway: Oneway,
+    pub(crate) separately_mapped: Vec<SeparatelyMapped>,
 }
 
 impl RoadBuilder {
@@ -152,34 +392,52 @@ impl RoadBuilder {
     pub(crate) fn from(
         generic_schemes: &Schemes,
         crate_schemes: &TagSchemes,
-        tags: &Tags,
+        tags: &TagsRef<'_>,
         locale: &Locale,
+        config: &Config,
         warnings: &mut RoadWarnings,
     ) -> Result<Self, RoadError> {
-        let oneway = crate_schemes.oneway;
+        let oneway = crate_schemes.oneway();
 
         let highway = match &generic_schemes.highway {
-            Ok(Some(highway)) => highway,
+            Ok(Some(highway)) => highway.clone(),
             Ok(None) => return Err(RoadError::WayNotRoad),
             Err(highway_error) => {
-                let tags = match &highway_error {
-                    HighwayError::Highway(s) => Tags::from_pair(keys::HIGHWAY, *s),
-                    HighwayError::ConstructionNone => {
-                        Tags::from_pair(keys::HIGHWAY, "construction")
-                    },
-                    HighwayError::Construction(s) => {
-                        Tags::from_pairs([(keys::HIGHWAY, "construction"), (keys::CONSTRUCTION, s)])
-                            .unwrap()
+                let (tags, lifecycle): (_, fn(HighwayType) -> Highway) = match &highway_error {
+                    HighwayError::Highway(s) => {
+                        (Tags::from_pair(keys::HIGHWAY, *s), Highway::active)
                     },
-                    HighwayError::ProposedNone => Tags::from_pair(keys::HIGHWAY, "proposed"),
-                    HighwayError::Proposed(s) => {
+                    HighwayError::ConstructionNone => (
+                        Tags::from_pair(keys::HIGHWAY, "construction"),
+                        Highway::construction,
+                    ),
+                    HighwayError::Construction(s) => (
+                        Tags::from_pairs([
+                            (keys::HIGHWAY, "construction"),
+                            (keys::CONSTRUCTION, s),
+                        ])
+                        .unwrap(),
+                        Highway::construction,
+                    ),
+                    HighwayError::ProposedNone => (
+                        Tags::from_pair(keys::HIGHWAY, "proposed"),
+                        Highway::proposed,
+                    ),
+                    HighwayError::Proposed(s) => (
                         Tags::from_pairs([(keys::HIGHWAY, "proposed"), (keys::PROPOSED, s)])
-                            .unwrap()
-                    },
+                            .unwrap(),
+                        Highway::proposed,
+                    ),
                 };
-                return Err(TagsToLanesMsg::unsupported(highway_error.to_string(), tags).into());
+                let msg = TagsToLanesMsg::unsupported(highway_error.to_string(), tags);
+                if !config.unknown_highway_as_road {
+                    return Err(msg.into());
+                }
+                warnings.push(msg);
+                lifecycle(HighwayType::UnknownRoad)
             },
         };
+        let highway = &highway;
 
         let designated = if tags.is("access", "no")
             && (tags.is("bus", "yes") || tags.is("psv", "yes")) // West Seattle
@@ -195,7 +453,11 @@ impl RoadBuilder {
         };
 
         const MAXSPEED: TagKey = TagKey::from_static("maxspeed");
-        let max_speed = match tags.get(&MAXSPEED).map(str::parse::<Speed>).transpose() {
+        let max_speed = match tags
+            .get(&MAXSPEED)
+            .map(|speed| Speed::parse_in(speed, locale.speed_unit()))
+            .transpose()
+        {
             Ok(max_speed) => max_speed,
             Err(e) => {
                 warnings.push(TagsToLanesMsg::unsupported(
@@ -206,15 +468,8 @@ impl RoadBuilder {
             },
         };
 
-        let width = locale.travel_width(&designated, highway.r#type());
-        let width = Width {
-            min: Infer::None,
-            target: Infer::Default(width),
-            max: Infer::None,
-        };
-
         let bus_lane_counts =
-            BusLaneCount::from_tags(&crate_schemes.busway, tags, locale, warnings);
+            BusLaneCount::from_tags(&crate_schemes.busway, tags, oneway, locale, warnings);
         let centre_turn_lanes = CentreTurnLaneScheme::from_tags(tags, oneway, locale, warnings);
         let lane_counts = Counts::new(
             tags,
@@ -224,7 +479,14 @@ impl RoadBuilder {
             &bus_lane_counts,
             locale,
             warnings,
-        );
+        )
+        .single_track(
+            config.inference != InferenceLevel::Strict
+                && is_narrow(tags, locale, config.measured_width),
+            tags,
+        )
+        .observe(config.observed_lanes, tags, oneway, warnings)
+        .strict(config.inference == InferenceLevel::Strict, tags, warnings);
         log::trace!("lane counts: {lane_counts:?}");
 
         let road = if let Counts::Directional {
@@ -240,7 +502,6 @@ impl RoadBuilder {
                 direction: Infer::Default(Direction::Forward),
                 designated: Infer::Default(designated),
                 max_speed: Infer::direct(max_speed),
-                width: width.clone(),
                 ..Default::default()
             })
             .take(forward.some().unwrap_or(0))
@@ -250,18 +511,27 @@ impl RoadBuilder {
                 direction: Infer::Default(Direction::Backward),
                 designated: Infer::Default(designated),
                 max_speed: Infer::direct(max_speed),
-                width: width.clone(),
                 ..Default::default()
             })
             .take(backward.some().unwrap_or(0))
             .collect();
 
             if centre_turn_lane.some().unwrap_or(false) {
+                // Turning across the opposing traffic, left when driving on the right
+                let across = match locale.driving_side {
+                    DrivingSide::Right => TurnIndication::Left,
+                    DrivingSide::Left => TurnIndication::Right,
+                };
                 forward_lanes.push_front(LaneBuilder {
                     r#type: Infer::Default(LaneType::Travel),
                     direction: Infer::Default(Direction::Both),
                     designated: Infer::Default(designated),
-                    width,
+                    max_speed: Infer::direct(max_speed),
+                    turn: match centre_turn_lanes.turn {
+                        Some(turn) => Infer::Direct(turn),
+                        None => Infer::Default(vec![across]),
+                    },
+                    centre_turn: centre_turn_lane,
                     ..Default::default()
                 });
             }
@@ -271,6 +541,7 @@ impl RoadBuilder {
                 backward_lanes,
                 highway: highway.clone(),
                 oneway,
+                separately_mapped: Vec::new(),
             }
         } else {
             RoadBuilder {
@@ -278,59 +549,79 @@ impl RoadBuilder {
                     r#type: Infer::Default(LaneType::Travel),
                     direction: Infer::Default(Direction::Both),
                     designated: Infer::Default(designated),
-                    width,
+                    max_speed: Infer::direct(max_speed),
                     ..Default::default()
                 }]),
                 backward_lanes: VecDeque::new(),
                 highway: highway.clone(),
                 oneway,
+                separately_mapped: Vec::new(),
             }
         };
 
         Ok(road)
     }
 
+    /// The highway of the road
+    #[must_use]
+    pub fn highway(&self) -> &Highway {
+        &self.highway
+    }
+
     /// Number of lanes
     ///
     /// # Panics
     ///
     /// Too many lanes
-    pub(crate) fn len(&self) -> usize {
+    #[must_use]
+    pub fn len(&self) -> usize {
         self.forward_len()
             .checked_add(self.backward_len())
             .expect("too many lanes")
     }
 
+    /// Without lanes
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.forward_lanes.is_empty() && self.backward_lanes.is_empty()
+    }
+
     /// Number of forward lanes
-    pub(crate) fn forward_len(&self) -> usize {
+    #[must_use]
+    pub fn forward_len(&self) -> usize {
         self.forward_lanes.len()
     }
     /// Number of backward lanes
-    pub(crate) fn backward_len(&self) -> usize {
+    #[must_use]
+    pub fn backward_len(&self) -> usize {
         self.backward_lanes.len()
     }
     /// Get inner-most forward lane
-    pub(crate) fn forward_inside(&self) -> Option<&LaneBuilder> {
+    #[must_use]
+    pub fn forward_inside(&self) -> Option<&LaneBuilder> {
         self.forward_lanes.front()
     }
     /// Get outer-most forward lane
-    pub(crate) fn forward_outside(&self) -> Option<&LaneBuilder> {
+    #[must_use]
+    pub fn forward_outside(&self) -> Option<&LaneBuilder> {
         self.forward_lanes.back()
     }
     /// Get inner-most backward lane
-    pub(crate) fn backward_inside(&self) -> Option<&LaneBuilder> {
+    #[must_use]
+    pub fn backward_inside(&self) -> Option<&LaneBuilder> {
         self.backward_lanes.front()
     }
     /// Get outer-most backward lane
-    pub(crate) fn backward_outside(&self) -> Option<&LaneBuilder> {
+    #[must_use]
+    pub fn backward_outside(&self) -> Option<&LaneBuilder> {
         self.backward_lanes.back()
     }
     /// Get inner-most forward lane
-    pub(crate) fn forward_inside_mut(&mut self) -> Option<&mut LaneBuilder> {
+    pub fn forward_inside_mut(&mut self) -> Option<&mut LaneBuilder> {
         self.forward_lanes.front_mut()
     }
     /// Get outer-most forward lane
-    pub(crate) fn forward_outside_mut(&mut self) -> Option<&mut LaneBuilder> {
+    pub fn forward_outside_mut(&mut self) -> Option<&mut LaneBuilder> {
         self.forward_lanes.back_mut()
     }
     /// Get inner-most backward lane
@@ -338,15 +629,23 @@ impl RoadBuilder {
         self.backward_lanes.front_mut()
     }
     /// Get outer-most backward lane
-    pub(crate) fn backward_outside_mut(&mut self) -> Option<&mut LaneBuilder> {
+    pub fn backward_outside_mut(&mut self) -> Option<&mut LaneBuilder> {
         self.backward_lanes.back_mut()
     }
+    /// Forward lanes, from the outside in
+    pub fn forward_outside_in_mut(&mut self) -> impl Iterator<Item = &mut LaneBuilder> {
+        self.forward_lanes.iter_mut().rev()
+    }
+    /// Backward lanes, from the outside in
+    pub fn backward_outside_in_mut(&mut self) -> impl Iterator<Item = &mut LaneBuilder> {
+        self.backward_lanes.iter_mut().rev()
+    }
     /// Push new inner-most forward lane
     pub(crate) fn _push_forward_inside(&mut self, lane: LaneBuilder) {
         self.forward_lanes.push_front(lane);
     }
     /// Push new outer-most forward lane
-    pub(crate) fn push_forward_outside(&mut self, lane: LaneBuilder) {
+    pub fn push_forward_outside(&mut self, lane: LaneBuilder) {
         self.forward_lanes.push_back(lane);
     }
     /// Push new inner-most backward lane
@@ -354,14 +653,15 @@ impl RoadBuilder {
         self.backward_lanes.push_front(lane);
     }
     /// Push new outer-most backward lane
-    pub(crate) fn push_backward_outside(&mut self, lane: LaneBuilder) {
+    pub fn push_backward_outside(&mut self, lane: LaneBuilder) {
         self.backward_lanes.push_back(lane);
     }
     /// Get lanes left to right
-    pub(crate) fn lanes_ltr<'this>(
+    #[must_use]
+    pub fn lanes_ltr<'this>(
         &'this self,
         locale: &Locale,
-    ) -> Box<dyn Iterator<Item = &LaneBuilder> + 'this> {
+    ) -> Box<dyn Iterator<Item = &'this LaneBuilder> + 'this> {
         match locale.driving_side {
             DrivingSide::Left => Box::new(
                 self.forward_lanes
@@ -378,10 +678,10 @@ impl RoadBuilder {
         }
     }
     /// Get lanes left to right
-    pub(crate) fn lanes_ltr_mut<'this>(
+    pub fn lanes_ltr_mut<'this>(
         &'this mut self,
         locale: &Locale,
-    ) -> Box<dyn Iterator<Item = &mut LaneBuilder> + 'this> {
+    ) -> Box<dyn Iterator<Item = &'this mut LaneBuilder> + 'this> {
         match locale.driving_side {
             DrivingSide::Left => Box::new(
                 self.forward_lanes
@@ -401,17 +701,17 @@ impl RoadBuilder {
     pub(crate) fn _forward_ltr<'this>(
         &'this self,
         locale: &Locale,
-    ) -> Box<dyn Iterator<Item = &LaneBuilder> + 'this> {
+    ) -> Box<dyn Iterator<Item = &'this LaneBuilder> + 'this> {
         match locale.driving_side {
             DrivingSide::Left => Box::new(self.forward_lanes.iter().rev()),
             DrivingSide::Right => Box::new(self.forward_lanes.iter()),
         }
     }
     /// Get forward lanes left to right
-    pub(crate) fn forward_ltr_mut<'this>(
+    pub fn forward_ltr_mut<'this>(
         &'this mut self,
         locale: &Locale,
-    ) -> Box<dyn Iterator<Item = &mut LaneBuilder> + 'this> {
+    ) -> Box<dyn Iterator<Item = &'this mut LaneBuilder> + 'this> {
         match locale.driving_side {
             DrivingSide::Left => Box::new(self.forward_lanes.iter_mut().rev()),
             DrivingSide::Right => Box::new(self.forward_lanes.iter_mut()),
@@ -421,24 +721,56 @@ impl RoadBuilder {
     pub(crate) fn _backward_ltr<'this>(
         &'this self,
         locale: &Locale,
-    ) -> Box<dyn Iterator<Item = &LaneBuilder> + 'this> {
+    ) -> Box<dyn Iterator<Item = &'this LaneBuilder> + 'this> {
         match locale.driving_side {
             DrivingSide::Left => Box::new(self.backward_lanes.iter().rev()),
             DrivingSide::Right => Box::new(self.backward_lanes.iter()),
         }
     }
     /// Get backward lanes left to right
-    pub(crate) fn backward_ltr_mut<'this>(
+    pub fn backward_ltr_mut<'this>(
         &'this mut self,
         locale: &Locale,
-    ) -> Box<dyn Iterator<Item = &mut LaneBuilder> + 'this> {
+    ) -> Box<dyn Iterator<Item = &'this mut LaneBuilder> + 'this> {
         match locale.driving_side {
             DrivingSide::Left => Box::new(self.backward_lanes.iter_mut().rev()),
             DrivingSide::Right => Box::new(self.backward_lanes.iter_mut()),
         }
     }
 
+    /// Apply the inference level to every lane,
+    /// returning the properties omitted from each lane left to right
+    pub(crate) fn apply_inference(
+        &mut self,
+        level: InferenceLevel,
+        locale: &Locale,
+    ) -> Vec<Vec<&'static str>> {
+        let highway = self.highway.r#type();
+        self.lanes_ltr_mut(locale)
+            .map(|lane| match level {
+                InferenceLevel::Strict => lane.keep_direct(),
+                InferenceLevel::Consensus => Vec::new(),
+                InferenceLevel::Fanciful => {
+                    lane.fill_defaults(highway, locale);
+                    Vec::new()
+                },
+            })
+            .collect()
+    }
+
+    /// Lanes left to right with the provenance of each property, without separators
+    pub(crate) fn provenanced_ltr(&self, locale: &Locale) -> Vec<ProvenancedLane> {
+        self.lanes_ltr(locale)
+            .cloned()
+            .map(LaneBuilder::build_provenanced)
+            .collect()
+    }
+
     /// Consume Road Builder to return Lanes left to right
+    ///
+    /// With `only_tagged_markings`, untagged separator markings are dropped,
+    /// and with `strict` inference untagged separators are also dropped,
+    /// warning for each omission.
     // TODO: a refactor...
     #[allow(
         clippy::needless_collect,
@@ -447,42 +779,54 @@ impl RoadBuilder {
     )]
     pub(crate) fn into_ltr(
         mut self,
-        tags: &Tags,
+        tags: &TagsRef<'_>,
         locale: &Locale,
         include_separators: bool,
+        only_tagged_markings: bool,
+        strict: bool,
         warnings: &mut RoadWarnings,
     ) -> Result<(Vec<Lane>, Highway, Oneway), RoadError> {
         let lanes: Vec<Lane> = if include_separators {
             let forward_edge = self
                 .forward_outside()
-                .and_then(|lane| outer_edge_semantic_separator(lane, tags, locale))
+                .and_then(|lane| outer_edge_semantic_separator(lane, tags, locale, true))
                 .and_then(|separator| {
                     semantic_edge_separator_to_lane(&separator, &self, tags, locale, warnings)
                 });
             let backward_edge = self
                 .backward_outside()
-                .and_then(|lane| outer_edge_semantic_separator(lane, tags, locale))
+                .and_then(|lane| outer_edge_semantic_separator(lane, tags, locale, false))
                 .and_then(|separator| {
                     semantic_edge_separator_to_lane(&separator, &self, tags, locale, warnings)
                 });
+            let mut tagged_middle_separator = false;
             let middle_separator = match [self.forward_inside(), self.backward_inside()] {
-                [Some(forward), Some(backward)] => lane_pair_to_semantic_separator(
-                    [forward, backward],
-                    &self,
-                    tags,
-                    locale,
-                    warnings,
-                )
-                .and_then(|separator| {
-                    semantic_separator_to_lane(
-                        [forward, backward],
-                        &separator,
-                        &self,
-                        tags,
-                        locale,
-                        warnings,
-                    )
-                }),
+                [Some(forward), Some(backward)] => {
+                    if let Some(separator) =
+                        tagged_centre_separator([forward, backward], tags, locale, warnings)
+                    {
+                        tagged_middle_separator = true;
+                        Some(separator)
+                    } else {
+                        lane_pair_to_semantic_separator(
+                            [forward, backward],
+                            &self,
+                            tags,
+                            locale,
+                            warnings,
+                        )
+                        .and_then(|separator| {
+                            semantic_separator_to_lane(
+                                [forward, backward],
+                                &separator,
+                                &self,
+                                tags,
+                                locale,
+                                warnings,
+                            )
+                        })
+                    }
+                },
                 [Some(lane), None] | [None, Some(lane)] => {
                     lane_to_inner_edge_separator(lane.mirror()).map(Lane::mirror)
                 },
@@ -545,6 +889,47 @@ impl RoadBuilder {
                 })
                 .collect();
 
+            // Only a tagged centre separator is kept when strict
+            let (
+                forward_edge,
+                backward_edge,
+                middle_separator,
+                forward_separators,
+                backward_separators,
+            ) = if strict {
+                let mut omit = |separator: Option<Lane>, name: &'static str| {
+                    if separator.is_some() {
+                        warnings.push(TagsToLanesMsg::inference_omitted_road(vec![name]));
+                    }
+                    None
+                };
+                (
+                    omit(forward_edge, "edge separator"),
+                    omit(backward_edge, "edge separator"),
+                    if tagged_middle_separator {
+                        middle_separator
+                    } else {
+                        omit(middle_separator, "centre separator")
+                    },
+                    forward_separators
+                        .into_iter()
+                        .map(|separator| omit(separator, "lane separator"))
+                        .collect(),
+                    backward_separators
+                        .into_iter()
+                        .map(|separator| omit(separator, "lane separator"))
+                        .collect(),
+                )
+            } else {
+                (
+                    forward_edge,
+                    backward_edge,
+                    middle_separator,
+                    forward_separators,
+                    backward_separators,
+                )
+            };
+
             let forward_lanes_with_separators: Vec<Option<Lane>> = self
                 .forward_lanes
                 .into_iter()
@@ -570,22 +955,40 @@ impl RoadBuilder {
                 .flat_map(|(a, b)| [a, b])
                 .collect();
 
-            match locale.driving_side {
-                DrivingSide::Left => forward_lanes_with_separators
-                    .into_iter()
-                    .rev()
-                    .chain(iter::once(middle_separator))
-                    .chain(backward_lanes_with_separators)
-                    .flatten()
-                    .collect(),
-                DrivingSide::Right => backward_lanes_with_separators
-                    .into_iter()
-                    .rev()
-                    .chain(iter::once(middle_separator))
-                    .chain(forward_lanes_with_separators)
-                    .flatten()
-                    .collect(),
+            let (left, right) = match locale.driving_side {
+                DrivingSide::Left => (
+                    forward_lanes_with_separators,
+                    backward_lanes_with_separators,
+                ),
+                DrivingSide::Right => (
+                    backward_lanes_with_separators,
+                    forward_lanes_with_separators,
+                ),
+            };
+            let middle_index = left.iter().flatten().count();
+            let mut lanes: Vec<Lane> = left
+                .into_iter()
+                .rev()
+                .chain(iter::once(middle_separator))
+                .chain(right)
+                .flatten()
+                .collect();
+
+            // Only a tagged centre separator keeps its markings
+            if only_tagged_markings {
+                for (index, lane) in lanes.iter_mut().enumerate() {
+                    if let Lane::Separator { markings, .. } = lane {
+                        if (!tagged_middle_separator || index != middle_index)
+                            && markings.take().is_some()
+                            && strict
+                        {
+                            warnings
+                                .push(TagsToLanesMsg::inference_omitted(index, vec!["markings"]));
+                        }
+                    }
+                }
             }
+            lanes
         } else {
             match locale.driving_side {
                 DrivingSide::Left => self
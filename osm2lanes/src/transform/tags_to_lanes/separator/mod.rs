@@ -1,19 +1,30 @@
 use celes::Country;
-use osm_tags::Tags;
+use osm_tag_schemes::{keys, ParkingCondition, PriorityRoad, Separation};
+use osm_tags::{TagKey, TagsRef};
 
-use crate::locale::Locale;
-use crate::metric::Metre;
-use crate::road::{Color, Designated, Direction, Lane, Marking, Markings, Style};
-use crate::transform::{RoadWarnings, TagsToLanesMsg};
+use crate::locale::{DrivingSide, Locale};
+use crate::metric::SpeedClass;
+use crate::road::{
+    Color, Designated, Direction, Elevation, KerbProfile, Lane, Marking, Markings, Style,
+};
+use crate::transform::{Divider, RoadWarnings, TagsToLanesMsg};
 
 mod semantic;
 
-use semantic::{Overtake, Separator, SpeedClass};
+use semantic::{Overtake, Separator};
 
-use self::semantic::{EdgeSeparator, LaneChange, ParkingCondition};
+use self::semantic::{EdgeSeparator, LaneChange, ParkingRestriction};
 use super::road::{LaneBuilder, LaneType, RoadBuilder};
 use crate::transform::tags_to_lanes::modes::CyclewayVariant;
-use crate::transform::tags_to_lanes::Infer;
+use crate::transform::tags_to_lanes::oneway::is_roundabout;
+use crate::transform::tags_to_lanes::overtaking::overtaking;
+use crate::transform::tags_to_lanes::{Infer, TagsNumeric};
+
+const PARKING_CONDITION: TagKey = TagKey::from_static("parking:condition");
+const DIVIDER: TagKey = TagKey::from_static("divider");
+const ZONE_TRAFFIC: TagKey = TagKey::from_static("zone:traffic");
+const MAXSPEED_TYPE: TagKey = TagKey::from_static("maxspeed:type");
+const SOURCE_MAXSPEED: TagKey = TagKey::from_static("source:maxspeed");
 
 impl From<&Separator> for crate::road::Semantic {
     fn from(internal: &Separator) -> Self {
@@ -21,8 +32,9 @@ impl From<&Separator> for crate::road::Semantic {
             Separator::Shoulder { .. } => Self::Shoulder,
             Separator::Lane { .. } => Self::Lane,
             Separator::Centre { .. } => Self::Centre,
+            Separator::CentreTurn { .. } => Self::CentreTurn,
             Separator::Modal { .. } => Self::Modal,
-            Separator::_Buffer { .. } => Self::Buffer,
+            Separator::Buffer { .. } => Self::Buffer,
             Separator::Kerb { .. } => Self::Kerb,
             Separator::Verge { .. } => Self::Verge,
         }
@@ -51,17 +63,17 @@ enum DirectionChange {
 pub(in crate::transform::tags_to_lanes) fn lane_pair_to_semantic_separator(
     lanes: [&LaneBuilder; 2],
     road: &RoadBuilder,
-    tags: &Tags,
+    tags: &TagsRef<'_>,
     locale: &Locale,
     warnings: &mut RoadWarnings,
 ) -> Option<Separator> {
     let [inside, outside] = lanes;
     let direction_change = match [inside.direction.some(), outside.direction.some()] {
-        [None | Some(Direction::Both), _] | [_, None | Some(Direction::Both)] => {
-            DirectionChange::None
-        },
         [Some(Direction::Forward), Some(Direction::Forward)]
-        | [Some(Direction::Backward), Some(Direction::Backward)] => DirectionChange::Same,
+        | [Some(Direction::Backward), Some(Direction::Backward)]
+        | [Some(Direction::Reversible), Some(Direction::Reversible)] => DirectionChange::Same,
+        [None | Some(Direction::Both | Direction::Reversible), _]
+        | [_, None | Some(Direction::Both | Direction::Reversible)] => DirectionChange::None,
         [Some(Direction::Forward), Some(Direction::Backward)]
         | [Some(Direction::Backward), Some(Direction::Forward)] => DirectionChange::Opposite,
     };
@@ -72,29 +84,65 @@ pub(in crate::transform::tags_to_lanes) fn lane_pair_to_semantic_separator(
         ],
         direction_change,
     ) {
+        // Verge, the strip itself separates its neighbours
+        ([(Some(LaneType::Verge), _), _] | [_, (Some(LaneType::Verge), _)], _) => None,
+        // Foot, painted on the carriageway
+        ([(_, Some(inside_designated)), (_, Some(Designated::Foot))], _)
+            if outside.elevation.some() == Some(Elevation::Carriageway) =>
+        {
+            Some(Separator::Modal {
+                speed: inside.max_speed.map(SpeedClass::from),
+                change: LaneChange::default(),
+                inside: inside_designated,
+                outside: Designated::Foot,
+                separation: None,
+            })
+        },
         // Foot
         ([_, (_, Some(Designated::Foot))], _) => Some(Separator::Kerb {
             parking_condition: None,
+            kerb: Infer::direct(outside.kerb).or_default(locale.kerb()),
+            height: outside.kerb_height,
         }),
         // Shoulder
         ([_, (Some(LaneType::Shoulder), _)], _) => Some(Separator::Shoulder {
             speed: inside.max_speed.map(SpeedClass::from),
         }),
-        // Motor to motor
-        ([(_, Some(Designated::Motor)), (_, Some(Designated::Motor))], _) => {
-            motor_lane_pair_to_semantic_separator(
-                [inside, outside],
-                direction_change,
-                road,
-                tags,
-                locale,
-                warnings,
-            )
-        },
+        // Motor to motor, high occupancy vehicle lanes are marked as motor lanes
+        (
+            [(_, Some(Designated::Motor | Designated::Hov)), (_, Some(Designated::Motor | Designated::Hov))],
+            _,
+        ) => motor_lane_pair_to_semantic_separator(
+            [inside, outside],
+            direction_change,
+            road,
+            tags,
+            locale,
+            warnings,
+        ),
         // Modal separation
         ([(_, Some(inside_designated)), (_, Some(outside_designated))], _)
             if inside_designated != outside_designated =>
         {
+            if let Some(width) = outside.buffer {
+                return Some(Separator::Buffer {
+                    width,
+                    separation: outside.separation,
+                });
+            }
+            match outside.separation {
+                Some(Separation::Kerb) => {
+                    return Some(Separator::Kerb {
+                        parking_condition: None,
+                        kerb: Infer::Default(locale.kerb()),
+                        height: None,
+                    })
+                },
+                Some(Separation::GrassVerge) => {
+                    return Some(Separator::Verge { width: Infer::None })
+                },
+                _ => {},
+            }
             if let Some(CyclewayVariant::Track) = outside.cycleway_variant {
                 return Some(Separator::Verge { width: Infer::None });
             }
@@ -103,6 +151,7 @@ pub(in crate::transform::tags_to_lanes) fn lane_pair_to_semantic_separator(
                 change: LaneChange::default(),
                 inside: inside_designated,
                 outside: outside_designated,
+                separation: outside.separation,
             })
         },
         // TODO: error return
@@ -116,40 +165,86 @@ pub(in crate::transform::tags_to_lanes) fn lane_pair_to_semantic_separator(
     }
 }
 
+/// Whether the way is a priority road outside of built-up areas, from `priority_road=*`,
+/// where built-up areas are tagged by an urban `zone:traffic=*` or maxspeed type, e.g. `DE:urban`
+fn is_rural_priority_road(tags: &TagsRef<'_>) -> bool {
+    let priority_road: Option<PriorityRoad> =
+        tags.get_parsed(&keys::PRIORITY_ROAD, &mut RoadWarnings::default());
+    let urban = [ZONE_TRAFFIC, MAXSPEED_TYPE, SOURCE_MAXSPEED]
+        .iter()
+        .any(|key| matches!(tags.get(key), Some(value) if value.ends_with(":urban")));
+    matches!(priority_road, Some(priority_road) if priority_road.is_priority()) && !urban
+}
+
 #[allow(clippy::unnecessary_wraps)]
 fn motor_lane_pair_to_semantic_separator(
-    [inside, _outside]: [&LaneBuilder; 2],
+    [inside, outside]: [&LaneBuilder; 2],
     direction_change: DirectionChange,
     road: &RoadBuilder,
-    _tags: &Tags,
+    tags: &TagsRef<'_>,
     locale: &Locale,
     _warnings: &mut RoadWarnings,
 ) -> Option<Separator> {
+    // The lane beside a centre turn lane determines which side of it the separator is on
+    let beside_centre_turn = match [inside.direction.some(), outside.direction.some()] {
+        [Some(Direction::Both), beside] | [beside, Some(Direction::Both)] => Some(beside),
+        _ => None,
+    };
+    if let Some(beside) = beside_centre_turn {
+        // Backward lanes are to the left of the centre when driving on the right
+        let turn_lane_left = match (beside, locale.driving_side) {
+            (Some(Direction::Forward), DrivingSide::Right)
+            | (Some(Direction::Backward), DrivingSide::Left) => true,
+            (Some(Direction::Backward), DrivingSide::Right)
+            | (Some(Direction::Forward), DrivingSide::Left) => false,
+            _ => return None,
+        };
+        return Some(Separator::CentreTurn {
+            speed: inside.max_speed.map(SpeedClass::from),
+            turn_lane_left,
+        });
+    }
+    // Every lane of a roundabout runs the same way, there is no centre line,
+    // nor between lanes of the same direction that restrict changing between them
+    let change = LaneChange::from_lanes([inside, outside], locale);
+    if is_roundabout(tags)
+        || matches!(
+            (direction_change, &change),
+            (DirectionChange::Same, LaneChange::Prohibited { .. })
+        )
+    {
+        return Some(Separator::Lane {
+            speed: inside.max_speed.map(SpeedClass::from),
+            change,
+        });
+    }
     match road
         .lanes_ltr(locale)
         .filter(|lane| {
             matches!(lane.r#type.some(), Some(LaneType::Travel))
                 && matches!(
                     lane.designated.some(),
-                    Some(Designated::Motor | Designated::Bus),
+                    Some(Designated::Motor | Designated::Bus | Designated::Hov),
                 )
         })
         .count()
     {
         2 => Some(Separator::Centre {
             speed: inside.max_speed.map(SpeedClass::from),
-            overtake: Overtake::default(),
+            overtake: Overtake::from(overtaking(tags, &mut RoadWarnings::default())),
             more_than_2_lanes: false,
+            priority_road: is_rural_priority_road(tags),
         }),
         _ => match direction_change {
             DirectionChange::Same => Some(Separator::Lane {
                 speed: inside.max_speed.map(SpeedClass::from),
-                change: LaneChange::default(),
+                change,
             }),
             DirectionChange::None | DirectionChange::Opposite => Some(Separator::Centre {
                 speed: inside.max_speed.map(SpeedClass::from),
-                overtake: Overtake::default(),
+                overtake: Overtake::from(overtaking(tags, &mut RoadWarnings::default())),
                 more_than_2_lanes: true,
+                priority_road: is_rural_priority_road(tags),
             }),
         },
     }
@@ -162,92 +257,88 @@ pub(in crate::transform::tags_to_lanes) fn semantic_separator_to_lane(
     [inside, outside]: [&LaneBuilder; 2],
     separator: &Separator,
     _road: &RoadBuilder,
-    tags: &Tags,
+    tags: &TagsRef<'_>,
     locale: &Locale,
     warnings: &mut RoadWarnings,
 ) -> Option<Lane> {
     match separator {
         // Foot
-        Separator::Kerb { .. } => Some(Lane::Separator {
+        Separator::Kerb { kerb, height, .. } => Some(Lane::Separator {
             semantic: Some(separator.into()),
             markings: Some(Markings::new(vec![Marking {
                 style: Style::KerbUp,
                 color: None,
                 width: Some(Marking::DEFAULT_WIDTH),
+                kerb: (*kerb).some().map(|kerb| KerbProfile {
+                    kerb,
+                    height: *height,
+                }),
             }])),
         }),
         // Shoulder
-        Separator::Shoulder { .. } => {
-            if tags.is("motorroad", "yes") {
-                if let Some(c) = &locale.country {
-                    if c == &Country::the_netherlands() {
-                        return Some(Lane::Separator {
-                            semantic: Some(separator.into()),
-                            // https://puc.overheid.nl/rijkswaterstaat/doc/PUC_125514_31/
-                            // 4.2.5 and 4.2.6
-                            markings: Some(Markings::new(vec![Marking {
-                                style: Style::SolidLine,
-                                color: Some(Color::White),
-                                width: Some(Marking::DEFAULT_WIDTH),
-                            }])),
-                        });
-                    }
-                }
+        Separator::Shoulder { .. } => Some(Lane::Separator {
+            semantic: Some(separator.into()),
+            markings: Some(
+                locale
+                    .edge_line_markings(tags.is("motorroad", "yes"))
+                    .unwrap_or_else(|| {
+                        Markings::new(vec![Marking {
+                            style: Style::SolidLine,
+                            color: Some(Color::White),
+                            width: Some(Marking::DEFAULT_WIDTH),
+                            kerb: None,
+                        }])
+                    }),
+            ),
+        }),
+        Separator::Centre {
+            overtake: Overtake::Prohibited { forward, backward },
+            ..
+        } => {
+            // A solid line on the side of the traffic that may not overtake, else a broken line
+            let line = |prohibited| Marking {
+                style: if prohibited {
+                    Style::SolidLine
+                } else {
+                    Style::BrokenLine
+                },
+                color: Some(locale.separator_motor_color()),
+                width: Some(locale.separator_motor_width()),
+                kerb: None,
+            };
+            let mut markings = vec![
+                line(*backward),
+                Marking {
+                    style: Style::NoFill,
+                    color: None,
+                    width: Some(Marking::DEFAULT_SPACE),
+                    kerb: None,
+                },
+                line(*forward),
+            ];
+            // Backward lanes are to the left of the centre when driving on the right
+            if locale.driving_side == DrivingSide::Left {
+                markings.reverse();
             }
             Some(Lane::Separator {
                 semantic: Some(separator.into()),
-                markings: Some(Markings::new(vec![Marking {
-                    style: Style::SolidLine,
-                    color: Some(Color::White),
-                    width: Some(Marking::DEFAULT_WIDTH),
-                }])),
+                markings: Some(Markings::new(markings)),
             })
         },
         Separator::Centre {
-            more_than_2_lanes, ..
+            more_than_2_lanes,
+            priority_road,
+            ..
         } => {
-            if tags.is("motorroad", "yes") {
-                if let Some(c) = &locale.country {
-                    if c == &Country::the_netherlands() {
-                        return Some(Lane::Separator {
-                            semantic: Some(separator.into()),
-                            // https://puc.overheid.nl/rijkswaterstaat/doc/PUC_125514_31/
-                            // 4.2.5 and 4.2.6
-                            markings: Some(Markings::new(vec![
-                                Marking {
-                                    style: Style::BrokenLine,
-                                    color: Some(Color::White),
-                                    width: Some(Metre::new(0.15_f64)),
-                                },
-                                Marking {
-                                    style: Style::SolidLine,
-                                    color: Some(Color::Green),
-                                    width: Some(2.0_f64 * Marking::DEFAULT_SPACE),
-                                },
-                                Marking {
-                                    style: Style::BrokenLine,
-                                    color: Some(Color::White),
-                                    width: Some(Metre::new(0.15_f64)),
-                                },
-                            ])),
-                        });
-                    }
-                }
-            }
-            if let Some(c) = &locale.country {
-                if c == &Country::the_united_kingdom_of_great_britain_and_northern_ireland() {
-                    return Some(Lane::Separator {
-                        semantic: Some(separator.into()),
-                        // https://assets.publishing.service.gov.uk/government/uploads/system/uploads/attachment_data/file/782724/traffic-signs-manual-chapter-03.pdf
-                        // Traffic Signs Manual, Chapter 3
-                        // Page 90, 9.3.3
-                        markings: Some(Markings::new(vec![Marking {
-                            style: Style::BrokenLine,
-                            color: Some(Color::White),
-                            width: Some(Metre::new(0.100_f64)),
-                        }])),
-                    });
-                }
+            if let Some(markings) = locale.centre_line_markings(
+                *more_than_2_lanes,
+                tags.is("motorroad", "yes"),
+                *priority_road,
+            ) {
+                return Some(Lane::Separator {
+                    semantic: Some(separator.into()),
+                    markings: Some(markings),
+                });
             }
             warnings.push(TagsToLanesMsg::separator_locale_unused(
                 inside.clone(),
@@ -261,16 +352,19 @@ pub(in crate::transform::tags_to_lanes) fn semantic_separator_to_lane(
                             style: Style::SolidLine,
                             color: Some(Color::White),
                             width: Some(Marking::DEFAULT_WIDTH),
+                            kerb: None,
                         },
                         Marking {
                             style: Style::NoFill,
                             color: None,
                             width: Some(Marking::DEFAULT_SPACE),
+                            kerb: None,
                         },
                         Marking {
                             style: Style::SolidLine,
                             color: Some(Color::White),
                             width: Some(Marking::DEFAULT_WIDTH),
+                            kerb: None,
                         },
                     ]))
                 } else {
@@ -278,52 +372,134 @@ pub(in crate::transform::tags_to_lanes) fn semantic_separator_to_lane(
                         style: Style::DottedLine,
                         color: Some(locale.separator_motor_color()),
                         width: Some(locale.separator_motor_width()),
+                        kerb: None,
                     }]))
                 },
             })
         },
+        Separator::CentreTurn { turn_lane_left, .. } => {
+            if !matches!(&locale.country, Some(c) if c == &Country::the_united_states_of_america())
+            {
+                warnings.push(TagsToLanesMsg::separator_locale_unused(
+                    inside.clone(),
+                    outside.clone(),
+                ));
+            }
+            // https://mutcd.fhwa.dot.gov/htm/2009/part3/part3b.htm
+            // Section 3B.03, solid towards the through lanes and broken towards the turn lane
+            let line = |style| Marking {
+                style,
+                color: Some(locale.separator_motor_color()),
+                width: Some(locale.separator_motor_width()),
+                kerb: None,
+            };
+            let mut markings = vec![
+                line(Style::BrokenLine),
+                Marking {
+                    style: Style::NoFill,
+                    color: None,
+                    width: Some(Marking::DEFAULT_SPACE),
+                    kerb: None,
+                },
+                line(Style::SolidLine),
+            ];
+            if !turn_lane_left {
+                markings.reverse();
+            }
+            Some(Lane::Separator {
+                semantic: Some(separator.into()),
+                markings: Some(Markings::new(markings)),
+            })
+        },
+        Separator::Lane {
+            change:
+                LaneChange::Prohibited {
+                    from_inside,
+                    from_outside,
+                },
+            ..
+        } => {
+            // A solid line on the side of the lane that may not change, else a dotted line
+            let line = |prohibited| Marking {
+                style: if prohibited {
+                    Style::SolidLine
+                } else {
+                    Style::DottedLine
+                },
+                color: Some(Color::White),
+                width: Some(Marking::DEFAULT_WIDTH),
+                kerb: None,
+            };
+            let markings = if *from_inside && *from_outside {
+                vec![line(true)]
+            } else {
+                let mut markings = vec![
+                    line(*from_inside),
+                    Marking {
+                        style: Style::NoFill,
+                        color: None,
+                        width: Some(Marking::DEFAULT_SPACE),
+                        kerb: None,
+                    },
+                    line(*from_outside),
+                ];
+                // The inside lane is to the right of the separator
+                // on the side of the road of the backward lanes
+                let forward = inside.direction.some() != Some(Direction::Backward);
+                if forward != (locale.driving_side == DrivingSide::Right) {
+                    markings.reverse();
+                }
+                markings
+            };
+            Some(Lane::Separator {
+                semantic: Some(separator.into()),
+                markings: Some(Markings::new(markings)),
+            })
+        },
         Separator::Lane { .. } => Some(Lane::Separator {
             semantic: Some(separator.into()),
             markings: Some(Markings::new(vec![Marking {
                 style: Style::DottedLine,
                 color: Some(Color::White),
                 width: Some(Marking::DEFAULT_WIDTH),
+                kerb: None,
             }])),
         }),
+        // Modal separation, as tagged
+        Separator::Modal {
+            separation: Some(separation),
+            outside: designated,
+            ..
+        } if *separation != Separation::ParkingLane => {
+            let mut markings = separation_markings(*separation);
+            // Lines painted in the width and colour of the locale
+            let locale_line = locale
+                .modal_line_markings(*designated)
+                .and_then(|markings| markings.first().cloned());
+            if let (Some(markings), Some(locale_line)) = (&mut markings, locale_line) {
+                for marking in markings
+                    .iter_mut()
+                    .filter(|marking| matches!(marking.style, Style::SolidLine | Style::DashedLine))
+                {
+                    marking.width = locale_line.width.or(marking.width);
+                    marking.color = locale_line.color.or(marking.color);
+                }
+            }
+            Some(Lane::Separator {
+                semantic: Some(separator.into()),
+                markings: markings.map(Markings::new),
+            })
+        },
         // Modal separation
         Separator::Modal {
             outside: designated,
             ..
         } => {
-            if let Some(c) = &locale.country {
-                if c == &Country::the_united_kingdom_of_great_britain_and_northern_ireland() {
-                    if designated == &Designated::Bus {
-                        return Some(Lane::Separator {
-                            semantic: Some(separator.into()),
-                            // https://assets.publishing.service.gov.uk/government/uploads/system/uploads/attachment_data/file/782724/traffic-signs-manual-chapter-03.pdf
-                            // Traffic Signs Manual, Chapter 3
-                            // Page 90, 9.3.3
-                            markings: Some(Markings::new(vec![Marking {
-                                style: Style::SolidLine,
-                                color: Some(Color::White),
-                                width: Some(Metre::new(0.250_f64)),
-                            }])),
-                        });
-                    }
-                    if designated == &Designated::Bicycle {
-                        return Some(Lane::Separator {
-                            semantic: Some(separator.into()),
-                            // https://assets.publishing.service.gov.uk/government/uploads/system/uploads/attachment_data/file/782724/traffic-signs-manual-chapter-03.pdf
-                            // Traffic Signs Manual, Chapter 3
-                            // Page 90, 9.3.3
-                            markings: Some(Markings::new(vec![Marking {
-                                style: Style::SolidLine,
-                                color: Some(Color::White),
-                                width: Some(Metre::new(0.150_f64)),
-                            }])),
-                        });
-                    }
-                }
+            if let Some(markings) = locale.modal_line_markings(*designated) {
+                return Some(Lane::Separator {
+                    semantic: Some(separator.into()),
+                    markings: Some(markings),
+                });
             }
             warnings.push(TagsToLanesMsg::separator_locale_unused(
                 inside.clone(),
@@ -335,6 +511,7 @@ pub(in crate::transform::tags_to_lanes) fn semantic_separator_to_lane(
                     style: Style::SolidLine,
                     color: Some(Color::White),
                     width: Some(Marking::DEFAULT_WIDTH),
+                    kerb: None,
                 }])),
             })
         },
@@ -342,42 +519,160 @@ pub(in crate::transform::tags_to_lanes) fn semantic_separator_to_lane(
             semantic: Some(separator.into()),
             markings: None,
         }),
-        // TODO
-        Separator::_Buffer { .. } => {
-            warnings.push(TagsToLanesMsg::separator_unknown(
-                inside.clone(),
-                outside.clone(),
-            ));
+        Separator::Buffer { width, separation } => {
+            // Lines either side of the buffer, with any physical separation in the middle of it
+            let line = || Marking {
+                style: Style::SolidLine,
+                color: Some(Color::White),
+                width: Some(Marking::DEFAULT_WIDTH),
+                kerb: None,
+            };
+            let object = separation
+                .and_then(separation_markings)
+                .filter(|markings| markings.iter().all(|marking| marking.style.is_object()));
+            let markings = match object {
+                Some(object) => {
+                    let space = Marking {
+                        style: Style::NoFill,
+                        color: None,
+                        width: Some(0.5_f64 * *width),
+                        kerb: None,
+                    };
+                    let mut markings = vec![line(), space.clone()];
+                    markings.extend(object);
+                    markings.extend([space, line()]);
+                    markings
+                },
+                None => vec![
+                    line(),
+                    Marking {
+                        style: Style::NoFill,
+                        color: None,
+                        width: Some(*width),
+                        kerb: None,
+                    },
+                    line(),
+                ],
+            };
             Some(Lane::Separator {
                 semantic: Some(separator.into()),
-                markings: Some(Markings::new(vec![Marking {
-                    style: Style::BrokenLine,
-                    color: Some(Color::Red),
-                    width: Some(Marking::DEFAULT_WIDTH),
-                }])),
+                markings: Some(Markings::new(markings)),
             })
         },
     }
 }
 
+/// Markings of a tagged separation between modes, inside to outside, `None` without markings
+fn separation_markings(separation: Separation) -> Option<Vec<Marking>> {
+    let marking = |style, color| Marking {
+        style,
+        color,
+        width: Some(Marking::DEFAULT_WIDTH),
+        kerb: None,
+    };
+    let space = Marking {
+        style: Style::NoFill,
+        color: None,
+        width: Some(Marking::DEFAULT_SPACE),
+        kerb: None,
+    };
+    match separation {
+        Separation::No | Separation::GrassVerge | Separation::ParkingLane => None,
+        Separation::SolidLine => Some(vec![marking(Style::SolidLine, Some(Color::White))]),
+        Separation::DashedLine => Some(vec![marking(Style::DashedLine, Some(Color::White))]),
+        Separation::DoubleSolidLine => Some(vec![
+            marking(Style::SolidLine, Some(Color::White)),
+            space,
+            marking(Style::SolidLine, Some(Color::White)),
+        ]),
+        Separation::Kerb => Some(vec![marking(Style::KerbUp, None)]),
+        Separation::SeparationKerb => Some(vec![
+            marking(Style::KerbUp, None),
+            space,
+            marking(Style::KerbDown, None),
+        ]),
+        Separation::Bollard => Some(vec![marking(Style::Bollard, None)]),
+        Separation::FlexPost => Some(vec![marking(Style::FlexPost, None)]),
+        Separation::VerticalPanel => Some(vec![marking(Style::VerticalPanel, None)]),
+        Separation::Planter => Some(vec![marking(Style::Planter, Some(Color::Green))]),
+        Separation::Fence => Some(vec![marking(Style::Fence, None)]),
+        Separation::TreeRow => Some(vec![marking(Style::TreeRow, Some(Color::Green))]),
+    }
+}
+
+/// The separator between the two directions from `divider=*`,
+/// replacing the inferred centre separator entirely.
+/// Given the inside forward and backward lanes.
+pub(in crate::transform::tags_to_lanes) fn tagged_centre_separator(
+    lanes: [&LaneBuilder; 2],
+    tags: &TagsRef<'_>,
+    locale: &Locale,
+    warnings: &mut RoadWarnings,
+) -> Option<Lane> {
+    // https://wiki.openstreetmap.org/wiki/Key:divider
+    let value = tags.get(&DIVIDER)?;
+    if lanes
+        .iter()
+        .any(|lane| lane.direction.some() == Some(Direction::Both))
+    {
+        warnings.push(TagsToLanesMsg::unsupported(
+            "divider beside a centre turn lane",
+            tags.subset(&[DIVIDER]),
+        ));
+        return None;
+    }
+    if let Some(divider) = Divider::parse(value) {
+        Some(Lane::Separator {
+            semantic: Some(crate::road::Semantic::Centre),
+            markings: divider.markings(locale),
+        })
+    } else {
+        warnings.push(TagsToLanesMsg::unsupported_tag(DIVIDER, value));
+        None
+    }
+}
+
+/// The restriction on stopping or parking along the outer edge of a way,
+/// on the side of the forward or backward lanes,
+/// from `parking:condition:<side>=*` if the locale paints it
+pub(in crate::transform::tags_to_lanes) fn edge_parking_restriction(
+    tags: &TagsRef<'_>,
+    locale: &Locale,
+    forward: bool,
+) -> Option<ParkingRestriction> {
+    let side = match (forward, locale.driving_side) {
+        (true, DrivingSide::Right) | (false, DrivingSide::Left) => "right",
+        (true, DrivingSide::Left) | (false, DrivingSide::Right) => "left",
+    };
+    let key = [side, "both"]
+        .into_iter()
+        .map(|side| PARKING_CONDITION + side)
+        .find(|key| tags.get(key).is_some())?;
+    let condition = tags.get(&key)?.parse::<ParkingCondition>().ok()?;
+    let at_times = tags.get(&(key + "time_interval")).is_some();
+    locale
+        .parking_condition_markings(condition, at_times)
+        .map(|_markings| ParkingRestriction {
+            condition,
+            at_times,
+        })
+}
+
 /// Given a lane on the outer edge of a way
 /// what should the separator be.
 /// Lanes are defined inside to outside
 #[allow(clippy::unnecessary_wraps)]
 pub(super) fn outer_edge_semantic_separator(
     lane: &LaneBuilder,
-    tags: &Tags,
+    tags: &TagsRef<'_>,
     locale: &Locale,
+    forward: bool,
 ) -> Option<EdgeSeparator> {
     if lane.r#type.some() == Some(LaneType::Travel) {
-        if let Some(c) = &locale.country {
-            if c == &Country::the_united_kingdom_of_great_britain_and_northern_ireland()
-                && tags.is("parking:condition:both", "no_stopping")
-            {
-                return Some(EdgeSeparator::Hard {
-                    parking_condition: Some(ParkingCondition::NoStopping),
-                });
-            }
+        if let Some(parking_condition) = edge_parking_restriction(tags, locale, forward) {
+            return Some(EdgeSeparator::Hard {
+                parking_condition: Some(parking_condition),
+            });
         }
     }
     None
@@ -389,30 +684,16 @@ pub(super) fn outer_edge_semantic_separator(
 pub(in crate::transform::tags_to_lanes) fn semantic_edge_separator_to_lane(
     separator: &EdgeSeparator,
     _road: &RoadBuilder,
-    _tags: &Tags,
-    _locale: &Locale,
+    _tags: &TagsRef<'_>,
+    locale: &Locale,
     _warnings: &mut RoadWarnings,
 ) -> Option<Lane> {
     match separator {
-        EdgeSeparator::Hard { .. } => Some(Lane::Separator {
+        EdgeSeparator::Hard { parking_condition } => Some(Lane::Separator {
             semantic: Some(separator.into()),
-            markings: Some(Markings::new(vec![
-                Marking {
-                    style: Style::SolidLine,
-                    color: Some(Color::Red),
-                    width: Some(Metre::new(0.100)),
-                },
-                Marking {
-                    style: Style::NoFill,
-                    color: None,
-                    width: Some(Metre::new(0.080)),
-                },
-                Marking {
-                    style: Style::SolidLine,
-                    color: Some(Color::Red),
-                    width: Some(Metre::new(0.100)),
-                },
-            ])),
+            markings: parking_condition.as_ref().and_then(|restriction| {
+                locale.parking_condition_markings(restriction.condition, restriction.at_times)
+            }),
         }),
     }
 }
@@ -429,6 +710,7 @@ pub(super) fn lane_to_inner_edge_separator(_lane: &LaneBuilder) -> Option<Lane>
             style: Style::SolidLine,
             color: Some(Color::White),
             width: Some(Marking::DEFAULT_WIDTH),
+            kerb: None,
         }])),
     })
 }
@@ -1,33 +1,9 @@
-use crate::metric::{Metre, Speed};
-use crate::road::{Designated, Style};
-use crate::transform::tags_to_lanes::Infer;
+use osm_tag_schemes::{Kerb, Overtaking, ParkingCondition, Separation};
 
-/// Semantic speed class
-#[derive(Debug)]
-pub enum SpeedClass {
-    Walking,
-    /// ~30kph / ~20mph
-    Living,
-    /// ~50kph / ~30mph
-    Intra,
-    /// ~80kph / ~50mph
-    Inter,
-    Max,
-}
-
-impl From<Speed> for SpeedClass {
-    #[allow(clippy::panic)]
-    fn from(s: Speed) -> Self {
-        match s.kph() {
-            s if (0.0_f64..15.0_f64).contains(&s) => Self::Walking,
-            s if (15.0_f64..40.0_f64).contains(&s) => Self::Living,
-            s if (40.0_f64..70.0_f64).contains(&s) => Self::Intra,
-            s if (70.0_f64..100.0_f64).contains(&s) => Self::Inter,
-            s if (100.0_f64..200.0_f64).contains(&s) => Self::Max,
-            _ => panic!("unexpected speed {s}"),
-        }
-    }
-}
+use crate::locale::{DrivingSide, Locale};
+use crate::metric::{Metre, SpeedClass};
+use crate::road::Designated;
+use crate::transform::tags_to_lanes::{Infer, LaneBuilder};
 
 /// Overtaking rules
 ///
@@ -36,7 +12,23 @@ impl From<Speed> for SpeedClass {
 #[derive(Debug)]
 pub enum Overtake {
     Permitted,
-    _Prohibited,
+    /// Prohibited for traffic in the direction of the way, against it, or both
+    Prohibited {
+        forward: bool,
+        backward: bool,
+    },
+}
+
+impl From<Option<Overtaking>> for Overtake {
+    fn from(overtaking: Option<Overtaking>) -> Self {
+        match overtaking {
+            None | Some(Overtaking::Yes) => Self::Permitted,
+            Some(overtaking) => Self::Prohibited {
+                forward: !overtaking.permitted(true),
+                backward: !overtaking.permitted(false),
+            },
+        }
+    }
 }
 
 impl Default for Overtake {
@@ -53,7 +45,30 @@ impl Default for Overtake {
 #[derive(Debug)]
 pub enum LaneChange {
     Permitted,
-    _Prohibited,
+    /// Prohibited from the inside lane to the outside lane, the other way, or both
+    Prohibited {
+        from_inside: bool,
+        from_outside: bool,
+    },
+}
+
+impl LaneChange {
+    /// From the `change:lanes=*` of a pair of lanes in the same direction, inside to outside
+    pub fn from_lanes([inside, outside]: [&LaneBuilder; 2], locale: &Locale) -> Self {
+        // Traffic has the centre of the road on its left when driving on the right
+        let outwards_is_left = locale.driving_side == DrivingSide::Left;
+        let permitted = |lane: &LaneBuilder, left: bool| !matches!(lane.change.some(), Some(change) if !change.permitted(left));
+        let from_inside = !permitted(inside, outwards_is_left);
+        let from_outside = !permitted(outside, !outwards_is_left);
+        if from_inside || from_outside {
+            Self::Prohibited {
+                from_inside,
+                from_outside,
+            }
+        } else {
+            Self::Permitted
+        }
+    }
 }
 
 impl Default for LaneChange {
@@ -63,13 +78,12 @@ impl Default for LaneChange {
     }
 }
 
-/// Overtaking rules
-///
-/// Note: this does not take into account the local vehicle-specific rules,
-/// e.g. for motorcycle filtering or overtaking tractors.
+/// Restriction on stopping or parking alongside
 #[derive(Debug)]
-pub enum ParkingCondition {
-    NoStopping,
+pub struct ParkingRestriction {
+    pub condition: ParkingCondition,
+    /// Only applies at times, `parking:condition:<side>:time_interval=*`
+    pub at_times: bool,
 }
 
 /// Semantic lane separator
@@ -87,6 +101,14 @@ pub enum Separator {
         speed: Infer<SpeedClass>,
         overtake: Overtake,
         more_than_2_lanes: bool,
+        /// A priority road outside of built-up areas
+        priority_road: bool,
+    },
+    /// Road paint between a centre turn lane and a lane in either direction
+    CentreTurn {
+        speed: Infer<SpeedClass>,
+        /// The centre turn lane is to the left of the separator
+        turn_lane_left: bool,
     },
     /// Road paint between different modes
     // TODO: solve directionality
@@ -95,14 +117,22 @@ pub enum Separator {
         change: LaneChange,
         inside: Designated,
         outside: Designated,
+        /// Tagged separation of the outside lane
+        separation: Option<Separation>,
+    },
+    /// Painted area, possibly with a physical separation within it
+    Buffer {
+        width: Metre,
+        separation: Option<Separation>,
     },
-    /// Painted area
-    _Buffer { width: Metre, style: Style },
     /// Kerb step
     // TODO: solve directionality
     Kerb {
         // https://wiki.openstreetmap.org/wiki/Key:parking:condition
-        parking_condition: Option<ParkingCondition>,
+        parking_condition: Option<ParkingRestriction>,
+        // https://wiki.openstreetmap.org/wiki/Key:kerb
+        kerb: Infer<Kerb>,
+        height: Option<Metre>,
     },
     /// Grassy verge
     Verge { width: Infer<Metre> },
@@ -116,6 +146,6 @@ pub enum EdgeSeparator {
     /// Into a building or other hard surface
     Hard {
         // https://wiki.openstreetmap.org/wiki/Key:parking:condition
-        parking_condition: Option<ParkingCondition>,
+        parking_condition: Option<ParkingRestriction>,
     },
 }
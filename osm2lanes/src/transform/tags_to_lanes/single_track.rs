@@ -0,0 +1,89 @@
+use osm_tags::{TagKey, TagsRef};
+
+use super::{TagsNumeric, TagsToLanesMsg};
+use crate::locale::Locale;
+use crate::metric::{LenientMetre, Metre};
+use crate::road::{Direction, Lane};
+use crate::transform::RoadWarnings;
+
+const LANES: TagKey = TagKey::from_static("lanes");
+const WIDTH: TagKey = TagKey::from_static("width");
+const WIDTH_CARRIAGEWAY: TagKey = TagKey::from_static("width:carriageway");
+const PASSING_PLACES: TagKey = TagKey::from_static("passing_places");
+
+/// Whether the carriageway is too narrow for a lane in each direction,
+/// from a measured width, or else `width:carriageway=*`, or else `width=*`,
+/// so that a two-way road without `lanes=*` is assumed to be a single track
+pub(super) fn is_narrow(tags: &TagsRef<'_>, locale: &Locale, measured: Option<Metre>) -> bool {
+    // The widths are parsed again, and warned about, when the lanes are fitted to them
+    let tagged = |key: &TagKey| {
+        tags.get_parsed::<_, LenientMetre>(key, &mut RoadWarnings::default())
+            .map(|width| width.target())
+    };
+    let width = measured
+        .or_else(|| tagged(&WIDTH_CARRIAGEWAY))
+        .or_else(|| tagged(&WIDTH));
+    matches!(width, Some(width) if width.val() < locale.single_track_width().val())
+}
+
+/// Whether a single track road has places to let oncoming traffic pass, from `passing_places=*`
+/// <https://wiki.openstreetmap.org/wiki/Key:passing_places>
+pub(super) fn passing_places(
+    tags: &TagsRef<'_>,
+    lanes: &[Lane],
+    warnings: &mut RoadWarnings,
+) -> bool {
+    match tags.get(&PASSING_PLACES) {
+        Some("yes") => {
+            let single_track = lanes.iter().any(|lane| {
+                lane.is_motor()
+                    && matches!(
+                        lane,
+                        Lane::Travel {
+                            direction: Some(Direction::Both),
+                            ..
+                        }
+                    )
+            });
+            if !single_track {
+                warnings.push(TagsToLanesMsg::unsupported(
+                    "passing places without a single lane in both directions",
+                    tags.subset(&[PASSING_PLACES, LANES]),
+                ));
+            }
+            single_track
+        },
+        Some("no") | None => false,
+        Some(value) => {
+            warnings.push(TagsToLanesMsg::unsupported_tag(PASSING_PLACES, value));
+            false
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::transform::tags_to_lanes::tests::road_from_pairs;
+    use crate::transform::{InferenceLevel, TagsToLanesConfig};
+
+    #[test]
+    fn single_track() {
+        let lanes = |tags: &[(&'static str, &'static str)], inference: InferenceLevel| {
+            let config = TagsToLanesConfig {
+                include_separators: false,
+                inference,
+                ..TagsToLanesConfig::default()
+            };
+            road_from_pairs(tags, &config)
+                .road
+                .lanes
+                .iter()
+                .filter(|lane| lane.is_motor())
+                .count()
+        };
+        let narrow = [("highway", "tertiary"), ("width", "4")];
+        assert_eq!(lanes(&narrow, InferenceLevel::Fanciful), 1);
+        // The assumed lane count is omitted when strict, leaving a lane of unknown direction
+        assert_eq!(lanes(&narrow, InferenceLevel::Strict), 1);
+    }
+}
@@ -0,0 +1,46 @@
+use osm_tag_schemes::{keys, HighwayType, Incline};
+use osm_tags::TagsRef;
+
+use super::{TagsNumeric, TagsToLanesMsg};
+use crate::road::Steps;
+use crate::transform::RoadWarnings;
+
+/// Whether there is a handrail, from `handrail=*`,
+/// or else from `handrail:left=*`, `handrail:right=*` and `handrail:center=*`,
+/// where a handrail on any side is a handrail
+fn handrail(tags: &TagsRef<'_>, warnings: &mut RoadWarnings) -> Option<bool> {
+    let mut parse = |key| match tags.get(&key) {
+        Some("yes") => Some(true),
+        Some("no") => Some(false),
+        Some(value) => {
+            warnings.push(TagsToLanesMsg::unsupported_tag(key, value));
+            None
+        },
+        None => None,
+    };
+    if let Some(handrail) = parse(keys::HANDRAIL) {
+        return Some(handrail);
+    }
+    let sides: Vec<bool> = ["left", "right", "center"]
+        .into_iter()
+        .filter_map(|side| parse(keys::HANDRAIL + side))
+        .collect();
+    if sides.is_empty() {
+        None
+    } else {
+        Some(sides.into_iter().any(|side| side))
+    }
+}
+
+/// The flight of steps of a `highway=steps` way, `None` for other highways
+pub(super) fn steps(
+    tags: &TagsRef<'_>,
+    highway: HighwayType,
+    warnings: &mut RoadWarnings,
+) -> Option<Steps> {
+    (highway == HighwayType::Steps).then(|| Steps {
+        incline: tags.get_parsed::<_, Incline>(&keys::INCLINE, warnings),
+        handrail: handrail(tags, warnings),
+        step_count: tags.get_parsed(&keys::STEP_COUNT, warnings),
+    })
+}
@@ -1,4 +1,4 @@
-use osm_tags::Tags;
+use osm_tags::TagsRef;
 
 use crate::locale::Locale;
 use crate::transform::{RoadWarnings, TagsToLanesMsg};
@@ -7,26 +7,23 @@ use crate::transform::{RoadWarnings, TagsToLanesMsg};
 /// Catch-all for unsupported or unimplemented but known constructs
 #[allow(clippy::unnecessary_wraps)]
 pub(in crate::transform::tags_to_lanes) fn unsupported(
-    tags: &Tags,
+    tags: &TagsRef<'_>,
     _locale: &Locale,
     warnings: &mut RoadWarnings,
 ) -> Result<(), TagsToLanesMsg> {
     // https://wiki.openstreetmap.org/wiki/Key:access#Transport_mode_restrictions
-    const ACCESS_KEYS: [&str; 43] = [
-        "access",
+    // not handled by the access mode
+    const ACCESS_KEYS: [&str; 35] = [
         "dog",
         "ski",
         "inline_skates",
         "horse",
-        "vehicle",
-        "bicycle",
         "electric_bicycle",
         "carriage",
         "hand_cart",
         "quadracycle",
         "trailer",
         "caravan",
-        "motor_vehicle",
         "motorcycle",
         "moped",
         "mofa",
@@ -42,12 +39,8 @@ pub(in crate::transform::tags_to_lanes) fn unsupported(
         "golf_cart",
         "atv",
         "snowmobile",
-        "psv",
-        "bus",
-        "taxi",
         "minibus",
         "share_taxi",
-        "hov",
         "car_sharing",
         "emergency",
         "hazmat",
@@ -0,0 +1,682 @@
+//! The width of each lane, once all the modes have built the lanes.
+//!
+//! Each lane takes the first width available, in order of precedence:
+//!
+//! 1. `width:lanes=*`, for the lanes of the carriageway
+//! 2. The width of the part of the way on its side,
+//!    `PREFIX:SIDE:width=*`, then `PREFIX:both:width=*`, then `PREFIX:width=*`,
+//!    for sidewalks, cycle lanes and tracks, parking lanes, bus bays, shoulders and verges
+//! 3. The locale default for the type of lane,
+//!    only for motor and bus lanes, parking lanes, bus bays, verges,
+//!    and shoulders where the locale has one,
+//!    and for any other lane only with `InferenceLevel::Fanciful`
+//! 4. Fitted to the total width, `width=*` or `width:carriageway=*` or a measured width,
+//!    which replaces the defaults but never a tagged width, see `WidthReconciliation`
+//!
+//! Tagged widths may be ranges, keeping their minimum and maximum for fitting,
+//! and with `InferenceLevel::Strict` only the tagged widths are kept.
+
+use osm_tag_schemes::{HighwayType, LaneDependent, ParkingOrientation};
+use osm_tags::{TagKey, Tags, TagsRef};
+
+use super::modes::set_lanes;
+use crate::locale::{DrivingSide, Locale};
+use crate::metric::{LenientMetre, Metre, MetreError};
+use crate::road::{Designated, Lane};
+use crate::transform::tags::{CYCLEWAY, SHOULDER, SIDEWALK, VERGE};
+use crate::transform::tags_to_lanes::road::{LaneType, Width};
+use crate::transform::tags_to_lanes::{
+    Infer, LaneBuilder, RoadBuilder, TagsNumeric, TagsToLanesMsg,
+};
+use crate::transform::RoadWarnings;
+
+const WIDTH_LANES: TagKey = TagKey::from_static("width:lanes");
+const PARKING_LANE: TagKey = TagKey::from_static("parking:lane");
+const PARKING: TagKey = TagKey::from_static("parking");
+const BUS_BAY: TagKey = TagKey::from_static("bus_bay");
+
+/// Width of a single lane, `None` if untagged
+fn parse_width(lane: &str) -> Result<Option<LenientMetre>, MetreError> {
+    if lane.is_empty() {
+        return Ok(None);
+    }
+    lane.parse().map(Some)
+}
+
+fn set_width(lane: &mut LaneBuilder, width: LenientMetre) -> Result<(), TagsToLanesMsg> {
+    let width = Width::from(width);
+    lane.width.min.set(width.min)?;
+    lane.width.target.set(width.target)?;
+    lane.width.max.set(width.max)?;
+    Ok(())
+}
+
+/// Widths of the lanes on one side of the way, `PREFIX:SIDE:width`, then `PREFIX:both:width`,
+/// then `PREFIX:width`.
+#[derive(Clone, Copy)]
+struct SideWidths {
+    sidewalk: Option<LenientMetre>,
+    cycleway: Option<LenientMetre>,
+    parking: Option<LenientMetre>,
+    bus_bay: Option<LenientMetre>,
+    shoulder: Option<LenientMetre>,
+    verge: Option<LenientMetre>,
+}
+
+impl SideWidths {
+    fn from_tags(tags: &TagsRef<'_>, side: Option<&str>, warnings: &mut RoadWarnings) -> Self {
+        let mut part = |prefix: &TagKey| {
+            let key = match side {
+                Some(side) => prefix + side + "width",
+                None => prefix + "width",
+            };
+            tags.get_parsed(&key, warnings)
+        };
+        Self {
+            sidewalk: part(&SIDEWALK),
+            cycleway: part(&CYCLEWAY),
+            parking: part(&PARKING_LANE).or_else(|| part(&PARKING)),
+            bus_bay: part(&BUS_BAY),
+            shoulder: part(&SHOULDER),
+            verge: part(&VERGE),
+        }
+    }
+
+    fn or(self, other: Self) -> Self {
+        Self {
+            sidewalk: self.sidewalk.or(other.sidewalk),
+            cycleway: self.cycleway.or(other.cycleway),
+            parking: self.parking.or(other.parking),
+            bus_bay: self.bus_bay.or(other.bus_bay),
+            shoulder: self.shoulder.or(other.shoulder),
+            verge: self.verge.or(other.verge),
+        }
+    }
+
+    fn get(&self, lane: &LaneBuilder) -> Option<LenientMetre> {
+        match (lane.r#type.some(), lane.designated.some()) {
+            (Some(LaneType::Travel), Some(Designated::Foot)) => self.sidewalk,
+            (Some(LaneType::Travel), Some(Designated::Bicycle)) => self.cycleway,
+            (Some(LaneType::Parking), Some(Designated::Bus)) => self.bus_bay,
+            (Some(LaneType::Parking), _) => self.parking,
+            (Some(LaneType::Shoulder), _) => self.shoulder,
+            (Some(LaneType::Verge), _) => self.verge,
+            _ => None,
+        }
+    }
+}
+
+/// Width of each lane, from `width:lanes=*` and the width of each part of the way
+fn tagged(
+    tags: &TagsRef<'_>,
+    locale: &Locale,
+    road: &mut RoadBuilder,
+    warnings: &mut RoadWarnings,
+) -> Result<(), TagsToLanesMsg> {
+    // https://wiki.openstreetmap.org/wiki/Key:width#Lanes
+    match LaneDependent::from_tags_with(tags, &WIDTH_LANES, parse_width) {
+        Ok(Some(lanes)) => set_lanes(
+            &WIDTH_LANES,
+            lanes,
+            tags,
+            locale,
+            road,
+            warnings,
+            |lane, width| width.map_or(Ok(()), |width| set_width(lane, width)),
+        )?,
+        Ok(None) => {},
+        Err(e) => warnings.push(e.into()),
+    }
+
+    let any = SideWidths::from_tags(tags, None, warnings);
+    let both = SideWidths::from_tags(tags, Some("both"), warnings).or(any);
+    let side = |side: DrivingSide, warnings: &mut RoadWarnings| {
+        SideWidths::from_tags(tags, Some(side.tag().as_str()), warnings).or(both)
+    };
+    let forward = side(locale.driving_side, warnings);
+    let backward = side(locale.driving_side.opposite(), warnings);
+
+    for lane in road.forward_ltr_mut(locale) {
+        if let Some(width) = forward.get(lane) {
+            set_width(lane, width)?;
+        }
+    }
+    for lane in road.backward_ltr_mut(locale) {
+        if let Some(width) = backward.get(lane) {
+            set_width(lane, width)?;
+        }
+    }
+    Ok(())
+}
+
+/// The locale default width of a lane, for the types of lane that always have a width
+fn default(lane: &LaneBuilder, highway: HighwayType, locale: &Locale) -> Option<Metre> {
+    match (lane.r#type.some(), lane.designated.some()) {
+        (
+            Some(LaneType::Travel),
+            Some(designated @ (Designated::Motor | Designated::Bus | Designated::Hov)),
+        ) => Some(if lane.embedded_rail.some() == Some(true) {
+            locale.embedded_rail_width(&designated, highway)
+        } else {
+            locale.travel_width(&designated, highway)
+        }),
+        (Some(LaneType::Parking), Some(Designated::Bus)) => Some(locale.bus_bay_width()),
+        (Some(LaneType::Parking), _) => Some(
+            locale.parking_width(
+                lane.parking_orientation
+                    .unwrap_or(ParkingOrientation::Parallel),
+            ),
+        ),
+        (Some(LaneType::Shoulder), _) => locale.shoulder_width(highway),
+        (Some(LaneType::Verge), _) => Some(locale.verge_width(highway)),
+        _ => None,
+    }
+}
+
+/// Width of each lane from the tags, else the locale default,
+/// the widths are fitted to the total width later by `reconcile_width`,
+/// after the inference level is applied
+pub(in crate::transform::tags_to_lanes) fn widths(
+    tags: &TagsRef<'_>,
+    locale: &Locale,
+    road: &mut RoadBuilder,
+    warnings: &mut RoadWarnings,
+) -> Result<(), TagsToLanesMsg> {
+    tagged(tags, locale, road, warnings)?;
+    let highway = road.highway.r#type();
+    for lane in road.lanes_ltr_mut(locale) {
+        if lane.width.target.is_none() {
+            if let Some(width) = default(lane, highway, locale) {
+                lane.width.target = Infer::Default(width);
+            }
+        }
+    }
+    Ok(())
+}
+
+/// How lane widths are fitted to a tagged total width, `width=*` or `width:carriageway=*`
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum WidthReconciliation {
+    /// Lane widths are independent of the total width
+    #[default]
+    Ignore,
+    /// Lane widths that are not tagged are scaled together, clamped to the locale range of each lane,
+    /// and sidewalks are only scaled if the other lanes cannot make up the total
+    Scale,
+}
+
+const WIDTH: TagKey = TagKey::from_static("width");
+const WIDTH_CARRIAGEWAY: TagKey = TagKey::from_static("width:carriageway");
+
+/// A lane width being fitted, in metres
+struct Fit {
+    index: usize,
+    width: f64,
+    min: f64,
+    max: f64,
+}
+
+impl Fit {
+    fn new(
+        index: usize,
+        lane: &LaneBuilder,
+        highway: HighwayType,
+        locale: &Locale,
+    ) -> Option<Self> {
+        let (width, range) = match (lane.r#type.some(), lane.designated.some()) {
+            (Some(LaneType::Travel), Some(designated)) => (
+                locale.travel_width(&designated, highway),
+                locale.travel_width_range(&designated),
+            ),
+            (Some(LaneType::Parking), _) => {
+                let orientation = lane
+                    .parking_orientation
+                    .unwrap_or(ParkingOrientation::Parallel);
+                (
+                    locale.parking_width(orientation),
+                    locale.parking_width_range(orientation),
+                )
+            },
+            (Some(LaneType::Shoulder), _) => (
+                locale
+                    .shoulder_width(highway)
+                    .unwrap_or(Lane::DEFAULT_WIDTH),
+                locale.shoulder_width_range(),
+            ),
+            _ => return None,
+        };
+        // A tagged range takes precedence over the locale
+        Some(Self {
+            index,
+            width: lane.width.target.some().unwrap_or(width).val(),
+            min: lane.width.min.some().unwrap_or(*range.start()).val(),
+            max: lane.width.max.some().unwrap_or(*range.end()).val(),
+        })
+    }
+}
+
+/// Scale the widths to sum to `total`, clamping each to its range,
+/// returning what could not be made up
+fn fit(fits: &mut [Fit], total: f64) -> f64 {
+    let mut clamped = vec![false; fits.len()];
+    loop {
+        let (fixed, free) =
+            fits.iter()
+                .zip(clamped.iter())
+                .fold((0.0, 0.0), |(fixed, free), (fit, clamped)| {
+                    if *clamped {
+                        (fixed + fit.width, free)
+                    } else {
+                        (fixed, free + fit.width)
+                    }
+                });
+        if free <= 0.0 {
+            return total - fixed - free;
+        }
+        let factor = (total - fixed) / free;
+        let mut changed = false;
+        for (fit, clamped) in fits.iter_mut().zip(clamped.iter_mut()) {
+            if *clamped {
+                continue;
+            }
+            let width = fit.width * factor;
+            if width < fit.min || width > fit.max {
+                fit.width = width.clamp(fit.min, fit.max);
+                *clamped = true;
+                changed = true;
+            }
+        }
+        if !changed {
+            for (fit, clamped) in fits.iter_mut().zip(clamped.iter()) {
+                if !clamped {
+                    fit.width *= factor;
+                }
+            }
+            return 0.0;
+        }
+    }
+}
+
+/// Fit the lane widths to a measured carriageway width, excluding sidewalks,
+/// or else `width:carriageway=*`, excluding sidewalks, or else `width=*`.
+/// A measured width is fitted even if tagged widths are ignored,
+/// with a warning if the tagged widths disagree with it by more than a metre.
+/// Separators are not counted.
+/// Bus bays widen the way at stops beyond its tagged width, so they are not counted either.
+pub(in crate::transform::tags_to_lanes) fn reconcile_width(
+    tags: &TagsRef<'_>,
+    locale: &Locale,
+    road: &mut RoadBuilder,
+    strategy: WidthReconciliation,
+    measured: Option<Metre>,
+    warnings: &mut RoadWarnings,
+) {
+    // Within a centimetre is close enough
+    const TOLERANCE: f64 = 0.01;
+    // Measurements from polygons or imagery are rough
+    const MEASURED_TOLERANCE: f64 = 1.0;
+
+    if strategy == WidthReconciliation::Ignore && measured.is_none() {
+        return;
+    }
+    let (key, carriageway) = if tags.get(&WIDTH_CARRIAGEWAY).is_some() {
+        (&WIDTH_CARRIAGEWAY, true)
+    } else {
+        (&WIDTH, false)
+    };
+    let tagged = tags
+        .get_parsed::<_, LenientMetre>(key, warnings)
+        .and_then(|total| Width::from(total).target.some())
+        .map(|total| total.val());
+    let (total, carriageway) = match (measured, tagged) {
+        (Some(measured), tagged) => {
+            let measured = measured.val();
+            // The carriageway is at most as wide as the whole way
+            let disagrees = match tagged {
+                Some(tagged) if carriageway => (tagged - measured).abs() > MEASURED_TOLERANCE,
+                Some(tagged) => measured - tagged > MEASURED_TOLERANCE,
+                None => false,
+            };
+            if disagrees {
+                if let Some(val) = tags.get(key) {
+                    warnings.push(
+                        TagsToLanesMsg::ambiguous_tag(key.clone(), val).with_suggestion(
+                            Tags::from_pair(key.clone(), format!("{measured:.1}")),
+                        ),
+                    );
+                }
+            }
+            (measured, true)
+        },
+        (None, Some(tagged)) => (tagged, carriageway),
+        (None, None) => return,
+    };
+
+    let highway = road.highway.r#type();
+    let mut lanes: Vec<&mut LaneBuilder> = road
+        .lanes_ltr_mut(locale)
+        .filter(|lane| !(carriageway && lane.designated.some() == Some(Designated::Foot)))
+        .filter(|lane| {
+            !(lane.r#type.some() == Some(LaneType::Parking)
+                && lane.designated.some() == Some(Designated::Bus))
+        })
+        .collect();
+
+    let mut fixed = 0.0_f64;
+    let mut others = Vec::new();
+    let mut sidewalks = Vec::new();
+    for (index, lane) in lanes.iter().enumerate() {
+        match lane.width.target {
+            Infer::Direct(width) => fixed += width.val(),
+            _ => match Fit::new(index, lane, highway, locale) {
+                Some(fit) if lane.designated.some() == Some(Designated::Foot) => {
+                    sidewalks.push(fit);
+                },
+                Some(fit) => others.push(fit),
+                None => {},
+            },
+        }
+    }
+
+    let sidewalks_width: f64 = sidewalks.iter().map(|fit| fit.width).sum();
+    let mut residual = fit(&mut others, total - fixed - sidewalks_width);
+    if residual.abs() > TOLERANCE {
+        residual = fit(&mut sidewalks, sidewalks_width + residual);
+    }
+    if residual.abs() > TOLERANCE {
+        if measured.is_some() {
+            warnings.push(TagsToLanesMsg::ambiguous_str(format!(
+                "measured width of {total}m does not fit the lanes"
+            )));
+        } else if let Some(val) = tags.get(key) {
+            warnings.push(TagsToLanesMsg::ambiguous_tag(key.clone(), val));
+        }
+    }
+
+    for fit in others.into_iter().chain(sidewalks) {
+        if let Some(lane) = lanes.get_mut(fit.index) {
+            lane.width.target = Infer::Calculated(Metre::new(fit.width));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use osm_tags::Tags;
+
+    use super::{fit, Fit, WidthReconciliation};
+    use crate::locale::{DrivingSide, Locale};
+    use crate::metric::Metre;
+    use crate::road::{Designated, Lane};
+    use crate::transform::{tags_to_lanes, RoadFromTags, TagsToLanesConfig};
+
+    fn close(left: f64, right: f64) -> bool {
+        (left - right).abs() < 1e-9
+    }
+
+    fn lane(width: f64, min: f64, max: f64) -> Fit {
+        Fit {
+            index: 0,
+            width,
+            min,
+            max,
+        }
+    }
+
+    #[test]
+    fn test_fit() {
+        let mut scaled = vec![lane(3.0, 2.0, 4.0), lane(3.0, 2.0, 4.0)];
+        assert!(close(fit(&mut scaled, 7.0), 0.0));
+        assert!(scaled.iter().all(|fit| close(fit.width, 3.5)));
+
+        // The clamped lane stops growing, the rest makes up the difference
+        let mut clamped = vec![lane(3.0, 2.0, 3.2), lane(2.0, 1.0, 4.0)];
+        assert!(close(fit(&mut clamped, 7.0), 0.0));
+        let widths: Vec<f64> = clamped.iter().map(|fit| fit.width).collect();
+        assert!(
+            matches!(widths.as_slice(), &[first, second] if close(first, 3.2) && close(second, 3.8))
+        );
+
+        let mut residual = vec![lane(3.0, 2.0, 4.0)];
+        assert!(close(fit(&mut residual, 5.0), 1.0));
+    }
+
+    #[test]
+    fn reconcile_width() {
+        let tags = Tags::from_pairs([
+            ("highway", "residential"),
+            ("lanes", "2"),
+            ("sidewalk", "both"),
+            ("width", "13"),
+        ])
+        .unwrap();
+        let locale = Locale::builder().driving_side(DrivingSide::Right).build();
+        let road = tags_to_lanes(
+            &tags,
+            &locale,
+            &TagsToLanesConfig {
+                include_separators: false,
+                width_reconciliation: WidthReconciliation::Scale,
+                ..TagsToLanesConfig::default()
+            },
+        )
+        .unwrap()
+        .road;
+        let width = |designated| {
+            road.lanes
+                .iter()
+                .find_map(|lane| match lane {
+                    Lane::Travel {
+                        designated: d,
+                        width,
+                        ..
+                    } if *d == designated => *width,
+                    _ => None,
+                })
+                .unwrap()
+                .val()
+        };
+        // The motor lanes are clamped, then the sidewalks make up the rest
+        assert!(close(width(Designated::Motor), 4.0));
+        assert!(close(width(Designated::Foot), 2.5));
+    }
+
+    #[test]
+    fn reconcile_measured_width() {
+        let tags = Tags::from_pairs([
+            ("highway", "residential"),
+            ("lanes", "2"),
+            ("sidewalk", "both"),
+            ("width:carriageway", "5"),
+        ])
+        .unwrap();
+        let locale = Locale::builder().driving_side(DrivingSide::Right).build();
+        let config = |measured| TagsToLanesConfig {
+            include_separators: false,
+            measured_width: Some(Metre::new(measured)),
+            ..TagsToLanesConfig::default()
+        };
+        let motor_widths = |output: &RoadFromTags| -> Vec<f64> {
+            output
+                .road
+                .lanes
+                .iter()
+                .filter_map(|lane| match lane {
+                    Lane::Travel {
+                        designated: Designated::Motor,
+                        width,
+                        ..
+                    } => width.map(|width| width.val()),
+                    _ => None,
+                })
+                .collect()
+        };
+
+        // Close to the tagged width, the measurement is fitted without warnings
+        let output = tags_to_lanes(&tags, &locale, &config(5.6_f64)).unwrap();
+        assert!(output.warnings.is_empty());
+        assert!(motor_widths(&output)
+            .iter()
+            .all(|width| close(*width, 2.8_f64)));
+
+        // Far from the tagged width, the measurement is still fitted with a warning
+        let output = tags_to_lanes(&tags, &locale, &config(7.0_f64)).unwrap();
+        assert_eq!(output.warnings.as_slice().len(), 1);
+        assert_eq!(
+            output
+                .warnings
+                .as_slice()
+                .first()
+                .unwrap()
+                .suggestion()
+                .map(Tags::to_vec),
+            Some(vec!["width:carriageway=7.0".to_owned()])
+        );
+        assert!(motor_widths(&output)
+            .iter()
+            .all(|width| close(*width, 3.5_f64)));
+    }
+
+    #[test]
+    fn reconcile_width_bus_bay() {
+        let tags = Tags::from_pairs([
+            ("highway", "residential"),
+            ("lanes", "2"),
+            ("bus_bay", "right"),
+            ("width", "7"),
+        ])
+        .unwrap();
+        let locale = Locale::builder().driving_side(DrivingSide::Right).build();
+        let road = tags_to_lanes(
+            &tags,
+            &locale,
+            &TagsToLanesConfig {
+                include_separators: false,
+                width_reconciliation: WidthReconciliation::Scale,
+                ..TagsToLanesConfig::default()
+            },
+        )
+        .unwrap()
+        .road;
+        let widths: Vec<f64> = road
+            .lanes
+            .iter()
+            .map(|lane| match lane {
+                Lane::Travel { width, .. } | Lane::Parking { width, .. } => width.unwrap().val(),
+                _ => unreachable!(),
+            })
+            .collect();
+        // The bay widens the road, the travel lanes keep their share of the tagged width
+        assert!(
+            matches!(widths.as_slice(), &[first, second, bay] if close(first, 3.5) && close(second, 3.5) && close(bay, 3.0))
+        );
+    }
+
+    /// Widths of the lanes matching the filter, left to right
+    fn lane_widths(tags: &Tags, locale: &Locale, filter: fn(&Lane) -> bool) -> Vec<f64> {
+        let output = tags_to_lanes(
+            tags,
+            locale,
+            &TagsToLanesConfig {
+                include_separators: false,
+                ..TagsToLanesConfig::default()
+            },
+        )
+        .unwrap();
+        assert!(output.warnings.is_empty(), "{:?}", output.warnings);
+        output
+            .road
+            .lanes
+            .iter()
+            .filter(|lane| filter(lane))
+            .map(|lane| match lane {
+                Lane::Travel { width, .. }
+                | Lane::Parking { width, .. }
+                | Lane::Shoulder { width, .. }
+                | Lane::Verge { width } => width.unwrap().val(),
+                Lane::Separator { .. } => unreachable!(),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn tagged_over_default() {
+        let tags = Tags::from_pairs([
+            ("highway", "residential"),
+            ("parking:both", "lane"),
+            ("parking:right:width", "2"),
+        ])
+        .unwrap();
+        let locale = Locale::builder().iso_3166("US").build();
+        let widths = lane_widths(&tags, &locale, |lane| matches!(lane, Lane::Parking { .. }));
+        // Parallel parking is 2.4m by default in the US
+        assert!(
+            matches!(widths.as_slice(), &[left, right] if close(left, 2.4) && close(right, 2.0))
+        );
+    }
+
+    #[test]
+    fn default_shoulder() {
+        let tags = Tags::from_pair("highway", "motorway");
+        let locale = Locale::builder().iso_3166("NL").build();
+        let shoulder = |lane: &Lane| matches!(lane, Lane::Shoulder { .. });
+        let widths = lane_widths(&tags, &locale, shoulder);
+        assert!(matches!(widths.as_slice(), &[width] if close(width, 3.5)));
+
+        let tags = Tags::from_pairs([("highway", "motorway"), ("shoulder:width", "2.5")]).unwrap();
+        let widths = lane_widths(&tags, &locale, shoulder);
+        assert!(matches!(widths.as_slice(), &[width] if close(width, 2.5)));
+    }
+
+    #[test]
+    fn cycle_lane_width() {
+        let locale = Locale::builder().driving_side(DrivingSide::Right).build();
+        let bicycle = |lane: &Lane| {
+            matches!(
+                lane,
+                Lane::Travel {
+                    designated: Designated::Bicycle,
+                    ..
+                }
+            )
+        };
+        // The middle of a range
+        let tags = Tags::from_pairs([
+            ("highway", "residential"),
+            ("cycleway:right", "lane"),
+            ("cycleway:right:width", "1.5-2"),
+        ])
+        .unwrap();
+        let widths = lane_widths(&tags, &locale, bicycle);
+        assert!(matches!(widths.as_slice(), &[width] if close(width, 1.75)));
+
+        // An approximate width
+        let tags = Tags::from_pairs([
+            ("highway", "residential"),
+            ("cycleway:right", "lane"),
+            ("cycleway:right:width", "~1.5 m"),
+        ])
+        .unwrap();
+        let widths = lane_widths(&tags, &locale, bicycle);
+        assert!(matches!(widths.as_slice(), &[width] if close(width, 1.5)));
+    }
+
+    #[test]
+    fn embedded_rail_width() {
+        let tags = Tags::from_pairs([
+            ("highway", "secondary"),
+            ("lanes", "4"),
+            ("railway", "tram"),
+        ])
+        .unwrap();
+        // Lanes are 3m by default in the UK, widened where shared with trams
+        let locale = Locale::builder().iso_3166("GB").build();
+        let widths = lane_widths(&tags, &locale, Lane::is_carriageway);
+        assert!(
+            matches!(widths.as_slice(), &[a, b, c, d] if close(a, 3.0) && close(b, 3.5) && close(c, 3.5) && close(d, 3.0)),
+            "{widths:?}"
+        );
+    }
+}
@@ -61,7 +61,11 @@ export class LaneEditor {
   }
 
   #diffTags() {
-    const currentTags = js_lanes_to_tags(this.road, this.locale);
+    const { tags: currentTags, warnings } = js_lanes_to_tags(
+      this.road,
+      this.locale,
+      { check_roundtrip: true }
+    );
 
     var output = "<table>";
     for (const [key, origValue] of Object.entries(this.originalTags)) {
@@ -82,6 +86,13 @@ export class LaneEditor {
       }
     }
     output += "</table>";
+    if (warnings.length > 0) {
+      output += "<ul>";
+      for (const warning of warnings) {
+        output += `<li>${warning.code}: ${warning.message}</li>`;
+      }
+      output += "</ul>";
+    }
 
     document.getElementById("diff-tags-table").innerHTML = output;
   }